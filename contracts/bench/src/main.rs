@@ -0,0 +1,218 @@
+//! Gas benchmarks for the CasperFlow contract entry points.
+//!
+//! For each contributor count the benchmark funds that many accounts, has
+//! every one of them contribute to a remittance, and records the gas of the
+//! calls whose cost could grow with the count: the last contribution, the
+//! release, and, on a second remittance, the cancellation and the last
+//! refund claim. Results are compared with `thresholds.toml` and the run
+//! fails if any call costs more than its recorded gas plus the tolerance.
+//! Counts without a recorded baseline are reported but never fail.
+//!
+//! ```bash
+//! make bench
+//! # or, for chosen counts and to record new baselines:
+//! cargo run -p casperflow-bench --features test-support --release \
+//!     --target x86_64-unknown-linux-gnu -- --contributors 1,100 --update
+//! ```
+//!
+//! `--full` adds the 10,000 contributor scenario. Every contribution
+//! currently rewrites the remittance's contributor list, so that run takes
+//! hours in the in-memory engine.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::process::ExitCode;
+
+use casperflow_tests::fixture::TestContext;
+use serde::{Deserialize, Serialize};
+
+/// Contributor counts measured when none are given
+const DEFAULT_CONTRIBUTORS: [usize; 2] = [1, 100];
+
+/// Contributor counts measured with `--full`
+const FULL_CONTRIBUTORS: [usize; 3] = [1, 100, 10_000];
+
+/// Amount each account contributes (1 CSPR)
+const CONTRIBUTION: u64 = 1_000_000_000;
+
+/// Recorded gas, relative to the crate root
+const THRESHOLDS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/thresholds.toml");
+
+/// Written above the thresholds when `--update` rewrites the file
+const THRESHOLDS_HEADER: &str = "\
+# Gas recorded by casperflow-bench, in motes at a gas price of 1.
+# Regenerate with `cargo run -p casperflow-bench ... -- --update` after an
+# intentional change and commit the result.
+
+";
+
+#[derive(Default, Deserialize, Serialize)]
+struct Thresholds {
+    /// Allowed increase over the recorded gas, in percent
+    tolerance_percent: u64,
+    /// Recorded gas per entry point, keyed by contributor count
+    gas: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+impl Thresholds {
+    fn load() -> Result<Self, Box<dyn Error>> {
+        let contents = std::fs::read_to_string(THRESHOLDS_FILE)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    fn save(&self) -> Result<(), Box<dyn Error>> {
+        let contents = format!("{}{}", THRESHOLDS_HEADER, toml::to_string(self)?);
+        Ok(std::fs::write(THRESHOLDS_FILE, contents)?)
+    }
+
+    /// Highest gas accepted for an entry point, if one is recorded
+    fn limit(&self, entry_point: &str, contributors: usize) -> Option<u64> {
+        let recorded = *self.gas.get(entry_point)?.get(&contributors.to_string())?;
+        Some(recorded + recorded * self.tolerance_percent / 100)
+    }
+
+    fn record(&mut self, entry_point: &str, contributors: usize, gas: u64) {
+        self.gas
+            .entry(entry_point.to_string())
+            .or_default()
+            .insert(contributors.to_string(), gas);
+    }
+}
+
+struct Options {
+    contributors: Vec<usize>,
+    update: bool,
+}
+
+impl Options {
+    fn parse() -> Result<Self, Box<dyn Error>> {
+        let mut options = Options {
+            contributors: DEFAULT_CONTRIBUTORS.to_vec(),
+            update: false,
+        };
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--contributors" => {
+                    let counts = args.next().ok_or("--contributors needs a value")?;
+                    options.contributors = counts
+                        .split(',')
+                        .map(|count| count.trim().parse())
+                        .collect::<Result<_, _>>()?;
+                }
+                "--full" => options.contributors = FULL_CONTRIBUTORS.to_vec(),
+                "--update" => options.update = true,
+                other => return Err(format!("unknown argument {}", other).into()),
+            }
+        }
+
+        if options.contributors.contains(&0) {
+            return Err("contributor counts must be at least 1".into());
+        }
+        Ok(options)
+    }
+}
+
+/// Gas of each measured call with `contributors` funded accounts
+fn measure(contributors: usize) -> BTreeMap<&'static str, u64> {
+    let mut ctx = TestContext::with_accounts(contributors);
+    let (alice, bob) = (ctx.alice, ctx.bob);
+    let last = *ctx.accounts.last().expect("at least one contributor");
+    let target = CONTRIBUTION * contributors as u64;
+    let mut gas = BTreeMap::new();
+
+    let mut record = |ctx: &TestContext, entry_point| {
+        gas.insert(entry_point, ctx.last_gas_cost().as_u64());
+    };
+
+    let released = ctx.create_remittance(alice, bob, target, "Benchmark release");
+    record(&ctx, "create_remittance");
+    contribute_all(&mut ctx, released);
+    record(&ctx, "contribute");
+    ctx.release_funds(bob, released)
+        .expect("funded remittance should release");
+    record(&ctx, "release_funds");
+
+    let cancelled = ctx.create_remittance(alice, bob, target, "Benchmark refund");
+    contribute_all(&mut ctx, cancelled);
+    ctx.cancel_remittance(alice, cancelled)
+        .expect("creator should cancel");
+    record(&ctx, "cancel_remittance");
+    ctx.claim_refund(last, cancelled)
+        .expect("contributor should be refunded");
+    record(&ctx, "claim_refund");
+
+    gas
+}
+
+/// Contributes [`CONTRIBUTION`] from every extra account
+fn contribute_all(ctx: &mut TestContext, remittance_id: u64) {
+    let accounts = ctx.accounts.clone();
+    for (index, account) in accounts.iter().enumerate() {
+        ctx.contribute(*account, remittance_id, CONTRIBUTION)
+            .expect("contribution should succeed");
+        if (index + 1) % 1_000 == 0 {
+            eprintln!("  {} of {} contributions", index + 1, accounts.len());
+        }
+    }
+}
+
+fn run() -> Result<bool, Box<dyn Error>> {
+    let options = Options::parse()?;
+    let mut thresholds = Thresholds::load()?;
+    let mut regressed = false;
+
+    println!(
+        "{:<20} {:>12} {:>16} {:>16}",
+        "entry point", "contributors", "gas", "limit"
+    );
+    for &contributors in &options.contributors {
+        eprintln!("measuring with {} contributors", contributors);
+
+        for (entry_point, gas) in measure(contributors) {
+            let limit = thresholds.limit(entry_point, contributors);
+            let status = match limit {
+                Some(limit) if gas > limit => {
+                    regressed = true;
+                    "REGRESSED"
+                }
+                Some(_) => "ok",
+                None => "no threshold",
+            };
+            println!(
+                "{:<20} {:>12} {:>16} {:>16} {}",
+                entry_point,
+                contributors,
+                gas,
+                limit.map_or_else(|| "-".to_string(), |limit| limit.to_string()),
+                status
+            );
+
+            if options.update {
+                thresholds.record(entry_point, contributors, gas);
+            }
+        }
+    }
+
+    if options.update {
+        thresholds.save()?;
+        println!("recorded gas in {}", THRESHOLDS_FILE);
+        return Ok(true);
+    }
+    Ok(!regressed)
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => {
+            eprintln!("gas regressed beyond the thresholds in {}", THRESHOLDS_FILE);
+            ExitCode::FAILURE
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
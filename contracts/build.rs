@@ -0,0 +1,57 @@
+//! Embeds the git commit of the build, reported by `get_version`.
+//!
+//! Set `CASPERFLOW_GIT_HASH` to override it, e.g. when building from a
+//! source archive without a `.git` directory.
+
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=CASPERFLOW_GIT_HASH");
+
+    let git_hash = std::env::var("CASPERFLOW_GIT_HASH")
+        .ok()
+        .filter(|hash| !hash.is_empty())
+        .or_else(git_hash)
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=CASPERFLOW_GIT_HASH={}", git_hash);
+}
+
+/// Short hash of `HEAD`, suffixed with `-dirty` for uncommitted changes
+fn git_hash() -> Option<String> {
+    // Rerun when sources change (for the dirty flag), HEAD moves, a commit
+    // lands, or the index changes
+    println!("cargo:rerun-if-changed=src");
+    for path in ["HEAD", "index"] {
+        println!(
+            "cargo:rerun-if-changed={}",
+            git(&["rev-parse", "--git-path", path])?
+        );
+    }
+    if let Some(head_ref) = git(&["symbolic-ref", "-q", "HEAD"]) {
+        let ref_path = git(&["rev-parse", "--git-path", &head_ref])?;
+        println!("cargo:rerun-if-changed={}", ref_path);
+    }
+
+    let hash = git(&["rev-parse", "--short=12", "HEAD"])?;
+    let clean = Command::new("git")
+        .args(["diff", "--quiet", "HEAD", "--"])
+        .status()
+        .ok()?
+        .success();
+    Some(if clean {
+        hash
+    } else {
+        format!("{}-dirty", hash)
+    })
+}
+
+/// Runs git and returns its trimmed output, or `None` if it fails
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout)
+        .ok()
+        .map(|stdout| stdout.trim().to_string())
+}
@@ -0,0 +1,76 @@
+//! Helpers shared by the CasperFlow session wasm.
+//!
+//! Each session reads the deploy's named arguments, rebuilds the runtime
+//! args of one contract entry point, and calls the contract named by the
+//! `contract_hash` argument. Calling from session code keeps the deploying
+//! account as the contract's caller.
+
+#![no_std]
+
+extern crate alloc;
+
+use casper_contract::{contract_api::runtime, ext_ffi};
+use casper_types::{
+    api_error,
+    bytesrepr::{FromBytes, ToBytes},
+    ApiError, CLTyped, ContractHash, RuntimeArgs,
+};
+
+/// Gets an optional named argument, or `None` if the deploy did not pass it.
+///
+/// A present but mistyped argument reverts like a required one.
+pub fn get_optional_arg<T: FromBytes + CLTyped>(name: &str) -> Option<T> {
+    if has_arg(name) {
+        Some(runtime::get_named_arg(name))
+    } else {
+        None
+    }
+}
+
+/// Checks whether the deploy passed a named argument.
+pub fn has_arg(name: &str) -> bool {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
+        )
+    };
+
+    match api_error::result_from(ret) {
+        Ok(()) => true,
+        Err(ApiError::MissingArgument) => false,
+        Err(error) => runtime::revert(error),
+    }
+}
+
+/// Inserts a runtime arg; values read from the deploy always serialize.
+pub fn insert<T: CLTyped + ToBytes>(args: &mut RuntimeArgs, name: &str, value: T) {
+    if args.insert(name, value).is_err() {
+        runtime::revert(ApiError::InvalidArgument);
+    }
+}
+
+/// Starts the entry point args with the remittance reference.
+///
+/// Forwards `remittance_key` when the deploy passed one, otherwise the
+/// required `remittance_id`.
+pub fn remittance_args() -> RuntimeArgs {
+    let mut args = RuntimeArgs::new();
+    match get_optional_arg::<[u8; 32]>("remittance_key") {
+        Some(remittance_key) => insert(&mut args, "remittance_key", remittance_key),
+        None => insert(
+            &mut args,
+            "remittance_id",
+            runtime::get_named_arg::<u64>("remittance_id"),
+        ),
+    }
+    args
+}
+
+/// Calls `entry_point` on the contract named by the `contract_hash` argument.
+pub fn call_contract(entry_point: &str, args: RuntimeArgs) {
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    runtime::call_contract::<()>(contract_hash, entry_point, args);
+}
@@ -0,0 +1,59 @@
+//! Session wasm: contribute to a remittance from the main purse.
+//!
+//! Stored contracts cannot draw from the caller's main purse, so this moves
+//! `amount` into a fresh cargo purse and passes it to `contribute` as
+//! `purse`. With `fee_cover` set, the purse tops up the remittance's fee
+//! cover through `top_up_fee_cover` instead.
+//!
+//! # Arguments
+//!
+//! * `contract_hash` - Hash of the CasperFlow contract (ByteArray(32))
+//! * `remittance_id` - ID of the remittance (u64), or
+//! * `remittance_key` - Key of a private remittance (ByteArray(32))
+//! * `amount` - Amount to contribute in motes (U512)
+//! * `amount_commitment` - `blake2b(amount || blinding)` (ByteArray(32));
+//!   confidential remittances only
+//! * `blinding` - Blinding factor of `amount_commitment` (ByteArray(32));
+//!   required with it
+//! * `idempotency_key` - Client-generated key rejecting retried
+//!   contributions (ByteArray(32)); optional
+//! * `fee_cover` - Pre-fund the platform fee instead of contributing
+//!   (Bool); optional, creator or recipient only
+
+#![no_std]
+#![no_main]
+
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::U512;
+use casperflow_session_common::{call_contract, get_optional_arg, insert, remittance_args};
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let amount: U512 = runtime::get_named_arg("amount");
+
+    let cargo_purse = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), cargo_purse, amount, None)
+        .unwrap_or_revert();
+
+    let mut args = remittance_args();
+    insert(&mut args, "amount", amount);
+    insert(&mut args, "purse", cargo_purse);
+    if get_optional_arg::<bool>("fee_cover").unwrap_or(false) {
+        call_contract("top_up_fee_cover", args);
+        return;
+    }
+    if let Some(commitment) = get_optional_arg::<[u8; 32]>("amount_commitment") {
+        insert(&mut args, "amount_commitment", commitment);
+    }
+    if let Some(blinding) = get_optional_arg::<[u8; 32]>("blinding") {
+        insert(&mut args, "blinding", blinding);
+    }
+    if let Some(idempotency_key) = get_optional_arg::<[u8; 32]>("idempotency_key") {
+        insert(&mut args, "idempotency_key", idempotency_key);
+    }
+
+    call_contract("contribute", args);
+}
@@ -0,0 +1,31 @@
+//! Session wasm: claim a refund from a cancelled remittance.
+//!
+//! # Arguments
+//!
+//! * `contract_hash` - Hash of the CasperFlow contract (ByteArray(32))
+//! * `remittance_id` - ID of the remittance (u64), or
+//! * `remittance_key` - Key of a private remittance (ByteArray(32))
+//! * `amount` - Contributed amount (U512); confidential remittances only
+//! * `blinding` - Blinding factor of the amount commitment (ByteArray(32));
+//!   confidential remittances only
+//!
+//! The refund is paid to the sending account's main purse.
+
+#![no_std]
+#![no_main]
+
+use casper_types::U512;
+use casperflow_session_common::{call_contract, get_optional_arg, insert, remittance_args};
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut args = remittance_args();
+    if let Some(amount) = get_optional_arg::<U512>("amount") {
+        insert(&mut args, "amount", amount);
+    }
+    if let Some(blinding) = get_optional_arg::<[u8; 32]>("blinding") {
+        insert(&mut args, "blinding", blinding);
+    }
+
+    call_contract("claim_refund", args);
+}
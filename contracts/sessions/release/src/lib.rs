@@ -0,0 +1,19 @@
+//! Session wasm: release a funded remittance to its recipient.
+//!
+//! # Arguments
+//!
+//! * `contract_hash` - Hash of the CasperFlow contract (ByteArray(32))
+//! * `remittance_id` - ID of the remittance (u64), or
+//! * `remittance_key` - Key of a private remittance (ByteArray(32))
+//!
+//! Must be sent by the recipient.
+
+#![no_std]
+#![no_main]
+
+use casperflow_session_common::{call_contract, remittance_args};
+
+#[no_mangle]
+pub extern "C" fn call() {
+    call_contract("release_funds", remittance_args());
+}
@@ -0,0 +1,93 @@
+//! Runtime argument parsing for the CasperFlow remittance contract.
+//!
+//! Wraps named-argument retrieval so that a missing or mistyped argument
+//! reverts with `Error::MissingArgument` or `Error::InvalidArgumentType`
+//! instead of an opaque `ApiError`, and emits an `ArgumentRejected`
+//! diagnostic naming the offending argument.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+
+use casper_contract::{contract_api::runtime, ext_ffi};
+use casper_types::{
+    api_error,
+    bytesrepr::{self, FromBytes},
+    ApiError, CLTyped,
+};
+
+use crate::{
+    errors::Error,
+    events::{ContractEvent, Emit},
+};
+
+/// Gets a required named argument.
+///
+/// Reverts with `MissingArgument` if it was not passed, or
+/// `InvalidArgumentType` if its bytes do not decode as `T`.
+pub fn get<T: FromBytes + CLTyped>(name: &str) -> T {
+    let size = arg_size(name).unwrap_or_else(|| reject(name, Error::MissingArgument));
+
+    let mut bytes = vec![0u8; size];
+    if size > 0 {
+        let ret = unsafe {
+            ext_ffi::casper_get_named_arg(
+                name.as_bytes().as_ptr(),
+                name.len(),
+                bytes.as_mut_ptr(),
+                size,
+            )
+        };
+        if api_error::result_from(ret).is_err() {
+            reject(name, Error::InvalidArgumentType);
+        }
+    }
+
+    bytesrepr::deserialize(bytes).unwrap_or_else(|_| reject(name, Error::InvalidArgumentType))
+}
+
+/// Gets an optional named argument, or `None` if it was not passed.
+///
+/// A present but mistyped argument still reverts with `InvalidArgumentType`.
+pub fn get_optional<T: FromBytes + CLTyped>(name: &str) -> Option<T> {
+    if has(name) {
+        Some(get(name))
+    } else {
+        None
+    }
+}
+
+/// Checks whether a named argument was passed to the current entry point.
+pub fn has(name: &str) -> bool {
+    arg_size(name).is_some()
+}
+
+/// Gets the serialized size of a named argument, or `None` if it is absent.
+fn arg_size(name: &str) -> Option<usize> {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
+        )
+    };
+
+    match api_error::result_from(ret) {
+        Ok(()) => Some(arg_size),
+        Err(ApiError::MissingArgument) => None,
+        Err(error) => runtime::revert(error),
+    }
+}
+
+/// Emits the diagnostic for a rejected argument and reverts.
+fn reject(name: &str, error: Error) -> ! {
+    ContractEvent::ArgumentRejected {
+        name: String::from(name),
+        error_code: error as u32,
+    }
+    .emit();
+
+    runtime::revert(error)
+}
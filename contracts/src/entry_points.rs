@@ -6,58 +6,267 @@
 extern crate alloc;
 
 use alloc::string::String;
+use alloc::vec::Vec;
 
 use casper_contract::{
-    contract_api::runtime,
+    contract_api::{account, runtime, system},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{account::AccountHash, CLValue, U512};
+#[cfg(feature = "admin")]
+use casper_types::ContractHash;
+use casper_types::{account::AccountHash, runtime_args, CLValue, URef, U512};
 
+#[cfg(feature = "stats")]
+use crate::remittance::AccountingSnapshot;
 use crate::{
-    errors::{Error, MAX_PURPOSE_LENGTH},
-    events::{ContractEvent, get_current_timestamp},
-    remittance::Remittance,
+    args,
+    errors::{
+        Error, BASIS_POINTS, BRIDGE_PAYOUT_WINDOW_MS, MAX_CHAIN_ID_LENGTH, MAX_CID_LENGTH,
+        MAX_DOCUMENTS, MAX_FEE_PPM, MAX_FOREIGN_ADDRESS_LENGTH, MAX_LOCALIZED_PURPOSES,
+        MAX_PAYLOAD_URI_LENGTH, MAX_PAYOUT_LABEL_LENGTH, MAX_PAYOUT_REFERENCE_LENGTH,
+        MAX_PAYOUT_TARGETS, MAX_REASON_LENGTH, MAX_REFERENCE_LENGTH, MAX_UPDATES,
+        MAX_UPDATE_LENGTH, MILLIS_PER_DAY,
+    },
+    events::{get_current_timestamp, ContractEvent, Emit, EVENT_SCHEMA_VERSION},
+    ledger::{LedgerEntry, LedgerEntryKind},
+    remittance::{
+        purpose_hash, BackupRecipient, BridgePayout, BuildInfo, ContributionSnapshot, DocumentRef,
+        DocumentType, FeeCover, InstantSplit, InsurancePolicy, LocalizedPurpose, Namespace,
+        PayoutRoute, PayoutSchedule, PayoutTarget, RateLimitedAction, ReceiptAcknowledgement,
+        RecipientContributionPolicy, RecipientMultisig, RefundPolicy, ReleaseBreakdown, ReleaseSla,
+        Remittance, RemittanceUpdate, ReviewPolicy, SavingsPool, ScheduledStart, TravelRuleData,
+        FLAG_CHARITY_VERIFIED, FLAG_CONFIDENTIAL, FLAG_HASHED_ID, FLAG_PENDING_REVIEW,
+        FLAG_REFUND_EXCESS, RISK_SCORE_ENTRY_POINT,
+    },
+    schema::ContractSchema,
     storage,
-    utils,
+    utils::{self, FeeModel},
 };
+#[cfg(feature = "views")]
+use crate::{
+    errors::{CONTRACT_VERSION, MAX_DAY_RANGE},
+    remittance::{localized_purpose, AccountOverview, ContractInfo},
+};
+#[cfg(feature = "admin")]
+use crate::{
+    errors::{
+        MAX_BATCH_SIZE, MAX_CURRENCY_DISPLAY_LENGTH, MAX_INSURANCE_COVERAGE_BPS,
+        MAX_INSURANCE_PREMIUM_BPS, MAX_PARTNER_CORRIDORS, MAX_PARTNER_NAME_LENGTH,
+        MAX_PLATFORM_NAME_LENGTH, PPM_PER_BPS,
+    },
+    remittance::{
+        Branding, CategoryTemplate, GasLimits, PaymentGuards, PotDeposit, RateLimits, RiskEngine,
+        SettlementPartner, REMITTANCE_FORMAT_VERSION,
+    },
+    utils::FeeRounding,
+};
+
+/// Initializes contract storage under the contract's own context.
+///
+/// Called by the installer session immediately after the contract is stored.
+/// Reverts with `AlreadyInitialized` if storage has already been initialized,
+/// so the owner, fees, and purse can only change afterwards through the admin
+/// entry points.
+///
+/// # Arguments (via runtime args)
+///
+/// * `access_token` - Package access URef, taken into contract custody so the
+///   installing key alone cannot add contract versions later
+/// * `max_fee_ppm` - Optional cap on the platform fee in ppm (default and
+///   maximum `MAX_FEE_PPM`); it can later be lowered but never raised
+/// * `fee_model` - Optional `0` to charge fees on release (default) or `1`
+///   to charge them on each contribution; fixed for the installation
+///
+/// The entry point and event schema is stored under `contract_schema`, the
+/// event schema version under `event_schema_version`, and the crate version
+/// and git commit of the build under `build_info`.
+pub fn init_entry() {
+    if storage::is_initialized() {
+        runtime::revert(Error::AlreadyInitialized);
+    }
+
+    let access_token: URef = args::get("access_token");
+    let max_fee_ppm: u64 = args::get_optional("max_fee_ppm").unwrap_or(MAX_FEE_PPM);
+
+    if max_fee_ppm > MAX_FEE_PPM {
+        runtime::revert(Error::FeeTooHigh);
+    }
+
+    let fee_model = match args::get_optional::<u8>("fee_model") {
+        Some(model) => FeeModel::from_u8(model).unwrap_or_revert(),
+        None => FeeModel::OnRelease,
+    };
+
+    storage::initialize_contract(max_fee_ppm, fee_model);
+    storage::store_access_token(access_token);
+    storage::store_schema(ContractSchema::new(crate::contract_entry_points()));
+    storage::store_event_schema_version(EVENT_SCHEMA_VERSION);
+    storage::store_build_info(current_build_info());
+}
 
 /// Creates a new remittance request.
 ///
 /// # Arguments (via runtime args)
 ///
 /// * `recipient` - AccountHash of the recipient
-/// * `target_amount` - Target amount in motes (U512)
+/// * `target_amount` - Target amount in motes (U512); omit for an
+///   open-ended pot, which the recipient withdraws from at any time via
+///   `withdraw_pot`
 /// * `purpose` - Description string (max 256 chars)
+/// * `purpose_commitment` - Optional `blake2b(purpose || salt)`; when given,
+///   `purpose` must be empty and is revealed later via `reveal_purpose`
+/// * `confidential` - Optional; when `true`, contributions record amount
+///   commitments instead of amounts (experimental). Not allowed for an
+///   open-ended pot
+/// * `refund_excess` - Optional; when `true`, release pays out only the
+///   target and contributors reclaim the excess via `claim_excess_refund`
+/// * `external_reference` - Optional invoice or case number (max 64 chars),
+///   unique per creator and indexed for `get_remittance_by_reference`
+/// * `corridor` - Optional corridor code for reporting (e.g. "US-NG")
+/// * `deadline` - Optional block time (ms) after which the remittance can be
+///   expired via `expire_remittance` if its target is still unmet
+/// * `starts_at` - Optional block time (ms) before which contributions are
+///   rejected; must be in the future and before `deadline`
+/// * `max_contributors` - Optional cap on the number of unique contributors
+///   (u64), e.g. for private pools
+/// * `instant_share_bps` - Optional share of each contribution (1-9999 bps)
+///   forwarded to the recipient immediately; the rest is escrowed until
+///   release. Not allowed with `confidential` or `refund_excess`, or for an
+///   open-ended pot
+/// * `backup_recipient` - Optional account that may release to themselves
+///   if the recipient has not released within `backup_grace_days` of the
+///   target being met (AccountHash)
+/// * `backup_grace_days` - Days after funding before the backup may release;
+///   required with `backup_recipient` (u64). Not allowed for an open-ended
+///   pot
+/// * `installment_amount` - Optional amount (U512, at most the target) paid
+///   to the recipient per installment via `process_installment` once funded,
+///   in place of a single release. Must be below the review threshold in
+///   force at creation. Not allowed with `confidential` or for an open-ended
+///   pot
+/// * `installment_interval_days` - Days between installments; required with
+///   `installment_amount` (u64)
+/// * `recipient_signers` - Optional accounts (Vec<AccountHash>, 2 to
+///   `MAX_RECIPIENT_SIGNERS`, including the recipient) that must approve
+///   before the recipient can release
+/// * `recipient_threshold` - Number of signer approvals a release needs;
+///   required with `recipient_signers` (u64)
+/// * `insured` - Optional; when `true`, each contribution pays the current
+///   insurance premium into the pool, and contributors may claim a top-up
+///   if claims are approved after cancellation. Not allowed with
+///   `confidential`
+/// * `refund_policy` - Optional refund policy code (u8, see
+///   `RefundPolicyKind`); full refunds by default
+/// * `refund_policy_bps` - Deduction rate for the refund policy (u64, at
+///   most `MAX_REFUND_DEDUCTION_BPS`); required unless the policy is full
+/// * `namespace` - Optional namespace the remittance belongs to (String);
+///   its fee and pause switch apply instead of the global ones
+/// * `category` - Optional category (String) whose template fills in the
+///   deadline and excess-refund setting when not given, and whose fee, if
+///   set, applies in place of the global or namespace fee
 ///
 /// # Returns
 ///
 /// Remittance ID (u64)
 pub fn create_remittance_entry() {
+    let remittance = create_remittance_from_args(false);
+
+    // Return remittance ID
+    runtime::ret(CLValue::from_t(remittance.id).unwrap_or_revert());
+}
+
+/// Creates a new remittance identified by a non-enumerable hash key.
+///
+/// Takes the same arguments as `create_remittance`, plus `key_salt`. The
+/// remittance can only be addressed through the returned key (passed as
+/// `remittance_key`), so it cannot be discovered by scanning sequential IDs.
+/// It emits no events and stays out of the account indexes, since those
+/// would publish its ID and parties.
+///
+/// # Arguments (via runtime args)
+///
+/// * `key_salt` - Secret random bytes ([u8; 32]) mixed into the key, so it
+///   cannot be derived from public data
+///
+/// # Returns
+///
+/// Remittance key (32 bytes)
+pub fn create_private_remittance_entry() {
+    let key_salt: [u8; 32] = args::get("key_salt");
+    let remittance = create_remittance_from_args(true);
+
+    let remittance_key = utils::derive_remittance_key(
+        &remittance.creator,
+        remittance.id,
+        remittance.created_at,
+        &key_salt,
+    );
+    storage::register_remittance_key(remittance_key, remittance.id);
+
+    // Return remittance key
+    runtime::ret(CLValue::from_t(remittance_key).unwrap_or_revert());
+}
+
+/// Validates creation arguments and stores a new remittance.
+///
+/// Shared by `create_remittance` and `create_private_remittance`; when
+/// `hashed_id` is set the remittance is hidden from numeric ID lookups.
+fn create_remittance_from_args(hashed_id: bool) -> Remittance {
     // Check if contract is paused
     if storage::is_contract_paused() {
         runtime::revert(Error::ContractPaused);
     }
 
     // Get arguments
-    let recipient: AccountHash = runtime::get_named_arg("recipient");
-    let target_amount: U512 = runtime::get_named_arg("target_amount");
-    let purpose: String = runtime::get_named_arg("purpose");
+    let recipient: AccountHash = args::get("recipient");
+    let target_amount: Option<U512> = args::get_optional("target_amount");
+    let purpose: String = args::get("purpose");
 
     // Get caller
     let creator = utils::get_caller();
+    enforce_rate_limit(creator, RateLimitedAction::Create);
 
     // Validate inputs
     utils::validate_account_hash(&recipient).unwrap_or_revert();
     utils::validate_account_hash(&creator).unwrap_or_revert();
 
-    if target_amount.is_zero() {
-        runtime::revert(Error::InvalidTargetAmount);
+    // Fees sent to the collector must not flow back out as a payout
+    if recipient == storage::get_fee_collector() {
+        runtime::revert(Error::RecipientIsFeeCollector);
+    }
+
+    if creator == recipient && storage::get_payment_guards().reject_self_remittance {
+        runtime::revert(Error::SelfRemittance);
+    }
+
+    // An open-ended pot has no target to check
+    if let Some(target_amount) = target_amount {
+        if target_amount.is_zero() {
+            runtime::revert(Error::InvalidTargetAmount);
+        }
+
+        // Keep the installation within its transaction-size limits
+        let max_target_amount = max_target_amount_for(recipient);
+        if !max_target_amount.is_zero() && target_amount > max_target_amount {
+            runtime::revert(Error::TargetTooLarge);
+        }
+
+        // Keep dust remittances off the platform
+        if target_amount < storage::get_min_target_amount() {
+            runtime::revert(Error::TargetTooSmall);
+        }
     }
 
-    utils::validate_string_length(&purpose, MAX_PURPOSE_LENGTH).unwrap_or_revert();
+    // A committed purpose is hidden until revealed, so no plaintext is allowed
+    let purpose_commitment: Option<[u8; 32]> = args::get_optional("purpose_commitment");
 
-    if purpose.trim().is_empty() {
-        runtime::revert(Error::PurposeMaxLength);
+    if purpose_commitment.is_some() {
+        if !purpose.is_empty() {
+            runtime::revert(Error::InvalidPurposeCommitment);
+        }
+    } else {
+        utils::validate_purpose(&purpose).unwrap_or_revert();
+        check_purpose_allowed(&purpose);
     }
 
     // Get next remittance ID
@@ -65,7 +274,7 @@ pub fn create_remittance_entry() {
 
     // Create remittance
     let timestamp = get_current_timestamp();
-    let remittance = Remittance::new(
+    let mut remittance = Remittance::new(
         remittance_id,
         creator,
         recipient,
@@ -74,199 +283,462 @@ pub fn create_remittance_entry() {
         timestamp,
     );
 
-    // Store remittance
-    storage::store_remittance(&remittance);
+    remittance.purpose_commitment = purpose_commitment;
 
-    // Add to user's list
-    storage::add_user_remittance(creator, remittance_id);
+    // A category fills in the defaults not given explicitly
+    let category = args::get_optional::<String>("category").map(|name| {
+        let template = storage::get_category(&name).unwrap_or_revert_with(Error::CategoryNotFound);
+        (name, template)
+    });
 
-    // Add to recipient's list
-    storage::add_recipient_remittance(recipient, remittance_id);
+    let deadline = args::get_optional::<u64>("deadline")
+        .or_else(|| category.as_ref().and_then(|(_, template)| template.deadline_from(timestamp)));
+    if let Some(deadline) = deadline {
+        if deadline <= timestamp {
+            runtime::revert(Error::InvalidDeadline);
+        }
+        remittance.deadline = Some(deadline);
+    }
 
-    // Emit event
-    ContractEvent::RemittanceCreated {
-        remittance_id,
-        creator,
-        recipient,
-        target_amount,
-        purpose,
-        timestamp,
+    // A scheduled remittance opens for contributions at its start time
+    if let Some(starts_at) = args::get_optional::<u64>("starts_at") {
+        let after_deadline = remittance
+            .deadline
+            .map_or(false, |deadline| starts_at >= deadline);
+        if starts_at <= timestamp || after_deadline {
+            runtime::revert(Error::InvalidStartTime);
+        }
+        storage::store_scheduled_start(remittance_id, ScheduledStart::new(starts_at));
     }
-    .emit();
 
-    // Return remittance ID
-    runtime::ret(CLValue::from_t(remittance_id).unwrap_or_revert());
-}
+    let charity_verified = storage::is_verified_charity(recipient);
+    if charity_verified {
+        remittance.set_flag(FLAG_CHARITY_VERIFIED);
+    }
 
-/// Contributes funds to an existing remittance.
-///
-/// # Arguments (via runtime args)
-///
-/// * `remittance_id` - ID of the remittance (u64)
-/// * `amount` - Amount to contribute in motes (U512)
-pub fn contribute_entry() {
-    // Check if contract is paused
-    if storage::is_contract_paused() {
-        runtime::revert(Error::ContractPaused);
+    if args::get_optional::<bool>("confidential").unwrap_or(false) {
+        // An open-ended pot refunds only deposits made since its last
+        // withdrawal, so it needs to see the amounts
+        if remittance.is_open_ended() {
+            runtime::revert(Error::ConfidentialRemittance);
+        }
+        remittance.set_flag(FLAG_CONFIDENTIAL);
     }
 
-    // Get arguments
-    let remittance_id: u64 = runtime::get_named_arg("remittance_id");
-    let amount: U512 = runtime::get_named_arg("amount");
+    // Release only the target; anything contributed beyond it is refundable
+    let refund_excess = args::get_optional::<bool>("refund_excess")
+        .or_else(|| category.as_ref().map(|(_, template)| template.refund_excess))
+        .unwrap_or(false);
+    if refund_excess {
+        remittance.set_flag(FLAG_REFUND_EXCESS);
+    }
 
-    // Get caller
-    let contributor = utils::get_caller();
+    if let Some(reference) = args::get_optional::<String>("external_reference") {
+        index_external_reference(&creator, &reference, remittance_id, hashed_id);
+    }
 
-    // Validate amount
-    utils::validate_non_zero_amount(&amount).unwrap_or_revert();
+    let corridor: Option<String> = args::get_optional("corridor");
+    if let Some(corridor) = &corridor {
+        utils::validate_corridor(corridor).unwrap_or_revert();
+        storage::set_corridor(remittance_id, corridor);
+    }
 
-    // Get remittance
-    let mut remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    if let Some(max_contributors) = args::get_optional::<u64>("max_contributors") {
+        if max_contributors == 0 {
+            runtime::revert(Error::InvalidContributorCap);
+        }
+        storage::set_contributor_cap(remittance_id, max_contributors);
+    }
 
-    // Verify remittance is active
-    if !remittance.is_active() {
-        if remittance.is_released {
-            runtime::revert(Error::AlreadyReleased);
-        } else if remittance.is_cancelled {
-            runtime::revert(Error::RemittanceCancelled);
+    // Forwarded amounts must be visible and must not be needed for refunds
+    // of the excess over target; an open-ended pot's refunds assume only
+    // withdrawals pay anything out
+    if let Some(share_bps) = args::get_optional::<u64>("instant_share_bps") {
+        if share_bps == 0
+            || share_bps >= BASIS_POINTS
+            || remittance.is_confidential()
+            || remittance.refunds_excess()
+            || remittance.is_open_ended()
+        {
+            runtime::revert(Error::InvalidInstantShare);
         }
+        storage::store_instant_split(remittance_id, InstantSplit::new(share_bps));
     }
 
-    // Receive payment from contributor
-    utils::receive_payment(amount).unwrap_or_revert();
+    if let Some(backup) = args::get_optional::<AccountHash>("backup_recipient") {
+        let grace_days: u64 = args::get("backup_grace_days");
+        utils::validate_account_hash(&backup).unwrap_or_revert();
 
-    // Update remittance current amount
-    remittance.current_amount = remittance
-        .current_amount
-        .checked_add(amount)
-        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+        // The grace period runs from funding, which an open pot never reaches
+        if backup == recipient || grace_days == 0 || remittance.is_open_ended() {
+            runtime::revert(Error::InvalidBackupRecipient);
+        }
 
-    // Store updated remittance
-    storage::store_remittance(&remittance);
+        let grace_ms = grace_days
+            .checked_mul(MILLIS_PER_DAY)
+            .unwrap_or_revert_with(Error::InvalidBackupRecipient);
+        storage::store_backup_recipient(
+            remittance_id,
+            BackupRecipient {
+                account: backup,
+                grace_ms,
+                funded_at: None,
+            },
+        );
+    }
 
-    // Store contribution
-    storage::store_contribution(remittance_id, contributor, amount);
+    // Installments pay fixed amounts, so contributions must be visible and
+    // the total known. They cannot be held for review, so whether they would
+    // need it is settled here, against the policy in force at creation.
+    if let Some(installment) = args::get_optional::<U512>("installment_amount") {
+        let interval_days: u64 = args::get("installment_interval_days");
+        if installment.is_zero()
+            || target_amount.map_or(true, |target_amount| installment > target_amount)
+            || interval_days == 0
+            || remittance.is_confidential()
+            || storage::get_review_policy().requires_review(&installment)
+        {
+            runtime::revert(Error::InvalidPayoutSchedule);
+        }
 
-    // Add to contributors list
-    storage::add_contributor(remittance_id, contributor);
+        let interval_ms = interval_days
+            .checked_mul(MILLIS_PER_DAY)
+            .unwrap_or_revert_with(Error::InvalidPayoutSchedule);
+        storage::store_payout_schedule(
+            remittance_id,
+            PayoutSchedule::new(installment, interval_ms),
+        );
+    }
+
+    if let Some(signers) = args::get_optional::<Vec<AccountHash>>("recipient_signers") {
+        let threshold: u64 = args::get("recipient_threshold");
+        utils::validate_recipient_signers(recipient, &signers, threshold).unwrap_or_revert();
+        storage::store_recipient_multisig(
+            remittance_id,
+            RecipientMultisig::new(signers, threshold),
+        );
+    }
+
+    // Premiums and top-ups are sized from contribution amounts
+    if args::get_optional::<bool>("insured").unwrap_or(false) {
+        if remittance.is_confidential() {
+            runtime::revert(Error::InvalidInsuranceTerms);
+        }
+        let premium_bps = storage::get_insurance_premium_bps();
+        storage::store_insurance_policy(remittance_id, InsurancePolicy::new(premium_bps));
+    }
+
+    // Refund terms are fixed at creation so contributors know them upfront
+    if let Some(kind) = args::get_optional::<u8>("refund_policy") {
+        let rate_bps = args::get_optional::<u64>("refund_policy_bps").unwrap_or(0);
+        let policy = RefundPolicy::new(kind, rate_bps).unwrap_or_revert();
+        storage::store_refund_policy(remittance_id, policy);
+    }
+
+    // Namespaced remittances follow their operator's fee and pause switch
+    if let Some(namespace) = args::get_optional::<String>("namespace") {
+        let entry =
+            storage::get_namespace(&namespace).unwrap_or_revert_with(Error::NamespaceNotFound);
+        if entry.paused {
+            runtime::revert(Error::NamespacePaused);
+        }
+        storage::set_remittance_namespace(remittance_id, &namespace);
+    }
+
+    // The category fee is fixed now, so later template changes do not apply
+    if let Some((name, template)) = &category {
+        storage::set_remittance_category(remittance_id, name);
+        if let Some(fee_ppm) = template.fee_ppm {
+            storage::set_remittance_fee_ppm(remittance_id, fee_ppm);
+        }
+    }
+
+    // Update reporting aggregates
+    #[cfg(feature = "stats")]
+    storage::update_period_stats(utils::day_index(timestamp), corridor.as_deref(), |stats| {
+        stats.record_created(target_amount.unwrap_or_default())
+    });
+
+    // Private remittances stay out of the enumerable indexes, and their
+    // events are not emitted
+    if hashed_id {
+        remittance.set_flag(FLAG_HASHED_ID);
+        storage::mark_hashed_remittance(remittance_id);
+    } else {
+        storage::add_day_remittance(utils::day_index(timestamp), remittance_id);
+        storage::add_user_remittance(creator, remittance_id);
+        storage::add_recipient_remittance(recipient, remittance_id);
+    }
+
+    // Store remittance
+    storage::store_remittance(&remittance);
 
     // Emit event
-    let timestamp = get_current_timestamp();
-    ContractEvent::ContributionMade {
+    ContractEvent::RemittanceCreated {
         remittance_id,
-        contributor,
-        amount,
-        new_total: remittance.current_amount,
+        creator,
+        recipient,
+        target_amount: target_amount.unwrap_or_default(),
+        purpose,
+        charity_verified,
         timestamp,
     }
     .emit();
+
+    remittance
 }
 
-/// Releases funds to the recipient once target is met.
+/// Reverts with `RateLimited` if `account` has used up its limit for `action`.
+fn enforce_rate_limit(account: AccountHash, action: RateLimitedAction) {
+    if !storage::record_rate_limited_action(account, action, get_current_timestamp()) {
+        runtime::revert(Error::RateLimited);
+    }
+}
+
+/// Reverts with `InvalidBatchSize` unless `count` is between one and the
+/// configured batch size.
+fn check_batch_size(count: u64) {
+    if count == 0 || count > storage::get_gas_limits().batch_size_limit() {
+        runtime::revert(Error::InvalidBatchSize);
+    }
+}
+
+/// Reverts with `InvalidBatchSize` unless `count` is between one and the
+/// configured page size.
+#[cfg(feature = "views")]
+fn check_page_size(count: u64) {
+    if count == 0 || count > storage::get_gas_limits().page_size_limit() {
+        runtime::revert(Error::InvalidBatchSize);
+    }
+}
+
+/// Validates an external reference and indexes it for the creator.
+///
+/// Private remittances cannot carry a reference, since the index would let
+/// anyone who knows it recover the hidden ID.
+fn index_external_reference(
+    creator: &AccountHash,
+    reference: &str,
+    remittance_id: u64,
+    hashed_id: bool,
+) {
+    if hashed_id || reference.trim().is_empty() || reference.len() > MAX_REFERENCE_LENGTH {
+        runtime::revert(Error::InvalidExternalReference);
+    }
+
+    let reference_key = utils::derive_reference_key(creator, reference);
+    if storage::get_remittance_by_reference(reference_key).is_some() {
+        runtime::revert(Error::DuplicateExternalReference);
+    }
+
+    storage::index_external_reference(reference_key, remittance_id);
+}
+
+/// Reverts with `PurposeBlocked` if `purpose` matches a blocklisted template.
+fn check_purpose_allowed(purpose: &str) {
+    if storage::is_purpose_blocked(purpose_hash(purpose)) {
+        runtime::revert(Error::PurposeBlocked);
+    }
+}
+
+/// Creates a rotating savings pool (ROSCA, tontine) with no single
+/// recipient.
+///
+/// Creates one remittance per member, in payout order. Cycle `i` pays
+/// `members[i]` a pot of one `contribution` from every member, opens
+/// `i * cycle_days` after creation, and can be expired and refunded if it
+/// is not fully paid by the time the next cycle opens. Only members may
+/// contribute to a cycle, each up to `contribution`, and the member whose
+/// turn it is releases the pot with `release_funds` like any recipient.
 ///
 /// # Arguments (via runtime args)
 ///
-/// * `remittance_id` - ID of the remittance (u64)
+/// * `members` - Members in payout order (Vec<AccountHash>, 2 to
+///   `MAX_POOL_MEMBERS`)
+/// * `contribution` - Amount each member pays per cycle in motes (U512)
+/// * `cycle_days` - Length of each cycle in days (u64)
+/// * `purpose` - Purpose shown on every cycle (max 256 chars)
+///
+/// # Returns
+///
+/// Pool ID (u64)
 ///
 /// # Access Control
 ///
-/// Only the recipient can call this function.
-pub fn release_funds_entry() {
+/// Anyone can call this function; the caller becomes the organizer and the
+/// creator of every cycle.
+pub fn create_savings_pool_entry() {
     // Check if contract is paused
     if storage::is_contract_paused() {
         runtime::revert(Error::ContractPaused);
     }
 
     // Get arguments
-    let remittance_id: u64 = runtime::get_named_arg("remittance_id");
+    let members: Vec<AccountHash> = args::get("members");
+    let contribution: U512 = args::get("contribution");
+    let cycle_days: u64 = args::get("cycle_days");
+    let purpose: String = args::get("purpose");
 
     // Get caller
-    let caller = utils::get_caller();
+    let organizer = utils::get_caller();
+    enforce_rate_limit(organizer, RateLimitedAction::Create);
 
-    // Get remittance
-    let mut remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    // Validate inputs
+    utils::validate_pool_members(&members).unwrap_or_revert();
+    if contribution.is_zero() || cycle_days == 0 {
+        runtime::revert(Error::InvalidSavingsPool);
+    }
+    let cycle_ms = cycle_days
+        .checked_mul(MILLIS_PER_DAY)
+        .unwrap_or_revert_with(Error::InvalidSavingsPool);
 
-    // Verify caller is recipient
-    if caller != remittance.recipient {
-        runtime::revert(Error::Unauthorized);
+    // Fees sent to the collector must not flow back out as a payout
+    if members.contains(&storage::get_fee_collector()) {
+        runtime::revert(Error::RecipientIsFeeCollector);
     }
 
-    // Verify remittance is not already released
-    if remittance.is_released {
-        runtime::revert(Error::AlreadyReleased);
+    utils::validate_purpose(&purpose).unwrap_or_revert();
+    check_purpose_allowed(&purpose);
+
+    // The cycles are created together, so their IDs are consecutive
+    let first_remittance_id = storage::get_remittance_count()
+        .checked_add(1)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    let timestamp = get_current_timestamp();
+    let pool = SavingsPool {
+        organizer,
+        members,
+        contribution,
+        cycle_ms,
+        created_at: timestamp,
+        first_remittance_id,
+    };
+
+    // Each pot is held to the same limits as any remittance target
+    let pot = pool.pot().unwrap_or_revert();
+    if pot < storage::get_min_target_amount() {
+        runtime::revert(Error::TargetTooSmall);
+    }
+    for member in &pool.members {
+        let max_target_amount = max_target_amount_for(*member);
+        if !max_target_amount.is_zero() && pot > max_target_amount {
+            runtime::revert(Error::TargetTooLarge);
+        }
     }
 
-    // Verify remittance is not cancelled
-    if remittance.is_cancelled {
-        runtime::revert(Error::RemittanceCancelled);
+    let pool_id = storage::store_savings_pool(pool.clone());
+    for cycle in 0..pool.cycle_count() {
+        create_pool_cycle(pool_id, &pool, cycle, pot, &purpose);
     }
 
-    // Verify target is met
-    if !remittance.is_target_met() {
-        runtime::revert(Error::TargetNotMet);
+    // Emit event
+    ContractEvent::SavingsPoolCreated {
+        pool_id,
+        organizer,
+        members: pool.members,
+        contribution,
+        cycle_ms,
+        first_remittance_id,
+        timestamp,
     }
+    .emit();
 
-    // Calculate platform fee
-    let fee_bps = storage::get_platform_fee_bps();
-    let platform_fee = utils::calculate_fee(&remittance.current_amount, fee_bps);
+    // Return pool ID
+    runtime::ret(CLValue::from_t(pool_id).unwrap_or_revert());
+}
 
-    // Calculate recipient amount
-    let recipient_amount = remittance
-        .current_amount
-        .checked_sub(platform_fee)
-        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+/// Creates the remittance for one cycle of a savings pool, paying that
+/// cycle's member and open only during the cycle.
+fn create_pool_cycle(pool_id: u64, pool: &SavingsPool, cycle: u64, pot: U512, purpose: &str) {
+    let remittance_id = storage::get_next_remittance_id();
+    if remittance_id != pool.cycle_remittance_id(cycle) {
+        runtime::revert(Error::StorageError);
+    }
 
-    // Mark as released
-    remittance.is_released = true;
-    storage::store_remittance(&remittance);
+    let recipient = pool.members[cycle as usize];
+    let timestamp = pool.created_at;
+    let mut remittance = Remittance::new(
+        remittance_id,
+        pool.organizer,
+        recipient,
+        Some(pot),
+        String::from(purpose),
+        timestamp,
+    );
 
-    // Get contract purse and fee collector
-    let contract_purse = storage::get_contract_purse();
-    let fee_collector = storage::get_fee_collector();
+    // Each cycle closes when the next opens; an unpaid cycle is expirable
+    let opens_at = pool.cycle_opens_at(cycle);
+    remittance.deadline = Some(pool.cycle_opens_at(cycle.saturating_add(1)));
+    if opens_at > timestamp {
+        storage::store_scheduled_start(remittance_id, ScheduledStart::new(opens_at));
+    }
 
-    // Transfer fee to fee collector
-    if !platform_fee.is_zero() {
-        utils::transfer_cspr(contract_purse, fee_collector, platform_fee).unwrap_or_revert();
+    let charity_verified = storage::is_verified_charity(recipient);
+    if charity_verified {
+        remittance.set_flag(FLAG_CHARITY_VERIFIED);
     }
 
-    // Transfer amount to recipient
-    utils::transfer_cspr(contract_purse, remittance.recipient, recipient_amount)
-        .unwrap_or_revert();
+    // Update reporting aggregates
+    #[cfg(feature = "stats")]
+    storage::update_period_stats(utils::day_index(timestamp), None, |stats| {
+        stats.record_created(pot)
+    });
 
-    // Emit event
-    let timestamp = get_current_timestamp();
-    ContractEvent::FundsReleased {
+    storage::add_day_remittance(utils::day_index(timestamp), remittance_id);
+    storage::store_remittance(&remittance);
+    storage::set_remittance_pool(remittance_id, pool_id);
+    storage::add_user_remittance(pool.organizer, remittance_id);
+    storage::add_recipient_remittance(recipient, remittance_id);
+
+    ContractEvent::RemittanceCreated {
         remittance_id,
-        recipient: remittance.recipient,
-        amount: recipient_amount,
-        platform_fee,
+        creator: pool.organizer,
+        recipient,
+        target_amount: pot,
+        purpose: String::from(purpose),
+        charity_verified,
         timestamp,
     }
     .emit();
 }
 
-/// Cancels a remittance and enables refunds.
+/// Reverts unless `contributor` is a member of the savings pool and the
+/// escrowed `net_amount` keeps them within its per-cycle contribution.
+fn check_pool_contribution(
+    pool_id: u64,
+    remittance_id: u64,
+    contributor: AccountHash,
+    net_amount: U512,
+) {
+    let pool = storage::get_savings_pool(pool_id).unwrap_or_revert_with(Error::StorageError);
+    if !pool.is_member(&contributor) {
+        runtime::revert(Error::NotPoolMember);
+    }
+
+    let total = storage::get_contribution(remittance_id, contributor)
+        .checked_add(net_amount)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    if total > pool.contribution {
+        runtime::revert(Error::PoolContributionExceeded);
+    }
+}
+
+/// Reveals a purpose that was committed at creation.
 ///
 /// # Arguments (via runtime args)
 ///
 /// * `remittance_id` - ID of the remittance (u64)
+/// * `purpose` - The plaintext purpose (max 256 chars)
+/// * `salt` - The 32-byte salt used in the commitment
 ///
 /// # Access Control
 ///
 /// Only the creator can call this function.
-///
-/// # Note
-///
-/// This uses the pull-over-push pattern. Contributors must claim
-/// refunds individually via `claim_refund`.
-pub fn cancel_remittance_entry() {
-    // Check if contract is paused
-    if storage::is_contract_paused() {
-        runtime::revert(Error::ContractPaused);
-    }
-
+pub fn reveal_purpose_entry() {
     // Get arguments
-    let remittance_id: u64 = runtime::get_named_arg("remittance_id");
+    let remittance_id = utils::get_remittance_id_arg();
+    let purpose: String = args::get("purpose");
+    let salt: [u8; 32] = args::get("salt");
 
     // Get caller
     let caller = utils::get_caller();
@@ -279,49 +751,57 @@ pub fn cancel_remittance_entry() {
         runtime::revert(Error::Unauthorized);
     }
 
-    // Verify remittance is not already released
-    if remittance.is_released {
-        runtime::revert(Error::AlreadyReleased);
-    }
+    // Verify the reveal matches the commitment
+    let commitment = remittance
+        .purpose_commitment
+        .unwrap_or_revert_with(Error::NoPurposeCommitment);
 
-    // Verify remittance is not already cancelled
-    if remittance.is_cancelled {
-        runtime::revert(Error::RemittanceCancelled);
+    if utils::compute_purpose_commitment(&purpose, &salt) != commitment {
+        runtime::revert(Error::InvalidPurposeCommitment);
     }
 
-    // Mark as cancelled
-    remittance.is_cancelled = true;
+    utils::validate_purpose(&purpose).unwrap_or_revert();
+    check_purpose_allowed(&purpose);
+
+    // Store revealed purpose
+    remittance.purpose = purpose.clone();
+    remittance.purpose_commitment = None;
     storage::store_remittance(&remittance);
 
     // Emit event
     let timestamp = get_current_timestamp();
-    ContractEvent::RemittanceCancelled {
+    ContractEvent::PurposeRevealed {
         remittance_id,
-        creator: remittance.creator,
-        total_amount: remittance.current_amount,
+        purpose,
         timestamp,
     }
     .emit();
 }
 
-/// Claims refund for a cancelled remittance.
+/// Attaches an IPFS document reference to a remittance.
 ///
 /// # Arguments (via runtime args)
 ///
 /// * `remittance_id` - ID of the remittance (u64)
+/// * `cid` - IPFS content identifier (max 128 chars)
+/// * `content_hash` - Hash of the document contents ([u8; 32])
+/// * `doc_type` - Document type code (0 = invoice, 1 = medical bill,
+///   2 = school fees, 3 = other)
 ///
-/// # Note
+/// # Access Control
 ///
-/// This implements the pull pattern for gas-efficient refunds.
-/// Each contributor must claim their own refund.
-pub fn claim_refund_entry() {
+/// Only the creator or recipient can call this function.
+pub fn attach_document_entry() {
     // Check if contract is paused
     if storage::is_contract_paused() {
         runtime::revert(Error::ContractPaused);
     }
 
     // Get arguments
-    let remittance_id: u64 = runtime::get_named_arg("remittance_id");
+    let remittance_id = utils::get_remittance_id_arg();
+    let cid: String = args::get("cid");
+    let content_hash: [u8; 32] = args::get("content_hash");
+    let doc_type: u8 = args::get("doc_type");
 
     // Get caller
     let caller = utils::get_caller();
@@ -329,111 +809,5824 @@ pub fn claim_refund_entry() {
     // Get remittance
     let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
 
-    // Verify remittance is cancelled
-    if !remittance.is_cancelled {
-        runtime::revert(Error::NotCancelled);
+    // Verify caller is creator or recipient
+    if caller != remittance.creator && caller != remittance.recipient {
+        runtime::revert(Error::Unauthorized);
     }
 
-    // Get contributor's contribution
-    let contribution_amount = storage::get_contribution(remittance_id, caller);
+    // Validate inputs
+    DocumentType::from_u8(doc_type).unwrap_or_revert();
 
-    // Verify contribution exists
-    if contribution_amount.is_zero() {
-        runtime::revert(Error::NoContribution);
+    if cid.trim().is_empty() || cid.len() > MAX_CID_LENGTH {
+        runtime::revert(Error::InvalidDocumentCid);
     }
 
-    // Verify refund not already claimed
-    if storage::is_refund_claimed(remittance_id, caller) {
-        runtime::revert(Error::RefundAlreadyClaimed);
+    let mut documents = storage::get_documents(remittance_id);
+    if documents.len() >= MAX_DOCUMENTS {
+        runtime::revert(Error::TooManyDocuments);
     }
 
-    // Mark refund as claimed
-    storage::mark_refund_claimed(remittance_id, caller);
+    // Store document reference
+    let timestamp = get_current_timestamp();
+    documents.push(DocumentRef {
+        cid: cid.clone(),
+        content_hash,
+        doc_type,
+        attached_by: caller,
+        attached_at: timestamp,
+    });
+    storage::store_documents(remittance_id, documents);
 
-    // Transfer refund from contract purse to contributor
+    // Emit event
+    ContractEvent::DocumentAttached {
+        remittance_id,
+        cid,
+        doc_type,
+        attached_by: caller,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Posts a progress update on a remittance so contributors can follow it.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `message` - Update text (max `MAX_UPDATE_LENGTH` bytes)
+///
+/// # Access Control
+///
+/// Only the creator can call this function.
+pub fn post_update_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+    let message: String = args::get("message");
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    // Get remittance
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    // Verify caller is creator
+    if caller != remittance.creator {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    // Validate inputs
+    if message.trim().is_empty() || message.len() > MAX_UPDATE_LENGTH {
+        runtime::revert(Error::InvalidUpdateMessage);
+    }
+
+    let mut updates = storage::get_updates(remittance_id);
+    if updates.len() >= MAX_UPDATES {
+        runtime::revert(Error::TooManyUpdates);
+    }
+
+    // Store update
+    let timestamp = get_current_timestamp();
+    updates.push(RemittanceUpdate {
+        message: message.clone(),
+        posted_at: timestamp,
+    });
+    storage::store_updates(remittance_id, updates);
+
+    // Emit event
+    ContractEvent::UpdatePosted {
+        remittance_id,
+        creator: caller,
+        message,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets or removes a translation of a remittance's purpose.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `lang` - Language code (2-`MAX_LANGUAGE_CODE_LENGTH` lowercase letters,
+///   digits, or hyphens, e.g. "pt-br")
+/// * `purpose` - Purpose in that language (max 256 chars); empty removes the
+///   translation
+///
+/// # Access Control
+///
+/// Only the creator can call this function.
+pub fn set_localized_purpose_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+    let lang: String = args::get("lang");
+    let purpose: String = args::get("purpose");
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    // Get remittance
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    // Verify caller is creator
+    if caller != remittance.creator {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    utils::validate_language_code(&lang).unwrap_or_revert();
+
+    let mut purposes = storage::get_localized_purposes(remittance_id);
+    let existing = purposes.iter().position(|localized| localized.lang == lang);
+
+    if purpose.is_empty() {
+        if let Some(index) = existing {
+            purposes.remove(index);
+        }
+    } else {
+        utils::validate_purpose(&purpose).unwrap_or_revert();
+        check_purpose_allowed(&purpose);
+
+        let localized = LocalizedPurpose {
+            lang: lang.clone(),
+            purpose: purpose.clone(),
+        };
+        match existing {
+            Some(index) => purposes[index] = localized,
+            None if purposes.len() >= MAX_LOCALIZED_PURPOSES => {
+                runtime::revert(Error::TooManyLocalizedPurposes)
+            }
+            None => purposes.push(localized),
+        }
+    }
+    storage::store_localized_purposes(remittance_id, purposes);
+
+    // Emit event
+    let timestamp = get_current_timestamp();
+    ContractEvent::LocalizedPurposeSet {
+        remittance_id,
+        lang,
+        purpose,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Records the recipient's confirmation that released funds arrived.
+///
+/// Each remittance can be acknowledged once; the note stays on-chain for
+/// contributors to read.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `message` - Thank-you or usage note (max `MAX_UPDATE_LENGTH` bytes)
+///
+/// # Access Control
+///
+/// Only the recipient can call this function, after release.
+pub fn acknowledge_receipt_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+    let message: String = args::get("message");
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    // Get remittance
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    // Verify caller is recipient
+    if caller != remittance.recipient {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    if !remittance.is_released() {
+        runtime::revert(Error::NotReleased);
+    }
+
+    if storage::get_acknowledgement(remittance_id).is_some() {
+        runtime::revert(Error::AlreadyAcknowledged);
+    }
+
+    // Validate inputs
+    if message.trim().is_empty() || message.len() > MAX_UPDATE_LENGTH {
+        runtime::revert(Error::InvalidAcknowledgement);
+    }
+
+    // Store acknowledgement
+    let timestamp = get_current_timestamp();
+    storage::store_acknowledgement(
+        remittance_id,
+        ReceiptAcknowledgement {
+            message: message.clone(),
+            acknowledged_at: timestamp,
+        },
+    );
+
+    // Emit event
+    ContractEvent::ReceiptAcknowledged {
+        remittance_id,
+        recipient: caller,
+        message,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Adds an account to the caller's payout address book, or repoints an
+/// existing label.
+///
+/// Recipients choose a target by label when releasing, e.g. a savings
+/// account or an agent, instead of being paid to their own main purse.
+///
+/// # Arguments (via runtime args)
+///
+/// * `label` - Name of the target (String, max `MAX_PAYOUT_LABEL_LENGTH`)
+/// * `target` - Account to pay when the label is chosen (AccountHash)
+pub fn set_payout_target_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let label: String = args::get("label");
+    let target: AccountHash = args::get("target");
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    // Validate inputs
+    if label.trim().is_empty() || label.len() > MAX_PAYOUT_LABEL_LENGTH {
+        runtime::revert(Error::InvalidPayoutLabel);
+    }
+
+    let mut targets = storage::get_payout_targets(caller);
+    match targets.iter_mut().find(|entry| entry.label == label) {
+        Some(entry) => entry.account = target,
+        None => {
+            if targets.len() >= MAX_PAYOUT_TARGETS {
+                runtime::revert(Error::TooManyPayoutTargets);
+            }
+            targets.push(PayoutTarget {
+                label: label.clone(),
+                account: target,
+            });
+        }
+    }
+    storage::set_payout_targets(caller, targets);
+
+    // Emit event
+    ContractEvent::PayoutTargetSet {
+        owner: caller,
+        label,
+        target,
+        timestamp: get_current_timestamp(),
+    }
+    .emit();
+}
+
+/// Removes a target from the caller's payout address book.
+///
+/// Releases already routed to the target are still paid to it.
+///
+/// # Arguments (via runtime args)
+///
+/// * `label` - Name of the target (String)
+pub fn remove_payout_target_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let label: String = args::get("label");
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    let mut targets = storage::get_payout_targets(caller);
+    let count = targets.len();
+    targets.retain(|entry| entry.label != label);
+    if targets.len() == count {
+        runtime::revert(Error::PayoutTargetNotFound);
+    }
+    storage::set_payout_targets(caller, targets);
+
+    // Emit event
+    ContractEvent::PayoutTargetRemoved {
+        owner: caller,
+        label,
+        timestamp: get_current_timestamp(),
+    }
+    .emit();
+}
+
+/// Registers a human-readable handle for a remittance.
+///
+/// Handles are unique across the contract and cannot be changed once set,
+/// so shared links keep resolving to the same remittance.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `handle` - 3-64 lowercase letters, digits, or inner hyphens, e.g.
+///   "maria-surgery-2025"
+///
+/// # Access Control
+///
+/// Only the creator can call this function. Private remittances cannot have
+/// a handle, since it would reveal their ID.
+pub fn register_handle_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+    let handle: String = args::get("handle");
+
+    // Get remittance
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    let caller = utils::get_caller();
+    if caller != remittance.creator {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    // Validate inputs
+    utils::validate_handle(&handle).unwrap_or_revert();
+    if remittance.has_flag(FLAG_HASHED_ID) {
+        runtime::revert(Error::InvalidHandle);
+    }
+    if storage::get_handle(remittance_id).is_some() {
+        runtime::revert(Error::HandleAlreadySet);
+    }
+    if storage::resolve_handle(&handle).is_some() {
+        runtime::revert(Error::HandleTaken);
+    }
+
+    storage::register_handle(&handle, remittance_id);
+
+    // Emit event
+    ContractEvent::HandleRegistered {
+        remittance_id,
+        creator: caller,
+        handle,
+        timestamp: get_current_timestamp(),
+    }
+    .emit();
+}
+
+/// Attaches travel-rule data to a remittance.
+///
+/// Attaching again replaces the previous record, so creators can correct it
+/// before release.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `data_hash` - Hash of the originator/beneficiary data ([u8; 32])
+/// * `payload_uri` - Pointer to the encrypted off-chain payload (max 256 chars)
+///
+/// # Access Control
+///
+/// Only the creator can call this function, while the remittance is active.
+pub fn attach_travel_rule_data_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+    let data_hash: [u8; 32] = args::get("data_hash");
+    let payload_uri: String = args::get("payload_uri");
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    // Get remittance
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    // Verify caller is creator
+    if caller != remittance.creator {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    // Verify remittance is active
+    if remittance.is_released() {
+        runtime::revert(Error::AlreadyReleased);
+    }
+    if remittance.is_cancelled() {
+        runtime::revert(Error::RemittanceCancelled);
+    }
+    if remittance.is_expired() {
+        runtime::revert(Error::RemittanceExpired);
+    }
+
+    // Validate payload pointer
+    if payload_uri.trim().is_empty() || payload_uri.len() > MAX_PAYLOAD_URI_LENGTH {
+        runtime::revert(Error::InvalidTravelRulePayload);
+    }
+
+    // Store travel-rule data
+    let timestamp = get_current_timestamp();
+    storage::store_travel_rule_data(
+        remittance_id,
+        TravelRuleData {
+            data_hash,
+            payload_uri,
+            attached_at: timestamp,
+        },
+    );
+
+    // Emit event
+    ContractEvent::TravelRuleDataAttached {
+        remittance_id,
+        data_hash,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Contributes funds to an existing remittance.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `amount` - Amount to contribute in motes (U512)
+/// * `amount_commitment` - `blake2b(amount || blinding)`; required for
+///   confidential remittances
+/// * `blinding` - Blinding factor of `amount_commitment` ([u8; 32]); required
+///   with it, so the commitment is known to open to the amount paid
+/// * `idempotency_key` - Optional client-generated key ([u8; 32]); a second
+///   contribution from the same account with the same key is rejected
+pub fn contribute_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+    let amount: U512 = args::get("amount");
+
+    // Get caller
+    let contributor = utils::get_caller();
+    enforce_rate_limit(contributor, RateLimitedAction::Contribute);
+
+    // Validate amount
+    utils::validate_non_zero_amount(&amount).unwrap_or_revert();
+
+    // Get remittance
+    let mut remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    // Verify remittance is active
+    if !remittance.is_active() {
+        if remittance.is_released() {
+            runtime::revert(Error::AlreadyReleased);
+        } else if remittance.is_cancelled() {
+            runtime::revert(Error::RemittanceCancelled);
+        } else {
+            runtime::revert(Error::RemittanceExpired);
+        }
+    }
+
+    // The amount under review must not change while it is reviewed
+    if remittance.is_pending_review() {
+        runtime::revert(Error::ReleasePendingReview);
+    }
+
+    // Nor may the amount a bridge relayer was told to deliver
+    if storage::get_bridge_payout(remittance_id).is_some() {
+        runtime::revert(Error::BridgePayoutPending);
+    }
+
+    // Nor while the remittance's namespace is paused
+    check_namespace_unpaused(remittance_id).unwrap_or_revert();
+
+    // No new funds once the deadline has passed, even before it is expired
+    if remittance.is_past_deadline(get_current_timestamp()) {
+        runtime::revert(Error::RemittanceExpired);
+    }
+
+    // Nor before a scheduled start
+    activate_scheduled_start(remittance_id);
+
+    // Pool members pay in every cycle, including the one paying them out;
+    // elsewhere a recipient funding their own remittance inflates its
+    // apparent support
+    let pool_id = storage::get_remittance_pool(remittance_id);
+    if pool_id.is_none() && contributor == remittance.recipient {
+        check_recipient_contribution(remittance_id, contributor);
+    }
+
+    // A capped remittance only takes more from those already contributing
+    if let Some(max_contributors) = storage::get_contributor_cap(remittance_id) {
+        if storage::get_contributor_count(remittance_id) >= max_contributors
+            && !has_contributed(&remittance, contributor)
+        {
+            runtime::revert(Error::ContributorCapReached);
+        }
+    }
+
+    // A wallet retrying the same deploy must not pay in twice
+    if let Some(idempotency_key) = args::get_optional::<[u8; 32]>("idempotency_key") {
+        if !storage::record_idempotency_key(remittance_id, contributor, idempotency_key) {
+            runtime::revert(Error::DuplicateContribution);
+        }
+    }
+
+    if remittance.is_confidential() {
+        contribute_confidential(remittance, contributor, amount);
+        return;
+    }
+
+    // Receive payment from contributor
+    utils::receive_payment(amount).unwrap_or_revert();
+
+    // Under the on-contribute fee model only the net amount is escrowed, so
+    // the target, release, and refunds all work on net amounts
+    let fee = if storage::get_fee_model() == FeeModel::OnContribute {
+        platform_fee(&remittance, &amount)
+    } else {
+        U512::zero()
+    };
+    let after_fee = amount
+        .checked_sub(fee)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+
+    // Insured remittances also pay the premium into the pool before escrow
+    let policy = storage::get_insurance_policy(remittance_id);
+    let premium = policy
+        .as_ref()
+        .map_or(U512::zero(), |policy| policy.premium(after_fee));
+    let net_amount = after_fee
+        .checked_sub(premium)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    utils::validate_non_zero_amount(&net_amount).unwrap_or_revert();
+
+    // Pool shares are escrowed amounts, so they are compared net of fees
+    if let Some(pool_id) = pool_id {
+        check_pool_contribution(pool_id, remittance_id, contributor, net_amount);
+    }
+
+    // Update remittance current amount
+    remittance.current_amount = remittance
+        .current_amount
+        .checked_add(net_amount)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+
+    // Store updated remittance
+    storage::store_remittance(&remittance);
+
+    // Store contribution
+    storage::store_contribution(remittance_id, contributor, net_amount);
+    storage::increase_ledger_total(storage::ESCROW_LIABILITIES, net_amount);
+
+    // An open-ended pot tracks what each withdrawal has not yet paid out
+    if remittance.is_open_ended() {
+        let mut deposit = storage::get_pot_deposit(remittance_id, contributor);
+        deposit
+            .deposit(net_amount, remittance.released_amount)
+            .unwrap_or_revert();
+        storage::store_pot_deposit(remittance_id, contributor, deposit);
+    }
+
+    // Add to contributors list
+    storage::add_contributor(remittance_id, contributor);
+
+    // Update momentum aggregates
+    let timestamp = get_current_timestamp();
+    let mut analytics = storage::get_analytics(remittance_id);
+    analytics.record(net_amount, timestamp).unwrap_or_revert();
+    storage::store_analytics(remittance_id, analytics);
+    storage::update_contribution_weights(remittance_id, contributor, |weight| {
+        weight.deposit(net_amount, timestamp)
+    });
+
+    // Emit event
+    ContractEvent::ContributionMade {
+        remittance_id,
+        contributor,
+        amount: net_amount,
+        new_total: remittance.current_amount,
+        timestamp,
+    }
+    .emit();
+
+    record_funding(&remittance, timestamp);
+
+    // The ledger keeps the gross contribution and the fee as separate entries
+    record_ledger_entry(&remittance, LedgerEntryKind::Contribution, contributor, amount, timestamp);
+
+    if !fee.is_zero() {
+        collect_contribution_fee(&remittance, contributor, fee, timestamp);
+    }
+
+    if let Some(policy) = policy {
+        collect_premium(&remittance, policy, contributor, premium, timestamp);
+    }
+
+    if let Some(split) = storage::get_instant_split(remittance_id) {
+        forward_instant_share(remittance, split, contributor, net_amount, timestamp);
+    }
+}
+
+/// Contributes from the caller's main purse, taking only primitive args.
+///
+/// A session entry point: it runs in the caller's account context, so it
+/// can move `amount` into a cargo purse itself and pass that to
+/// `contribute`. The deploy then carries just an ID and an amount, which a
+/// hardware wallet can show in full, instead of a purse URef or session
+/// wasm. Confidential contributions and idempotency keys need `contribute`.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `amount` - Amount to contribute in motes (U512)
+pub fn contribute_simple_entry() {
+    // Get arguments
+    let remittance_id: u64 = args::get("remittance_id");
+    let amount: U512 = args::get("amount");
+
+    let cargo_purse = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), cargo_purse, amount, None)
+        .unwrap_or_revert();
+
+    runtime::call_contract::<()>(
+        utils::get_session_contract_hash(),
+        "contribute",
+        runtime_args! {
+            "remittance_id" => remittance_id,
+            "amount" => amount,
+            "purse" => cargo_purse,
+        },
+    );
+}
+
+/// Forwards the instant share of a net contribution to the recipient of a
+/// split remittance; the rest stays escrowed until release.
+///
+/// The forwarded amount pays the platform fee a release would, and counts as
+/// released, so refunds only return what is still escrowed.
+fn forward_instant_share(
+    mut remittance: Remittance,
+    mut split: InstantSplit,
+    contributor: AccountHash,
+    net_amount: U512,
+    timestamp: u64,
+) {
+    let (instant, _) = split.split(net_amount);
+    if instant.is_zero() {
+        return;
+    }
+
+    let charity_verified = storage::is_verified_charity(remittance.recipient);
+    let fee = release_fee(&remittance, &instant, charity_verified);
+    let amount = instant
+        .checked_sub(fee)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+
+    remittance.released_amount = remittance
+        .released_amount
+        .checked_add(instant)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    storage::store_remittance(&remittance);
+
+    split.record(instant, fee).unwrap_or_revert();
+    storage::store_instant_split(remittance.id, split);
+    storage::decrease_ledger_total(storage::ESCROW_LIABILITIES, instant);
+
+    if !fee.is_zero() {
+        collect_contribution_fee(&remittance, contributor, fee, timestamp);
+    }
+
+    let recipient = remittance.recipient;
+    utils::pay_out(&[(recipient, amount)]).unwrap_or_revert();
+    record_ledger_entry(&remittance, LedgerEntryKind::Release, recipient, amount, timestamp);
+
+    ContractEvent::InstantPayout {
+        remittance_id: remittance.id,
+        recipient,
+        amount,
+        platform_fee: fee,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Applies the recipient contribution policy to a contribution from the
+/// remittance's own recipient: reverts with `RecipientContribution` under
+/// `Reject`, emits `RecipientContributionFlagged` under `Flag`.
+fn check_recipient_contribution(remittance_id: u64, recipient: AccountHash) {
+    match storage::get_payment_guards().recipient_contribution_policy() {
+        RecipientContributionPolicy::Allow => {}
+        RecipientContributionPolicy::Flag => {
+            ContractEvent::RecipientContributionFlagged {
+                remittance_id,
+                recipient,
+                timestamp: get_current_timestamp(),
+            }
+            .emit();
+        }
+        RecipientContributionPolicy::Reject => runtime::revert(Error::RecipientContribution),
+    }
+}
+
+/// Checks if `contributor` has already contributed to a remittance.
+fn has_contributed(remittance: &Remittance, contributor: AccountHash) -> bool {
+    if remittance.is_confidential() {
+        storage::get_contribution_commitment(remittance.id, contributor).is_some()
+    } else {
+        !storage::get_contribution(remittance.id, contributor).is_zero()
+    }
+}
+
+/// Appends an entry to a remittance's ledger.
+///
+/// Confidential remittances keep amounts out of contract state, so they
+/// have no ledger; zero amounts are not recorded.
+fn record_ledger_entry(
+    remittance: &Remittance,
+    kind: LedgerEntryKind,
+    account: AccountHash,
+    amount: U512,
+    timestamp: u64,
+) {
+    if remittance.is_confidential() || amount.is_zero() {
+        return;
+    }
+
+    storage::append_ledger_entry(
+        remittance.id,
+        LedgerEntry {
+            kind,
+            account,
+            amount,
+            timestamp,
+        },
+    );
+}
+
+/// Gets the largest target a new remittance to `recipient` may have.
+///
+/// KYB-verified businesses get the higher KYB cap when the owner has set
+/// one above the standard cap; everyone else gets the standard cap.
+fn max_target_amount_for(recipient: AccountHash) -> U512 {
+    let max_target_amount = storage::get_max_target_amount();
+    let kyb_max_target_amount = storage::get_kyb_max_target_amount();
+
+    if !max_target_amount.is_zero()
+        && kyb_max_target_amount > max_target_amount
+        && storage::is_verified_business(recipient)
+    {
+        kyb_max_target_amount
+    } else {
+        max_target_amount
+    }
+}
+
+/// Calculates the platform fee on `amount`; verified charities pay none.
+fn platform_fee(remittance: &Remittance, amount: &U512) -> U512 {
+    if storage::is_verified_charity(remittance.recipient) {
+        return U512::zero();
+    }
+
+    let fee_ppm = fee_ppm_for(remittance.id);
+    let rounding = storage::get_fee_rounding();
+    utils::calculate_fee(amount, fee_ppm, rounding)
+}
+
+/// Gets the namespace a remittance belongs to, if any.
+fn remittance_namespace(remittance_id: u64) -> Option<Namespace> {
+    storage::get_remittance_namespace(remittance_id).and_then(|name| storage::get_namespace(&name))
+}
+
+/// Checks that a remittance's namespace, if it has one, is not paused.
+fn check_namespace_unpaused(remittance_id: u64) -> Result<(), Error> {
+    match remittance_namespace(remittance_id) {
+        Some(namespace) if namespace.paused => Err(Error::NamespacePaused),
+        _ => Ok(()),
+    }
+}
+
+/// Platform fee rate of a remittance: the rate fixed by its category, its
+/// namespace's, or the global rate.
+fn fee_ppm_for(remittance_id: u64) -> u64 {
+    if let Some(fee_ppm) = storage::get_remittance_fee_ppm(remittance_id) {
+        return fee_ppm;
+    }
+
+    remittance_namespace(remittance_id)
+        .map_or_else(storage::get_platform_fee_ppm, |namespace| namespace.fee_ppm)
+}
+
+/// Account paid a remittance's fees: its namespace owner, or the fee
+/// collector.
+fn fee_collector_for(remittance_id: u64) -> AccountHash {
+    remittance_namespace(remittance_id)
+        .map_or_else(storage::get_fee_collector, |namespace| namespace.owner)
+}
+
+/// Pays out a fee taken from a contribution, accruing the creator rebate.
+fn collect_contribution_fee(
+    remittance: &Remittance,
+    contributor: AccountHash,
+    fee: U512,
+    timestamp: u64,
+) {
+    let creator_share_bps = storage::get_creator_fee_share_bps();
+    let (collector_fee, creator_rebate) = utils::split_fee(&fee, creator_share_bps);
+
+    if !creator_rebate.is_zero() {
+        storage::add_creator_rewards(remittance.creator, creator_rebate);
+        storage::increase_ledger_total(storage::ACCRUED_CREATOR_REWARDS, creator_rebate);
+    }
+
+    let fee_collector = fee_collector_for(remittance.id);
+    utils::pay_out(&[(fee_collector, collector_fee)]).unwrap_or_revert();
+    record_ledger_entry(remittance, LedgerEntryKind::Fee, fee_collector, fee, timestamp);
+
+    #[cfg(feature = "stats")]
+    {
+        let corridor = storage::get_corridor(remittance.id);
+        storage::update_period_stats(utils::day_index(timestamp), corridor.as_deref(), |stats| {
+            stats.record_fee(fee)
+        });
+    }
+
+    ContractEvent::ContributionFeeCharged {
+        remittance_id: remittance.id,
+        contributor,
+        fee,
+        timestamp,
+    }
+    .emit();
+
+    if !creator_rebate.is_zero() {
+        ContractEvent::CreatorRebateAccrued {
+            remittance_id: remittance.id,
+            creator: remittance.creator,
+            amount: creator_rebate,
+            timestamp,
+        }
+        .emit();
+    }
+}
+
+/// Moves an insurance premium taken from a contribution into the pool.
+fn collect_premium(
+    remittance: &Remittance,
+    mut policy: InsurancePolicy,
+    contributor: AccountHash,
+    premium: U512,
+    timestamp: u64,
+) {
+    if premium.is_zero() {
+        return;
+    }
+
+    policy.premiums = policy
+        .premiums
+        .checked_add(premium)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    storage::store_insurance_policy(remittance.id, policy);
+    storage::increase_ledger_total(storage::INSURANCE_POOL, premium);
+    record_ledger_entry(remittance, LedgerEntryKind::Premium, contributor, premium, timestamp);
+
+    ContractEvent::InsurancePremiumCollected {
+        remittance_id: remittance.id,
+        contributor,
+        premium,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Records a contribution to a confidential remittance.
+///
+/// Only the `amount_commitment` is stored per contributor; the transfer is
+/// checked against the contract purse balance and folded into the public
+/// aggregate. The amount is still visible in the deploy's own arguments, so
+/// this keeps amounts out of contract state and events, not off-chain.
+fn contribute_confidential(mut remittance: Remittance, contributor: AccountHash, amount: U512) {
+    let commitment: [u8; 32] = args::get("amount_commitment");
+    let blinding: [u8; 32] = args::get("blinding");
+
+    // Refunds pay out whatever opens the commitment, so it must open to the
+    // amount actually paid
+    if utils::compute_amount_commitment(&amount, &blinding) != commitment {
+        runtime::revert(Error::InvalidAmountCommitment);
+    }
+
+    // One commitment per contributor, since commitments cannot be summed
+    if storage::get_contribution_commitment(remittance.id, contributor).is_some() {
+        runtime::revert(Error::CommitmentAlreadyRecorded);
+    }
+
+    // Receive payment and verify it against the purse delta
+    utils::receive_payment_verified(amount).unwrap_or_revert();
+
+    // Update aggregate total
+    remittance.current_amount = remittance
+        .current_amount
+        .checked_add(amount)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+
+    storage::store_remittance(&remittance);
+
+    // Store commitment in place of the amount
+    storage::store_contribution_commitment(remittance.id, contributor, commitment);
+    storage::increase_ledger_total(storage::ESCROW_LIABILITIES, amount);
+    storage::add_contributor(remittance.id, contributor);
+
+    // Emit event
+    let timestamp = get_current_timestamp();
+    ContractEvent::ConfidentialContributionMade {
+        remittance_id: remittance.id,
+        contributor,
+        amount_commitment: commitment,
+        timestamp,
+    }
+    .emit();
+
+    record_funding(&remittance, timestamp);
+}
+
+/// Rejects contributions before a remittance's scheduled start, and
+/// activates it on the first contribution after.
+fn activate_scheduled_start(remittance_id: u64) {
+    let mut start = match storage::get_scheduled_start(remittance_id) {
+        Some(start) => start,
+        None => return,
+    };
+
+    let timestamp = get_current_timestamp();
+    if !start.has_started(timestamp) {
+        runtime::revert(Error::RemittanceNotStarted);
+    }
+    if start.activated_at.is_some() {
+        return;
+    }
+
+    let starts_at = start.starts_at;
+    start.activated_at = Some(timestamp);
+    storage::store_scheduled_start(remittance_id, start);
+
+    ContractEvent::RemittanceActivated {
+        remittance_id,
+        starts_at,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Starts the release SLA clock, a backup recipient's grace period, and an
+/// installment schedule when the target is first met.
+///
+/// An open-ended pot is withdrawn from as it fills, so nothing waits on it.
+fn record_funding(remittance: &Remittance, timestamp: u64) {
+    if !remittance.is_target_met() || remittance.is_open_ended() {
+        return;
+    }
+
+    if storage::get_release_sla(remittance.id).is_none() {
+        storage::store_release_sla(remittance.id, ReleaseSla::new(timestamp));
+    }
+
+    if let Some(mut backup) = storage::get_backup_recipient(remittance.id) {
+        if backup.funded_at.is_none() {
+            backup.funded_at = Some(timestamp);
+            storage::store_backup_recipient(remittance.id, backup);
+        }
+    }
+
+    if let Some(mut schedule) = storage::get_payout_schedule(remittance.id) {
+        if schedule.funded_at.is_none() {
+            schedule.funded_at = Some(timestamp);
+            storage::store_payout_schedule(remittance.id, schedule);
+        }
+    }
+}
+
+/// Pre-funds the platform fee of a remittance so its release pays the
+/// recipient the full amount.
+///
+/// Called through the contribute session wasm with `fee_cover` set, which
+/// passes the funds in a cargo purse. The first top-up makes the caller the
+/// cover's sponsor; unused cover is returned to them at release, or claimed
+/// back with `claim_fee_cover` if the remittance ends refundable.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `amount` - Amount to add to the cover in motes (U512)
+/// * `purse` - Purse holding `amount` (URef)
+///
+/// # Access Control
+///
+/// Only the creator or recipient can call this function, and only the
+/// sponsor once the cover exists.
+pub fn top_up_fee_cover_entry() {
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    let remittance_id = utils::get_remittance_id_arg();
+    let amount: U512 = args::get("amount");
+    let caller = utils::get_caller();
+
+    utils::validate_non_zero_amount(&amount).unwrap_or_revert();
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    if caller != remittance.creator && caller != remittance.recipient {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    if remittance.is_released() {
+        runtime::revert(Error::AlreadyReleased);
+    }
+    if remittance.is_cancelled() {
+        runtime::revert(Error::RemittanceCancelled);
+    }
+    if remittance.is_expired() {
+        runtime::revert(Error::RemittanceExpired);
+    }
+
+    // The bridge relayer was already told the amount to deliver
+    if storage::get_bridge_payout(remittance_id).is_some() {
+        runtime::revert(Error::BridgePayoutPending);
+    }
+
+    let mut cover = storage::get_fee_cover(remittance_id).unwrap_or_else(|| FeeCover::new(caller));
+    if cover.sponsor != caller {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    utils::receive_payment(amount).unwrap_or_revert();
+
+    cover.balance = cover
+        .balance
+        .checked_add(amount)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    let balance = cover.balance;
+    storage::store_fee_cover(remittance_id, cover);
+    storage::increase_ledger_total(storage::ESCROW_LIABILITIES, amount);
+
+    let timestamp = get_current_timestamp();
+    record_ledger_entry(&remittance, LedgerEntryKind::Match, caller, amount, timestamp);
+
+    ContractEvent::FeeCoverToppedUp {
+        remittance_id,
+        sponsor: caller,
+        amount,
+        balance,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Releases funds to the recipient once target is met.
+///
+/// The recipient may instead route the payout to a registered settlement
+/// partner serving the remittance's corridor, and collect it in cash with
+/// the reference code.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `partner` - Optional settlement partner to pay instead (AccountHash)
+/// * `reference_code` - Pickup code for the partner; required with
+///   `partner` (String, max `MAX_PAYOUT_REFERENCE_LENGTH`)
+/// * `payout_target` - Optional label of an account in the recipient's
+///   payout address book to pay instead (String); not with `partner`
+///
+/// # Access Control
+///
+/// Only the recipient can call this function.
+pub fn release_funds_entry() {
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let remittance = checked_release(remittance_id);
+
+    let partner = args::get_optional::<AccountHash>("partner");
+    let payout_target = args::get_optional::<String>("payout_target");
+    if partner.is_some() && payout_target.is_some() {
+        runtime::revert(Error::ConflictingPayoutRoute);
+    }
+
+    // The route is kept with the remittance so a held release honours it
+    if let Some(partner) = partner {
+        let reference_code: String = args::get("reference_code");
+        let route = check_payout_route(&remittance, partner, reference_code).unwrap_or_revert();
+        storage::set_payout_route(remittance_id, route);
+    }
+
+    // As is the chosen payout target, resolved now so later address book
+    // edits do not redirect a held release
+    if let Some(label) = payout_target {
+        let target = find_payout_target(remittance.recipient, &label).unwrap_or_revert();
+        storage::set_payout_selection(remittance_id, target);
+    }
+
+    release_or_hold(remittance);
+}
+
+/// Releases funds to the recipient, taking only the remittance ID.
+///
+/// The single-argument counterpart of `release_funds` for hardware wallets:
+/// always pays the recipient directly, without a settlement partner or
+/// payout target.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+///
+/// # Access Control
+///
+/// Only the recipient can call this function.
+pub fn release_simple_entry() {
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let remittance = checked_release(remittance_id);
+    release_or_hold(remittance);
+}
+
+/// Verifies that the caller may release `remittance_id`, recording an
+/// escrow shortfall before reverting.
+fn checked_release(remittance_id: u64) -> Remittance {
+    let caller = utils::get_caller();
+
+    check_release(remittance_id, caller).unwrap_or_else(|error| {
+        if error == Error::InsufficientEscrowBalance {
+            report_escrow_shortfall(remittance_id);
+        }
+        runtime::revert(error)
+    })
+}
+
+/// Settles a checked release, unless it is large enough to wait for the
+/// compliance officer instead.
+fn release_or_hold(remittance: Remittance) {
+    let policy = storage::get_review_policy();
+    if policy.requires_review(&remittance.release_amount()) {
+        hold_for_review(remittance, &policy);
+        return;
+    }
+
+    settle_release(remittance);
+}
+
+/// Releases a funded remittance to its backup recipient.
+///
+/// Covers a recipient who cannot release, e.g. through illness or lost
+/// access to their account, once the creator's grace period after funding
+/// has passed.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+///
+/// # Access Control
+///
+/// Only the backup recipient can call this function. Releases large enough
+/// to need compliance review must be requested by the recipient.
+pub fn release_to_backup_entry() {
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    // Verify all release preconditions, recording an escrow shortfall
+    let remittance = check_backup_release(remittance_id, caller).unwrap_or_else(|error| {
+        if error == Error::InsufficientEscrowBalance {
+            report_escrow_shortfall(remittance_id);
+        }
+        runtime::revert(error)
+    });
+
+    if storage::get_review_policy().requires_review(&remittance.release_amount()) {
+        runtime::revert(Error::BackupReviewRequired);
+    }
+
+    let recipient = remittance.recipient;
+    let amount = pay_release(remittance, caller);
+
+    ContractEvent::BackupReleaseTriggered {
+        remittance_id,
+        recipient,
+        backup: caller,
+        amount,
+        timestamp: get_current_timestamp(),
+    }
+    .emit();
+}
+
+/// Records a signer's approval of a release to a multi-signature recipient.
+///
+/// Once `threshold` signers have approved, the recipient can call
+/// `release_funds` (or `request_bridge_payout`) as usual. Approvals are kept
+/// for the life of the remittance.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+///
+/// # Access Control
+///
+/// Only the remittance's recipient signers can call this function, once
+/// each.
+pub fn approve_release_as_recipient_entry() {
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    let mut multisig = match storage::get_recipient_multisig(remittance_id) {
+        Some(multisig) => multisig,
+        None => runtime::revert(Error::Unauthorized),
+    };
+    if !multisig.is_signer(&caller) {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    if remittance.is_released() {
+        runtime::revert(Error::AlreadyReleased);
+    }
+    if remittance.is_cancelled() {
+        runtime::revert(Error::RemittanceCancelled);
+    }
+    if remittance.is_expired() {
+        runtime::revert(Error::RemittanceExpired);
+    }
+    if !remittance.is_target_met() {
+        runtime::revert(Error::TargetNotMet);
+    }
+
+    if multisig.has_approved(&caller) {
+        runtime::revert(Error::AlreadyApproved);
+    }
+
+    multisig.approvals.push(caller);
+    let approvals = multisig.approvals.len() as u64;
+    let threshold = multisig.threshold;
+    storage::store_recipient_multisig(remittance_id, multisig);
+
+    ContractEvent::RecipientApprovalAdded {
+        remittance_id,
+        signer: caller,
+        approvals,
+        threshold,
+        timestamp: get_current_timestamp(),
+    }
+    .emit();
+}
+
+/// Checks that a release can be routed to `partner` for cash pickup.
+fn check_payout_route(
+    remittance: &Remittance,
+    partner: AccountHash,
+    reference_code: String,
+) -> Result<PayoutRoute, Error> {
+    if reference_code.is_empty() || reference_code.len() > MAX_PAYOUT_REFERENCE_LENGTH {
+        return Err(Error::InvalidPayoutReference);
+    }
+
+    let entry = storage::get_settlement_partner(partner).ok_or(Error::PartnerNotRegistered)?;
+
+    // Partners serve corridors, so a remittance without one cannot be routed
+    let corridor = storage::get_corridor(remittance.id).ok_or(Error::PartnerCorridorUnsupported)?;
+    if !entry.serves(&corridor) {
+        return Err(Error::PartnerCorridorUnsupported);
+    }
+
+    Ok(PayoutRoute {
+        partner,
+        reference_code,
+    })
+}
+
+/// Looks up a target in `owner`'s payout address book by label.
+fn find_payout_target(owner: AccountHash, label: &str) -> Result<PayoutTarget, Error> {
+    storage::get_payout_targets(owner)
+        .into_iter()
+        .find(|target| target.label == label)
+        .ok_or(Error::PayoutTargetNotFound)
+}
+
+/// Pays a checked release to the payout target the recipient chose, or to
+/// the recipient's own account if they chose none.
+fn settle_to_payout_target(remittance: Remittance) {
+    let (remittance_id, recipient) = (remittance.id, remittance.recipient);
+    let target = match storage::get_payout_selection(remittance_id) {
+        Some(target) => target,
+        None => {
+            pay_release(remittance, recipient);
+            return;
+        }
+    };
+
+    let amount = pay_release(remittance, target.account);
+
+    ContractEvent::PayoutTargetUsed {
+        remittance_id,
+        recipient,
+        label: target.label,
+        target: target.account,
+        amount,
+        timestamp: get_current_timestamp(),
+    }
+    .emit();
+}
+
+/// Pays out a checked release to the recipient, or to the settlement partner
+/// or payout target the recipient routed it to.
+fn settle_release(remittance: Remittance) {
+    let route = match storage::get_payout_route(remittance.id) {
+        Some(route) => route,
+        None => {
+            settle_to_payout_target(remittance);
+            return;
+        }
+    };
+
+    // The partner may have been removed while the release was under review
+    let route =
+        check_payout_route(&remittance, route.partner, route.reference_code).unwrap_or_revert();
+    let (remittance_id, recipient) = (remittance.id, remittance.recipient);
+    let amount = pay_release(remittance, route.partner);
+
+    ContractEvent::PartnerPayoutRouted {
+        remittance_id,
+        recipient,
+        partner: route.partner,
+        reference_code: route.reference_code,
+        amount,
+        timestamp: get_current_timestamp(),
+    }
+    .emit();
+}
+
+/// Calculates the platform fee due on releasing `release_amount`.
+///
+/// No fee is due if it was taken from each contribution; confidential
+/// contributions are committed gross, so they pay on release.
+fn release_fee(remittance: &Remittance, release_amount: &U512, charity_verified: bool) -> U512 {
+    let fee_taken =
+        storage::get_fee_model() == FeeModel::OnContribute && !remittance.is_confidential();
+    if charity_verified || fee_taken {
+        return U512::zero();
+    }
+
+    let fee_ppm = fee_ppm_for(remittance.id);
+    let rounding = storage::get_fee_rounding();
+    utils::calculate_fee(release_amount, fee_ppm, rounding)
+}
+
+/// Holds a release for compliance review until the policy's window closes.
+fn hold_for_review(mut remittance: Remittance, policy: &ReviewPolicy) {
+    let timestamp = get_current_timestamp();
+    let review_deadline = timestamp.saturating_add(policy.window_ms);
+
+    remittance.set_flag(FLAG_PENDING_REVIEW);
+    storage::store_remittance(&remittance);
+    storage::set_review_deadline(remittance.id, review_deadline);
+
+    ContractEvent::ReleaseReviewRequested {
+        remittance_id: remittance.id,
+        amount: remittance.release_amount(),
+        review_deadline,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Pays out a remittance whose release preconditions have been checked.
+///
+/// The net amount goes to `payee`: the recipient, or the bridge operator
+/// reimbursed for a cross-chain payout. Returns the net amount paid.
+fn pay_release(mut remittance: Remittance, payee: AccountHash) -> U512 {
+    let remittance_id = remittance.id;
+
+    // Verified charities pay no platform fee; re-check the registry so a
+    // deregistration before release takes effect
+    let charity_verified = storage::is_verified_charity(remittance.recipient);
+    if charity_verified {
+        remittance.set_flag(FLAG_CHARITY_VERIFIED);
+    } else {
+        remittance.clear_flag(FLAG_CHARITY_VERIFIED);
+    }
+
+    // Excess-refund remittances pay out only the target; the rest stays
+    // escrowed for `claim_excess_refund`. Split remittances have already
+    // forwarded part of it, which is not paid again.
+    let release_amount = remittance.release_amount();
+    let payout_amount = remittance.remaining_release_amount();
+
+    // Calculate platform fee
+    let platform_fee = release_fee(&remittance, &payout_amount, charity_verified);
+
+    // A fee cover pays the fee on the payee's behalf; its unused rest goes
+    // back to the sponsor
+    let fee_cover = storage::get_fee_cover(remittance_id);
+    let (covered, cover_returned) = fee_cover
+        .as_ref()
+        .map_or((U512::zero(), U512::zero()), |cover| cover.split(&platform_fee));
+
+    // Calculate recipient amount
+    let recipient_amount = payout_amount
+        .checked_sub(platform_fee)
+        .and_then(|amount| amount.checked_add(covered))
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+
+    // Mark as released
+    remittance.mark_released();
+    remittance.released_amount = release_amount;
+    storage::store_remittance(&remittance);
+    close_escrow(&remittance);
+
+    // Update reporting aggregates
+    let timestamp = get_current_timestamp();
+    #[cfg(feature = "stats")]
+    {
+        let corridor = storage::get_corridor(remittance_id);
+        storage::update_period_stats(utils::day_index(timestamp), corridor.as_deref(), |stats| {
+            stats.record_released(release_amount, platform_fee)
+        });
+    }
+
+    // Split the fee between the fee collector and the creator rebate
+    let creator_share_bps = storage::get_creator_fee_share_bps();
+    let (collector_fee, creator_rebate) = utils::split_fee(&platform_fee, creator_share_bps);
+
+    // Escrowed funds leave the ledger; the rebate becomes an accrued fee
+    storage::decrease_ledger_total(storage::ESCROW_LIABILITIES, payout_amount);
+    if let Some(mut cover) = fee_cover.clone() {
+        storage::decrease_ledger_total(storage::ESCROW_LIABILITIES, cover.balance);
+        cover.balance = U512::zero();
+        storage::store_fee_cover(remittance_id, cover);
+    }
+
+    // Accrue the creator rebate; it stays in the contract purse until claimed
+    if !creator_rebate.is_zero() {
+        storage::add_creator_rewards(remittance.creator, creator_rebate);
+        storage::increase_ledger_total(storage::ACCRUED_CREATOR_REWARDS, creator_rebate);
+    }
+
+    // Pay the fee collector and recipient through an isolated payout purse;
+    // any failure reverts the whole release, including the released flag
+    let fee_collector = fee_collector_for(remittance_id);
+    utils::pay_out(&[(fee_collector, collector_fee), (payee, recipient_amount)]).unwrap_or_revert();
+    record_ledger_entry(&remittance, LedgerEntryKind::Fee, fee_collector, platform_fee, timestamp);
+    record_ledger_entry(&remittance, LedgerEntryKind::Release, payee, recipient_amount, timestamp);
+
+    // Keep the itemized split for statements
+    storage::store_release_breakdown(
+        remittance_id,
+        ReleaseBreakdown {
+            gross: payout_amount,
+            platform_fee,
+            collector_fee,
+            creator_rebate,
+            fee_covered: covered,
+            net: recipient_amount,
+        },
+    );
+
+    if let Some(cover) = fee_cover {
+        if !cover_returned.is_zero() {
+            utils::pay_out(&[(cover.sponsor, cover_returned)]).unwrap_or_revert();
+            record_ledger_entry(
+                &remittance,
+                LedgerEntryKind::Refund,
+                cover.sponsor,
+                cover_returned,
+                timestamp,
+            );
+        }
+
+        ContractEvent::FeeCoverApplied {
+            remittance_id,
+            sponsor: cover.sponsor,
+            covered,
+            returned: cover_returned,
+            timestamp,
+        }
+        .emit();
+    }
+
+    // Emit event
+    ContractEvent::FundsReleased {
+        remittance_id,
+        recipient: remittance.recipient,
+        amount: recipient_amount,
+        platform_fee,
+        gross_amount: payout_amount,
+        creator_rebate,
+        fee_covered: covered,
+        charity_verified,
+        timestamp,
+    }
+    .emit();
+
+    if !creator_rebate.is_zero() {
+        ContractEvent::CreatorRebateAccrued {
+            remittance_id,
+            creator: remittance.creator,
+            amount: creator_rebate,
+            timestamp,
+        }
+        .emit();
+    }
+
+    recipient_amount
+}
+
+/// Pays the next due installment of a scheduled payout to the recipient.
+///
+/// Callable by anyone, so keepers can pay stipends on time. Each call pays
+/// one installment; the last pays whatever remains and completes the
+/// release as `release_funds` would. Installments before the last do not
+/// draw on a fee cover. A multi-signature recipient's signers must approve
+/// once before the first installment is paid. Installments were checked
+/// against the review policy when the schedule was created, so a later
+/// threshold change does not stop them.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+///
+/// # Access Control
+///
+/// Anyone can call this function.
+pub fn process_installment_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let mut schedule = storage::get_payout_schedule(remittance_id)
+        .unwrap_or_revert_with(Error::InvalidPayoutSchedule);
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    check_releasable(remittance_id, &remittance).unwrap_or_else(|error| {
+        if error == Error::InsufficientEscrowBalance {
+            report_escrow_shortfall(remittance_id);
+        }
+        runtime::revert(error)
+    });
+
+    // A multi-signature recipient's approval covers the whole schedule
+    if let Some(multisig) = storage::get_recipient_multisig(remittance_id) {
+        if !multisig.is_approved() {
+            runtime::revert(Error::RecipientApprovalRequired);
+        }
+    }
+
+    let timestamp = get_current_timestamp();
+    if !schedule.is_due(timestamp) {
+        runtime::revert(Error::InstallmentNotDue);
+    }
+
+    schedule.paid_count = schedule
+        .paid_count
+        .checked_add(1)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    let installment = schedule.paid_count;
+    let gross = schedule.installment;
+    storage::store_payout_schedule(remittance_id, schedule);
+
+    let recipient = remittance.recipient;
+    let creator = remittance.creator;
+
+    // The last installment is an ordinary release of what remains
+    if remittance.remaining_release_amount() <= gross {
+        let amount = pay_release(remittance, recipient);
+        let platform_fee = storage::get_release_breakdown(remittance_id)
+            .map(|breakdown| breakdown.platform_fee)
+            .unwrap_or_default();
+
+        ContractEvent::InstallmentPaid {
+            remittance_id,
+            recipient,
+            installment,
+            amount,
+            platform_fee,
+            timestamp,
+        }
+        .emit();
+        return;
+    }
+
+    let (amount, platform_fee, creator_rebate) = pay_partial_release(remittance, gross, timestamp);
+
+    ContractEvent::InstallmentPaid {
+        remittance_id,
+        recipient,
+        installment,
+        amount,
+        platform_fee,
+        timestamp,
+    }
+    .emit();
+
+    if !creator_rebate.is_zero() {
+        ContractEvent::CreatorRebateAccrued {
+            remittance_id,
+            creator,
+            amount: creator_rebate,
+            timestamp,
+        }
+        .emit();
+    }
+}
+
+/// Withdraws everything an open-ended pot holds, leaving it open for more.
+///
+/// Pays the recipient directly, less the platform fee, without drawing on a
+/// fee cover. Releasing the pot with `release_funds` instead pays out what
+/// remains and closes it.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+///
+/// # Access Control
+///
+/// Only the recipient can call this function. Withdrawals large enough to
+/// need compliance review must be made by releasing the pot.
+pub fn withdraw_pot_entry() {
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let remittance = checked_release(remittance_id);
+    if !remittance.is_open_ended() {
+        runtime::revert(Error::NotOpenEnded);
+    }
+
+    // Withdrawals cannot be held, so a large one goes through release
+    let gross = remittance.remaining_release_amount();
+    if storage::get_review_policy().requires_review(&gross) {
+        runtime::revert(Error::WithdrawalReviewRequired);
+    }
+
+    let recipient = remittance.recipient;
+    let creator = remittance.creator;
+    let timestamp = get_current_timestamp();
+    let (amount, platform_fee, creator_rebate) = pay_partial_release(remittance, gross, timestamp);
+
+    ContractEvent::PotWithdrawn {
+        remittance_id,
+        recipient,
+        amount,
+        platform_fee,
+        timestamp,
+    }
+    .emit();
+
+    if !creator_rebate.is_zero() {
+        ContractEvent::CreatorRebateAccrued {
+            remittance_id,
+            creator,
+            amount: creator_rebate,
+            timestamp,
+        }
+        .emit();
+    }
+}
+
+/// Pays `gross` out of escrow to the recipient ahead of the release, less
+/// the platform fee, without drawing on a fee cover.
+///
+/// Returns the amount paid, the platform fee, and the creator rebate.
+fn pay_partial_release(
+    mut remittance: Remittance,
+    gross: U512,
+    timestamp: u64,
+) -> (U512, U512, U512) {
+    let remittance_id = remittance.id;
+    let recipient = remittance.recipient;
+    let charity_verified = storage::is_verified_charity(recipient);
+    let platform_fee = release_fee(&remittance, &gross, charity_verified);
+    let amount = gross
+        .checked_sub(platform_fee)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+
+    // Paid amounts count as released, so refunds after a cancellation only
+    // return what is still escrowed
+    remittance.released_amount = remittance
+        .released_amount
+        .checked_add(gross)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    storage::store_remittance(&remittance);
+    storage::decrease_ledger_total(storage::ESCROW_LIABILITIES, gross);
+
+    // Split the fee between the fee collector and the creator rebate
+    let creator_share_bps = storage::get_creator_fee_share_bps();
+    let (collector_fee, creator_rebate) = utils::split_fee(&platform_fee, creator_share_bps);
+    if !creator_rebate.is_zero() {
+        storage::add_creator_rewards(remittance.creator, creator_rebate);
+        storage::increase_ledger_total(storage::ACCRUED_CREATOR_REWARDS, creator_rebate);
+    }
+
+    let fee_collector = fee_collector_for(remittance_id);
+    utils::pay_out(&[(fee_collector, collector_fee), (recipient, amount)]).unwrap_or_revert();
+    record_ledger_entry(&remittance, LedgerEntryKind::Fee, fee_collector, platform_fee, timestamp);
+    record_ledger_entry(&remittance, LedgerEntryKind::Release, recipient, amount, timestamp);
+
+    #[cfg(feature = "stats")]
+    {
+        let corridor = storage::get_corridor(remittance_id);
+        storage::update_period_stats(utils::day_index(timestamp), corridor.as_deref(), |stats| {
+            stats.record_fee(platform_fee)
+        });
+    }
+
+    (amount, platform_fee, creator_rebate)
+}
+
+/// Cancels a remittance and enables refunds.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+///
+/// # Access Control
+///
+/// Only the creator can call this function.
+///
+/// # Note
+///
+/// This uses the pull-over-push pattern. Contributors must claim
+/// refunds individually via `claim_refund`.
+pub fn cancel_remittance_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    // Get remittance
+    let mut remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    // Verify caller is creator
+    if caller != remittance.creator {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    // Verify remittance is not already released
+    if remittance.is_released() {
+        runtime::revert(Error::AlreadyReleased);
+    }
+
+    // Verify remittance is not already cancelled
+    if remittance.is_cancelled() {
+        runtime::revert(Error::RemittanceCancelled);
+    }
+
+    // Verify remittance has not expired; refunds are already enabled
+    if remittance.is_expired() {
+        runtime::revert(Error::RemittanceExpired);
+    }
+
+    // A held release is settled by the compliance review instead
+    if remittance.is_pending_review() {
+        runtime::revert(Error::ReleasePendingReview);
+    }
+
+    // A bridge payout may already be in flight on the destination chain
+    if storage::get_bridge_payout(remittance_id).is_some() {
+        runtime::revert(Error::BridgePayoutPending);
+    }
+
+    // Mark as cancelled
+    remittance.mark_cancelled();
+    storage::store_remittance(&remittance);
+    close_escrow(&remittance);
+
+    // Emit event
+    let timestamp = get_current_timestamp();
+    ContractEvent::RemittanceCancelled {
+        remittance_id,
+        creator: remittance.creator,
+        total_amount: remittance.unreleased_amount(),
+        timestamp,
+    }
+    .emit();
+}
+
+/// Declines a remittance and enables refunds, as cancellation does.
+///
+/// For recipients who cannot or will not accept the funds. A release held
+/// for compliance review can still be declined.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+///
+/// # Access Control
+///
+/// Only the recipient can call this function.
+pub fn reject_remittance_entry() {
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let mut remittance = get_declinable_remittance(remittance_id);
+
+    // Mark as cancelled, dropping any pending review
+    remittance.clear_flag(FLAG_PENDING_REVIEW);
+    remittance.mark_cancelled();
+    storage::store_remittance(&remittance);
+    close_escrow(&remittance);
+
+    // Emit event
+    let timestamp = get_current_timestamp();
+    ContractEvent::RemittanceRejected {
+        remittance_id,
+        recipient: remittance.recipient,
+        total_amount: remittance.unreleased_amount(),
+        timestamp,
+    }
+    .emit();
+}
+
+/// Refuses a funded remittance, recording the reason for contributors, and
+/// enables refunds.
+///
+/// For funded remittances that do not match what the recipient expected,
+/// such as the wrong amount or currency. Works like `reject_remittance`,
+/// but the reason is kept on-chain for contributors to read.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `reason` - Why the funds were refused (String, max
+///   `MAX_REASON_LENGTH`)
+///
+/// # Access Control
+///
+/// Only the recipient can call this function.
+pub fn decline_release_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let reason: String = args::get("reason");
+
+    if reason.trim().is_empty() || reason.len() > MAX_REASON_LENGTH {
+        runtime::revert(Error::InvalidReason);
+    }
+
+    let mut remittance = get_declinable_remittance(remittance_id);
+
+    // Unfunded remittances are declined with `reject_remittance`
+    if !remittance.is_target_met() {
+        runtime::revert(Error::TargetNotMet);
+    }
+
+    remittance.clear_flag(FLAG_PENDING_REVIEW);
+    remittance.mark_cancelled();
+    storage::store_remittance(&remittance);
+    close_escrow(&remittance);
+    storage::set_decline_reason(remittance_id, &reason);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::ReleaseDeclined {
+        remittance_id,
+        recipient: remittance.recipient,
+        reason,
+        total_amount: remittance.unreleased_amount(),
+        timestamp,
+    }
+    .emit();
+}
+
+/// Gets a remittance its recipient, the caller, may still decline.
+///
+/// Reverts unless the remittance is active with no bridge payout in flight.
+fn get_declinable_remittance(remittance_id: u64) -> Remittance {
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    if utils::get_caller() != remittance.recipient {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    if remittance.is_released() {
+        runtime::revert(Error::AlreadyReleased);
+    }
+    if remittance.is_cancelled() {
+        runtime::revert(Error::RemittanceCancelled);
+    }
+    if remittance.is_expired() {
+        runtime::revert(Error::RemittanceExpired);
+    }
+
+    // A bridge payout may already be in flight on the destination chain
+    if storage::get_bridge_payout(remittance_id).is_some() {
+        runtime::revert(Error::BridgePayoutPending);
+    }
+
+    remittance
+}
+
+/// Asks for a funded remittance to be paid out on another chain.
+///
+/// Checks the same preconditions as `release_funds`, then locks the
+/// remittance and emits `BridgePayoutRequested` for the bridge relayer,
+/// carrying the net amount to deliver. The relayer pays out on the
+/// destination chain and the bridge operator then calls
+/// `confirm_bridge_payout` to be reimbursed from escrow. If the operator
+/// has not confirmed within `BRIDGE_PAYOUT_WINDOW_MS`, anyone can unlock the
+/// remittance with `cancel_bridge_payout`. Releases that
+/// would be held for compliance review cannot be bridged, and neither can
+/// private remittances, since the relayer never sees their events.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `chain_id` - Destination chain ID (String, max `MAX_CHAIN_ID_LENGTH`)
+/// * `foreign_address` - Payout address on the destination chain (String,
+///   max `MAX_FOREIGN_ADDRESS_LENGTH`)
+///
+/// # Access Control
+///
+/// Only the recipient can call this function.
+pub fn request_bridge_payout_entry() {
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+    let chain_id: String = args::get("chain_id");
+    let foreign_address: String = args::get("foreign_address");
+
+    if chain_id.is_empty()
+        || chain_id.len() > MAX_CHAIN_ID_LENGTH
+        || foreign_address.is_empty()
+        || foreign_address.len() > MAX_FOREIGN_ADDRESS_LENGTH
+    {
+        runtime::revert(Error::InvalidBridgeDestination);
+    }
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    // Verify all release preconditions, including that no payout is locked
+    let remittance = check_release(remittance_id, caller).unwrap_or_revert();
+    if remittance.has_flag(FLAG_HASHED_ID) {
+        runtime::revert(Error::PrivateBridgePayout);
+    }
+
+    let release_amount = remittance.release_amount();
+    if storage::get_review_policy().requires_review(&release_amount) {
+        runtime::revert(Error::BridgeReviewRequired);
+    }
+
+    // Lock the remittance until the bridge operator confirms
+    let timestamp = get_current_timestamp();
+    storage::set_bridge_payout(
+        remittance_id,
+        Some(BridgePayout {
+            chain_id: chain_id.clone(),
+            foreign_address: foreign_address.clone(),
+            requested_at: timestamp,
+            deadline: timestamp.saturating_add(BRIDGE_PAYOUT_WINDOW_MS),
+        }),
+    );
+
+    // The relayer delivers what the recipient would have been paid here
+    let charity_verified = storage::is_verified_charity(remittance.recipient);
+    let payout_amount = remittance.remaining_release_amount();
+    let fee = release_fee(&remittance, &payout_amount, charity_verified);
+    let covered = storage::get_fee_cover(remittance_id)
+        .map_or(U512::zero(), |cover| cover.split(&fee).0);
+    let amount = payout_amount
+        .checked_sub(fee)
+        .and_then(|amount| amount.checked_add(covered))
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+
+    ContractEvent::BridgePayoutRequested {
+        remittance_id,
+        recipient: caller,
+        chain_id,
+        foreign_address,
+        amount,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Confirms a bridge payout and reimburses the bridge operator from escrow.
+///
+/// Releases the remittance as `release_funds` would, paying the net amount
+/// to the bridge operator instead of the recipient.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `foreign_tx_hash` - Payout transaction on the destination chain
+///   (String, max `MAX_FOREIGN_ADDRESS_LENGTH`)
+///
+/// # Access Control
+///
+/// Only the bridge operator can call this function.
+pub fn confirm_bridge_payout_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+    let foreign_tx_hash: String = args::get("foreign_tx_hash");
+
+    if foreign_tx_hash.is_empty() || foreign_tx_hash.len() > MAX_FOREIGN_ADDRESS_LENGTH {
+        runtime::revert(Error::InvalidBridgeDestination);
+    }
+
+    // Get caller
+    let caller = utils::get_caller();
+    if caller != storage::get_bridge_operator() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    if storage::get_bridge_payout(remittance_id).is_none() {
+        runtime::revert(Error::NoBridgePayout);
+    }
+
+    // Re-check the shared preconditions; also rejects a second confirmation
+    check_releasable(remittance_id, &remittance).unwrap_or_revert();
+
+    let amount = pay_release(remittance, caller);
+
+    ContractEvent::BridgePayoutConfirmed {
+        remittance_id,
+        operator: caller,
+        foreign_tx_hash,
+        amount,
+        timestamp: get_current_timestamp(),
+    }
+    .emit();
+}
+
+/// Cancels a bridge payout the operator has not confirmed, unlocking the
+/// remittance so it can be released, cancelled, or bridged again.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+///
+/// # Access Control
+///
+/// The recipient and the compliance officer can cancel at any time. Once
+/// the payout's deadline has passed without confirmation, anyone can
+/// cancel, so funds are never stuck waiting for the operator.
+pub fn cancel_bridge_payout_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let caller = utils::get_caller();
+
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    let payout = storage::get_bridge_payout(remittance_id)
+        .unwrap_or_revert_with(Error::NoBridgePayout);
+
+    // A confirmed payout has been delivered and reimbursed
+    if remittance.is_released() {
+        runtime::revert(Error::AlreadyReleased);
+    }
+
+    let timestamp = get_current_timestamp();
+    if caller != remittance.recipient
+        && caller != storage::get_compliance_officer()
+        && timestamp <= payout.deadline
+    {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    storage::set_bridge_payout(remittance_id, None);
+
+    ContractEvent::BridgePayoutCancelled {
+        remittance_id,
+        cancelled_by: caller,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Pushes back the deadline of a remittance that has not yet reached it.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `new_deadline` - New block time (ms) after which the remittance can be
+///   expired; must be later than the current deadline
+///
+/// # Access Control
+///
+/// Only the creator can call this function. All extensions of one
+/// remittance together may not exceed the owner-set maximum, which is zero
+/// (extensions disabled) until configured.
+pub fn extend_deadline_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+    let new_deadline: u64 = args::get("new_deadline");
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    // Get remittance
+    let mut remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    // Verify caller is creator
+    if caller != remittance.creator {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    // Verify remittance is active
+    if remittance.is_released() {
+        runtime::revert(Error::AlreadyReleased);
+    }
+    if remittance.is_cancelled() {
+        runtime::revert(Error::RemittanceCancelled);
+    }
+
+    // A deadline that has passed can no longer be moved; expiry is due
+    let timestamp = get_current_timestamp();
+    if remittance.is_expired() || remittance.is_past_deadline(timestamp) {
+        runtime::revert(Error::RemittanceExpired);
+    }
+
+    let old_deadline = remittance.deadline.unwrap_or_revert_with(Error::InvalidDeadline);
+    if new_deadline <= old_deadline {
+        runtime::revert(Error::InvalidDeadline);
+    }
+
+    // Cap the total extension across calls
+    let max_extension = storage::get_max_deadline_extension();
+    let added = new_deadline - old_deadline;
+    if added > max_extension {
+        runtime::revert(Error::DeadlineExtensionTooLong);
+    }
+    let total_extension = storage::get_deadline_extension(remittance_id)
+        .checked_add(added)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    if total_extension > max_extension {
+        runtime::revert(Error::DeadlineExtensionTooLong);
+    }
+
+    remittance.deadline = Some(new_deadline);
+    storage::store_remittance(&remittance);
+    storage::set_deadline_extension(remittance_id, total_extension);
+
+    ContractEvent::DeadlineExtended {
+        remittance_id,
+        old_deadline,
+        new_deadline,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Expires a remittance whose deadline passed with its target unmet.
+///
+/// Callable by anyone, so keepers can sweep stalled remittances. Expiry
+/// enables refunds through `claim_refund`, as cancellation does.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+pub fn expire_remittance_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+
+    // Get remittance
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    let timestamp = get_current_timestamp();
+    if !remittance.is_expirable(timestamp) {
+        runtime::revert(Error::NotExpirable);
+    }
+
+    expire(remittance, timestamp);
+}
+
+/// Expires every stalled remittance in a range of IDs.
+///
+/// Callable by anyone. Remittances that cannot be expired, or do not exist,
+/// are skipped, so keepers can sweep the ID space in pages.
+///
+/// # Arguments (via runtime args)
+///
+/// * `start_id` - First remittance ID to inspect (u64)
+/// * `count` - Number of consecutive IDs to inspect (max the `get_gas_limits` batch size)
+///
+/// # Returns
+///
+/// Number of remittances expired (u64)
+pub fn expire_batch_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    let start_id: u64 = args::get("start_id");
+    let count: u64 = args::get("count");
+
+    check_batch_size(count);
+
+    let last_id = start_id
+        .saturating_add(count - 1)
+        .min(storage::get_remittance_count());
+
+    let timestamp = get_current_timestamp();
+    let mut expired = 0u64;
+    for id in start_id..=last_id {
+        if let Ok(remittance) = storage::get_remittance(id) {
+            if remittance.is_expirable(timestamp) {
+                expire(remittance, timestamp);
+                expired += 1;
+            }
+        }
+    }
+
+    runtime::ret(CLValue::from_t(expired).unwrap_or_revert());
+}
+
+/// Emits escalations for funded remittances left unreleased in a range of
+/// IDs.
+///
+/// Callable by anyone, so keepers can sweep the ID space in pages. Each
+/// remittance is escalated once per configured interval that has elapsed
+/// since its target was met, with `ReleaseOverdue` carrying the new level.
+///
+/// # Arguments (via runtime args)
+///
+/// * `start_id` - First remittance ID to inspect (u64)
+/// * `count` - Number of consecutive IDs to inspect (max the `get_gas_limits` batch size)
+///
+/// # Returns
+///
+/// Number of remittances escalated (u64)
+pub fn escalate_overdue_entry() {
+    let start_id: u64 = args::get("start_id");
+    let count: u64 = args::get("count");
+
+    check_batch_size(count);
+
+    let last_id = start_id
+        .saturating_add(count - 1)
+        .min(storage::get_remittance_count());
+
+    let intervals_ms = storage::get_sla_intervals();
+    let timestamp = get_current_timestamp();
+    let mut escalated = 0u64;
+    for id in start_id..=last_id {
+        if let Some((remittance, mut sla)) = awaiting_release(id) {
+            let level = sla.due_level(&intervals_ms, timestamp);
+            if level <= sla.escalation_level {
+                continue;
+            }
+
+            sla.escalation_level = level;
+            let funded_at = sla.funded_at;
+            storage::store_release_sla(id, sla);
+
+            ContractEvent::ReleaseOverdue {
+                remittance_id: id,
+                recipient: remittance.recipient,
+                level,
+                funded_at,
+                timestamp,
+            }
+            .emit();
+            escalated += 1;
+        }
+    }
+
+    runtime::ret(CLValue::from_t(escalated).unwrap_or_revert());
+}
+
+/// Gets a funded remittance still waiting for its recipient to release,
+/// with its release SLA.
+///
+/// Releases held for review or awaiting a bridge payout are waiting on the
+/// platform instead, and scheduled payouts on their keeper, so they are not
+/// tracked. Neither are private remittances, whose IDs must not be listed.
+fn awaiting_release(remittance_id: u64) -> Option<(Remittance, ReleaseSla)> {
+    let remittance = storage::get_remittance(remittance_id).ok()?;
+    if !remittance.is_active()
+        || remittance.is_pending_review()
+        || remittance.has_flag(FLAG_HASHED_ID)
+        || storage::get_bridge_payout(remittance_id).is_some()
+        || storage::get_payout_schedule(remittance_id).is_some()
+    {
+        return None;
+    }
+
+    let sla = storage::get_release_sla(remittance_id)?;
+    Some((remittance, sla))
+}
+
+/// Marks an expirable remittance as expired and emits `RemittanceExpired`.
+fn expire(mut remittance: Remittance, timestamp: u64) {
+    remittance.mark_expired();
+    storage::store_remittance(&remittance);
+    close_escrow(&remittance);
+
+    ContractEvent::RemittanceExpired {
+        remittance_id: remittance.id,
+        deadline: remittance.deadline.unwrap_or_default(),
+        total_amount: remittance.unreleased_amount(),
+        timestamp,
+    }
+    .emit();
+}
+
+/// Records that a remittance stopped being active.
+///
+/// Time weights stop accruing at this block, and when the remittance is
+/// cancelled or expired each contributor with funds in it gets it in their
+/// claimable refund index.
+fn close_escrow(remittance: &Remittance) {
+    storage::set_escrow_closed_at(remittance.id, get_current_timestamp());
+
+    // A private remittance must not show up in its contributors' indexes
+    if remittance.is_refundable() && !remittance.has_flag(FLAG_HASHED_ID) {
+        for contributor in storage::get_contributors(remittance.id) {
+            // Confidential amounts are not in contract state
+            if remittance.is_confidential()
+                || !storage::get_contribution(remittance.id, contributor).is_zero()
+            {
+                storage::add_claimable_refund(contributor, remittance.id);
+            }
+        }
+    }
+}
+
+/// Claims refund for a cancelled or expired remittance.
+///
+/// Refunds the caller's pro-rata share of what is still in escrow: the full
+/// contribution if nothing was released before cancellation or expiry. The
+/// remittance's refund policy may retain part of the share for the fee
+/// collector.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `amount`, `blinding` - Opening of the contribution commitment; required
+///   for confidential remittances
+/// * `contributor` - Optional contributor to claim for (AccountHash); the
+///   caller must be their refund delegate, and the refund is still paid to
+///   the contributor
+///
+/// # Note
+///
+/// This implements the pull pattern for gas-efficient refunds.
+/// Each contributor, or their delegate, must claim their own refund.
+pub fn claim_refund_entry() {
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    let contributor = match args::get_optional::<AccountHash>("contributor") {
+        Some(contributor) if contributor != caller => {
+            if storage::get_refund_delegate(contributor) != Some(caller) {
+                runtime::revert(Error::Unauthorized);
+            }
+            contributor
+        }
+        _ => caller,
+    };
+
+    // Verify all refund preconditions
+    let refund_share = check_refund(remittance_id, contributor).unwrap_or_revert();
+
+    pay_refund(remittance_id, contributor, refund_share);
+}
+
+/// Authorizes an account to claim the caller's refunds, or revokes it.
+///
+/// The delegate, such as a custodial service, can call `claim_refund` with
+/// the caller as `contributor`; refunds are still paid to the caller. One
+/// delegate covers all of the caller's remittances.
+///
+/// # Arguments (via runtime args)
+///
+/// * `delegate` - Account to authorize (AccountHash); omit to revoke
+pub fn set_refund_delegate_entry() {
+    let delegate = args::get_optional::<AccountHash>("delegate");
+    let contributor = utils::get_caller();
+
+    storage::set_refund_delegate(contributor, delegate);
+
+    ContractEvent::RefundDelegateSet {
+        contributor,
+        delegate,
+        timestamp: get_current_timestamp(),
+    }
+    .emit();
+}
+
+/// Claims the caller's refunds from up to `max_items` cancelled or expired
+/// remittances in their claimable refund index, oldest first.
+///
+/// Refunds on confidential remittances need the amount opening, so they
+/// stay in the index for `claim_refund`. Entries with nothing left to refund
+/// are dropped from the index without counting toward `max_items`.
+///
+/// # Arguments (via runtime args)
+///
+/// * `max_items` - Most refunds to claim (max the `get_gas_limits` batch size)
+///
+/// # Returns
+///
+/// Number of refunds claimed (u64)
+pub fn claim_all_refunds_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    let max_items: u64 = args::get("max_items");
+    check_batch_size(max_items);
+
+    let caller = utils::get_caller();
+    let mut claimed = 0u64;
+    for remittance_id in storage::get_claimable_refunds(caller) {
+        if claimed == max_items {
+            break;
+        }
+
+        // Confidential refunds need the amount opening
+        match storage::get_remittance(remittance_id) {
+            Ok(remittance) if !remittance.is_confidential() => {}
+            _ => continue,
+        }
+
+        match check_refund(remittance_id, caller) {
+            Ok(refund_share) => {
+                pay_refund(remittance_id, caller, refund_share);
+                claimed += 1;
+            }
+            // Nothing left to refund
+            Err(_) => storage::remove_claimable_refund(caller, remittance_id),
+        }
+    }
+
+    runtime::ret(CLValue::from_t(claimed).unwrap_or_revert());
+}
+
+/// Pays `contributor` their checked refund share of a remittance, less any
+/// refund policy deduction, and marks it claimed.
+fn pay_refund(remittance_id: u64, contributor: AccountHash, refund_share: U512) {
+    // Apply the refund policy chosen at creation
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    let timestamp = get_current_timestamp();
+    let retained = storage::get_refund_policy(remittance_id).deduction(
+        refund_share,
+        remittance.created_at,
+        timestamp,
+    );
+    let refund_amount = refund_share - retained;
+
+    // Mark refund as claimed
+    storage::mark_refund_claimed(remittance_id, contributor);
+    storage::remove_claimable_refund(contributor, remittance_id);
+    storage::add_refunded_amount(remittance_id, refund_share);
+    storage::decrease_ledger_total(storage::ESCROW_LIABILITIES, refund_share);
+
+    // Transfer refund from contract purse to contributor
+    let contract_purse = storage::get_contract_purse();
+    utils::transfer_cspr(contract_purse, contributor, refund_amount).unwrap_or_revert();
+
+    // Pay the retained part to the fee collector
+    if !retained.is_zero() {
+        let fee_collector = fee_collector_for(remittance_id);
+        utils::pay_out(&[(fee_collector, retained)]).unwrap_or_revert();
+        record_ledger_entry(&remittance, LedgerEntryKind::Fee, fee_collector, retained, timestamp);
+    }
+
+    // Emit event
+    ContractEvent::RefundClaimed {
+        remittance_id,
+        contributor,
+        amount: refund_amount,
+        timestamp,
+    }
+    .emit();
+
+    record_ledger_entry(
+        &remittance,
+        LedgerEntryKind::Refund,
+        contributor,
+        refund_amount,
+        timestamp,
+    );
+}
+
+/// Claims the caller's share of the excess over target of a released remittance.
+///
+/// Only remittances created with `refund_excess` keep an excess in escrow.
+/// Each contributor receives the excess in proportion to their contribution,
+/// rounded down.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `amount`, `blinding` - Opening of the contribution commitment; required
+///   for confidential remittances
+pub fn claim_excess_refund_entry() {
+    // Get arguments
+    let remittance_id = utils::get_remittance_id_arg();
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    // Verify all excess refund preconditions
+    let refund_amount = check_excess_refund(remittance_id, caller).unwrap_or_revert();
+
+    // A released remittance can no longer be refunded in full, so the refund
+    // marker records the excess claim instead
+    storage::mark_refund_claimed(remittance_id, caller);
+    storage::add_refunded_amount(remittance_id, refund_amount);
+    storage::decrease_ledger_total(storage::ESCROW_LIABILITIES, refund_amount);
+
+    // Transfer the share from contract purse to contributor
+    let contract_purse = storage::get_contract_purse();
+    utils::transfer_cspr(contract_purse, caller, refund_amount).unwrap_or_revert();
+
+    // Emit event
+    let timestamp = get_current_timestamp();
+    ContractEvent::ExcessRefundClaimed {
+        remittance_id,
+        contributor: caller,
+        amount: refund_amount,
+        timestamp,
+    }
+    .emit();
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    record_ledger_entry(&remittance, LedgerEntryKind::Refund, caller, refund_amount, timestamp);
+}
+
+/// Claims the caller's insurance top-up on a cancelled or expired insured
+/// remittance, once the compliance officer has approved claims.
+///
+/// The top-up is the approved coverage of the caller's contribution, paid
+/// from the insurance pool on top of any refund and capped at what the pool
+/// holds.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+pub fn claim_insurance_entry() {
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    let remittance_id = utils::get_remittance_id_arg();
+    let caller = utils::get_caller();
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    let policy =
+        storage::get_insurance_policy(remittance_id).unwrap_or_revert_with(Error::NotInsured);
+
+    if !remittance.is_refundable() {
+        runtime::revert(Error::NotCancelled);
+    }
+
+    if !policy.is_approved() {
+        runtime::revert(Error::InsuranceNotApproved);
+    }
+
+    let contribution = storage::get_contribution(remittance_id, caller);
+    if contribution.is_zero() {
+        runtime::revert(Error::NoContribution);
+    }
+
+    if storage::is_insurance_claimed(remittance_id, caller) {
+        runtime::revert(Error::InsuranceAlreadyClaimed);
+    }
+
+    let pool = storage::get_ledger_total(storage::INSURANCE_POOL);
+    let amount = core::cmp::min(policy.top_up(contribution), pool);
+    if amount.is_zero() {
+        runtime::revert(Error::InsurancePoolEmpty);
+    }
+
+    // Mark the claim before transferring
+    storage::mark_insurance_claimed(remittance_id, caller);
+    storage::decrease_ledger_total(storage::INSURANCE_POOL, amount);
+
+    let contract_purse = storage::get_contract_purse();
+    utils::transfer_cspr(contract_purse, caller, amount).unwrap_or_revert();
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::InsuranceClaimPaid {
+        remittance_id,
+        contributor: caller,
+        amount,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Claims back the unused fee cover of a cancelled or expired remittance.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+///
+/// # Access Control
+///
+/// Only the cover's sponsor can call this function.
+pub fn claim_fee_cover_entry() {
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    let remittance_id = utils::get_remittance_id_arg();
+    let caller = utils::get_caller();
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    if !remittance.is_refundable() {
+        runtime::revert(Error::NotCancelled);
+    }
+
+    let mut cover = match storage::get_fee_cover(remittance_id) {
+        Some(cover) if cover.sponsor == caller && !cover.balance.is_zero() => cover,
+        _ => runtime::revert(Error::NoFeeCover),
+    };
+
+    let amount = cover.balance;
+    cover.balance = U512::zero();
+    storage::store_fee_cover(remittance_id, cover);
+    storage::decrease_ledger_total(storage::ESCROW_LIABILITIES, amount);
+
+    let contract_purse = storage::get_contract_purse();
+    utils::transfer_cspr(contract_purse, caller, amount).unwrap_or_revert();
+
+    let timestamp = get_current_timestamp();
+    record_ledger_entry(&remittance, LedgerEntryKind::Refund, caller, amount, timestamp);
+
+    ContractEvent::FeeCoverRefunded {
+        remittance_id,
+        sponsor: caller,
+        amount,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Claims the caller's accrued creator fee rebates.
+///
+/// Rebates accrue on each successful release of a remittance the caller
+/// created, while a creator fee share is configured.
+pub fn claim_creator_rewards_entry() {
+    // Check if contract is paused
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    // Get caller
+    let caller = utils::get_caller();
+
+    // Get accrued rewards
+    let amount = storage::get_creator_rewards(caller);
+
+    if amount.is_zero() {
+        runtime::revert(Error::NoCreatorRewards);
+    }
+
+    // Clear balance before transferring
+    storage::clear_creator_rewards(caller);
+    storage::decrease_ledger_total(storage::ACCRUED_CREATOR_REWARDS, amount);
+
+    // Transfer rewards from contract purse to creator
     let contract_purse = storage::get_contract_purse();
-    utils::transfer_cspr(contract_purse, caller, contribution_amount).unwrap_or_revert();
+    utils::transfer_cspr(contract_purse, caller, amount).unwrap_or_revert();
+
+    // Emit event
+    let timestamp = get_current_timestamp();
+    ContractEvent::CreatorRewardsClaimed {
+        creator: caller,
+        amount,
+        timestamp,
+    }
+    .emit();
+}
+
+// ============================================================================
+// Precondition Checks
+// ============================================================================
+
+/// Verifies that `caller` may release funds for `remittance_id`.
+///
+/// Shared by `release_funds` and the `validate_release` dry-run view so both
+/// always agree on which error a release would fail with.
+fn check_release(remittance_id: u64, caller: AccountHash) -> Result<Remittance, Error> {
+    if storage::is_contract_paused() {
+        return Err(Error::ContractPaused);
+    }
+
+    let remittance = storage::get_remittance(remittance_id)?;
+
+    if caller != remittance.recipient {
+        return Err(Error::Unauthorized);
+    }
+
+    check_unlocked_release(remittance_id, &remittance)?;
+
+    // A multi-signature recipient releases only once enough signers approved
+    if let Some(multisig) = storage::get_recipient_multisig(remittance_id) {
+        if !multisig.is_approved() {
+            return Err(Error::RecipientApprovalRequired);
+        }
+    }
+
+    Ok(remittance)
+}
+
+/// Verifies that `caller` may release `remittance_id` as its backup recipient.
+fn check_backup_release(remittance_id: u64, caller: AccountHash) -> Result<Remittance, Error> {
+    if storage::is_contract_paused() {
+        return Err(Error::ContractPaused);
+    }
+
+    let remittance = storage::get_remittance(remittance_id)?;
+
+    let backup = storage::get_backup_recipient(remittance_id).ok_or(Error::Unauthorized)?;
+    if caller != backup.account {
+        return Err(Error::Unauthorized);
+    }
+
+    check_unlocked_release(remittance_id, &remittance)?;
+
+    if !backup.is_eligible(get_current_timestamp()) {
+        return Err(Error::BackupNotEligible);
+    }
+
+    Ok(remittance)
+}
+
+/// Checks that no review or bridge payout holds a release, then the
+/// preconditions that do not depend on the caller.
+fn check_unlocked_release(remittance_id: u64, remittance: &Remittance) -> Result<(), Error> {
+    // Scheduled payouts leave escrow only through `process_installment`
+    if storage::get_payout_schedule(remittance_id).is_some() {
+        return Err(Error::PayoutScheduled);
+    }
+
+    if remittance.is_pending_review() {
+        return Err(Error::ReleasePendingReview);
+    }
+
+    if storage::get_bridge_payout(remittance_id).is_some() {
+        return Err(Error::BridgePayoutPending);
+    }
+
+    check_releasable(remittance_id, remittance)
+}
+
+/// Checks the release preconditions that do not depend on the caller.
+///
+/// Shared by `check_unlocked_release` and `approve_release`.
+fn check_releasable(remittance_id: u64, remittance: &Remittance) -> Result<(), Error> {
+    check_namespace_unpaused(remittance_id)?;
+
+    if remittance.is_released() {
+        return Err(Error::AlreadyReleased);
+    }
+
+    if remittance.is_cancelled() {
+        return Err(Error::RemittanceCancelled);
+    }
+
+    if remittance.is_expired() {
+        return Err(Error::RemittanceExpired);
+    }
+
+    if !remittance.is_target_met() {
+        return Err(Error::TargetNotMet);
+    }
+
+    let travel_rule_threshold = storage::get_travel_rule_threshold();
+    if !travel_rule_threshold.is_zero()
+        && remittance.current_amount >= travel_rule_threshold
+        && storage::get_travel_rule_data(remittance_id).is_none()
+    {
+        return Err(Error::TravelRuleDataRequired);
+    }
+
+    // Never pay out more than the purse holds, even if the ledger says so;
+    // amounts already forwarded have left the purse
+    if utils::get_contract_purse_balance()? < remittance.unreleased_amount() {
+        return Err(Error::InsufficientEscrowBalance);
+    }
+
+    check_risk_score(remittance)
+}
+
+/// Asks the registered risk engine to score a release, if it is large enough.
+fn check_risk_score(remittance: &Remittance) -> Result<(), Error> {
+    let engine = match storage::get_risk_engine() {
+        Some(engine) => engine,
+        None => return Ok(()),
+    };
+
+    let amount = remittance.release_amount();
+    if amount < engine.min_amount {
+        return Ok(());
+    }
+
+    let score: u64 = runtime::call_contract(
+        engine.contract_hash,
+        RISK_SCORE_ENTRY_POINT,
+        runtime_args! {
+            "creator" => remittance.creator,
+            "recipient" => remittance.recipient,
+            "amount" => amount,
+        },
+    );
+
+    if score > engine.max_score {
+        return Err(Error::RiskScoreTooHigh);
+    }
+
+    Ok(())
+}
+
+/// Emits `EscrowShortfall` for a release the purse cannot cover.
+fn report_escrow_shortfall(remittance_id: u64) {
+    let required = storage::get_remittance(remittance_id)
+        .map(|remittance| remittance.unreleased_amount())
+        .unwrap_or_default();
+
+    ContractEvent::EscrowShortfall {
+        remittance_id,
+        required,
+        available: utils::get_contract_purse_balance().unwrap_or_default(),
+        timestamp: get_current_timestamp(),
+    }
+    .emit();
+}
+
+/// Verifies that `contributor` may claim a refund for `remittance_id`.
+///
+/// Returns the contributor's share of the unreleased amount on success.
+/// Shared by `claim_refund` and the `validate_refund` dry-run view.
+fn check_refund(remittance_id: u64, contributor: AccountHash) -> Result<U512, Error> {
+    if storage::is_contract_paused() {
+        return Err(Error::ContractPaused);
+    }
+
+    let remittance = storage::get_remittance(remittance_id)?;
+
+    if !remittance.is_refundable() {
+        return Err(Error::NotCancelled);
+    }
+
+    let contribution_amount = if remittance.is_confidential() {
+        reveal_committed_amount(remittance_id, contributor)?
+    } else {
+        storage::get_contribution(remittance_id, contributor)
+    };
+
+    if contribution_amount.is_zero() {
+        return Err(Error::NoContribution);
+    }
+
+    if storage::is_refund_claimed(remittance_id, contributor) {
+        return Err(Error::RefundAlreadyClaimed);
+    }
+
+    // Anything already released is not refunded. Each withdrawal from an
+    // open-ended pot paid out everything deposited before it, so only later
+    // deposits come back; elsewhere each contributor bears their share
+    let refund_amount = if remittance.is_open_ended() {
+        storage::get_pot_deposit(remittance_id, contributor).unwithdrawn(remittance.released_amount)
+    } else {
+        remittance.unreleased_share(contribution_amount)?
+    };
+    let refund_amount = refund_amount.min(unrefunded_amount(&remittance));
+    if refund_amount.is_zero() {
+        return Err(Error::NoContribution);
+    }
+
+    Ok(refund_amount)
+}
+
+/// Verifies that `contributor` may claim an excess refund for `remittance_id`.
+///
+/// Returns the contributor's pro-rata share of the excess on success.
+fn check_excess_refund(remittance_id: u64, contributor: AccountHash) -> Result<U512, Error> {
+    if storage::is_contract_paused() {
+        return Err(Error::ContractPaused);
+    }
+
+    let remittance = storage::get_remittance(remittance_id)?;
+
+    if !remittance.is_released() || !remittance.refunds_excess() {
+        return Err(Error::NoExcessRefund);
+    }
+
+    let contribution_amount = if remittance.is_confidential() {
+        reveal_committed_amount(remittance_id, contributor)?
+    } else {
+        storage::get_contribution(remittance_id, contributor)
+    };
+
+    if contribution_amount.is_zero() {
+        return Err(Error::NoContribution);
+    }
+
+    if storage::is_refund_claimed(remittance_id, contributor) {
+        return Err(Error::RefundAlreadyClaimed);
+    }
+
+    let refund_amount = remittance
+        .unreleased_share(contribution_amount)?
+        .min(unrefunded_amount(&remittance));
+    if refund_amount.is_zero() {
+        return Err(Error::NoExcessRefund);
+    }
+
+    Ok(refund_amount)
+}
+
+/// Calculates what a remittance still holds in escrow for refunds: the
+/// unreleased amount less what has already been refunded from it.
+///
+/// No single refund may exceed it, so a forged or mistaken claim can never
+/// reach into other remittances' funds in the shared purse.
+fn unrefunded_amount(remittance: &Remittance) -> U512 {
+    remittance
+        .unreleased_amount()
+        .saturating_sub(storage::get_refunded_amount(remittance.id))
+}
+
+/// Opens a confidential contribution from the `amount` and `blinding` args.
+fn reveal_committed_amount(remittance_id: u64, contributor: AccountHash) -> Result<U512, Error> {
+    let commitment = storage::get_contribution_commitment(remittance_id, contributor)
+        .ok_or(Error::NoContribution)?;
+
+    let amount: U512 = args::get("amount");
+    let blinding: [u8; 32] = args::get("blinding");
+
+    if utils::compute_amount_commitment(&amount, &blinding) != commitment {
+        return Err(Error::InvalidAmountCommitment);
+    }
+    Ok(amount)
+}
+
+/// Converts a precondition check into the dry-run view result.
+///
+/// `Ok(())` means the call would succeed; `Err(code)` carries the user error
+/// code the real entry point would revert with.
+#[cfg(feature = "views")]
+fn to_validation_result<T>(result: Result<T, Error>) -> Result<(), u32> {
+    result.map(|_| ()).map_err(|error| error as u32)
+}
+
+/// Records the contract purse balance against the ledger at the current block.
+///
+/// Callable by anyone. Comparing snapshots over time shows whether the purse
+/// and the ledger of escrowed funds and accrued fees are drifting apart.
+///
+/// # Returns
+///
+/// Index of the new snapshot (u64)
+#[cfg(feature = "stats")]
+pub fn snapshot_accounting_entry() {
+    let snapshot = AccountingSnapshot {
+        timestamp: get_current_timestamp(),
+        purse_balance: utils::get_contract_purse_balance().unwrap_or_revert(),
+        escrow_liabilities: storage::get_ledger_total(storage::ESCROW_LIABILITIES),
+        accrued_fees: storage::get_ledger_total(storage::ACCRUED_CREATOR_REWARDS),
+    };
+
+    let index = storage::store_snapshot(snapshot.clone());
+
+    ContractEvent::AccountingSnapshotTaken {
+        index,
+        purse_balance: snapshot.purse_balance,
+        escrow_liabilities: snapshot.escrow_liabilities,
+        accrued_fees: snapshot.accrued_fees,
+        purse_short: snapshot.surplus().is_none(),
+        timestamp: snapshot.timestamp,
+    }
+    .emit();
+
+    runtime::ret(CLValue::from_t(index).unwrap_or_revert());
+}
+
+/// Freezes the contributor amounts of a remittance under a snapshot ID.
+///
+/// Callable by anyone. The snapshot keeps the weights as they stood at this
+/// block, so later refunds or releases do not change an airdrop computed
+/// from it. Contributors refunded to zero are left out.
+///
+/// # Arguments
+///
+/// * `remittance_id` - ID of the remittance
+///
+/// # Returns
+///
+/// ID of the new snapshot (u64)
+pub fn snapshot_contributions_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    if remittance.is_confidential() {
+        runtime::revert(Error::ConfidentialRemittance);
+    }
+
+    let contributors = storage::get_contributors(remittance_id);
+    if !storage::get_gas_limits().allows_contributors(contributors.len() as u64) {
+        runtime::revert(Error::TooManyContributors);
+    }
+
+    let contributions: Vec<(AccountHash, U512)> = contributors
+        .into_iter()
+        .map(|contributor| (contributor, storage::get_contribution(remittance_id, contributor)))
+        .filter(|(_, amount)| !amount.is_zero())
+        .collect();
+
+    let snapshot = ContributionSnapshot {
+        remittance_id,
+        taken_at: get_current_timestamp(),
+        contributions,
+    };
+    let contributors = snapshot.contributions.len() as u64;
+    let total = snapshot.total();
+    let timestamp = snapshot.taken_at;
+
+    let snapshot_id = storage::store_contribution_snapshot(snapshot);
+
+    ContractEvent::ContributionsSnapshotTaken {
+        snapshot_id,
+        remittance_id,
+        taken_by: utils::get_caller(),
+        contributors,
+        total,
+        timestamp,
+    }
+    .emit();
+
+    runtime::ret(CLValue::from_t(snapshot_id).unwrap_or_revert());
+}
+
+// ============================================================================
+// View Functions (Read-Only)
+// ============================================================================
+
+/// Gets remittance details by ID.
+#[cfg(feature = "views")]
+pub fn get_remittance_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(remittance).unwrap_or_revert());
+}
+
+/// Gets contribution amount for a specific contributor.
+#[cfg(feature = "views")]
+pub fn get_contribution_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let contributor: AccountHash = args::get("contributor");
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    if remittance.is_confidential() {
+        runtime::revert(Error::ConfidentialRemittance);
+    }
+
+    let amount = storage::get_contribution(remittance_id, contributor);
+    runtime::ret(CLValue::from_t(amount).unwrap_or_revert());
+}
+
+/// Gets a contributor's time-weighted contribution: each amount multiplied
+/// by how long it was escrowed, in mote-milliseconds.
+///
+/// Weight accrues until the remittance is released, cancelled, or expired,
+/// or until the contributor is force-refunded. Divided by
+/// `get_total_contribution_weight` it gives the contributor's share of any
+/// reward paid on the escrowed funds. Contributions made before weights were
+/// tracked carry none.
+///
+/// # Returns
+///
+/// U512
+#[cfg(feature = "views")]
+pub fn get_contribution_weight_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let contributor: AccountHash = args::get("contributor");
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    if remittance.is_confidential() {
+        runtime::revert(Error::ConfidentialRemittance);
+    }
+
+    let weight = storage::get_contribution_weight(remittance_id, contributor)
+        .weight_at(weight_accrual_end(remittance_id));
+    runtime::ret(CLValue::from_t(weight).unwrap_or_revert());
+}
+
+/// Gets the combined time-weighted contributions to a remittance, in
+/// mote-milliseconds.
+///
+/// # Returns
+///
+/// U512
+#[cfg(feature = "views")]
+pub fn get_total_contribution_weight_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let weight = storage::get_remittance_weight(remittance_id)
+        .weight_at(weight_accrual_end(remittance_id));
+    runtime::ret(CLValue::from_t(weight).unwrap_or_revert());
+}
+
+/// Block time time weights accrue to: when the remittance closed, or now.
+#[cfg(feature = "views")]
+fn weight_accrual_end(remittance_id: u64) -> u64 {
+    storage::get_escrow_closed_at(remittance_id).unwrap_or_else(get_current_timestamp)
+}
+
+/// Gets everything a wallet shows for one account in a single call: the
+/// remittances it created, receives, and contributed to, the refunds it can
+/// claim now, and its creator fee rebates.
+///
+/// Refunds on confidential remittances need the amount opening, so they are
+/// not listed.
+///
+/// # Arguments (via runtime args)
+///
+/// * `account` - Account to summarise (AccountHash)
+///
+/// # Returns
+///
+/// `AccountOverview`
+#[cfg(feature = "views")]
+pub fn get_account_overview_entry() {
+    let account: AccountHash = args::get("account");
+    let now = get_current_timestamp();
+
+    let refundable = storage::get_claimable_refunds(account)
+        .into_iter()
+        .filter_map(|remittance_id| {
+            claimable_refund(remittance_id, account, now).map(|amount| (remittance_id, amount))
+        })
+        .collect();
+
+    let overview = AccountOverview {
+        created: storage::get_user_remittances(account),
+        receiving: storage::get_recipient_remittances(account),
+        contributed: storage::get_contributed_remittances(account),
+        refundable,
+        creator_rewards: storage::get_creator_rewards(account),
+    };
+    runtime::ret(CLValue::from_t(overview).unwrap_or_revert());
+}
+
+/// Gets the IDs of cancelled or expired remittances the account has an
+/// unclaimed refund in, in order of closing.
+///
+/// Remittances closed before the index existed are not listed, and entries
+/// leave the list when the refund is claimed.
+///
+/// # Arguments (via runtime args)
+///
+/// * `account` - Contributor to look up (AccountHash)
+///
+/// # Returns
+///
+/// `Vec<u64>`
+#[cfg(feature = "views")]
+pub fn get_claimable_refunds_entry() {
+    let account: AccountHash = args::get("account");
+    let remittance_ids = storage::get_claimable_refunds(account);
+    runtime::ret(CLValue::from_t(remittance_ids).unwrap_or_revert());
+}
+
+/// Refund `contributor` would receive from a non-confidential remittance at
+/// block time `now`, after the refund policy deduction, if they can claim one.
+#[cfg(feature = "views")]
+fn claimable_refund(remittance_id: u64, contributor: AccountHash, now: u64) -> Option<U512> {
+    let remittance = storage::get_remittance(remittance_id).ok()?;
+    if remittance.is_confidential() {
+        return None;
+    }
+
+    let share = check_refund(remittance_id, contributor).ok()?;
+    let retained =
+        storage::get_refund_policy(remittance_id).deduction(share, remittance.created_at, now);
+    Some(share - retained)
+}
+
+/// Gets a contributor's amount commitment on a confidential remittance.
+#[cfg(feature = "views")]
+pub fn get_contribution_commitment_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let contributor: AccountHash = args::get("contributor");
+
+    let commitment = storage::get_contribution_commitment(remittance_id, contributor);
+    runtime::ret(CLValue::from_t(commitment).unwrap_or_revert());
+}
+
+/// Gets the remittance ID indexed under a creator's external reference.
+#[cfg(feature = "views")]
+pub fn get_remittance_by_reference_entry() {
+    let creator: AccountHash = args::get("creator");
+    let reference: String = args::get("reference");
+
+    let reference_key = utils::derive_reference_key(&creator, &reference);
+    let remittance_id = storage::get_remittance_by_reference(reference_key)
+        .unwrap_or_revert_with(Error::RemittanceNotFound);
+
+    runtime::ret(CLValue::from_t(remittance_id).unwrap_or_revert());
+}
+
+/// Resolves a handle to the ID of the remittance it names.
+#[cfg(feature = "views")]
+pub fn resolve_handle_entry() {
+    let handle: String = args::get("handle");
+
+    let remittance_id =
+        storage::resolve_handle(&handle).unwrap_or_revert_with(Error::RemittanceNotFound);
+
+    runtime::ret(CLValue::from_t(remittance_id).unwrap_or_revert());
+}
+
+/// Gets the handle registered for a remittance, if any.
+#[cfg(feature = "views")]
+pub fn get_handle_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let handle = storage::get_handle(remittance_id);
+    runtime::ret(CLValue::from_t(handle).unwrap_or_revert());
+}
+
+/// Checks if an account is a verified charity.
+#[cfg(feature = "views")]
+pub fn is_verified_charity_entry() {
+    let account: AccountHash = args::get("account");
+
+    let verified = storage::is_verified_charity(account);
+    runtime::ret(CLValue::from_t(verified).unwrap_or_revert());
+}
+
+/// Checks if an account is a KYB-verified business.
+#[cfg(feature = "views")]
+pub fn is_verified_business_entry() {
+    let account: AccountHash = args::get("account");
+
+    let verified = storage::is_verified_business(account);
+    runtime::ret(CLValue::from_t(verified).unwrap_or_revert());
+}
+
+/// Checks if a purpose hash is blocklisted.
+///
+/// # Arguments (via runtime args)
+///
+/// * `purpose_hash` - `purpose_hash` of the purpose ([u8; 32])
+#[cfg(feature = "views")]
+pub fn is_purpose_blocked_entry() {
+    let purpose_hash: [u8; 32] = args::get("purpose_hash");
+
+    let blocked = storage::is_purpose_blocked(purpose_hash);
+    runtime::ret(CLValue::from_t(blocked).unwrap_or_revert());
+}
+
+/// Checks if a remittance pays out to a KYB-verified business.
+///
+/// Reflects the registry as it stands, so contributors see when a merchant
+/// loses its verification after the remittance was created.
+#[cfg(feature = "views")]
+pub fn is_business_payout_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    let verified = storage::is_verified_business(remittance.recipient);
+    runtime::ret(CLValue::from_t(verified).unwrap_or_revert());
+}
+
+/// Gets the reporting aggregates for a day.
+///
+/// # Arguments (via runtime args)
+///
+/// * `day_index` - Days since the Unix epoch (u64)
+/// * `corridor` - Optional corridor code narrowing the bucket
+#[cfg(all(feature = "views", feature = "stats"))]
+pub fn get_period_stats_entry() {
+    let day_index: u64 = args::get("day_index");
+    let corridor: Option<String> = args::get_optional("corridor");
+
+    let stats = storage::get_period_stats(day_index, corridor.as_deref());
+    runtime::ret(CLValue::from_t(stats).unwrap_or_revert());
+}
+
+/// Gets an accounting snapshot by index.
+#[cfg(all(feature = "views", feature = "stats"))]
+pub fn get_snapshot_entry() {
+    let index: u64 = args::get("index");
+
+    let snapshot = storage::get_snapshot(index).unwrap_or_revert_with(Error::SnapshotNotFound);
+    runtime::ret(CLValue::from_t(snapshot).unwrap_or_revert());
+}
+
+/// Gets the number of accounting snapshots taken.
+#[cfg(all(feature = "views", feature = "stats"))]
+pub fn get_snapshot_count_entry() {
+    let count = storage::get_snapshot_count();
+    runtime::ret(CLValue::from_t(count).unwrap_or_revert());
+}
+
+/// Gets a contribution snapshot by ID.
+///
+/// # Returns
+///
+/// `Option<ContributionSnapshot>`
+#[cfg(feature = "views")]
+pub fn get_contribution_snapshot_entry() {
+    let snapshot_id: u64 = args::get("snapshot_id");
+    let snapshot = storage::get_contribution_snapshot(snapshot_id);
+    runtime::ret(CLValue::from_t(snapshot).unwrap_or_revert());
+}
+
+/// Gets the crate version and git commit of the installed build.
+///
+/// Installs that predate the `build_info` key report the build of the
+/// running wasm.
+///
+/// # Returns
+///
+/// `BuildInfo`
+#[cfg(feature = "views")]
+pub fn get_version_entry() {
+    let build_info = storage::get_build_info().unwrap_or_else(current_build_info);
+    runtime::ret(CLValue::from_t(build_info).unwrap_or_revert());
+}
+
+/// Version and git commit embedded by the build script.
+fn current_build_info() -> BuildInfo {
+    BuildInfo {
+        version: String::from(env!("CARGO_PKG_VERSION")),
+        git_hash: String::from(env!("CASPERFLOW_GIT_HASH")),
+    }
+}
+
+/// Gets the entry point and event schema of this contract.
+///
+/// Installs that predate the `contract_schema` key get the schema of the
+/// running code.
+#[cfg(feature = "views")]
+pub fn get_schema_entry() {
+    let schema = storage::get_schema()
+        .unwrap_or_else(|| ContractSchema::new(crate::contract_entry_points()));
+    runtime::ret(CLValue::from_t(schema).unwrap_or_revert());
+}
+
+/// Gets the version of the event layouts this contract emits.
+///
+/// Every event also carries the version it was emitted under as its last
+/// field. Installs that predate versioned events report 0 until
+/// `migrate_records` runs on upgraded code.
+#[cfg(feature = "views")]
+pub fn get_event_schema_version_entry() {
+    let version = storage::get_event_schema_version();
+    runtime::ret(CLValue::from_t(version).unwrap_or_revert());
+}
+
+/// Gets the travel-rule data attached to a remittance, if any.
+#[cfg(feature = "views")]
+pub fn get_travel_rule_data_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let data = storage::get_travel_rule_data(remittance_id);
+    runtime::ret(CLValue::from_t(data).unwrap_or_revert());
+}
+
+/// Gets the travel-rule threshold in motes (zero when disabled).
+#[cfg(feature = "views")]
+pub fn get_travel_rule_threshold_entry() {
+    let threshold = storage::get_travel_rule_threshold();
+    runtime::ret(CLValue::from_t(threshold).unwrap_or_revert());
+}
+
+/// Gets the human-readable message for a contract error code.
+///
+/// Takes the code as `u32`, since CLType has no 16-bit integer. Unknown
+/// codes return `"Unknown error"`.
+#[cfg(feature = "views")]
+pub fn get_error_message_entry() {
+    let code: u32 = args::get("code");
+
+    let message = u16::try_from(code)
+        .ok()
+        .and_then(|code| Error::try_from(code).ok())
+        .map(Error::message)
+        .unwrap_or("Unknown error");
+    runtime::ret(CLValue::from_t(String::from(message)).unwrap_or_revert());
+}
+
+/// Gets the compliance officer account.
+#[cfg(feature = "views")]
+pub fn get_compliance_officer_entry() {
+    let officer = storage::get_compliance_officer();
+    runtime::ret(CLValue::from_t(officer).unwrap_or_revert());
+}
+
+/// Gets a registered settlement partner, if any.
+#[cfg(feature = "views")]
+pub fn get_settlement_partner_entry() {
+    let partner: AccountHash = args::get("partner");
+
+    let entry = storage::get_settlement_partner(partner);
+    runtime::ret(CLValue::from_t(entry).unwrap_or_revert());
+}
+
+/// Gets the instant split of a remittance, if it forwards contributions.
+#[cfg(feature = "views")]
+pub fn get_instant_split_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let split = storage::get_instant_split(remittance_id);
+    runtime::ret(CLValue::from_t(split).unwrap_or_revert());
+}
+
+/// Gets the backup recipient of a remittance, if the creator nominated one.
+#[cfg(feature = "views")]
+pub fn get_backup_recipient_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let backup = storage::get_backup_recipient(remittance_id);
+    runtime::ret(CLValue::from_t(backup).unwrap_or_revert());
+}
+
+/// Gets the installment schedule of a remittance, if it pays out in
+/// installments.
+#[cfg(feature = "views")]
+pub fn get_payout_schedule_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let schedule = storage::get_payout_schedule(remittance_id);
+    runtime::ret(CLValue::from_t(schedule).unwrap_or_revert());
+}
+
+/// Gets a savings pool by ID.
+#[cfg(feature = "views")]
+pub fn get_savings_pool_entry() {
+    let pool_id: u64 = args::get("pool_id");
+
+    let pool = storage::get_savings_pool(pool_id);
+    runtime::ret(CLValue::from_t(pool).unwrap_or_revert());
+}
+
+/// Gets the savings pool a remittance is a cycle of, if any.
+#[cfg(feature = "views")]
+pub fn get_remittance_pool_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let pool_id = storage::get_remittance_pool(remittance_id);
+    runtime::ret(CLValue::from_t(pool_id).unwrap_or_revert());
+}
+
+/// Gets the signer group and approvals of a multi-signature recipient, if any.
+#[cfg(feature = "views")]
+pub fn get_recipient_multisig_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let multisig = storage::get_recipient_multisig(remittance_id);
+    runtime::ret(CLValue::from_t(multisig).unwrap_or_revert());
+}
+
+/// Gets the scheduled start of a remittance, if the creator set one.
+#[cfg(feature = "views")]
+pub fn get_scheduled_start_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let start = storage::get_scheduled_start(remittance_id);
+    runtime::ret(CLValue::from_t(start).unwrap_or_revert());
+}
+
+/// Gets the contribution analytics of a remittance as of the current block.
+///
+/// The hourly buckets are aged to block time first, so
+/// `recent_contributions()` of the result counts the last 24 hours.
+/// Confidential remittances keep amounts private and report no analytics.
+#[cfg(feature = "views")]
+pub fn get_remittance_analytics_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let mut analytics = storage::get_analytics(remittance_id);
+    analytics.advance(get_current_timestamp());
+    runtime::ret(CLValue::from_t(analytics).unwrap_or_revert());
+}
+
+/// Gets the insurance policy of a remittance, if it is insured.
+#[cfg(feature = "views")]
+pub fn get_insurance_policy_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let policy = storage::get_insurance_policy(remittance_id);
+    runtime::ret(CLValue::from_t(policy).unwrap_or_revert());
+}
+
+/// Gets the fee cover of a remittance, if anyone pre-funded its fee.
+#[cfg(feature = "views")]
+pub fn get_fee_cover_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let cover = storage::get_fee_cover(remittance_id);
+    runtime::ret(CLValue::from_t(cover).unwrap_or_revert());
+}
+
+/// Gets the itemized split of a remittance's release, once released.
+#[cfg(feature = "views")]
+pub fn get_release_breakdown_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let breakdown = storage::get_release_breakdown(remittance_id);
+    runtime::ret(CLValue::from_t(breakdown).unwrap_or_revert());
+}
+
+/// Gets a remittance's refund policy (full refunds unless one was chosen).
+#[cfg(feature = "views")]
+pub fn get_refund_policy_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    // Fail on unknown IDs rather than reporting full refunds
+    storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    let policy = storage::get_refund_policy(remittance_id);
+    runtime::ret(CLValue::from_t(policy).unwrap_or_revert());
+}
+
+/// Gets the balance of the insurance pool.
+#[cfg(feature = "views")]
+pub fn get_insurance_pool_entry() {
+    let pool = storage::get_ledger_total(storage::INSURANCE_POOL);
+    runtime::ret(CLValue::from_t(pool).unwrap_or_revert());
+}
+
+/// Gets the settlement partner a release was routed to, if any.
+#[cfg(feature = "views")]
+pub fn get_payout_route_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let route = storage::get_payout_route(remittance_id);
+    runtime::ret(CLValue::from_t(route).unwrap_or_revert());
+}
+
+/// Gets the bridge operator account.
+#[cfg(feature = "views")]
+pub fn get_bridge_operator_entry() {
+    let operator = storage::get_bridge_operator();
+    runtime::ret(CLValue::from_t(operator).unwrap_or_revert());
+}
+
+/// Gets the bridge payout requested for a remittance, if any.
+///
+/// The record is kept after confirmation, as the destination of the payout,
+/// and removed on cancellation.
+#[cfg(feature = "views")]
+pub fn get_bridge_payout_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let payout = storage::get_bridge_payout(remittance_id);
+    runtime::ret(CLValue::from_t(payout).unwrap_or_revert());
+}
+
+/// Gets the documents attached to a remittance.
+#[cfg(feature = "views")]
+pub fn get_documents_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    // Fail on unknown IDs rather than returning an empty list
+    storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    let documents = storage::get_documents(remittance_id);
+    runtime::ret(CLValue::from_t(documents).unwrap_or_revert());
+}
+
+/// Gets the updates posted on a remittance, oldest first.
+#[cfg(feature = "views")]
+pub fn get_updates_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    // Fail on unknown IDs rather than returning an empty list
+    storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    let updates = storage::get_updates(remittance_id);
+    runtime::ret(CLValue::from_t(updates).unwrap_or_revert());
+}
+
+/// Gets a remittance's purpose in the requested language.
+///
+/// Falls back to a translation for the primary language (`"pt"` for
+/// `"pt-br"`), then to the purpose given at creation.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `lang` - Requested language code (String)
+#[cfg(feature = "views")]
+pub fn get_purpose_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let lang: String = args::get("lang");
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    let purposes = storage::get_localized_purposes(remittance_id);
+
+    let purpose = match localized_purpose(&purposes, &lang) {
+        Some(localized) => String::from(localized),
+        None => remittance.purpose,
+    };
+    runtime::ret(CLValue::from_t(purpose).unwrap_or_revert());
+}
+
+/// Gets the recipient's acknowledgement of a released remittance, if any.
+#[cfg(feature = "views")]
+pub fn get_acknowledgement_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let acknowledgement = storage::get_acknowledgement(remittance_id);
+    runtime::ret(CLValue::from_t(acknowledgement).unwrap_or_revert());
+}
+
+/// Gets an account's payout address book.
+#[cfg(feature = "views")]
+pub fn get_payout_targets_entry() {
+    let account: AccountHash = args::get("account");
+
+    let targets = storage::get_payout_targets(account);
+    runtime::ret(CLValue::from_t(targets).unwrap_or_revert());
+}
+
+/// Gets progress towards a remittance's target in basis points (0-10000).
+#[cfg(feature = "views")]
+pub fn get_progress_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(remittance.progress_bps()).unwrap_or_revert());
+}
+
+/// Gets a remittance's amounts, deadline, and status in one response.
+#[cfg(feature = "views")]
+pub fn get_funding_state_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    let state = remittance.funding_state(get_current_timestamp());
+    runtime::ret(CLValue::from_t(state).unwrap_or_revert());
+}
+
+/// Gets the number of unique contributors to a remittance.
+#[cfg(feature = "views")]
+pub fn get_contributor_count_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    // Fail on unknown IDs rather than returning zero
+    storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    let count = storage::get_contributor_count(remittance_id);
+    runtime::ret(CLValue::from_t(count).unwrap_or_revert());
+}
+
+/// Gets the number of entries in a remittance's ledger.
+#[cfg(feature = "views")]
+pub fn get_ledger_length_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    // Fail on unknown IDs rather than returning zero
+    storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    let length = storage::get_ledger_length(remittance_id);
+    runtime::ret(CLValue::from_t(length).unwrap_or_revert());
+}
+
+/// Gets a page of a remittance's ledger entries, oldest first.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `start` - Index of the first entry to return (u64)
+/// * `count` - Maximum number of entries to return (max the `get_gas_limits` page size)
+///
+/// # Returns
+///
+/// `Vec<LedgerEntry>`, shorter than `count` at the end of the ledger
+#[cfg(feature = "views")]
+pub fn get_ledger_entries_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let start: u64 = args::get("start");
+    let count: u64 = args::get("count");
+
+    check_page_size(count);
+
+    // Fail on unknown IDs rather than returning an empty list
+    storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    let end = storage::get_ledger_length(remittance_id).min(start.saturating_add(count));
+    let entries: Vec<LedgerEntry> = (start..end)
+        .filter_map(|index| storage::get_ledger_entry(remittance_id, index))
+        .collect();
+    runtime::ret(CLValue::from_t(entries).unwrap_or_revert());
+}
+
+/// Gets a page of the IDs of remittances created between two days.
+///
+/// Days are block time divided by `MILLIS_PER_DAY`, and both ends are
+/// inclusive. IDs are returned oldest first, skipping the first `offset`.
+/// Private remittances and those created before the index existed are not
+/// included.
+#[cfg(feature = "views")]
+pub fn get_remittances_created_between_entry() {
+    let day_from: u64 = args::get("day_from");
+    let day_to: u64 = args::get("day_to");
+    let offset: u64 = args::get("offset");
+    let limit: u64 = args::get("limit");
+
+    check_page_size(limit);
+    if day_from > day_to || day_to - day_from >= MAX_DAY_RANGE {
+        runtime::revert(Error::InvalidDayRange);
+    }
+
+    let remittance_ids: Vec<u64> = (day_from..=day_to)
+        .flat_map(storage::get_day_remittances)
+        .skip(offset as usize)
+        .take(limit as usize)
+        .collect();
+    runtime::ret(CLValue::from_t(remittance_ids).unwrap_or_revert());
+}
+
+/// Checks if a refund has been claimed.
+#[cfg(feature = "views")]
+pub fn is_refund_claimed_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let contributor: AccountHash = args::get("contributor");
+
+    let claimed = storage::is_refund_claimed(remittance_id, contributor);
+    runtime::ret(CLValue::from_t(claimed).unwrap_or_revert());
+}
+
+/// Gets the current platform fee in basis points.
+#[cfg(feature = "views")]
+pub fn get_platform_fee_entry() {
+    let fee_bps = storage::get_platform_fee_bps();
+    runtime::ret(CLValue::from_t(fee_bps).unwrap_or_revert());
+}
+
+/// Gets the owner, fee settings, pause state, remittance count, code
+/// version, and purse balance in one call.
+///
+/// # Returns
+///
+/// `ContractInfo`
+#[cfg(feature = "views")]
+pub fn get_contract_info_entry() {
+    let info = ContractInfo {
+        owner: storage::get_contract_owner(),
+        fee_collector: storage::get_fee_collector(),
+        platform_fee_bps: storage::get_platform_fee_bps(),
+        is_paused: storage::is_contract_paused(),
+        remittance_count: storage::get_remittance_count(),
+        contract_version: CONTRACT_VERSION,
+        purse_balance: utils::get_contract_purse_balance().unwrap_or_revert(),
+    };
+    runtime::ret(CLValue::from_t(info).unwrap_or_revert());
+}
+
+/// Checks whether the contract is paused.
+#[cfg(feature = "views")]
+pub fn is_paused_entry() {
+    let paused = storage::is_contract_paused();
+    runtime::ret(CLValue::from_t(paused).unwrap_or_revert());
+}
+
+/// Checks whether the owner has renounced admin control.
+#[cfg(feature = "views")]
+pub fn is_admin_renounced_entry() {
+    let renounced = storage::is_admin_renounced();
+    runtime::ret(CLValue::from_t(renounced).unwrap_or_revert());
+}
+
+/// Gets the contract owner account.
+#[cfg(feature = "views")]
+pub fn get_owner_entry() {
+    let owner = storage::get_contract_owner();
+    runtime::ret(CLValue::from_t(owner).unwrap_or_revert());
+}
+
+/// Gets the account platform fees are paid to.
+#[cfg(feature = "views")]
+pub fn get_fee_collector_entry() {
+    let collector = storage::get_fee_collector();
+    runtime::ret(CLValue::from_t(collector).unwrap_or_revert());
+}
+
+/// Dry-runs `release_funds` for the caller without changing state.
+///
+/// Returns `Ok(())` if the release would succeed, otherwise `Err(code)` with
+/// the error code the release would revert with.
+#[cfg(feature = "views")]
+pub fn validate_release_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let caller = utils::get_caller();
+
+    let result = to_validation_result(check_release(remittance_id, caller));
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+/// Dry-runs `claim_refund` for a contributor without changing state.
+///
+/// Returns `Ok(())` if the refund claim would succeed, otherwise `Err(code)`
+/// with the error code the claim would revert with.
+#[cfg(feature = "views")]
+pub fn validate_refund_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let contributor: AccountHash = args::get("contributor");
+
+    let result = to_validation_result(check_refund(remittance_id, contributor));
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+/// Gets the current platform fee in parts-per-million.
+#[cfg(feature = "views")]
+pub fn get_platform_fee_ppm_entry() {
+    let fee_ppm = storage::get_platform_fee_ppm();
+    runtime::ret(CLValue::from_t(fee_ppm).unwrap_or_revert());
+}
+
+/// Gets the claimable creator reward balance for an account.
+#[cfg(feature = "views")]
+pub fn get_creator_rewards_entry() {
+    let creator: AccountHash = args::get("creator");
+
+    let amount = storage::get_creator_rewards(creator);
+    runtime::ret(CLValue::from_t(amount).unwrap_or_revert());
+}
+
+/// Gets the creator fee share in basis points of the platform fee.
+#[cfg(feature = "views")]
+pub fn get_creator_fee_share_entry() {
+    let share_bps = storage::get_creator_fee_share_bps();
+    runtime::ret(CLValue::from_t(share_bps).unwrap_or_revert());
+}
+
+/// Gets the per-account rate limits (zero limits are disabled).
+#[cfg(feature = "views")]
+pub fn get_rate_limits_entry() {
+    let limits = storage::get_rate_limits();
+    runtime::ret(CLValue::from_t(limits).unwrap_or_revert());
+}
+
+/// Gets the self-remittance and recipient contribution guards.
+#[cfg(feature = "views")]
+pub fn get_payment_guards_entry() {
+    let guards = storage::get_payment_guards();
+    runtime::ret(CLValue::from_t(guards).unwrap_or_revert());
+}
+
+/// Gets the per-call limits on batch sizes, page sizes, and contributors
+/// processed.
+#[cfg(feature = "views")]
+pub fn get_gas_limits_entry() {
+    let limits = storage::get_gas_limits();
+    runtime::ret(CLValue::from_t(limits).unwrap_or_revert());
+}
+
+/// Gets the most a creator may extend a deadline in total, in milliseconds.
+#[cfg(feature = "views")]
+pub fn get_max_deadline_extension_entry() {
+    let max_extension_ms = storage::get_max_deadline_extension();
+    runtime::ret(CLValue::from_t(max_extension_ms).unwrap_or_revert());
+}
+
+/// Gets the largest target amount a new remittance may have (zero = no cap).
+#[cfg(feature = "views")]
+pub fn get_max_target_amount_entry() {
+    let max_target_amount = storage::get_max_target_amount();
+    runtime::ret(CLValue::from_t(max_target_amount).unwrap_or_revert());
+}
+
+/// Gets the smallest target amount a new remittance may have (zero = no
+/// minimum).
+#[cfg(feature = "views")]
+pub fn get_min_target_amount_entry() {
+    let min_target_amount = storage::get_min_target_amount();
+    runtime::ret(CLValue::from_t(min_target_amount).unwrap_or_revert());
+}
+
+/// Gets the largest target amount a new remittance to a KYB-verified
+/// business may have (zero = the standard maximum applies).
+#[cfg(feature = "views")]
+pub fn get_kyb_max_target_amount_entry() {
+    let kyb_max_target_amount = storage::get_kyb_max_target_amount();
+    runtime::ret(CLValue::from_t(kyb_max_target_amount).unwrap_or_revert());
+}
+
+/// Gets a namespace by name, if it exists.
+#[cfg(feature = "views")]
+pub fn get_namespace_entry() {
+    let namespace: String = args::get("namespace");
+
+    let entry = storage::get_namespace(&namespace);
+    runtime::ret(CLValue::from_t(entry).unwrap_or_revert());
+}
+
+/// Gets a category template by name.
+#[cfg(feature = "views")]
+pub fn get_category_entry() {
+    let category: String = args::get("category");
+
+    let template = storage::get_category(&category);
+    runtime::ret(CLValue::from_t(template).unwrap_or_revert());
+}
+
+/// Gets the name of the category a remittance was created in, if any.
+#[cfg(feature = "views")]
+pub fn get_remittance_category_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let category = storage::get_remittance_category(remittance_id);
+    runtime::ret(CLValue::from_t(category).unwrap_or_revert());
+}
+
+/// Gets why the recipient declined a remittance, if they did.
+#[cfg(feature = "views")]
+pub fn get_decline_reason_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let reason = storage::get_decline_reason(remittance_id);
+    runtime::ret(CLValue::from_t(reason).unwrap_or_revert());
+}
+
+/// Gets the name of the namespace a remittance belongs to, if any.
+#[cfg(feature = "views")]
+pub fn get_remittance_namespace_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+
+    let namespace = storage::get_remittance_namespace(remittance_id);
+    runtime::ret(CLValue::from_t(namespace).unwrap_or_revert());
+}
+
+/// Gets how frontends should present this instance.
+#[cfg(feature = "views")]
+pub fn get_branding_entry() {
+    let branding = storage::get_branding();
+    runtime::ret(CLValue::from_t(branding).unwrap_or_revert());
+}
+
+/// Gets the release escalation intervals in milliseconds.
+#[cfg(feature = "views")]
+pub fn get_sla_intervals_entry() {
+    let intervals_ms = storage::get_sla_intervals();
+    runtime::ret(CLValue::from_t(intervals_ms).unwrap_or_revert());
+}
+
+/// Gets the funded remittances in a range of IDs that have gone unreleased
+/// past the first escalation interval.
+///
+/// # Arguments (via runtime args)
+///
+/// * `start_id` - First remittance ID to inspect (u64)
+/// * `count` - Number of consecutive IDs to inspect (max the `get_gas_limits` page size)
+///
+/// # Returns
+///
+/// IDs of the overdue remittances (Vec<u64>)
+#[cfg(feature = "views")]
+pub fn get_overdue_remittances_entry() {
+    let start_id: u64 = args::get("start_id");
+    let count: u64 = args::get("count");
+
+    check_page_size(count);
+
+    let last_id = start_id
+        .saturating_add(count - 1)
+        .min(storage::get_remittance_count());
+
+    let intervals_ms = storage::get_sla_intervals();
+    let timestamp = get_current_timestamp();
+    let overdue: Vec<u64> = (start_id..=last_id)
+        .filter(|id| {
+            awaiting_release(*id)
+                .map_or(false, |(_, sla)| sla.due_level(&intervals_ms, timestamp) > 0)
+        })
+        .collect();
+
+    runtime::ret(CLValue::from_t(overdue).unwrap_or_revert());
+}
+
+/// Gets the insurance premium charged on new insured remittances, in basis
+/// points.
+#[cfg(feature = "views")]
+pub fn get_insurance_premium_entry() {
+    let premium_bps = storage::get_insurance_premium_bps();
+    runtime::ret(CLValue::from_t(premium_bps).unwrap_or_revert());
+}
+
+/// Gets the release review policy (a zero threshold disables review).
+#[cfg(feature = "views")]
+pub fn get_review_policy_entry() {
+    let policy = storage::get_review_policy();
+    runtime::ret(CLValue::from_t(policy).unwrap_or_revert());
+}
+
+/// Gets the registered risk engine, if any.
+#[cfg(feature = "views")]
+pub fn get_risk_engine_entry() {
+    let engine = storage::get_risk_engine();
+    runtime::ret(CLValue::from_t(engine).unwrap_or_revert());
+}
+
+/// Gets the current fee rounding mode (0 = floor, 1 = ceil, 2 = round-half-up).
+#[cfg(feature = "views")]
+pub fn get_fee_rounding_entry() {
+    let rounding = storage::get_fee_rounding();
+    runtime::ret(CLValue::from_t(rounding as u8).unwrap_or_revert());
+}
+
+/// Gets the fee model (0 = on release, 1 = on contribution).
+#[cfg(feature = "views")]
+pub fn get_fee_model_entry() {
+    let model = storage::get_fee_model();
+    runtime::ret(CLValue::from_t(model as u8).unwrap_or_revert());
+}
+
+/// Gets the highest platform fee this installation accepts, in ppm.
+#[cfg(feature = "views")]
+pub fn get_max_fee_ppm_entry() {
+    let max_fee_ppm = storage::get_max_fee_ppm();
+    runtime::ret(CLValue::from_t(max_fee_ppm).unwrap_or_revert());
+}
+
+/// Gets the target a new remittance needs for its recipient to net
+/// `desired_net` after the current platform fee and rounding.
+///
+/// Uses the fee a remittance created with the same `category` and
+/// `namespace` would pay. Under the on-contribute fee model contributions
+/// are escrowed net of the fee, so the target already is the payout.
+///
+/// # Arguments (via runtime args)
+///
+/// * `desired_net` - Amount the recipient should receive (U512)
+/// * `category` - Optional category the remittance will be created in
+///   (String)
+/// * `namespace` - Optional namespace the remittance will belong to
+///   (String)
+///
+/// # Returns
+///
+/// Target amount to create the remittance with (U512)
+#[cfg(feature = "views")]
+pub fn compute_grossed_up_target_entry() {
+    let desired_net: U512 = args::get("desired_net");
+
+    let category_fee_ppm = args::get_optional::<String>("category").and_then(|name| {
+        storage::get_category(&name)
+            .unwrap_or_revert_with(Error::CategoryNotFound)
+            .fee_ppm
+    });
+    let fee_ppm = match category_fee_ppm {
+        Some(fee_ppm) => fee_ppm,
+        None => match args::get_optional::<String>("namespace") {
+            Some(name) => {
+                storage::get_namespace(&name)
+                    .unwrap_or_revert_with(Error::NamespaceNotFound)
+                    .fee_ppm
+            }
+            None => storage::get_platform_fee_ppm(),
+        },
+    };
+
+    let target = if storage::get_fee_model() == FeeModel::OnContribute {
+        desired_net
+    } else {
+        utils::gross_up(&desired_net, fee_ppm, storage::get_fee_rounding())
+    };
+    runtime::ret(CLValue::from_t(target).unwrap_or_revert());
+}
+
+// ============================================================================
+// Admin Functions (Owner Only)
+// ============================================================================
+
+/// Sets the platform fee (owner only).
+#[cfg(feature = "admin")]
+pub fn set_platform_fee_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_fee_bps: u64 = args::get("fee_bps");
+
+    let new_fee_ppm = new_fee_bps
+        .checked_mul(PPM_PER_BPS)
+        .unwrap_or_revert_with(Error::FeeTooHigh);
+
+    if new_fee_ppm > storage::get_max_fee_ppm() {
+        runtime::revert(Error::FeeTooHigh);
+    }
+
+    let old_fee_bps = storage::get_platform_fee_bps();
+
+    // Update the platform fee
+    storage::set_platform_fee_ppm(new_fee_ppm);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::PlatformFeeUpdated {
+        old_fee_bps,
+        new_fee_bps,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets the platform fee in parts-per-million (owner only).
+#[cfg(feature = "admin")]
+pub fn set_platform_fee_ppm_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_fee_ppm: u64 = args::get("fee_ppm");
+
+    if new_fee_ppm > storage::get_max_fee_ppm() {
+        runtime::revert(Error::FeeTooHigh);
+    }
+
+    let old_fee_ppm = storage::get_platform_fee_ppm();
+
+    storage::set_platform_fee_ppm(new_fee_ppm);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::PlatformFeePpmUpdated {
+        old_fee_ppm,
+        new_fee_ppm,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Lowers the platform fee cap for this installation (owner only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `max_fee_ppm` - New cap in ppm; must not exceed the current cap or be
+///   below the current platform fee
+#[cfg(feature = "admin")]
+pub fn lower_max_fee_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_max_fee_ppm: u64 = args::get("max_fee_ppm");
+    let old_max_fee_ppm = storage::get_max_fee_ppm();
+
+    if new_max_fee_ppm > old_max_fee_ppm {
+        runtime::revert(Error::MaxFeeRaised);
+    }
+
+    // Lower the fee first; the cap never sits below the fee in force
+    if new_max_fee_ppm < storage::get_platform_fee_ppm() {
+        runtime::revert(Error::FeeTooHigh);
+    }
+
+    storage::set_max_fee_ppm(new_max_fee_ppm);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::MaxFeeLowered {
+        old_max_fee_ppm,
+        new_max_fee_ppm,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets the most a creator may extend a remittance's deadline in total
+/// (owner only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `max_extension_ms` - Maximum total extension in milliseconds (u64);
+///   zero disables extensions
+#[cfg(feature = "admin")]
+pub fn set_max_deadline_extension_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_max_extension_ms: u64 = args::get("max_extension_ms");
+    let old_max_extension_ms = storage::get_max_deadline_extension();
+
+    storage::set_max_deadline_extension(new_max_extension_ms);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::MaxDeadlineExtensionUpdated {
+        old_max_extension_ms,
+        new_max_extension_ms,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets the largest target amount new remittances may have (owner only).
+///
+/// Existing remittances are not affected.
+///
+/// # Arguments (via runtime args)
+///
+/// * `max_target_amount` - Maximum target in motes (U512); zero removes the
+///   cap. Must not be below the minimum target.
+#[cfg(feature = "admin")]
+pub fn set_max_target_amount_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_max_target_amount: U512 = args::get("max_target_amount");
+    if !new_max_target_amount.is_zero()
+        && new_max_target_amount < storage::get_min_target_amount()
+    {
+        runtime::revert(Error::InvalidTargetAmount);
+    }
+    let old_max_target_amount = storage::get_max_target_amount();
+
+    storage::set_max_target_amount(new_max_target_amount);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::MaxTargetAmountUpdated {
+        old_max_target_amount,
+        new_max_target_amount,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets the smallest target amount new remittances may have (owner only).
+///
+/// Existing remittances are not affected.
+///
+/// # Arguments (via runtime args)
+///
+/// * `min_target_amount` - Minimum target in motes (U512); zero removes the
+///   minimum. Must not exceed the maximum target, if one is set.
+#[cfg(feature = "admin")]
+pub fn set_min_target_amount_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_min_target_amount: U512 = args::get("min_target_amount");
+    let max_target_amount = storage::get_max_target_amount();
+    if !max_target_amount.is_zero() && new_min_target_amount > max_target_amount {
+        runtime::revert(Error::InvalidTargetAmount);
+    }
+    let old_min_target_amount = storage::get_min_target_amount();
+
+    storage::set_min_target_amount(new_min_target_amount);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::MinTargetAmountUpdated {
+        old_min_target_amount,
+        new_min_target_amount,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets the largest target amount new remittances to KYB-verified
+/// businesses may have (owner only).
+///
+/// Only takes effect while it is above the standard maximum, which keeps
+/// applying to everyone when no maximum is set. Existing remittances are not
+/// affected.
+///
+/// # Arguments (via runtime args)
+///
+/// * `kyb_max_target_amount` - Maximum target in motes (U512); zero removes
+///   the higher cap. Must not be below the minimum target.
+#[cfg(feature = "admin")]
+pub fn set_kyb_max_target_amount_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_kyb_max_target_amount: U512 = args::get("kyb_max_target_amount");
+    if !new_kyb_max_target_amount.is_zero()
+        && new_kyb_max_target_amount < storage::get_min_target_amount()
+    {
+        runtime::revert(Error::InvalidTargetAmount);
+    }
+    let old_kyb_max_target_amount = storage::get_kyb_max_target_amount();
+
+    storage::set_kyb_max_target_amount(new_kyb_max_target_amount);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::KybMaxTargetAmountUpdated {
+        old_kyb_max_target_amount,
+        new_kyb_max_target_amount,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets how frontends should present this instance (owner only).
+///
+/// Replaces the whole branding; pass an empty string to leave a field to
+/// the frontend's default.
+///
+/// # Arguments (via runtime args)
+///
+/// * `platform_name` - Name shown for the platform (String, required, max
+///   `MAX_PLATFORM_NAME_LENGTH`)
+/// * `logo_cid` - IPFS CID of the logo (String, max `MAX_CID_LENGTH`)
+/// * `support_url` - Where users can get help (String, max
+///   `MAX_PAYLOAD_URI_LENGTH`)
+/// * `currency_display` - How amounts are labelled (String, max
+///   `MAX_CURRENCY_DISPLAY_LENGTH`)
+#[cfg(feature = "admin")]
+pub fn set_branding_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let branding = Branding {
+        platform_name: args::get("platform_name"),
+        logo_cid: args::get("logo_cid"),
+        support_url: args::get("support_url"),
+        currency_display: args::get("currency_display"),
+    };
+
+    if branding.platform_name.trim().is_empty()
+        || branding.platform_name.len() > MAX_PLATFORM_NAME_LENGTH
+        || branding.logo_cid.len() > MAX_CID_LENGTH
+        || branding.support_url.len() > MAX_PAYLOAD_URI_LENGTH
+        || branding.currency_display.len() > MAX_CURRENCY_DISPLAY_LENGTH
+    {
+        runtime::revert(Error::InvalidBranding);
+    }
+
+    let platform_name = branding.platform_name.clone();
+    storage::set_branding(branding);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::BrandingUpdated {
+        platform_name,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Creates a namespace administered by its own operator (owner only).
+///
+/// Remittances created with `namespace` are charged the namespace's fee,
+/// which is paid to the namespace owner, and follow its pause switch.
+///
+/// # Arguments (via runtime args)
+///
+/// * `namespace` - Name of the namespace (String, 1-`MAX_NAMESPACE_LENGTH`
+///   lowercase letters, digits, or hyphens)
+/// * `namespace_owner` - Operator administering it (AccountHash)
+/// * `fee_ppm` - Platform fee in parts per million (u64), within the
+///   installation's cap
+#[cfg(feature = "admin")]
+pub fn create_namespace_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let namespace: String = args::get("namespace");
+    let namespace_owner: AccountHash = args::get("namespace_owner");
+    let fee_ppm: u64 = args::get("fee_ppm");
+
+    utils::validate_namespace(&namespace).unwrap_or_revert();
+    utils::validate_account_hash(&namespace_owner).unwrap_or_revert();
+
+    if storage::get_namespace(&namespace).is_some() {
+        runtime::revert(Error::NamespaceExists);
+    }
+
+    if fee_ppm > storage::get_max_fee_ppm() {
+        runtime::revert(Error::FeeTooHigh);
+    }
+
+    storage::set_namespace(
+        &namespace,
+        Namespace {
+            owner: namespace_owner,
+            fee_ppm,
+            paused: false,
+        },
+    );
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::NamespaceCreated {
+        namespace,
+        owner: namespace_owner,
+        fee_ppm,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Gets a namespace, reverting unless `caller` owns it.
+fn owned_namespace(namespace: &str, caller: AccountHash) -> Namespace {
+    let entry = storage::get_namespace(namespace).unwrap_or_revert_with(Error::NamespaceNotFound);
+    if caller != entry.owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    entry
+}
+
+/// Sets a namespace's platform fee (namespace owner only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `namespace` - Name of the namespace (String)
+/// * `fee_ppm` - Platform fee in parts per million (u64), within the
+///   installation's cap
+pub fn set_namespace_fee_entry() {
+    let namespace: String = args::get("namespace");
+    let new_fee_ppm: u64 = args::get("fee_ppm");
+    let caller = utils::get_caller();
+
+    let mut entry = owned_namespace(&namespace, caller);
+
+    if new_fee_ppm > storage::get_max_fee_ppm() {
+        runtime::revert(Error::FeeTooHigh);
+    }
+
+    let old_fee_ppm = entry.fee_ppm;
+    entry.fee_ppm = new_fee_ppm;
+    storage::set_namespace(&namespace, entry);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::NamespaceFeeUpdated {
+        namespace,
+        old_fee_ppm,
+        new_fee_ppm,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Pauses contributions and releases in a namespace (namespace owner only).
+///
+/// Cancellation and refunds stay open.
+pub fn pause_namespace_entry() {
+    let namespace: String = args::get("namespace");
+    let caller = utils::get_caller();
+
+    let mut entry = owned_namespace(&namespace, caller);
+    entry.paused = true;
+    storage::set_namespace(&namespace, entry);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::NamespacePaused { namespace, timestamp }.emit();
+}
+
+/// Unpauses a namespace (namespace owner only).
+pub fn unpause_namespace_entry() {
+    let namespace: String = args::get("namespace");
+    let caller = utils::get_caller();
+
+    let mut entry = owned_namespace(&namespace, caller);
+    entry.paused = false;
+    storage::set_namespace(&namespace, entry);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::NamespaceUnpaused { namespace, timestamp }.emit();
+}
+
+/// Sets the intervals after funding at which unreleased remittances are
+/// escalated (owner only).
+///
+/// Levels already reported for a remittance are kept, so shortening the
+/// intervals does not repeat escalations.
+///
+/// # Arguments (via runtime args)
+///
+/// * `intervals_ms` - Escalation intervals in milliseconds (Vec<u64>), e.g.
+///   72 hours then 7 days; non-zero, strictly increasing, at most
+///   `MAX_SLA_INTERVALS`. Empty disables escalation
+#[cfg(feature = "admin")]
+pub fn set_sla_intervals_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let intervals_ms: Vec<u64> = args::get("intervals_ms");
+    utils::validate_sla_intervals(&intervals_ms).unwrap_or_revert();
+
+    storage::set_sla_intervals(intervals_ms.clone());
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::SlaIntervalsUpdated {
+        intervals_ms,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets the defaults applied to remittances created in a category (owner
+/// only).
+///
+/// Replaces any existing template for the category; remittances already
+/// created keep the terms they were created with.
+///
+/// # Arguments (via runtime args)
+///
+/// * `category` - Name of the category (String, 1-`MAX_CATEGORY_LENGTH`
+///   lowercase letters, digits, or hyphens)
+/// * `deadline_days` - Days from creation until the default deadline (u64);
+///   0 for none
+/// * `fee_bps` - Optional platform fee in basis points (u64), within the
+///   installation's cap; the global or namespace fee applies when absent
+/// * `refund_excess` - Whether contributions beyond the target are refunded
+///   by default (bool)
+#[cfg(feature = "admin")]
+pub fn set_category_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let category: String = args::get("category");
+    utils::validate_category(&category).unwrap_or_revert();
+
+    let fee_ppm = args::get_optional::<u64>("fee_bps").map(|fee_bps| {
+        let fee_ppm = fee_bps
+            .checked_mul(PPM_PER_BPS)
+            .unwrap_or_revert_with(Error::FeeTooHigh);
+        if fee_ppm > storage::get_max_fee_ppm() {
+            runtime::revert(Error::FeeTooHigh);
+        }
+        fee_ppm
+    });
+
+    let template = CategoryTemplate {
+        deadline_days: args::get("deadline_days"),
+        fee_ppm,
+        refund_excess: args::get("refund_excess"),
+    };
+    let (deadline_days, refund_excess) = (template.deadline_days, template.refund_excess);
+    storage::set_category(&category, template);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::CategoryTemplateSet {
+        category,
+        deadline_days,
+        fee_ppm,
+        refund_excess,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets the insurance premium charged on new insured remittances (owner
+/// only).
+///
+/// Remittances keep the premium in force when they were created.
+///
+/// # Arguments (via runtime args)
+///
+/// * `premium_bps` - Premium in basis points (u64, max
+///   `MAX_INSURANCE_PREMIUM_BPS`)
+#[cfg(feature = "admin")]
+pub fn set_insurance_premium_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_premium_bps: u64 = args::get("premium_bps");
+    if new_premium_bps > MAX_INSURANCE_PREMIUM_BPS {
+        runtime::revert(Error::InvalidInsuranceTerms);
+    }
+
+    let old_premium_bps = storage::get_insurance_premium_bps();
+
+    storage::set_insurance_premium_bps(new_premium_bps);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::InsurancePremiumUpdated {
+        old_premium_bps,
+        new_premium_bps,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets the fee rounding mode (owner only).
+#[cfg(feature = "admin")]
+pub fn set_fee_rounding_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_mode: u8 = args::get("rounding");
+    let rounding = FeeRounding::from_u8(new_mode).unwrap_or_revert();
+
+    let old_mode = storage::get_fee_rounding() as u8;
+
+    storage::set_fee_rounding(rounding);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::FeeRoundingUpdated {
+        old_mode,
+        new_mode,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets the share of the platform fee rebated to creators (owner only).
+#[cfg(feature = "admin")]
+pub fn set_creator_fee_share_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_share_bps: u64 = args::get("share_bps");
+
+    if new_share_bps > BASIS_POINTS {
+        runtime::revert(Error::InvalidFeeShare);
+    }
+
+    let old_share_bps = storage::get_creator_fee_share_bps();
+
+    storage::set_creator_fee_share_bps(new_share_bps);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::CreatorFeeShareUpdated {
+        old_share_bps,
+        new_share_bps,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets per-account rate limits on creation and contribution (owner only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `window_ms` - Length of the rolling window in milliseconds (u64)
+/// * `max_creations` - Remittances one account may create per window (u64);
+///   zero disables the limit
+/// * `max_contributions` - Contributions one account may make per window
+///   (u64); zero disables the limit
+#[cfg(feature = "admin")]
+pub fn set_rate_limits_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let limits = RateLimits {
+        window_ms: args::get("window_ms"),
+        max_creations: args::get("max_creations"),
+        max_contributions: args::get("max_contributions"),
+    };
+
+    if limits.window_ms == 0 && (limits.max_creations != 0 || limits.max_contributions != 0) {
+        runtime::revert(Error::InvalidRateLimitWindow);
+    }
+
+    storage::set_rate_limits(limits.clone());
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::RateLimitsUpdated {
+        window_ms: limits.window_ms,
+        max_creations: limits.max_creations,
+        max_contributions: limits.max_contributions,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets the payment guards (owner only).
+///
+/// A remittance's recipient may never be the fee collector; these guards
+/// additionally control self-remittances and contributions from the
+/// recipient.
+///
+/// # Arguments (via runtime args)
+///
+/// * `reject_self_remittance` - Whether creators may not name themselves as
+///   recipient (bool)
+/// * `recipient_contributions` - Treatment of contributions from the
+///   recipient (u8): 0 = allow, 1 = allow and flag, 2 = reject
+#[cfg(feature = "admin")]
+pub fn set_payment_guards_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let reject_self_remittance: bool = args::get("reject_self_remittance");
+    let recipient_contributions: u8 = args::get("recipient_contributions");
+    let guards =
+        PaymentGuards::new(reject_self_remittance, recipient_contributions).unwrap_or_revert();
+
+    storage::set_payment_guards(guards);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::PaymentGuardsUpdated {
+        reject_self_remittance,
+        recipient_contributions,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Sets the per-call gas-sanity limits (owner only).
+///
+/// Calls sized beyond them revert at once, so a misconfigured client cannot
+/// submit deploys that will always run out of gas.
+///
+/// # Arguments (via runtime args)
+///
+/// * `max_batch_size` - Most remittances or refunds one batch call may
+///   process (u64); zero means `MAX_BATCH_SIZE`, which it may not exceed
+/// * `max_page_size` - Most items one paginated view may return (u64); zero
+///   means `MAX_BATCH_SIZE`, which it may not exceed
+/// * `max_contributors` - Most contributors one call may process for a
+///   single remittance (u64); zero disables the limit
+#[cfg(feature = "admin")]
+pub fn set_gas_limits_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let limits = GasLimits {
+        max_batch_size: args::get("max_batch_size"),
+        max_page_size: args::get("max_page_size"),
+        max_contributors: args::get("max_contributors"),
+    };
+
+    if limits.max_batch_size > MAX_BATCH_SIZE || limits.max_page_size > MAX_BATCH_SIZE {
+        runtime::revert(Error::InvalidBatchSize);
+    }
+
+    storage::set_gas_limits(limits.clone());
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::GasLimitsUpdated {
+        max_batch_size: limits.max_batch_size,
+        max_page_size: limits.max_page_size,
+        max_contributors: limits.max_contributors,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Rewrites a batch of legacy or older-version remittances in the current
+/// encoding (owner only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `start_id` - First remittance ID to inspect (u64)
+/// * `count` - Number of consecutive IDs to inspect (max the `get_gas_limits` batch size)
+///
+/// # Returns
+///
+/// Number of records migrated (u64). IDs that are already migrated or do
+/// not exist are skipped, so batches can be safely re-run.
+#[cfg(feature = "admin")]
+pub fn migrate_records_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let start_id: u64 = args::get("start_id");
+    let count: u64 = args::get("count");
+
+    check_batch_size(count);
+
+    let last_id = start_id
+        .saturating_add(count - 1)
+        .min(storage::get_remittance_count());
+
+    let mut migrated = 0u64;
+    for id in start_id..=last_id {
+        let remittance = match storage::get_stored_format_version(id) {
+            Some(version) if version == REMITTANCE_FORMAT_VERSION => continue,
+            Some(_) => storage::get_remittance(id).ok(),
+            None => storage::get_legacy_remittance(id),
+        };
+
+        if let Some(remittance) = remittance {
+            storage::store_remittance(&remittance);
+            migrated += 1;
+        }
+    }
+
+    // Migrations run on upgraded code, which may emit newer event layouts
+    storage::store_event_schema_version(EVENT_SCHEMA_VERSION);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::RecordsMigrated {
+        start_id,
+        count,
+        migrated,
+        timestamp,
+    }
+    .emit();
+
+    runtime::ret(CLValue::from_t(migrated).unwrap_or_revert());
+}
+
+/// Rotates the contract owner to a new account (owner only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `new_owner` - AccountHash of the new owner
+#[cfg(feature = "admin")]
+pub fn rotate_owner_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_owner: AccountHash = args::get("new_owner");
+    utils::validate_account_hash(&new_owner).unwrap_or_revert();
+
+    storage::set_contract_owner(new_owner);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::OwnerRotated {
+        old_owner: owner,
+        new_owner,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Appoints the compliance officer (owner only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `officer` - AccountHash of the new compliance officer
+#[cfg(feature = "admin")]
+pub fn set_compliance_officer_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_officer: AccountHash = args::get("officer");
+    utils::validate_account_hash(&new_officer).unwrap_or_revert();
+
+    let old_officer = storage::get_compliance_officer();
+    storage::set_compliance_officer(new_officer);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::ComplianceOfficerUpdated {
+        old_officer,
+        new_officer,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Appoints the bridge operator (owner only).
+///
+/// The operator confirms cross-chain payouts and is reimbursed from escrow
+/// for them, so it should be the bridge relayer's account.
+///
+/// # Arguments (via runtime args)
+///
+/// * `operator` - AccountHash of the new bridge operator
+#[cfg(feature = "admin")]
+pub fn set_bridge_operator_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let new_operator: AccountHash = args::get("operator");
+    utils::validate_account_hash(&new_operator).unwrap_or_revert();
+
+    let old_operator = storage::get_bridge_operator();
+    storage::set_bridge_operator(new_operator);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::BridgeOperatorUpdated {
+        old_operator,
+        new_operator,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Adds a verified charity to the registry (compliance officer only).
+///
+/// Remittances to registered charities are released without a platform fee.
+///
+/// # Arguments (via runtime args)
+///
+/// * `charity` - AccountHash of the charity
+#[cfg(feature = "admin")]
+pub fn register_charity_entry() {
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let charity: AccountHash = args::get("charity");
+    utils::validate_account_hash(&charity).unwrap_or_revert();
+
+    storage::set_verified_charity(charity, true);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::CharityRegistered { charity, timestamp }.emit();
+}
+
+/// Marks a recipient as a KYB-verified business (compliance officer only).
+///
+/// Remittances to verified businesses may use the higher KYB target cap,
+/// and `is_business_payout` shows contributors they fund a registered
+/// merchant.
+///
+/// # Arguments (via runtime args)
+///
+/// * `business` - AccountHash of the business
+#[cfg(feature = "admin")]
+pub fn register_business_entry() {
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let business: AccountHash = args::get("business");
+    utils::validate_account_hash(&business).unwrap_or_revert();
+
+    storage::set_verified_business(business, true);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::BusinessRegistered { business, timestamp }.emit();
+}
+
+/// Sets the amount at or above which releases require travel-rule data
+/// (compliance officer only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `threshold` - Threshold in motes (U512); zero disables the requirement
+#[cfg(feature = "admin")]
+pub fn set_travel_rule_threshold_entry() {
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let new_threshold: U512 = args::get("threshold");
+
+    let old_threshold = storage::get_travel_rule_threshold();
+    storage::set_travel_rule_threshold(new_threshold);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::TravelRuleThresholdUpdated {
+        old_threshold,
+        new_threshold,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Registers the risk engine consulted before large releases
+/// (compliance officer only).
+///
+/// The engine must expose a `risk_score` entry point taking `creator`,
+/// `recipient` and `amount` and returning a `u64` score. Releases of at least
+/// `min_amount` revert with `RiskScoreTooHigh` when the score exceeds
+/// `max_score`.
+///
+/// # Arguments (via runtime args)
+///
+/// * `contract_hash` - ContractHash of the risk engine
+/// * `min_amount` - Smallest release (U512, motes) that gets scored
+/// * `max_score` - Highest score (u64) a release may have
+#[cfg(feature = "admin")]
+pub fn set_risk_engine_entry() {
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let contract_hash: ContractHash = args::get("contract_hash");
+    let min_amount: U512 = args::get("min_amount");
+    let max_score: u64 = args::get("max_score");
+
+    storage::set_risk_engine(Some(RiskEngine {
+        contract_hash,
+        min_amount,
+        max_score,
+    }));
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::RiskEngineSet {
+        contract_hash,
+        min_amount,
+        max_score,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Removes the risk engine so releases are no longer scored
+/// (compliance officer only).
+#[cfg(feature = "admin")]
+pub fn clear_risk_engine_entry() {
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    storage::set_risk_engine(None);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::RiskEngineCleared { timestamp }.emit();
+}
+
+/// Sets the amount at or above which releases are held for review
+/// (compliance officer only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `threshold` - Threshold in motes (U512); zero disables review
+/// * `window_ms` - Time the compliance officer has to approve a held release,
+///   in milliseconds (u64)
+#[cfg(feature = "admin")]
+pub fn set_review_policy_entry() {
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let policy = ReviewPolicy {
+        threshold: args::get("threshold"),
+        window_ms: args::get("window_ms"),
+    };
+
+    if !policy.threshold.is_zero() && policy.window_ms == 0 {
+        runtime::revert(Error::InvalidReviewWindow);
+    }
+
+    storage::set_review_policy(policy.clone());
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::ReviewPolicyUpdated {
+        threshold: policy.threshold,
+        window_ms: policy.window_ms,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Approves a release held for review and pays it out (compliance officer
+/// only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+///
+/// # Errors
+///
+/// Reverts with `ReviewWindowElapsed` once the review window has passed;
+/// the release can then only be rejected.
+#[cfg(feature = "admin")]
+pub fn approve_release_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    let mut remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    if !remittance.is_pending_review() {
+        runtime::revert(Error::NotPendingReview);
+    }
+
+    let timestamp = get_current_timestamp();
+    if timestamp > storage::get_review_deadline(remittance_id).unwrap_or_revert() {
+        runtime::revert(Error::ReviewWindowElapsed);
+    }
+
+    // Conditions may have changed since the release was requested
+    remittance.clear_flag(FLAG_PENDING_REVIEW);
+    check_releasable(remittance_id, &remittance).unwrap_or_else(|error| {
+        if error == Error::InsufficientEscrowBalance {
+            report_escrow_shortfall(remittance_id);
+        }
+        runtime::revert(error)
+    });
+
+    ContractEvent::ReleaseApproved {
+        remittance_id,
+        officer: caller,
+        timestamp,
+    }
+    .emit();
+
+    settle_release(remittance);
+}
+
+/// Rejects a release held for review; contributors can then claim refunds.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+///
+/// # Access Control
+///
+/// The compliance officer can reject at any time during review. Once the
+/// review window has passed without approval, anyone can reject, so funds
+/// are never stuck waiting for a decision.
+#[cfg(feature = "admin")]
+pub fn reject_release_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let caller = utils::get_caller();
+
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    let mut remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    if !remittance.is_pending_review() {
+        runtime::revert(Error::NotPendingReview);
+    }
+
+    let timestamp = get_current_timestamp();
+    let review_deadline = storage::get_review_deadline(remittance_id).unwrap_or_revert();
+    if caller != storage::get_compliance_officer() && timestamp <= review_deadline {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    // A rejected release is refunded like a cancellation
+    remittance.clear_flag(FLAG_PENDING_REVIEW);
+    remittance.mark_cancelled();
+    storage::store_remittance(&remittance);
+    close_escrow(&remittance);
+
+    ContractEvent::ReleaseRejected {
+        remittance_id,
+        rejected_by: caller,
+        total_amount: remittance.unreleased_amount(),
+        timestamp,
+    }
+    .emit();
+}
+
+/// Approves insurance claims on a cancelled or expired insured remittance
+/// (compliance officer only).
+///
+/// The officer approves once they have established that the remittance
+/// failed through no fault of its contributors, such as a creator who
+/// absconded. Coverage cannot be changed once approved.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `coverage_bps` - Top-up per contributor in basis points of their
+///   contribution (u64, max `MAX_INSURANCE_COVERAGE_BPS`)
+#[cfg(feature = "admin")]
+pub fn approve_insurance_claims_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let coverage_bps: u64 = args::get("coverage_bps");
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+    let mut policy =
+        storage::get_insurance_policy(remittance_id).unwrap_or_revert_with(Error::NotInsured);
+
+    if !remittance.is_refundable() {
+        runtime::revert(Error::NotCancelled);
+    }
+
+    if policy.is_approved() || coverage_bps == 0 || coverage_bps > MAX_INSURANCE_COVERAGE_BPS {
+        runtime::revert(Error::InvalidInsuranceTerms);
+    }
+
+    policy.coverage_bps = coverage_bps;
+    storage::store_insurance_policy(remittance_id, policy);
 
-    // Emit event
     let timestamp = get_current_timestamp();
-    ContractEvent::RefundClaimed {
+    ContractEvent::InsuranceClaimsApproved {
         remittance_id,
-        contributor: caller,
-        amount: contribution_amount,
+        coverage_bps,
+        officer: caller,
         timestamp,
     }
     .emit();
 }
 
-// ============================================================================
-// View Functions (Read-Only)
-// ============================================================================
+/// Cancels a remittance that violates the terms, such as a fraudulent
+/// campaign, and enables refunds (compliance officer only).
+///
+/// The reason is recorded in the `RemittanceForceCancelled` event so the
+/// decision can be audited.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `reason` - Why the remittance was cancelled (String, max
+///   `MAX_REASON_LENGTH`)
+#[cfg(feature = "admin")]
+pub fn force_cancel_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let reason: String = args::get("reason");
+    let caller = utils::get_caller();
 
-/// Gets remittance details by ID.
-pub fn get_remittance_entry() {
-    let remittance_id: u64 = runtime::get_named_arg("remittance_id");
-    let remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
-    runtime::ret(CLValue::from_t(remittance).unwrap_or_revert());
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    if reason.trim().is_empty() || reason.len() > MAX_REASON_LENGTH {
+        runtime::revert(Error::InvalidReason);
+    }
+
+    let mut remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    if remittance.is_released() {
+        runtime::revert(Error::AlreadyReleased);
+    }
+
+    if remittance.is_cancelled() {
+        runtime::revert(Error::RemittanceCancelled);
+    }
+
+    // Refunds are already enabled
+    if remittance.is_expired() {
+        runtime::revert(Error::RemittanceExpired);
+    }
+
+    // A held release is settled by `reject_release` instead
+    if remittance.is_pending_review() {
+        runtime::revert(Error::ReleasePendingReview);
+    }
+
+    // A bridge payout may already be in flight on the destination chain
+    if storage::get_bridge_payout(remittance_id).is_some() {
+        runtime::revert(Error::BridgePayoutPending);
+    }
+
+    remittance.mark_cancelled();
+    storage::store_remittance(&remittance);
+    close_escrow(&remittance);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::RemittanceForceCancelled {
+        remittance_id,
+        officer: caller,
+        reason,
+        total_amount: remittance.unreleased_amount(),
+        timestamp,
+    }
+    .emit();
 }
 
-/// Gets contribution amount for a specific contributor.
-pub fn get_contribution_entry() {
-    let remittance_id: u64 = runtime::get_named_arg("remittance_id");
-    let contributor: AccountHash = runtime::get_named_arg("contributor");
+/// Refunds one contributor in full from an active remittance, such as after
+/// a chargeback (compliance officer only).
+///
+/// The contribution leaves escrow and the remittance's current amount; the
+/// `ContributionForceRefunded` event records the officer and the reason.
+/// Only contributions still fully in escrow can be refunded this way.
+///
+/// # Arguments (via runtime args)
+///
+/// * `remittance_id` - ID of the remittance (u64)
+/// * `contributor` - Contributor to refund (AccountHash)
+/// * `reason` - Why the contribution was refunded (String, max
+///   `MAX_REASON_LENGTH`)
+#[cfg(feature = "admin")]
+pub fn force_refund_entry() {
+    let remittance_id = utils::get_remittance_id_arg();
+    let contributor: AccountHash = args::get("contributor");
+    let reason: String = args::get("reason");
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    if reason.trim().is_empty() || reason.len() > MAX_REASON_LENGTH {
+        runtime::revert(Error::InvalidReason);
+    }
+
+    let mut remittance = storage::get_remittance(remittance_id).unwrap_or_revert();
+
+    if !remittance.is_active() {
+        if remittance.is_released() {
+            runtime::revert(Error::AlreadyReleased);
+        } else if remittance.is_cancelled() {
+            runtime::revert(Error::RemittanceCancelled);
+        } else {
+            runtime::revert(Error::RemittanceExpired);
+        }
+    }
+
+    // An instant split has already paid part of every contribution out
+    if !remittance.released_amount.is_zero() {
+        runtime::revert(Error::AlreadyReleased);
+    }
+
+    if remittance.is_pending_review() {
+        runtime::revert(Error::ReleasePendingReview);
+    }
+
+    if storage::get_bridge_payout(remittance_id).is_some() {
+        runtime::revert(Error::BridgePayoutPending);
+    }
 
+    // Confidential amounts are not in contract state, so they read as zero
     let amount = storage::get_contribution(remittance_id, contributor);
-    runtime::ret(CLValue::from_t(amount).unwrap_or_revert());
+    if amount.is_zero() {
+        runtime::revert(Error::NoContribution);
+    }
+
+    remittance.current_amount = remittance
+        .current_amount
+        .checked_sub(amount)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    storage::store_remittance(&remittance);
+
+    storage::clear_contribution(remittance_id, contributor);
+    storage::decrease_ledger_total(storage::ESCROW_LIABILITIES, amount);
+    if remittance.is_open_ended() {
+        storage::store_pot_deposit(remittance_id, contributor, PotDeposit::default());
+    }
+
+    let contract_purse = storage::get_contract_purse();
+    utils::transfer_cspr(contract_purse, contributor, amount).unwrap_or_revert();
+
+    let timestamp = get_current_timestamp();
+    storage::update_contribution_weights(remittance_id, contributor, |weight| {
+        weight.withdraw(amount, timestamp)
+    });
+
+    ContractEvent::ContributionForceRefunded {
+        remittance_id,
+        officer: caller,
+        contributor,
+        amount,
+        new_total: remittance.current_amount,
+        reason,
+        timestamp,
+    }
+    .emit();
+
+    record_ledger_entry(&remittance, LedgerEntryKind::Refund, contributor, amount, timestamp);
 }
 
-/// Checks if a refund has been claimed.
-pub fn is_refund_claimed_entry() {
-    let remittance_id: u64 = runtime::get_named_arg("remittance_id");
-    let contributor: AccountHash = runtime::get_named_arg("contributor");
+/// Registers a settlement partner, or updates one already registered
+/// (compliance officer only).
+///
+/// Recipients can route releases in the partner's corridors to it for cash
+/// pickup.
+///
+/// # Arguments (via runtime args)
+///
+/// * `partner` - AccountHash of the partner
+/// * `name` - Display name (String, max `MAX_PARTNER_NAME_LENGTH`)
+/// * `corridors` - Corridor codes served (Vec<String>, max
+///   `MAX_PARTNER_CORRIDORS`)
+#[cfg(feature = "admin")]
+pub fn register_settlement_partner_entry() {
+    let caller = utils::get_caller();
 
-    let claimed = storage::is_refund_claimed(remittance_id, contributor);
-    runtime::ret(CLValue::from_t(claimed).unwrap_or_revert());
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let partner: AccountHash = args::get("partner");
+    let name: String = args::get("name");
+    let corridors: Vec<String> = args::get("corridors");
+    utils::validate_account_hash(&partner).unwrap_or_revert();
+
+    if name.is_empty()
+        || name.len() > MAX_PARTNER_NAME_LENGTH
+        || corridors.is_empty()
+        || corridors.len() > MAX_PARTNER_CORRIDORS
+    {
+        runtime::revert(Error::InvalidPartner);
+    }
+    for corridor in &corridors {
+        utils::validate_corridor(corridor).unwrap_or_revert();
+    }
+
+    storage::set_settlement_partner(
+        partner,
+        Some(SettlementPartner {
+            name: name.clone(),
+            corridors: corridors.clone(),
+        }),
+    );
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::SettlementPartnerRegistered {
+        partner,
+        name,
+        corridors,
+        timestamp,
+    }
+    .emit();
 }
 
-/// Gets the current platform fee in basis points.
-pub fn get_platform_fee_entry() {
-    let fee_bps = storage::get_platform_fee_bps();
-    runtime::ret(CLValue::from_t(fee_bps).unwrap_or_revert());
+/// Removes a settlement partner from the registry (compliance officer only).
+///
+/// Releases already routed to the partner and held for review can then no
+/// longer be approved, only rejected.
+///
+/// # Arguments (via runtime args)
+///
+/// * `partner` - AccountHash of the partner
+#[cfg(feature = "admin")]
+pub fn unregister_settlement_partner_entry() {
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let partner: AccountHash = args::get("partner");
+    if storage::get_settlement_partner(partner).is_none() {
+        runtime::revert(Error::PartnerNotRegistered);
+    }
+    storage::set_settlement_partner(partner, None);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::SettlementPartnerUnregistered { partner, timestamp }.emit();
 }
 
-// ============================================================================
-// Admin Functions (Owner Only)
-// ============================================================================
+/// Removes a charity from the registry (compliance officer only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `charity` - AccountHash of the charity
+#[cfg(feature = "admin")]
+pub fn unregister_charity_entry() {
+    let caller = utils::get_caller();
 
-/// Sets the platform fee (owner only).
-pub fn set_platform_fee_entry() {
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let charity: AccountHash = args::get("charity");
+    storage::set_verified_charity(charity, false);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::CharityUnregistered { charity, timestamp }.emit();
+}
+
+/// Blocklists a purpose template (compliance officer only).
+///
+/// Creating a remittance, or revealing a committed purpose, whose purpose
+/// hashes to a blocked value reverts with `PurposeBlocked`.
+///
+/// # Arguments (via runtime args)
+///
+/// * `purpose_hash` - `purpose_hash` of the purpose to block ([u8; 32])
+#[cfg(feature = "admin")]
+pub fn block_purpose_entry() {
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let purpose_hash: [u8; 32] = args::get("purpose_hash");
+    storage::set_purpose_blocked(purpose_hash, true);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::PurposeBlocked {
+        purpose_hash,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Lifts a purpose template block (compliance officer only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `purpose_hash` - `purpose_hash` of the purpose to unblock ([u8; 32])
+#[cfg(feature = "admin")]
+pub fn unblock_purpose_entry() {
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let purpose_hash: [u8; 32] = args::get("purpose_hash");
+    storage::set_purpose_blocked(purpose_hash, false);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::PurposeUnblocked {
+        purpose_hash,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Removes a business from the KYB registry (compliance officer only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `business` - AccountHash of the business
+#[cfg(feature = "admin")]
+pub fn unregister_business_entry() {
+    let caller = utils::get_caller();
+
+    if caller != storage::get_compliance_officer() {
+        runtime::revert(Error::Unauthorized);
+    }
+
+    let business: AccountHash = args::get("business");
+    storage::set_verified_business(business, false);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::BusinessUnregistered { business, timestamp }.emit();
+}
+
+/// Withdraws the package access token from contract custody (owner only).
+///
+/// The token is returned to the calling session, which is expected to use it
+/// to install a new contract version. Only the current owner can do this, so
+/// rotating the owner away from a compromised key also cuts off upgrades.
+///
+/// # Returns
+///
+/// The package access URef
+#[cfg(feature = "admin")]
+pub fn withdraw_access_token_entry() {
     let caller = utils::get_caller();
     let owner = storage::get_contract_owner();
 
     if caller != owner {
         runtime::revert(Error::Unauthorized);
     }
+    require_admin_active();
 
-    let new_fee_bps: u64 = runtime::get_named_arg("fee_bps");
+    let access_token =
+        storage::take_access_token().unwrap_or_revert_with(Error::AccessTokenUnavailable);
 
-    if new_fee_bps > crate::errors::MAX_FEE_BPS {
-        runtime::revert(Error::FeeTooHigh);
+    let timestamp = get_current_timestamp();
+    ContractEvent::AccessTokenWithdrawn {
+        owner: caller,
+        timestamp,
     }
+    .emit();
 
-    let old_fee_bps = storage::get_platform_fee_bps();
+    runtime::ret(CLValue::from_t(access_token).unwrap_or_revert());
+}
 
-    // Update the platform fee
-    storage::set_platform_fee_bps(new_fee_bps);
+/// Permanently revokes the package access token (owner only).
+///
+/// Once revoked no account can add new contract versions, locking the
+/// deployment at its current code.
+#[cfg(feature = "admin")]
+pub fn revoke_access_token_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    storage::take_access_token().unwrap_or_revert_with(Error::AccessTokenUnavailable);
 
     let timestamp = get_current_timestamp();
-    ContractEvent::PlatformFeeUpdated {
-        old_fee_bps,
-        new_fee_bps,
+    ContractEvent::AccessTokenRevoked {
+        owner: caller,
+        timestamp,
+    }
+    .emit();
+}
+
+/// Removes a stale named key from the contract (owner only).
+///
+/// # Arguments (via runtime args)
+///
+/// * `name` - Name of the key to remove; keys holding live state are refused
+#[cfg(feature = "admin")]
+pub fn remove_named_key_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    let name: String = args::get("name");
+
+    if storage::is_protected_named_key(&name) {
+        runtime::revert(Error::ProtectedNamedKey);
+    }
+
+    if !runtime::has_key(&name) {
+        runtime::revert(Error::NamedKeyNotFound);
+    }
+
+    runtime::remove_key(&name);
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::NamedKeyRemoved { name, timestamp }.emit();
+}
+
+/// Permanently gives up admin control (owner only).
+///
+/// Afterwards every owner-only entry point reverts with `AdminRenounced`:
+/// fee, limit, and policy setters, namespaces and categories, role
+/// appointments, owner rotation, record migration, named key removal,
+/// pausing, and access token withdrawal or revocation. None stays mutable,
+/// so the deployment can no longer be changed or upgraded. The contract must
+/// not be paused, since it could never be unpaused again. The contract has
+/// no timelocked admin actions, so nothing else can be left pending.
+#[cfg(feature = "admin")]
+pub fn renounce_admin_entry() {
+    let caller = utils::get_caller();
+    let owner = storage::get_contract_owner();
+
+    if caller != owner {
+        runtime::revert(Error::Unauthorized);
+    }
+    require_admin_active();
+
+    if storage::is_contract_paused() {
+        runtime::revert(Error::ContractPaused);
+    }
+
+    storage::set_admin_renounced();
+
+    let timestamp = get_current_timestamp();
+    ContractEvent::AdminRenounced {
+        owner: caller,
         timestamp,
     }
     .emit();
 }
 
+/// Reverts once the owner has renounced admin control.
+#[cfg(feature = "admin")]
+fn require_admin_active() {
+    if storage::is_admin_renounced() {
+        runtime::revert(Error::AdminRenounced);
+    }
+}
+
 /// Pauses the contract (owner only).
+#[cfg(feature = "admin")]
 pub fn pause_contract_entry() {
     let caller = utils::get_caller();
     let owner = storage::get_contract_owner();
@@ -441,6 +6634,7 @@ pub fn pause_contract_entry() {
     if caller != owner {
         runtime::revert(Error::Unauthorized);
     }
+    require_admin_active();
 
     // Set the contract to paused state
     storage::set_contract_paused(true);
@@ -450,6 +6644,7 @@ pub fn pause_contract_entry() {
 }
 
 /// Unpauses the contract (owner only).
+#[cfg(feature = "admin")]
 pub fn unpause_contract_entry() {
     let caller = utils::get_caller();
     let owner = storage::get_contract_owner();
@@ -457,6 +6652,7 @@ pub fn unpause_contract_entry() {
     if caller != owner {
         runtime::revert(Error::Unauthorized);
     }
+    require_admin_active();
 
     // Set the contract to unpaused state
     storage::set_contract_paused(false);
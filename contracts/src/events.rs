@@ -1,108 +1,444 @@
 //! Event emission for the CasperFlow remittance contract.
 //!
-//! This module handles event logging using Casper's CEP-88 event standard
-//! for efficient on-chain event tracking.
+//! Events are written in the Casper Event Standard (CES) layout: each one is
+//! appended to the `__events` dictionary, and the schema of every event is
+//! stored under `__events_schema` at install time.
 
 extern crate alloc;
 
 use alloc::string::String;
-use alloc::vec;
 
-use casper_contract::contract_api::runtime;
-use casper_types::{account::AccountHash, U512};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_event_standard::{Schema, Schemas, EVENTS_LENGTH};
+use casper_types::bytesrepr::{Bytes, ToBytes};
 
-/// Event types emitted by the contract
-pub enum ContractEvent {
-    /// Emitted when a new remittance is created
-    RemittanceCreated {
-        remittance_id: u64,
-        creator: AccountHash,
-        recipient: AccountHash,
-        target_amount: U512,
-        purpose: String,
-        timestamp: u64,
-    },
+pub use casperflow_types::events::{ContractEvent, EVENT_SCHEMA_VERSION};
 
-    /// Emitted when a contribution is made to a remittance
-    ContributionMade {
-        remittance_id: u64,
-        contributor: AccountHash,
-        amount: U512,
-        new_total: U512,
-        timestamp: u64,
-    },
+use crate::{errors::Error, storage};
 
-    /// Emitted when funds are released to the recipient
-    FundsReleased {
-        remittance_id: u64,
-        recipient: AccountHash,
-        amount: U512,
-        platform_fee: U512,
-        timestamp: u64,
-    },
-
-    /// Emitted when a remittance is cancelled
-    RemittanceCancelled {
-        remittance_id: u64,
-        creator: AccountHash,
-        total_amount: U512,
-        timestamp: u64,
-    },
-
-    /// Emitted when a contributor claims their refund
-    RefundClaimed {
-        remittance_id: u64,
-        contributor: AccountHash,
-        amount: U512,
-        timestamp: u64,
-    },
-
-    /// Emitted when platform fee is updated
-    PlatformFeeUpdated {
-        old_fee_bps: u64,
-        new_fee_bps: u64,
-        timestamp: u64,
-    },
+/// Creates the CES named keys and stores the schema of every event.
+///
+/// Called once from `storage::initialize_contract`.
+pub fn init_events() {
+    let mut schemas = Schemas::new();
+    for (name, fields) in ContractEvent::schemas() {
+        let mut schema = Schema::new();
+        for (field, cl_type) in fields {
+            schema.with_elem(field, cl_type);
+        }
+        schemas.0.insert(String::from(name), schema);
+    }
 
-    /// Emitted when contract is paused
-    ContractPaused { timestamp: u64 },
+    casper_event_standard::init(schemas);
+}
 
-    /// Emitted when contract is unpaused
-    ContractUnpaused { timestamp: u64 },
+/// Emission of contract events.
+///
+/// The event types are defined in `casperflow-types`; emitting them needs
+/// the contract runtime, so it lives here.
+pub trait Emit {
+    /// Emits the event.
+    fn emit(&self);
 }
 
-impl ContractEvent {
+impl Emit for ContractEvent {
     /// Emits the event to the blockchain.
     ///
-    /// Events are recorded in the contract's execution effects and can be
-    /// queried by clients for real-time updates.
-    ///
-    /// Note: In SDK 4.0, we use runtime::print for event logging.
-    /// For production use with SDK 5.x+, replace with CEP-88 events.
-    pub fn emit(&self) {
-        // Event emission is simplified for SDK 4.0 compatibility
-        // In production with SDK 5.x+, use proper CEP-88 event standard
-        // For now, events are logged for debugging purposes only
+    /// The event is appended to the CES events dictionary, so it shows up in
+    /// the deploy's execution effects for streaming clients. With the
+    /// `debug-events` cfg it is also printed.
+    fn emit(&self) {
         #[cfg(feature = "debug-events")]
         match self {
             ContractEvent::RemittanceCreated { remittance_id, .. } => {
                 runtime::print(&alloc::format!("RemittanceCreated: {}", remittance_id));
             }
+            ContractEvent::SavingsPoolCreated { pool_id, members, .. } => {
+                runtime::print(&alloc::format!(
+                    "SavingsPoolCreated: {} - {} members",
+                    pool_id, members.len()
+                ));
+            }
+            ContractEvent::PurposeRevealed { remittance_id, .. } => {
+                runtime::print(&alloc::format!("PurposeRevealed: {}", remittance_id));
+            }
+            ContractEvent::ConfidentialContributionMade { remittance_id, .. } => {
+                runtime::print(&alloc::format!("ConfidentialContributionMade: {}", remittance_id));
+            }
+            ContractEvent::DocumentAttached { remittance_id, cid, .. } => {
+                runtime::print(&alloc::format!("DocumentAttached: {} - {}", remittance_id, cid));
+            }
+            ContractEvent::HandleRegistered { remittance_id, handle, .. } => {
+                runtime::print(&alloc::format!("HandleRegistered: {} - {}", remittance_id, handle));
+            }
+            ContractEvent::UpdatePosted { remittance_id, message, .. } => {
+                runtime::print(&alloc::format!("UpdatePosted: {} - {}", remittance_id, message));
+            }
+            ContractEvent::ReceiptAcknowledged { remittance_id, message, .. } => {
+                runtime::print(&alloc::format!(
+                    "ReceiptAcknowledged: {} - {}",
+                    remittance_id, message
+                ));
+            }
+            ContractEvent::RemittanceActivated { remittance_id, starts_at, .. } => {
+                runtime::print(&alloc::format!("RemittanceActivated: {} - {}", remittance_id, starts_at));
+            }
             ContractEvent::ContributionMade { remittance_id, amount, .. } => {
                 runtime::print(&alloc::format!("ContributionMade: {} - {}", remittance_id, amount));
             }
             ContractEvent::FundsReleased { remittance_id, amount, .. } => {
                 runtime::print(&alloc::format!("FundsReleased: {} - {}", remittance_id, amount));
             }
+            ContractEvent::InstantPayout { remittance_id, amount, .. } => {
+                runtime::print(&alloc::format!("InstantPayout: {} - {}", remittance_id, amount));
+            }
+            ContractEvent::InstallmentPaid {
+                remittance_id,
+                installment,
+                amount,
+                ..
+            } => {
+                runtime::print(&alloc::format!(
+                    "InstallmentPaid: {} #{} - {}",
+                    remittance_id, installment, amount
+                ));
+            }
+            ContractEvent::PotWithdrawn { remittance_id, amount, .. } => {
+                runtime::print(&alloc::format!("PotWithdrawn: {} - {}", remittance_id, amount));
+            }
+            ContractEvent::BackupReleaseTriggered { remittance_id, backup, .. } => {
+                runtime::print(&alloc::format!(
+                    "BackupReleaseTriggered: {} - {}",
+                    remittance_id, backup
+                ));
+            }
+            ContractEvent::RecipientApprovalAdded {
+                remittance_id,
+                approvals,
+                threshold,
+                ..
+            } => {
+                runtime::print(&alloc::format!(
+                    "RecipientApprovalAdded: {} - {}/{}",
+                    remittance_id, approvals, threshold
+                ));
+            }
             ContractEvent::RemittanceCancelled { remittance_id, .. } => {
                 runtime::print(&alloc::format!("RemittanceCancelled: {}", remittance_id));
             }
+            ContractEvent::RemittanceForceCancelled { remittance_id, reason, .. } => {
+                runtime::print(&alloc::format!(
+                    "RemittanceForceCancelled: {} - {}",
+                    remittance_id, reason
+                ));
+            }
+            ContractEvent::RemittanceRejected { remittance_id, recipient, .. } => {
+                runtime::print(&alloc::format!("RemittanceRejected: {} - {}", remittance_id, recipient));
+            }
+            ContractEvent::ReleaseDeclined { remittance_id, reason, .. } => {
+                runtime::print(&alloc::format!("ReleaseDeclined: {} - {}", remittance_id, reason));
+            }
+            ContractEvent::ReleaseOverdue { remittance_id, level, .. } => {
+                runtime::print(&alloc::format!("ReleaseOverdue: {} - level {}", remittance_id, level));
+            }
+            ContractEvent::RemittanceExpired { remittance_id, .. } => {
+                runtime::print(&alloc::format!("RemittanceExpired: {}", remittance_id));
+            }
+            ContractEvent::DeadlineExtended { remittance_id, new_deadline, .. } => {
+                runtime::print(&alloc::format!("DeadlineExtended: {} - {}", remittance_id, new_deadline));
+            }
+            ContractEvent::BridgePayoutRequested { remittance_id, chain_id, .. } => {
+                runtime::print(&alloc::format!("BridgePayoutRequested: {} - {}", remittance_id, chain_id));
+            }
+            ContractEvent::BridgePayoutConfirmed { remittance_id, foreign_tx_hash, .. } => {
+                runtime::print(&alloc::format!(
+                    "BridgePayoutConfirmed: {} - {}",
+                    remittance_id, foreign_tx_hash
+                ));
+            }
+            ContractEvent::BridgePayoutCancelled { remittance_id, cancelled_by, .. } => {
+                runtime::print(&alloc::format!(
+                    "BridgePayoutCancelled: {} - {}",
+                    remittance_id, cancelled_by
+                ));
+            }
+            ContractEvent::ReleaseReviewRequested { remittance_id, review_deadline, .. } => {
+                runtime::print(&alloc::format!(
+                    "ReleaseReviewRequested: {} - {}",
+                    remittance_id, review_deadline
+                ));
+            }
+            ContractEvent::ReleaseApproved { remittance_id, officer, .. } => {
+                runtime::print(&alloc::format!("ReleaseApproved: {} - {}", remittance_id, officer));
+            }
+            ContractEvent::FeeCoverToppedUp { remittance_id, balance, .. } => {
+                runtime::print(&alloc::format!("FeeCoverToppedUp: {} - {}", remittance_id, balance));
+            }
+            ContractEvent::FeeCoverApplied { remittance_id, covered, .. } => {
+                runtime::print(&alloc::format!("FeeCoverApplied: {} - {}", remittance_id, covered));
+            }
+            ContractEvent::FeeCoverRefunded { remittance_id, amount, .. } => {
+                runtime::print(&alloc::format!("FeeCoverRefunded: {} - {}", remittance_id, amount));
+            }
+            ContractEvent::InsurancePremiumCollected { remittance_id, premium, .. } => {
+                runtime::print(&alloc::format!(
+                    "InsurancePremiumCollected: {} - {}",
+                    remittance_id, premium
+                ));
+            }
+            ContractEvent::InsuranceClaimsApproved { remittance_id, coverage_bps, .. } => {
+                runtime::print(&alloc::format!(
+                    "InsuranceClaimsApproved: {} - {} bps",
+                    remittance_id, coverage_bps
+                ));
+            }
+            ContractEvent::InsuranceClaimPaid { remittance_id, amount, .. } => {
+                runtime::print(&alloc::format!("InsuranceClaimPaid: {} - {}", remittance_id, amount));
+            }
+            ContractEvent::ReleaseRejected { remittance_id, rejected_by, .. } => {
+                runtime::print(&alloc::format!("ReleaseRejected: {} - {}", remittance_id, rejected_by));
+            }
+            ContractEvent::ContributionFeeCharged { remittance_id, fee, .. } => {
+                runtime::print(&alloc::format!("ContributionFeeCharged: {} - {}", remittance_id, fee));
+            }
             ContractEvent::RefundClaimed { remittance_id, contributor, .. } => {
                 runtime::print(&alloc::format!("RefundClaimed: {} - {}", remittance_id, contributor));
             }
+            ContractEvent::ContributionForceRefunded {
+                remittance_id, contributor, reason, ..
+            } => {
+                runtime::print(&alloc::format!(
+                    "ContributionForceRefunded: {} - {} - {}",
+                    remittance_id, contributor, reason
+                ));
+            }
+            ContractEvent::ExcessRefundClaimed { remittance_id, contributor, amount, .. } => {
+                runtime::print(&alloc::format!(
+                    "ExcessRefundClaimed: {} - {} - {}",
+                    remittance_id, contributor, amount
+                ));
+            }
+            ContractEvent::CreatorRebateAccrued { remittance_id, amount, .. } => {
+                runtime::print(&alloc::format!("CreatorRebateAccrued: {} - {}", remittance_id, amount));
+            }
+            ContractEvent::CreatorRewardsClaimed { creator, amount, .. } => {
+                runtime::print(&alloc::format!("CreatorRewardsClaimed: {} - {}", creator, amount));
+            }
             ContractEvent::PlatformFeeUpdated { new_fee_bps, .. } => {
                 runtime::print(&alloc::format!("PlatformFeeUpdated: {}", new_fee_bps));
             }
+            ContractEvent::PlatformFeePpmUpdated { new_fee_ppm, .. } => {
+                runtime::print(&alloc::format!("PlatformFeePpmUpdated: {}", new_fee_ppm));
+            }
+            ContractEvent::FeeRoundingUpdated { new_mode, .. } => {
+                runtime::print(&alloc::format!("FeeRoundingUpdated: {}", new_mode));
+            }
+            ContractEvent::CreatorFeeShareUpdated { new_share_bps, .. } => {
+                runtime::print(&alloc::format!("CreatorFeeShareUpdated: {}", new_share_bps));
+            }
+            ContractEvent::InsurancePremiumUpdated { new_premium_bps, .. } => {
+                runtime::print(&alloc::format!("InsurancePremiumUpdated: {}", new_premium_bps));
+            }
+            ContractEvent::MaxFeeLowered { new_max_fee_ppm, .. } => {
+                runtime::print(&alloc::format!("MaxFeeLowered: {}", new_max_fee_ppm));
+            }
+            ContractEvent::EscrowShortfall { remittance_id, required, available, .. } => {
+                runtime::print(&alloc::format!(
+                    "EscrowShortfall: {} - {} of {}",
+                    remittance_id, available, required
+                ));
+            }
+            ContractEvent::RecordsMigrated { start_id, migrated, .. } => {
+                runtime::print(&alloc::format!("RecordsMigrated: {} - {}", start_id, migrated));
+            }
+            ContractEvent::OwnerRotated { new_owner, .. } => {
+                runtime::print(&alloc::format!("OwnerRotated: {}", new_owner));
+            }
+            ContractEvent::ComplianceOfficerUpdated { new_officer, .. } => {
+                runtime::print(&alloc::format!("ComplianceOfficerUpdated: {}", new_officer));
+            }
+            ContractEvent::BridgeOperatorUpdated { new_operator, .. } => {
+                runtime::print(&alloc::format!("BridgeOperatorUpdated: {}", new_operator));
+            }
+            ContractEvent::CharityRegistered { charity, .. } => {
+                runtime::print(&alloc::format!("CharityRegistered: {}", charity));
+            }
+            ContractEvent::CharityUnregistered { charity, .. } => {
+                runtime::print(&alloc::format!("CharityUnregistered: {}", charity));
+            }
+            ContractEvent::BusinessRegistered { business, .. } => {
+                runtime::print(&alloc::format!("BusinessRegistered: {}", business));
+            }
+            ContractEvent::BusinessUnregistered { business, .. } => {
+                runtime::print(&alloc::format!("BusinessUnregistered: {}", business));
+            }
+            ContractEvent::SettlementPartnerRegistered { partner, .. } => {
+                runtime::print(&alloc::format!("SettlementPartnerRegistered: {}", partner));
+            }
+            ContractEvent::SettlementPartnerUnregistered { partner, .. } => {
+                runtime::print(&alloc::format!("SettlementPartnerUnregistered: {}", partner));
+            }
+            ContractEvent::PartnerPayoutRouted { remittance_id, partner, .. } => {
+                runtime::print(&alloc::format!("PartnerPayoutRouted: {} - {}", remittance_id, partner));
+            }
+            ContractEvent::PayoutTargetUsed { remittance_id, label, .. } => {
+                runtime::print(&alloc::format!("PayoutTargetUsed: {} - {}", remittance_id, label));
+            }
+            ContractEvent::PayoutTargetSet { owner, label, .. } => {
+                runtime::print(&alloc::format!("PayoutTargetSet: {} - {}", owner, label));
+            }
+            ContractEvent::PayoutTargetRemoved { owner, label, .. } => {
+                runtime::print(&alloc::format!("PayoutTargetRemoved: {} - {}", owner, label));
+            }
+            ContractEvent::RefundDelegateSet { contributor, delegate, .. } => {
+                runtime::print(&alloc::format!(
+                    "RefundDelegateSet: {} - {:?}",
+                    contributor, delegate
+                ));
+            }
+            ContractEvent::LocalizedPurposeSet { remittance_id, lang, .. } => {
+                runtime::print(&alloc::format!(
+                    "LocalizedPurposeSet: {} - {}",
+                    remittance_id, lang
+                ));
+            }
+            ContractEvent::PurposeBlocked { .. } => {
+                runtime::print("PurposeBlocked");
+            }
+            ContractEvent::PurposeUnblocked { .. } => {
+                runtime::print("PurposeUnblocked");
+            }
+            ContractEvent::TravelRuleDataAttached { remittance_id, .. } => {
+                runtime::print(&alloc::format!("TravelRuleDataAttached: {}", remittance_id));
+            }
+            ContractEvent::TravelRuleThresholdUpdated { new_threshold, .. } => {
+                runtime::print(&alloc::format!("TravelRuleThresholdUpdated: {}", new_threshold));
+            }
+            ContractEvent::MaxDeadlineExtensionUpdated { new_max_extension_ms, .. } => {
+                runtime::print(&alloc::format!(
+                    "MaxDeadlineExtensionUpdated: {}",
+                    new_max_extension_ms
+                ));
+            }
+            ContractEvent::MaxTargetAmountUpdated { new_max_target_amount, .. } => {
+                runtime::print(&alloc::format!(
+                    "MaxTargetAmountUpdated: {}",
+                    new_max_target_amount
+                ));
+            }
+            ContractEvent::MinTargetAmountUpdated { new_min_target_amount, .. } => {
+                runtime::print(&alloc::format!(
+                    "MinTargetAmountUpdated: {}",
+                    new_min_target_amount
+                ));
+            }
+            ContractEvent::KybMaxTargetAmountUpdated { new_kyb_max_target_amount, .. } => {
+                runtime::print(&alloc::format!(
+                    "KybMaxTargetAmountUpdated: {}",
+                    new_kyb_max_target_amount
+                ));
+            }
+            ContractEvent::BrandingUpdated { platform_name, .. } => {
+                runtime::print(&alloc::format!("BrandingUpdated: {}", platform_name));
+            }
+            ContractEvent::NamespaceCreated { namespace, owner, .. } => {
+                runtime::print(&alloc::format!("NamespaceCreated: {} - {}", namespace, owner));
+            }
+            ContractEvent::NamespaceFeeUpdated { namespace, new_fee_ppm, .. } => {
+                runtime::print(&alloc::format!(
+                    "NamespaceFeeUpdated: {} - {}",
+                    namespace, new_fee_ppm
+                ));
+            }
+            ContractEvent::NamespacePaused { namespace, .. } => {
+                runtime::print(&alloc::format!("NamespacePaused: {}", namespace));
+            }
+            ContractEvent::NamespaceUnpaused { namespace, .. } => {
+                runtime::print(&alloc::format!("NamespaceUnpaused: {}", namespace));
+            }
+            ContractEvent::SlaIntervalsUpdated { intervals_ms, .. } => {
+                runtime::print(&alloc::format!("SlaIntervalsUpdated: {:?}", intervals_ms));
+            }
+            ContractEvent::CategoryTemplateSet { category, deadline_days, .. } => {
+                runtime::print(&alloc::format!(
+                    "CategoryTemplateSet: {} - {}",
+                    category, deadline_days
+                ));
+            }
+            ContractEvent::ReviewPolicyUpdated { threshold, window_ms, .. } => {
+                runtime::print(&alloc::format!("ReviewPolicyUpdated: {} - {}", threshold, window_ms));
+            }
+            ContractEvent::RiskEngineSet { contract_hash, max_score, .. } => {
+                runtime::print(&alloc::format!("RiskEngineSet: {} - {}", contract_hash, max_score));
+            }
+            ContractEvent::RiskEngineCleared { .. } => {
+                runtime::print("RiskEngineCleared");
+            }
+            ContractEvent::RateLimitsUpdated { window_ms, max_creations, max_contributions, .. } => {
+                runtime::print(&alloc::format!(
+                    "RateLimitsUpdated: {} - {} - {}",
+                    window_ms, max_creations, max_contributions
+                ));
+            }
+            ContractEvent::GasLimitsUpdated {
+                max_batch_size,
+                max_page_size,
+                max_contributors,
+                ..
+            } => {
+                runtime::print(&alloc::format!(
+                    "GasLimitsUpdated: {} - {} - {}",
+                    max_batch_size, max_page_size, max_contributors
+                ));
+            }
+            ContractEvent::PaymentGuardsUpdated {
+                reject_self_remittance,
+                recipient_contributions,
+                ..
+            } => {
+                runtime::print(&alloc::format!(
+                    "PaymentGuardsUpdated: {} - {}",
+                    reject_self_remittance, recipient_contributions
+                ));
+            }
+            ContractEvent::RecipientContributionFlagged { remittance_id, recipient, .. } => {
+                runtime::print(&alloc::format!(
+                    "RecipientContributionFlagged: {} - {}",
+                    remittance_id, recipient
+                ));
+            }
+            ContractEvent::AccountingSnapshotTaken { index, purse_balance, .. } => {
+                runtime::print(&alloc::format!(
+                    "AccountingSnapshotTaken: {} - {}",
+                    index, purse_balance
+                ));
+            }
+            ContractEvent::ContributionsSnapshotTaken {
+                snapshot_id,
+                remittance_id,
+                contributors,
+                ..
+            } => {
+                runtime::print(&alloc::format!(
+                    "ContributionsSnapshotTaken: {} - {} ({} contributors)",
+                    snapshot_id, remittance_id, contributors
+                ));
+            }
+            ContractEvent::ArgumentRejected { name, error_code } => {
+                runtime::print(&alloc::format!("ArgumentRejected: {} - {}", name, error_code));
+            }
+            ContractEvent::AccessTokenWithdrawn { owner, .. } => {
+                runtime::print(&alloc::format!("AccessTokenWithdrawn: {}", owner));
+            }
+            ContractEvent::AccessTokenRevoked { owner, .. } => {
+                runtime::print(&alloc::format!("AccessTokenRevoked: {}", owner));
+            }
+            ContractEvent::AdminRenounced { owner, .. } => {
+                runtime::print(&alloc::format!("AdminRenounced: {}", owner));
+            }
+            ContractEvent::NamedKeyRemoved { name, .. } => {
+                runtime::print(&alloc::format!("NamedKeyRemoved: {}", name));
+            }
             ContractEvent::ContractPaused { .. } => {
                 runtime::print("ContractPaused");
             }
@@ -110,6 +446,25 @@ impl ContractEvent {
                 runtime::print("ContractUnpaused");
             }
         }
+
+        // The deploy reverts right after an argument is rejected, discarding
+        // any writes, so that diagnostic is only printed.
+        if let ContractEvent::ArgumentRejected { .. } = self {
+            return;
+        }
+
+        // Installs that predate CES have no events dictionary
+        if !runtime::has_key(EVENTS_LENGTH) {
+            return;
+        }
+
+        // A private remittance's events would publish its ID and parties
+        if self.remittance_id().map_or(false, storage::is_hashed_remittance) {
+            return;
+        }
+
+        let bytes = self.to_bytes().unwrap_or_revert_with(Error::StorageError);
+        casper_event_standard::emit_bytes(Bytes::from(bytes));
     }
 }
 
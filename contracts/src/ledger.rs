@@ -0,0 +1,6 @@
+//! Per-remittance ledger entries for escrow credits and debits.
+//!
+//! Defined in `casperflow-types` so off-chain clients share the same
+//! encoding.
+
+pub use casperflow_types::ledger::*;
@@ -18,224 +18,3118 @@
 //!
 //! ### User Functions
 //! - `create_remittance`: Create a new remittance request
+//! - `create_private_remittance`: Create a remittance addressed by a hash key
+//! - `create_savings_pool`: Create a rotating savings pool paying each member in turn
+//! - `reveal_purpose`: Reveal a committed purpose (creator only)
+//! - `attach_document`: Attach an IPFS document reference (creator or recipient)
+//! - `post_update`: Post a progress update for contributors (creator only)
+//! - `set_localized_purpose`: Set or remove a translation of the purpose (creator only)
+//! - `register_handle`: Register a unique human-readable handle (creator only)
+//! - `attach_travel_rule_data`: Attach hashed travel-rule data (creator only)
 //! - `contribute`: Contribute funds to a remittance
-//! - `release_funds`: Release funds to recipient (recipient only)
+//! - `contribute_simple`: Contribute from your main purse with only an ID and amount
+//! - `top_up_fee_cover`: Pre-fund the platform fee so the recipient receives the full amount
+//! - `release_funds`: Release funds to recipient or a settlement partner (recipient only)
+//! - `release_simple`: Release funds to recipient with only an ID (recipient only)
+//! - `release_to_backup`: Release to the backup recipient after the grace period
+//! - `approve_release_as_recipient`: Approve a release to a multi-signature recipient (signers only)
+//! - `acknowledge_receipt`: Confirm released funds arrived (recipient only)
+//! - `set_payout_target`: Add or repoint a labelled account in your payout address book
+//! - `remove_payout_target`: Remove a labelled account from your payout address book
 //! - `cancel_remittance`: Cancel and enable refunds (creator only)
+//! - `reject_remittance`: Decline a remittance and enable refunds (recipient only)
+//! - `decline_release`: Refuse a funded remittance with a reason and enable refunds (recipient only)
+//! - `request_bridge_payout`: Ask to be paid out on another chain (recipient only)
+//! - `confirm_bridge_payout`: Confirm a cross-chain payout and be reimbursed
+//!   (bridge operator only)
+//! - `cancel_bridge_payout`: Withdraw an unconfirmed cross-chain payout and
+//!   unlock the remittance
+//! - `extend_deadline`: Push back a remittance's deadline (creator only)
+//! - `expire_remittance`: Expire a remittance past its deadline and enable refunds
+//! - `expire_batch`: Expire stalled remittances in a range of IDs
+//! - `escalate_overdue`: Escalate funded remittances left unreleased in a range of IDs
+//! - `process_installment`: Pay the next due installment of a scheduled payout
+//! - `withdraw_pot`: Withdraw what an open-ended pot holds (recipient only)
 //! - `claim_refund`: Claim refund from cancelled remittance
+//! - `claim_all_refunds`: Claim refunds from several cancelled remittances at once
+//! - `set_refund_delegate`: Let another account claim your refunds on your behalf
+//! - `claim_excess_refund`: Claim share of the excess over target after release
+//! - `claim_fee_cover`: Claim back the fee cover of a refundable remittance (sponsor only)
+//! - `claim_insurance`: Claim an approved insurance top-up on an insured remittance
+//! - `claim_creator_rewards`: Claim accrued creator fee rebates
+//! - `snapshot_accounting`: Record purse balance against the ledger
+//! - `snapshot_contributions`: Freeze contributor amounts of a remittance for airdrops
 //!
 //! ### View Functions
 //! - `get_remittance`: Get remittance details
 //! - `get_contribution`: Get contribution amount
+//! - `get_contribution_weight`: Get a contributor's amount × escrow time
+//! - `get_total_contribution_weight`: Get a remittance's combined amount × escrow time
+//! - `get_contribution_commitment`: Get a confidential contribution's commitment
 //! - `is_refund_claimed`: Check if refund was claimed
+//! - `get_documents`: Get documents attached to a remittance
+//! - `get_updates`: Get progress updates posted on a remittance
+//! - `get_purpose`: Get the purpose in a requested language, with fallback
+//! - `get_acknowledgement`: Get the recipient's acknowledgement of receipt
+//! - `get_payout_targets`: Get an account's payout address book
+//! - `get_progress`: Get progress towards the target in basis points
+//! - `get_funding_state`: Get amounts, deadline, and status in one call
+//! - `get_contributor_count`: Get the number of unique contributors to a remittance
+//! - `get_remittance_analytics`: Get contributions in the last 24h, largest, and average size
+//! - `get_ledger_length`: Get the number of ledger entries of a remittance
+//! - `get_ledger_entries`: Get a page of a remittance's ledger entries
+//! - `get_remittance_by_reference`: Look up a remittance by external reference
+//! - `resolve_handle`: Look up a remittance by handle
+//! - `get_handle`: Get the handle of a remittance, if any
+//! - `get_remittances_created_between`: Get a page of remittance IDs by creation day
 //! - `get_platform_fee`: Get current platform fee
+//! - `get_contract_info`: Get owner, fees, pause state, counts, and purse balance at once
+//! - `is_paused`: Check if the contract is paused
+//! - `is_admin_renounced`: Check if the owner has renounced admin control
+//! - `get_owner`: Get the contract owner account
+//! - `get_fee_collector`: Get the account receiving platform fees
+//! - `validate_release`: Dry-run a release and report why it would fail
+//! - `validate_refund`: Dry-run a refund claim and report why it would fail
+//! - `get_platform_fee_ppm`: Get current platform fee in parts-per-million
+//! - `get_creator_fee_share`: Get creator share of the platform fee
+//! - `get_rate_limits`: Get per-account rate limits
+//! - `get_gas_limits`: Get the per-call batch, page, and contributor limits
+//! - `get_payment_guards`: Get the self-remittance and recipient contribution guards
+//! - `get_risk_engine`: Get the registered risk engine, if any
+//! - `get_review_policy`: Get the release review threshold and window
+//! - `get_max_deadline_extension`: Get the maximum total deadline extension
+//! - `get_max_target_amount`: Get the largest target a new remittance may have
+//! - `get_min_target_amount`: Get the smallest target a new remittance may have
+//! - `get_kyb_max_target_amount`: Get the larger target cap for KYB-verified businesses
+//! - `get_branding`: Get how frontends should present this instance
+//! - `get_sla_intervals`: Get the release escalation intervals
+//! - `get_overdue_remittances`: Get funded remittances left unreleased in a range of IDs
+//! - `get_namespace`: Get a namespace's owner, fee, and pause state
+//! - `get_remittance_namespace`: Get the namespace a remittance belongs to
+//! - `get_category`: Get the defaults applied to a category
+//! - `get_remittance_category`: Get the category a remittance was created in
+//! - `get_decline_reason`: Get why the recipient declined a remittance
+//! - `get_creator_rewards`: Get claimable creator rewards for an account
+//! - `get_account_overview`: Get an account's remittances, claimable refunds, and rewards
+//! - `get_claimable_refunds`: Get the remittances an account has an unclaimed refund in
+//! - `get_fee_rounding`: Get current fee rounding mode
+//! - `get_fee_model`: Get whether fees are charged on release or contribution
+//! - `get_max_fee_ppm`: Get the platform fee cap in parts-per-million
+//! - `compute_grossed_up_target`: Get the target that nets the recipient a desired amount after fees
+//! - `is_verified_charity`: Check if an account is a verified charity
+//! - `is_verified_business`: Check if an account is a KYB-verified business
+//! - `is_purpose_blocked`: Check if a purpose hash is blocklisted
+//! - `is_business_payout`: Check if a remittance pays out to a KYB-verified business
+//! - `get_compliance_officer`: Get the compliance officer account
+//! - `get_settlement_partner`: Get a registered settlement partner
+//! - `get_payout_route`: Get the settlement partner a release was routed to
+//! - `get_instant_split`: Get the instant share and forwarded totals of a remittance
+//! - `get_backup_recipient`: Get the backup recipient of a remittance
+//! - `get_payout_schedule`: Get the installment schedule of a remittance
+//! - `get_savings_pool`: Get the members, contribution, and cycles of a savings pool
+//! - `get_remittance_pool`: Get the savings pool a remittance is a cycle of
+//! - `get_recipient_multisig`: Get the signers, threshold, and approvals of a multi-signature recipient
+//! - `get_scheduled_start`: Get the start time and activation of a scheduled remittance
+//! - `get_insurance_policy`: Get the insurance premium, premiums paid, and coverage of a remittance
+//! - `get_fee_cover`: Get the sponsor and unused balance of a remittance's fee cover
+//! - `get_release_breakdown`: Get the gross, fee split, and net amount of a release
+//! - `get_refund_policy`: Get the refund terms chosen at creation
+//! - `get_insurance_pool`: Get the balance of the insurance pool
+//! - `get_insurance_premium`: Get the premium charged on new insured remittances
+//! - `get_bridge_operator`: Get the bridge operator account
+//! - `get_bridge_payout`: Get the bridge payout requested for a remittance
+//! - `get_error_message`: Get the message for a contract error code
+//! - `get_travel_rule_data`: Get travel-rule data attached to a remittance
+//! - `get_travel_rule_threshold`: Get the travel-rule release threshold
+//! - `get_period_stats`: Get daily (or daily per-corridor) reporting aggregates
+//! - `get_snapshot`: Get an accounting snapshot by index
+//! - `get_snapshot_count`: Get the number of accounting snapshots
+//! - `get_contribution_snapshot`: Get a frozen contributor snapshot by ID
+//! - `get_schema`: Get the entry point and event schema of this contract
+//! - `get_event_schema_version`: Get the version of the event layouts this contract emits
+//! - `get_version`: Get the crate version and git commit of the installed build
 //!
 //! ### Admin Functions (Owner Only)
 //! - `set_platform_fee`: Update platform fee
+//! - `set_platform_fee_ppm`: Update platform fee in parts-per-million
+//! - `lower_max_fee`: Lower the platform fee cap (it can never be raised)
+//! - `set_fee_rounding`: Update fee rounding mode
+//! - `set_max_deadline_extension`: Cap how far creators may extend deadlines
+//! - `set_max_target_amount`: Cap the target amount of new remittances
+//! - `set_min_target_amount`: Set a floor on the target amount of new remittances
+//! - `set_kyb_max_target_amount`: Raise the target cap for KYB-verified businesses
+//! - `set_branding`: Set the platform name, logo, support URL, and currency label
+//! - `create_namespace`: Create a sub-platform administered by its own operator
+//! - `set_category`: Set the default deadline, fee, and excess refunds of a category
+//! - `set_sla_intervals`: Set when unreleased remittances are escalated
+//! - `set_insurance_premium`: Set the premium charged on new insured remittances
+//! - `set_creator_fee_share`: Update creator share of the platform fee
+//! - `set_rate_limits`: Limit creations and contributions per account
+//! - `set_gas_limits`: Bound batch sizes, page sizes, and contributors processed per call
+//! - `set_payment_guards`: Reject self-remittances and allow, flag, or reject recipient contributions
+//! - `migrate_records`: Rewrite legacy-format remittances in batches
+//! - `rotate_owner`: Transfer admin rights to a new account
+//! - `set_compliance_officer`: Appoint the compliance officer
+//! - `set_bridge_operator`: Appoint the bridge operator
+//! - `withdraw_access_token`: Release the package access token for an upgrade
+//! - `revoke_access_token`: Permanently lock the contract package
+//! - `remove_named_key`: Remove a stale named key
 //! - `pause_contract`: Pause all operations
 //! - `unpause_contract`: Resume operations
+//! - `renounce_admin`: Permanently disable fee changes, pausing, and access token withdrawal
+//!
+//! ### Namespace Functions (Namespace Owner Only)
+//! - `set_namespace_fee`: Update the namespace's platform fee
+//! - `pause_namespace`: Pause contributions and releases in the namespace
+//! - `unpause_namespace`: Resume the namespace
+//!
+//! ### Compliance Functions (Compliance Officer Only)
+//! - `register_charity`: Add a verified charity (zero platform fee)
+//! - `unregister_charity`: Remove a verified charity
+//! - `register_business`: Mark a recipient as a KYB-verified business (higher target cap)
+//! - `unregister_business`: Remove a KYB-verified business
+//! - `block_purpose`: Blocklist a purpose template by hash
+//! - `unblock_purpose`: Lift a purpose template block
+//! - `register_settlement_partner`: Add or update a cash-pickup payout partner
+//! - `unregister_settlement_partner`: Remove a settlement partner
+//! - `set_travel_rule_threshold`: Require travel-rule data above an amount
+//! - `set_risk_engine`: Score large releases with an external risk engine
+//! - `clear_risk_engine`: Stop scoring releases
+//! - `set_review_policy`: Hold releases above an amount for review
+//! - `approve_release`: Approve and pay out a held release
+//! - `reject_release`: Reject a held release and enable refunds (anyone,
+//!   once the review window has passed)
+//! - `approve_insurance_claims`: Let contributors to a failed insured remittance
+//!   claim a top-up from the insurance pool
+//! - `force_cancel`: Cancel a remittance that violates the terms, with a reason
+//! - `force_refund`: Refund one contributor from an active remittance, with a
+//!   reason
+//!
+//! ## Features
+//!
+//! Entry points are grouped behind cargo features, all enabled by default,
+//! so deployments can install a smaller wasm:
+//! - `views`: the view functions above
+//! - `admin`: owner and compliance officer functions
+//! - `stats`: daily reporting aggregates, `snapshot_accounting`, and (with
+//!   `views`) `get_period_stats`, `get_snapshot`, and `get_snapshot_count`
+//! - `debug-events`: also print every emitted event (off by default)
+//!
+//! Without `admin` the fee, owner, and pause settings stay at their install
+//! values for the life of the contract.
 
 #![no_std]
 #![no_main]
 
-extern crate alloc;
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec;
+
+mod args;
+mod entry_points;
+mod errors;
+mod events;
+mod ledger;
+mod remittance;
+mod schema;
+mod storage;
+mod utils;
+
+use casper_contract::{
+    contract_api::{runtime, storage as contract_storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::NamedKeys, CLType, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Parameter, RuntimeArgs, URef,
+};
+
+/// Installer named key under which the package access token is created
+const ACCESS_TOKEN_KEY: &str = "casperflow_access_token";
+
+/// Installer named key under which the contract hash is stored
+const CONTRACT_HASH_KEY: &str = "casperflow_contract_hash";
+
+/// Contract entry point: create_remittance
+#[no_mangle]
+pub extern "C" fn create_remittance() {
+    entry_points::create_remittance_entry();
+}
+
+/// Contract entry point: create_private_remittance
+#[no_mangle]
+pub extern "C" fn create_private_remittance() {
+    entry_points::create_private_remittance_entry();
+}
+
+/// Contract entry point: create_savings_pool
+#[no_mangle]
+pub extern "C" fn create_savings_pool() {
+    entry_points::create_savings_pool_entry();
+}
+
+/// Contract entry point: reveal_purpose
+#[no_mangle]
+pub extern "C" fn reveal_purpose() {
+    entry_points::reveal_purpose_entry();
+}
+
+/// Contract entry point: attach_document
+#[no_mangle]
+pub extern "C" fn attach_document() {
+    entry_points::attach_document_entry();
+}
+
+/// Contract entry point: post_update
+#[no_mangle]
+pub extern "C" fn post_update() {
+    entry_points::post_update_entry();
+}
+
+/// Contract entry point: set_localized_purpose
+#[no_mangle]
+pub extern "C" fn set_localized_purpose() {
+    entry_points::set_localized_purpose_entry();
+}
+
+/// Contract entry point: acknowledge_receipt
+#[no_mangle]
+pub extern "C" fn acknowledge_receipt() {
+    entry_points::acknowledge_receipt_entry();
+}
+
+/// Contract entry point: set_payout_target
+#[no_mangle]
+pub extern "C" fn set_payout_target() {
+    entry_points::set_payout_target_entry();
+}
+
+/// Contract entry point: remove_payout_target
+#[no_mangle]
+pub extern "C" fn remove_payout_target() {
+    entry_points::remove_payout_target_entry();
+}
+
+/// Contract entry point: register_handle
+#[no_mangle]
+pub extern "C" fn register_handle() {
+    entry_points::register_handle_entry();
+}
+
+/// Contract entry point: attach_travel_rule_data
+#[no_mangle]
+pub extern "C" fn attach_travel_rule_data() {
+    entry_points::attach_travel_rule_data_entry();
+}
+
+/// Contract entry point: contribute
+#[no_mangle]
+pub extern "C" fn contribute() {
+    entry_points::contribute_entry();
+}
+
+/// Contract entry point: contribute_simple
+#[no_mangle]
+pub extern "C" fn contribute_simple() {
+    entry_points::contribute_simple_entry();
+}
+
+/// Contract entry point: top_up_fee_cover
+#[no_mangle]
+pub extern "C" fn top_up_fee_cover() {
+    entry_points::top_up_fee_cover_entry();
+}
+
+/// Contract entry point: release_funds
+#[no_mangle]
+pub extern "C" fn release_funds() {
+    entry_points::release_funds_entry();
+}
+
+/// Contract entry point: release_simple
+#[no_mangle]
+pub extern "C" fn release_simple() {
+    entry_points::release_simple_entry();
+}
+
+/// Contract entry point: release_to_backup
+#[no_mangle]
+pub extern "C" fn release_to_backup() {
+    entry_points::release_to_backup_entry();
+}
+
+/// Contract entry point: approve_release_as_recipient
+#[no_mangle]
+pub extern "C" fn approve_release_as_recipient() {
+    entry_points::approve_release_as_recipient_entry();
+}
+
+/// Contract entry point: cancel_remittance
+#[no_mangle]
+pub extern "C" fn cancel_remittance() {
+    entry_points::cancel_remittance_entry();
+}
+
+/// Contract entry point: reject_remittance
+#[no_mangle]
+pub extern "C" fn reject_remittance() {
+    entry_points::reject_remittance_entry();
+}
+
+/// Contract entry point: decline_release
+#[no_mangle]
+pub extern "C" fn decline_release() {
+    entry_points::decline_release_entry();
+}
+
+/// Contract entry point: request_bridge_payout
+#[no_mangle]
+pub extern "C" fn request_bridge_payout() {
+    entry_points::request_bridge_payout_entry();
+}
+
+/// Contract entry point: confirm_bridge_payout
+#[no_mangle]
+pub extern "C" fn confirm_bridge_payout() {
+    entry_points::confirm_bridge_payout_entry();
+}
+
+/// Contract entry point: cancel_bridge_payout
+#[no_mangle]
+pub extern "C" fn cancel_bridge_payout() {
+    entry_points::cancel_bridge_payout_entry();
+}
+
+/// Contract entry point: extend_deadline
+#[no_mangle]
+pub extern "C" fn extend_deadline() {
+    entry_points::extend_deadline_entry();
+}
+
+/// Contract entry point: expire_remittance
+#[no_mangle]
+pub extern "C" fn expire_remittance() {
+    entry_points::expire_remittance_entry();
+}
+
+/// Contract entry point: expire_batch
+#[no_mangle]
+pub extern "C" fn expire_batch() {
+    entry_points::expire_batch_entry();
+}
+
+/// Contract entry point: escalate_overdue
+#[no_mangle]
+pub extern "C" fn escalate_overdue() {
+    entry_points::escalate_overdue_entry();
+}
+
+/// Contract entry point: process_installment
+#[no_mangle]
+pub extern "C" fn process_installment() {
+    entry_points::process_installment_entry();
+}
+
+/// Contract entry point: withdraw_pot
+#[no_mangle]
+pub extern "C" fn withdraw_pot() {
+    entry_points::withdraw_pot_entry();
+}
+
+/// Contract entry point: claim_refund
+#[no_mangle]
+pub extern "C" fn claim_refund() {
+    entry_points::claim_refund_entry();
+}
+
+/// Contract entry point: claim_all_refunds
+#[no_mangle]
+pub extern "C" fn claim_all_refunds() {
+    entry_points::claim_all_refunds_entry();
+}
+
+/// Contract entry point: set_refund_delegate
+#[no_mangle]
+pub extern "C" fn set_refund_delegate() {
+    entry_points::set_refund_delegate_entry();
+}
+
+/// Contract entry point: claim_excess_refund
+#[no_mangle]
+pub extern "C" fn claim_excess_refund() {
+    entry_points::claim_excess_refund_entry();
+}
+
+/// Contract entry point: claim_fee_cover
+#[no_mangle]
+pub extern "C" fn claim_fee_cover() {
+    entry_points::claim_fee_cover_entry();
+}
+
+/// Contract entry point: claim_insurance
+#[no_mangle]
+pub extern "C" fn claim_insurance() {
+    entry_points::claim_insurance_entry();
+}
+
+/// Contract entry point: claim_creator_rewards
+#[no_mangle]
+pub extern "C" fn claim_creator_rewards() {
+    entry_points::claim_creator_rewards_entry();
+}
+
+/// Contract entry point: get_remittance
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_remittance() {
+    entry_points::get_remittance_entry();
+}
+
+/// Contract entry point: get_contribution
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_contribution() {
+    entry_points::get_contribution_entry();
+}
+
+/// Contract entry point: get_contribution_weight
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_contribution_weight() {
+    entry_points::get_contribution_weight_entry();
+}
+
+/// Contract entry point: get_total_contribution_weight
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_total_contribution_weight() {
+    entry_points::get_total_contribution_weight_entry();
+}
+
+/// Contract entry point: get_contribution_commitment
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_contribution_commitment() {
+    entry_points::get_contribution_commitment_entry();
+}
+
+/// Contract entry point: is_refund_claimed
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn is_refund_claimed() {
+    entry_points::is_refund_claimed_entry();
+}
+
+/// Contract entry point: get_platform_fee
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_platform_fee() {
+    entry_points::get_platform_fee_entry();
+}
+
+/// Contract entry point: get_contract_info
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_contract_info() {
+    entry_points::get_contract_info_entry();
+}
+
+/// Contract entry point: is_paused
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn is_paused() {
+    entry_points::is_paused_entry();
+}
+
+/// Contract entry point: is_admin_renounced
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn is_admin_renounced() {
+    entry_points::is_admin_renounced_entry();
+}
+
+/// Contract entry point: get_owner
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_owner() {
+    entry_points::get_owner_entry();
+}
+
+/// Contract entry point: get_fee_collector
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_fee_collector() {
+    entry_points::get_fee_collector_entry();
+}
+
+/// Contract entry point: validate_release
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn validate_release() {
+    entry_points::validate_release_entry();
+}
+
+/// Contract entry point: validate_refund
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn validate_refund() {
+    entry_points::validate_refund_entry();
+}
+
+/// Contract entry point: get_documents
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_documents() {
+    entry_points::get_documents_entry();
+}
+
+/// Contract entry point: get_updates
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_updates() {
+    entry_points::get_updates_entry();
+}
+
+/// Contract entry point: get_purpose
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_purpose() {
+    entry_points::get_purpose_entry();
+}
+
+/// Contract entry point: get_acknowledgement
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_acknowledgement() {
+    entry_points::get_acknowledgement_entry();
+}
+
+/// Contract entry point: get_payout_targets
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_payout_targets() {
+    entry_points::get_payout_targets_entry();
+}
+
+/// Contract entry point: get_progress
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_progress() {
+    entry_points::get_progress_entry();
+}
+
+/// Contract entry point: get_funding_state
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_funding_state() {
+    entry_points::get_funding_state_entry();
+}
+
+/// Contract entry point: get_contributor_count
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_contributor_count() {
+    entry_points::get_contributor_count_entry();
+}
+
+/// Contract entry point: get_ledger_length
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_ledger_length() {
+    entry_points::get_ledger_length_entry();
+}
+
+/// Contract entry point: get_ledger_entries
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_ledger_entries() {
+    entry_points::get_ledger_entries_entry();
+}
+
+/// Contract entry point: get_remittance_by_reference
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_remittance_by_reference() {
+    entry_points::get_remittance_by_reference_entry();
+}
+
+/// Contract entry point: resolve_handle
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn resolve_handle() {
+    entry_points::resolve_handle_entry();
+}
+
+/// Contract entry point: get_handle
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_handle() {
+    entry_points::get_handle_entry();
+}
+
+/// Contract entry point: get_remittances_created_between
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_remittances_created_between() {
+    entry_points::get_remittances_created_between_entry();
+}
+
+/// Contract entry point: get_platform_fee_ppm
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_platform_fee_ppm() {
+    entry_points::get_platform_fee_ppm_entry();
+}
+
+/// Contract entry point: get_creator_fee_share
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_creator_fee_share() {
+    entry_points::get_creator_fee_share_entry();
+}
+
+/// Contract entry point: get_rate_limits
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_rate_limits() {
+    entry_points::get_rate_limits_entry();
+}
+
+/// Contract entry point: get_gas_limits
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_gas_limits() {
+    entry_points::get_gas_limits_entry();
+}
+
+/// Contract entry point: get_payment_guards
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_payment_guards() {
+    entry_points::get_payment_guards_entry();
+}
+
+/// Contract entry point: get_risk_engine
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_risk_engine() {
+    entry_points::get_risk_engine_entry();
+}
+
+/// Contract entry point: get_review_policy
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_review_policy() {
+    entry_points::get_review_policy_entry();
+}
+
+/// Contract entry point: get_max_deadline_extension
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_max_deadline_extension() {
+    entry_points::get_max_deadline_extension_entry();
+}
+
+/// Contract entry point: get_max_target_amount
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_max_target_amount() {
+    entry_points::get_max_target_amount_entry();
+}
+
+/// Contract entry point: get_min_target_amount
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_min_target_amount() {
+    entry_points::get_min_target_amount_entry();
+}
+
+/// Contract entry point: get_kyb_max_target_amount
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_kyb_max_target_amount() {
+    entry_points::get_kyb_max_target_amount_entry();
+}
+
+/// Contract entry point: get_branding
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_branding() {
+    entry_points::get_branding_entry();
+}
+
+/// Contract entry point: get_sla_intervals
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_sla_intervals() {
+    entry_points::get_sla_intervals_entry();
+}
+
+/// Contract entry point: get_overdue_remittances
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_overdue_remittances() {
+    entry_points::get_overdue_remittances_entry();
+}
+
+/// Contract entry point: get_namespace
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_namespace() {
+    entry_points::get_namespace_entry();
+}
+
+/// Contract entry point: get_remittance_namespace
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_remittance_namespace() {
+    entry_points::get_remittance_namespace_entry();
+}
+
+/// Contract entry point: get_category
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_category() {
+    entry_points::get_category_entry();
+}
+
+/// Contract entry point: get_remittance_category
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_remittance_category() {
+    entry_points::get_remittance_category_entry();
+}
+
+/// Contract entry point: get_decline_reason
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_decline_reason() {
+    entry_points::get_decline_reason_entry();
+}
+
+/// Contract entry point: get_creator_rewards
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_creator_rewards() {
+    entry_points::get_creator_rewards_entry();
+}
+
+/// Contract entry point: get_account_overview
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_account_overview() {
+    entry_points::get_account_overview_entry();
+}
+
+/// Contract entry point: get_claimable_refunds
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_claimable_refunds() {
+    entry_points::get_claimable_refunds_entry();
+}
+
+/// Contract entry point: get_fee_rounding
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_fee_rounding() {
+    entry_points::get_fee_rounding_entry();
+}
+
+/// Contract entry point: get_fee_model
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_fee_model() {
+    entry_points::get_fee_model_entry();
+}
+
+/// Contract entry point: get_max_fee_ppm
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_max_fee_ppm() {
+    entry_points::get_max_fee_ppm_entry();
+}
+
+/// Contract entry point: compute_grossed_up_target
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn compute_grossed_up_target() {
+    entry_points::compute_grossed_up_target_entry();
+}
+
+/// Contract entry point: set_platform_fee (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_platform_fee() {
+    entry_points::set_platform_fee_entry();
+}
+
+/// Contract entry point: set_platform_fee_ppm (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_platform_fee_ppm() {
+    entry_points::set_platform_fee_ppm_entry();
+}
+
+/// Contract entry point: lower_max_fee (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn lower_max_fee() {
+    entry_points::lower_max_fee_entry();
+}
+
+/// Contract entry point: set_fee_rounding (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_fee_rounding() {
+    entry_points::set_fee_rounding_entry();
+}
+
+/// Contract entry point: set_max_deadline_extension (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_max_deadline_extension() {
+    entry_points::set_max_deadline_extension_entry();
+}
+
+/// Contract entry point: set_max_target_amount (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_max_target_amount() {
+    entry_points::set_max_target_amount_entry();
+}
+
+/// Contract entry point: set_min_target_amount (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_min_target_amount() {
+    entry_points::set_min_target_amount_entry();
+}
+
+/// Contract entry point: set_kyb_max_target_amount (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_kyb_max_target_amount() {
+    entry_points::set_kyb_max_target_amount_entry();
+}
+
+/// Contract entry point: set_branding (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_branding() {
+    entry_points::set_branding_entry();
+}
+
+/// Contract entry point: create_namespace (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn create_namespace() {
+    entry_points::create_namespace_entry();
+}
+
+/// Contract entry point: set_category (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_category() {
+    entry_points::set_category_entry();
+}
+
+/// Contract entry point: set_sla_intervals (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_sla_intervals() {
+    entry_points::set_sla_intervals_entry();
+}
+
+/// Contract entry point: set_namespace_fee (namespace owner only)
+#[no_mangle]
+pub extern "C" fn set_namespace_fee() {
+    entry_points::set_namespace_fee_entry();
+}
+
+/// Contract entry point: pause_namespace (namespace owner only)
+#[no_mangle]
+pub extern "C" fn pause_namespace() {
+    entry_points::pause_namespace_entry();
+}
+
+/// Contract entry point: unpause_namespace (namespace owner only)
+#[no_mangle]
+pub extern "C" fn unpause_namespace() {
+    entry_points::unpause_namespace_entry();
+}
+
+/// Contract entry point: set_insurance_premium (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_insurance_premium() {
+    entry_points::set_insurance_premium_entry();
+}
+
+/// Contract entry point: set_creator_fee_share (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_creator_fee_share() {
+    entry_points::set_creator_fee_share_entry();
+}
+
+/// Contract entry point: set_rate_limits (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_rate_limits() {
+    entry_points::set_rate_limits_entry();
+}
+
+/// Contract entry point: set_gas_limits (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_gas_limits() {
+    entry_points::set_gas_limits_entry();
+}
+
+/// Contract entry point: set_payment_guards (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_payment_guards() {
+    entry_points::set_payment_guards_entry();
+}
+
+/// Contract entry point: migrate_records (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn migrate_records() {
+    entry_points::migrate_records_entry();
+}
+
+/// Contract entry point: rotate_owner (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn rotate_owner() {
+    entry_points::rotate_owner_entry();
+}
+
+/// Contract entry point: set_compliance_officer (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_compliance_officer() {
+    entry_points::set_compliance_officer_entry();
+}
+
+/// Contract entry point: set_bridge_operator (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_bridge_operator() {
+    entry_points::set_bridge_operator_entry();
+}
+
+/// Contract entry point: register_charity (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn register_charity() {
+    entry_points::register_charity_entry();
+}
+
+/// Contract entry point: unregister_charity (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn unregister_charity() {
+    entry_points::unregister_charity_entry();
+}
+
+/// Contract entry point: register_business (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn register_business() {
+    entry_points::register_business_entry();
+}
+
+/// Contract entry point: unregister_business (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn unregister_business() {
+    entry_points::unregister_business_entry();
+}
+
+/// Contract entry point: block_purpose (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn block_purpose() {
+    entry_points::block_purpose_entry();
+}
+
+/// Contract entry point: unblock_purpose (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn unblock_purpose() {
+    entry_points::unblock_purpose_entry();
+}
+
+/// Contract entry point: register_settlement_partner (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn register_settlement_partner() {
+    entry_points::register_settlement_partner_entry();
+}
+
+/// Contract entry point: unregister_settlement_partner (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn unregister_settlement_partner() {
+    entry_points::unregister_settlement_partner_entry();
+}
+
+/// Contract entry point: set_travel_rule_threshold (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_travel_rule_threshold() {
+    entry_points::set_travel_rule_threshold_entry();
+}
+
+/// Contract entry point: set_risk_engine (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_risk_engine() {
+    entry_points::set_risk_engine_entry();
+}
+
+/// Contract entry point: clear_risk_engine (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn clear_risk_engine() {
+    entry_points::clear_risk_engine_entry();
+}
+
+/// Contract entry point: set_review_policy (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn set_review_policy() {
+    entry_points::set_review_policy_entry();
+}
+
+/// Contract entry point: approve_release (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn approve_release() {
+    entry_points::approve_release_entry();
+}
+
+/// Contract entry point: approve_insurance_claims (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn approve_insurance_claims() {
+    entry_points::approve_insurance_claims_entry();
+}
+
+/// Contract entry point: force_cancel (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn force_cancel() {
+    entry_points::force_cancel_entry();
+}
+
+/// Contract entry point: force_refund (compliance only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn force_refund() {
+    entry_points::force_refund_entry();
+}
+
+/// Contract entry point: reject_release
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn reject_release() {
+    entry_points::reject_release_entry();
+}
+
+/// Contract entry point: snapshot_accounting
+#[cfg(feature = "stats")]
+#[no_mangle]
+pub extern "C" fn snapshot_accounting() {
+    entry_points::snapshot_accounting_entry();
+}
+
+/// Contract entry point: get_snapshot
+#[cfg(all(feature = "views", feature = "stats"))]
+#[no_mangle]
+pub extern "C" fn get_snapshot() {
+    entry_points::get_snapshot_entry();
+}
+
+/// Contract entry point: get_snapshot_count
+#[cfg(all(feature = "views", feature = "stats"))]
+#[no_mangle]
+pub extern "C" fn get_snapshot_count() {
+    entry_points::get_snapshot_count_entry();
+}
+
+/// Contract entry point: snapshot_contributions
+#[no_mangle]
+pub extern "C" fn snapshot_contributions() {
+    entry_points::snapshot_contributions_entry();
+}
+
+/// Contract entry point: get_contribution_snapshot
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_contribution_snapshot() {
+    entry_points::get_contribution_snapshot_entry();
+}
+
+/// Contract entry point: get_schema
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_schema() {
+    entry_points::get_schema_entry();
+}
+
+/// Contract entry point: get_event_schema_version
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_event_schema_version() {
+    entry_points::get_event_schema_version_entry();
+}
+
+/// Contract entry point: get_version
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_version() {
+    entry_points::get_version_entry();
+}
+
+/// Contract entry point: get_period_stats
+#[cfg(all(feature = "views", feature = "stats"))]
+#[no_mangle]
+pub extern "C" fn get_period_stats() {
+    entry_points::get_period_stats_entry();
+}
+
+/// Contract entry point: get_travel_rule_data
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_travel_rule_data() {
+    entry_points::get_travel_rule_data_entry();
+}
+
+/// Contract entry point: get_travel_rule_threshold
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_travel_rule_threshold() {
+    entry_points::get_travel_rule_threshold_entry();
+}
+
+/// Contract entry point: is_verified_charity
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn is_verified_charity() {
+    entry_points::is_verified_charity_entry();
+}
+
+/// Contract entry point: is_verified_business
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn is_verified_business() {
+    entry_points::is_verified_business_entry();
+}
+
+/// Contract entry point: is_purpose_blocked
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn is_purpose_blocked() {
+    entry_points::is_purpose_blocked_entry();
+}
+
+/// Contract entry point: is_business_payout
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn is_business_payout() {
+    entry_points::is_business_payout_entry();
+}
+
+/// Contract entry point: get_error_message
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_error_message() {
+    entry_points::get_error_message_entry();
+}
+
+/// Contract entry point: get_compliance_officer
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_compliance_officer() {
+    entry_points::get_compliance_officer_entry();
+}
+
+/// Contract entry point: get_settlement_partner
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_settlement_partner() {
+    entry_points::get_settlement_partner_entry();
+}
+
+/// Contract entry point: get_backup_recipient
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_backup_recipient() {
+    entry_points::get_backup_recipient_entry();
+}
+
+/// Contract entry point: get_payout_schedule
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_payout_schedule() {
+    entry_points::get_payout_schedule_entry();
+}
+
+/// Contract entry point: get_savings_pool
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_savings_pool() {
+    entry_points::get_savings_pool_entry();
+}
+
+/// Contract entry point: get_remittance_pool
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_remittance_pool() {
+    entry_points::get_remittance_pool_entry();
+}
+
+/// Contract entry point: get_recipient_multisig
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_recipient_multisig() {
+    entry_points::get_recipient_multisig_entry();
+}
+
+/// Contract entry point: get_scheduled_start
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_scheduled_start() {
+    entry_points::get_scheduled_start_entry();
+}
+
+/// Contract entry point: get_remittance_analytics
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_remittance_analytics() {
+    entry_points::get_remittance_analytics_entry();
+}
+
+/// Contract entry point: get_insurance_policy
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_insurance_policy() {
+    entry_points::get_insurance_policy_entry();
+}
+
+/// Contract entry point: get_fee_cover
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_fee_cover() {
+    entry_points::get_fee_cover_entry();
+}
+
+/// Contract entry point: get_release_breakdown
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_release_breakdown() {
+    entry_points::get_release_breakdown_entry();
+}
+
+/// Contract entry point: get_refund_policy
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_refund_policy() {
+    entry_points::get_refund_policy_entry();
+}
+
+/// Contract entry point: get_insurance_pool
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_insurance_pool() {
+    entry_points::get_insurance_pool_entry();
+}
+
+/// Contract entry point: get_insurance_premium
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_insurance_premium() {
+    entry_points::get_insurance_premium_entry();
+}
+
+/// Contract entry point: get_instant_split
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_instant_split() {
+    entry_points::get_instant_split_entry();
+}
+
+/// Contract entry point: get_payout_route
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_payout_route() {
+    entry_points::get_payout_route_entry();
+}
+
+/// Contract entry point: get_bridge_operator
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_bridge_operator() {
+    entry_points::get_bridge_operator_entry();
+}
+
+/// Contract entry point: get_bridge_payout
+#[cfg(feature = "views")]
+#[no_mangle]
+pub extern "C" fn get_bridge_payout() {
+    entry_points::get_bridge_payout_entry();
+}
+
+/// Contract entry point: withdraw_access_token (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn withdraw_access_token() {
+    entry_points::withdraw_access_token_entry();
+}
+
+/// Contract entry point: revoke_access_token (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn revoke_access_token() {
+    entry_points::revoke_access_token_entry();
+}
+
+/// Contract entry point: remove_named_key (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn remove_named_key() {
+    entry_points::remove_named_key_entry();
+}
+
+/// Contract entry point: pause_contract (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn pause_contract() {
+    entry_points::pause_contract_entry();
+}
+
+/// Contract entry point: unpause_contract (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn unpause_contract() {
+    entry_points::unpause_contract_entry();
+}
+
+/// Contract entry point: renounce_admin (admin only)
+#[cfg(feature = "admin")]
+#[no_mangle]
+pub extern "C" fn renounce_admin() {
+    entry_points::renounce_admin_entry();
+}
+
+/// Contract entry point: init (called once by the installer)
+#[no_mangle]
+pub extern "C" fn init() {
+    entry_points::init_entry();
+}
+
+/// Builds the contract's entry points.
+///
+/// `call` stores the contract with them and `init` records them in the
+/// contract schema, so both describe the same build.
+pub(crate) fn contract_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    // Installation entry point
+    entry_points.add_entry_point(EntryPoint::new(
+        "init",
+        vec![
+            Parameter::new("access_token", CLType::URef),
+            Parameter::new("max_fee_ppm", CLType::U64),
+            Parameter::new("fee_model", CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    // User entry points
+    entry_points.add_entry_point(EntryPoint::new(
+        "create_remittance",
+        vec![
+            Parameter::new("recipient", CLType::Key),
+            Parameter::new("target_amount", CLType::U512),
+            Parameter::new("purpose", CLType::String),
+        ],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "create_private_remittance",
+        vec![
+            Parameter::new("recipient", CLType::Key),
+            Parameter::new("target_amount", CLType::U512),
+            Parameter::new("purpose", CLType::String),
+            Parameter::new("key_salt", CLType::ByteArray(32)),
+        ],
+        CLType::ByteArray(32),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "create_savings_pool",
+        vec![
+            Parameter::new("members", CLType::List(Box::new(CLType::ByteArray(32)))),
+            Parameter::new("contribution", CLType::U512),
+            Parameter::new("cycle_days", CLType::U64),
+            Parameter::new("purpose", CLType::String),
+        ],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "reveal_purpose",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("purpose", CLType::String),
+            Parameter::new("salt", CLType::ByteArray(32)),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "attach_document",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("cid", CLType::String),
+            Parameter::new("content_hash", CLType::ByteArray(32)),
+            Parameter::new("doc_type", CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "post_update",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("message", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_localized_purpose",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("lang", CLType::String),
+            Parameter::new("purpose", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "acknowledge_receipt",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("message", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_payout_target",
+        vec![
+            Parameter::new("label", CLType::String),
+            Parameter::new("target", CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "remove_payout_target",
+        vec![Parameter::new("label", CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "register_handle",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("handle", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "attach_travel_rule_data",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("data_hash", CLType::ByteArray(32)),
+            Parameter::new("payload_uri", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "contribute",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("amount", CLType::U512),
+            Parameter::new("purse", CLType::URef),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    // Runs in the caller's account context to draw from their main purse
+    entry_points.add_entry_point(EntryPoint::new(
+        "contribute_simple",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("amount", CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Session,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "top_up_fee_cover",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("amount", CLType::U512),
+            Parameter::new("purse", CLType::URef),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "release_funds",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("partner", CLType::Key),
+            Parameter::new("reference_code", CLType::String),
+            Parameter::new("payout_target", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "release_simple",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "release_to_backup",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "approve_release_as_recipient",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel_remittance",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "reject_remittance",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "decline_release",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("reason", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "request_bridge_payout",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("chain_id", CLType::String),
+            Parameter::new("foreign_address", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "confirm_bridge_payout",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("foreign_tx_hash", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel_bridge_payout",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "extend_deadline",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("new_deadline", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "expire_remittance",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "expire_batch",
+        vec![
+            Parameter::new("start_id", CLType::U64),
+            Parameter::new("count", CLType::U64),
+        ],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "escalate_overdue",
+        vec![
+            Parameter::new("start_id", CLType::U64),
+            Parameter::new("count", CLType::U64),
+        ],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "process_installment",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "withdraw_pot",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_refund",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_all_refunds",
+        vec![Parameter::new("max_items", CLType::U64)],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_refund_delegate",
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_excess_refund",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_fee_cover",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_insurance",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_creator_rewards",
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    // View entry points
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_remittance",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Any, // Returns Remittance struct
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_contribution",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("contributor", CLType::Key),
+        ],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_contribution_weight",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("contributor", CLType::Key),
+        ],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_total_contribution_weight",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_contribution_commitment",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("contributor", CLType::Key),
+        ],
+        CLType::Option(Box::new(CLType::ByteArray(32))),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_refund_claimed",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("contributor", CLType::Key),
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_documents",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::List(Box::new(CLType::Any)), // Returns Vec<DocumentRef>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_updates",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::List(Box::new(CLType::Any)), // Returns Vec<RemittanceUpdate>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_purpose",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("lang", CLType::String),
+        ],
+        CLType::String,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_acknowledgement",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<ReceiptAcknowledgement>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_payout_targets",
+        vec![Parameter::new("account", CLType::Key)],
+        CLType::List(Box::new(CLType::Any)), // Returns Vec<PayoutTarget>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_progress",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_funding_state",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Any, // Returns FundingState struct
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_contributor_count",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_ledger_length",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_ledger_entries",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("start", CLType::U64),
+            Parameter::new("count", CLType::U64),
+        ],
+        CLType::List(Box::new(CLType::Any)), // Returns Vec<LedgerEntry>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_remittance_by_reference",
+        vec![
+            Parameter::new("creator", CLType::Key),
+            Parameter::new("reference", CLType::String),
+        ],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "resolve_handle",
+        vec![Parameter::new("handle", CLType::String)],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_handle",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::String)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_remittances_created_between",
+        vec![
+            Parameter::new("day_from", CLType::U64),
+            Parameter::new("day_to", CLType::U64),
+            Parameter::new("offset", CLType::U64),
+            Parameter::new("limit", CLType::U64),
+        ],
+        CLType::List(Box::new(CLType::U64)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_platform_fee",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_contract_info",
+        vec![],
+        CLType::Any, // Returns ContractInfo struct
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_paused",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_admin_renounced",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_owner",
+        vec![],
+        CLType::ByteArray(32), // Returns AccountHash
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_fee_collector",
+        vec![],
+        CLType::ByteArray(32), // Returns AccountHash
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "validate_release",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U32),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "validate_refund",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("contributor", CLType::Key),
+        ],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U32),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_platform_fee_ppm",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_creator_fee_share",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_rate_limits",
+        vec![],
+        CLType::Any, // Returns RateLimits struct
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_gas_limits",
+        vec![],
+        CLType::Any, // Returns GasLimits struct
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_payment_guards",
+        vec![],
+        CLType::Any, // Returns PaymentGuards struct
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_risk_engine",
+        vec![],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<RiskEngine>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_review_policy",
+        vec![],
+        CLType::Any, // Returns ReviewPolicy struct
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_max_deadline_extension",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_max_target_amount",
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_min_target_amount",
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_kyb_max_target_amount",
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_branding",
+        vec![],
+        CLType::Any, // Returns Branding
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_sla_intervals",
+        vec![],
+        CLType::List(Box::new(CLType::U64)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_overdue_remittances",
+        vec![
+            Parameter::new("start_id", CLType::U64),
+            Parameter::new("count", CLType::U64),
+        ],
+        CLType::List(Box::new(CLType::U64)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_namespace",
+        vec![Parameter::new("namespace", CLType::String)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<Namespace>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_remittance_namespace",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::String)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_category",
+        vec![Parameter::new("category", CLType::String)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<CategoryTemplate>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_remittance_category",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::String)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_decline_reason",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::String)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_creator_rewards",
+        vec![Parameter::new("creator", CLType::Key)],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_account_overview",
+        vec![Parameter::new("account", CLType::Key)],
+        CLType::Any, // Returns AccountOverview struct
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_claimable_refunds",
+        vec![Parameter::new("account", CLType::Key)],
+        CLType::List(Box::new(CLType::U64)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_fee_rounding",
+        vec![],
+        CLType::U8,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_fee_model",
+        vec![],
+        CLType::U8,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_max_fee_ppm",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "compute_grossed_up_target",
+        vec![Parameter::new("desired_net", CLType::U512)],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    // Admin entry points
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_platform_fee",
+        vec![Parameter::new("fee_bps", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_platform_fee_ppm",
+        vec![Parameter::new("fee_ppm", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "lower_max_fee",
+        vec![Parameter::new("max_fee_ppm", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_fee_rounding",
+        vec![Parameter::new("rounding", CLType::U8)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_max_deadline_extension",
+        vec![Parameter::new("max_extension_ms", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_max_target_amount",
+        vec![Parameter::new("max_target_amount", CLType::U512)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_min_target_amount",
+        vec![Parameter::new("min_target_amount", CLType::U512)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_kyb_max_target_amount",
+        vec![Parameter::new("kyb_max_target_amount", CLType::U512)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_branding",
+        vec![
+            Parameter::new("platform_name", CLType::String),
+            Parameter::new("logo_cid", CLType::String),
+            Parameter::new("support_url", CLType::String),
+            Parameter::new("currency_display", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "create_namespace",
+        vec![
+            Parameter::new("namespace", CLType::String),
+            Parameter::new("namespace_owner", CLType::Key),
+            Parameter::new("fee_ppm", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_category",
+        vec![
+            Parameter::new("category", CLType::String),
+            Parameter::new("deadline_days", CLType::U64),
+            Parameter::new("refund_excess", CLType::Bool),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_sla_intervals",
+        vec![Parameter::new(
+            "intervals_ms",
+            CLType::List(Box::new(CLType::U64)),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_namespace_fee",
+        vec![
+            Parameter::new("namespace", CLType::String),
+            Parameter::new("fee_ppm", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "pause_namespace",
+        vec![Parameter::new("namespace", CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "unpause_namespace",
+        vec![Parameter::new("namespace", CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_insurance_premium",
+        vec![Parameter::new("premium_bps", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_creator_fee_share",
+        vec![Parameter::new("share_bps", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_rate_limits",
+        vec![
+            Parameter::new("window_ms", CLType::U64),
+            Parameter::new("max_creations", CLType::U64),
+            Parameter::new("max_contributions", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_gas_limits",
+        vec![
+            Parameter::new("max_batch_size", CLType::U64),
+            Parameter::new("max_page_size", CLType::U64),
+            Parameter::new("max_contributors", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_payment_guards",
+        vec![
+            Parameter::new("reject_self_remittance", CLType::Bool),
+            Parameter::new("recipient_contributions", CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "migrate_records",
+        vec![
+            Parameter::new("start_id", CLType::U64),
+            Parameter::new("count", CLType::U64),
+        ],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "rotate_owner",
+        vec![Parameter::new("new_owner", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_compliance_officer",
+        vec![Parameter::new("officer", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_bridge_operator",
+        vec![Parameter::new("operator", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "register_charity",
+        vec![Parameter::new("charity", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "unregister_charity",
+        vec![Parameter::new("charity", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "register_business",
+        vec![Parameter::new("business", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "unregister_business",
+        vec![Parameter::new("business", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "block_purpose",
+        vec![Parameter::new("purpose_hash", CLType::ByteArray(32))],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "unblock_purpose",
+        vec![Parameter::new("purpose_hash", CLType::ByteArray(32))],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "register_settlement_partner",
+        vec![
+            Parameter::new("partner", CLType::Key),
+            Parameter::new("name", CLType::String),
+            Parameter::new("corridors", CLType::List(Box::new(CLType::String))),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "unregister_settlement_partner",
+        vec![Parameter::new("partner", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_travel_rule_threshold",
+        vec![Parameter::new("threshold", CLType::U512)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_risk_engine",
+        vec![
+            Parameter::new("contract_hash", CLType::ByteArray(32)),
+            Parameter::new("min_amount", CLType::U512),
+            Parameter::new("max_score", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "clear_risk_engine",
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_review_policy",
+        vec![
+            Parameter::new("threshold", CLType::U512),
+            Parameter::new("window_ms", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "approve_release",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "approve_insurance_claims",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("coverage_bps", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "force_cancel",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("reason", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "force_refund",
+        vec![
+            Parameter::new("remittance_id", CLType::U64),
+            Parameter::new("contributor", CLType::Key),
+            Parameter::new("reason", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "reject_release",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "stats")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "snapshot_accounting",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(all(feature = "views", feature = "stats"))]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_snapshot",
+        vec![Parameter::new("index", CLType::U64)],
+        CLType::Any, // Returns AccountingSnapshot struct
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(all(feature = "views", feature = "stats"))]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_snapshot_count",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "snapshot_contributions",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_contribution_snapshot",
+        vec![Parameter::new("snapshot_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<ContributionSnapshot>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_schema",
+        vec![],
+        CLType::Any, // Returns ContractSchema struct
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_event_schema_version",
+        vec![],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_version",
+        vec![],
+        CLType::Any, // Returns BuildInfo struct
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-use alloc::string::{String, ToString};
-use alloc::vec;
+    #[cfg(all(feature = "views", feature = "stats"))]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_period_stats",
+        vec![
+            Parameter::new("day_index", CLType::U64),
+            Parameter::new("corridor", CLType::String),
+        ],
+        CLType::Any, // Returns PeriodStats struct
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-mod entry_points;
-mod errors;
-mod events;
-mod remittance;
-mod storage;
-mod utils;
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_travel_rule_data",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<TravelRuleData>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-use casper_contract::contract_api::{runtime, storage as contract_storage};
-use casper_types::{
-    contracts::NamedKeys, CLType, EntryPoint, EntryPointAccess,
-    EntryPointType, EntryPoints, Parameter,
-};
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_travel_rule_threshold",
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract entry point: create_remittance
-#[no_mangle]
-pub extern "C" fn create_remittance() {
-    entry_points::create_remittance_entry();
-}
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_verified_charity",
+        vec![Parameter::new("account", CLType::Key)],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract entry point: contribute
-#[no_mangle]
-pub extern "C" fn contribute() {
-    entry_points::contribute_entry();
-}
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_verified_business",
+        vec![Parameter::new("account", CLType::Key)],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract entry point: release_funds
-#[no_mangle]
-pub extern "C" fn release_funds() {
-    entry_points::release_funds_entry();
-}
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_purpose_blocked",
+        vec![Parameter::new("purpose_hash", CLType::ByteArray(32))],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract entry point: cancel_remittance
-#[no_mangle]
-pub extern "C" fn cancel_remittance() {
-    entry_points::cancel_remittance_entry();
-}
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_business_payout",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract entry point: claim_refund
-#[no_mangle]
-pub extern "C" fn claim_refund() {
-    entry_points::claim_refund_entry();
-}
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_error_message",
+        vec![Parameter::new("code", CLType::U32)],
+        CLType::String,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract entry point: get_remittance
-#[no_mangle]
-pub extern "C" fn get_remittance() {
-    entry_points::get_remittance_entry();
-}
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_compliance_officer",
+        vec![],
+        CLType::ByteArray(32), // Returns AccountHash
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract entry point: get_contribution
-#[no_mangle]
-pub extern "C" fn get_contribution() {
-    entry_points::get_contribution_entry();
-}
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_settlement_partner",
+        vec![Parameter::new("partner", CLType::Key)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<SettlementPartner>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract entry point: is_refund_claimed
-#[no_mangle]
-pub extern "C" fn is_refund_claimed() {
-    entry_points::is_refund_claimed_entry();
-}
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_payout_route",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<PayoutRoute>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract entry point: get_platform_fee
-#[no_mangle]
-pub extern "C" fn get_platform_fee() {
-    entry_points::get_platform_fee_entry();
-}
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_instant_split",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<InstantSplit>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract entry point: set_platform_fee (admin only)
-#[no_mangle]
-pub extern "C" fn set_platform_fee() {
-    entry_points::set_platform_fee_entry();
-}
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_backup_recipient",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<BackupRecipient>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract entry point: pause_contract (admin only)
-#[no_mangle]
-pub extern "C" fn pause_contract() {
-    entry_points::pause_contract_entry();
-}
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_payout_schedule",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<PayoutSchedule>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract entry point: unpause_contract (admin only)
-#[no_mangle]
-pub extern "C" fn unpause_contract() {
-    entry_points::unpause_contract_entry();
-}
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_savings_pool",
+        vec![Parameter::new("pool_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<SavingsPool>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-/// Contract installation entry point.
-///
-/// This function is called when the contract is first deployed.
-/// It initializes storage and sets up the contract.
-#[no_mangle]
-pub extern "C" fn call() {
-    // Initialize contract storage
-    storage::initialize_contract();
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_remittance_pool",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::U64)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-    // Define entry points
-    let mut entry_points = EntryPoints::new();
+    #[cfg(feature = "views")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_recipient_multisig",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<RecipientMultisig>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
 
-    // User entry points
+    #[cfg(feature = "views")]
     entry_points.add_entry_point(EntryPoint::new(
-        "create_remittance",
-        vec![
-            Parameter::new("recipient", CLType::Key),
-            Parameter::new("target_amount", CLType::U512),
-            Parameter::new("purpose", CLType::String),
-        ],
-        CLType::U64,
+        "get_scheduled_start",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<ScheduledStart>
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
 
+    #[cfg(feature = "views")]
     entry_points.add_entry_point(EntryPoint::new(
-        "contribute",
-        vec![
-            Parameter::new("remittance_id", CLType::U64),
-            Parameter::new("amount", CLType::U512),
-            Parameter::new("purse", CLType::URef),
-        ],
-        CLType::Unit,
+        "get_remittance_analytics",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Any, // Returns RemittanceAnalytics struct
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
 
+    #[cfg(feature = "views")]
     entry_points.add_entry_point(EntryPoint::new(
-        "release_funds",
+        "get_insurance_policy",
         vec![Parameter::new("remittance_id", CLType::U64)],
-        CLType::Unit,
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<InsurancePolicy>
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
 
+    #[cfg(feature = "views")]
     entry_points.add_entry_point(EntryPoint::new(
-        "cancel_remittance",
+        "get_fee_cover",
         vec![Parameter::new("remittance_id", CLType::U64)],
-        CLType::Unit,
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<FeeCover>
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
 
+    #[cfg(feature = "views")]
     entry_points.add_entry_point(EntryPoint::new(
-        "claim_refund",
+        "get_release_breakdown",
         vec![Parameter::new("remittance_id", CLType::U64)],
-        CLType::Unit,
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<ReleaseBreakdown>
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
 
-    // View entry points
+    #[cfg(feature = "views")]
     entry_points.add_entry_point(EntryPoint::new(
-        "get_remittance",
+        "get_refund_policy",
         vec![Parameter::new("remittance_id", CLType::U64)],
-        CLType::Any, // Returns Remittance struct
+        CLType::Any, // Returns RefundPolicy struct
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
 
+    #[cfg(feature = "views")]
     entry_points.add_entry_point(EntryPoint::new(
-        "get_contribution",
-        vec![
-            Parameter::new("remittance_id", CLType::U64),
-            Parameter::new("contributor", CLType::Key),
-        ],
+        "get_insurance_pool",
+        vec![],
         CLType::U512,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
 
+    #[cfg(feature = "views")]
     entry_points.add_entry_point(EntryPoint::new(
-        "is_refund_claimed",
-        vec![
-            Parameter::new("remittance_id", CLType::U64),
-            Parameter::new("contributor", CLType::Key),
-        ],
-        CLType::Bool,
+        "get_insurance_premium",
+        vec![],
+        CLType::U64,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
 
+    #[cfg(feature = "views")]
     entry_points.add_entry_point(EntryPoint::new(
-        "get_platform_fee",
+        "get_bridge_operator",
         vec![],
-        CLType::U64,
+        CLType::ByteArray(32), // Returns AccountHash
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
 
-    // Admin entry points
+    #[cfg(feature = "views")]
     entry_points.add_entry_point(EntryPoint::new(
-        "set_platform_fee",
-        vec![Parameter::new("fee_bps", CLType::U64)],
+        "get_bridge_payout",
+        vec![Parameter::new("remittance_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Any)), // Returns Option<BridgePayout>
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "withdraw_access_token",
+        vec![],
+        CLType::URef,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_access_token",
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "remove_named_key",
+        vec![Parameter::new("name", CLType::String)],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
 
+    #[cfg(feature = "admin")]
     entry_points.add_entry_point(EntryPoint::new(
         "pause_contract",
         vec![],
@@ -244,6 +3138,7 @@ pub extern "C" fn call() {
         EntryPointType::Contract,
     ));
 
+    #[cfg(feature = "admin")]
     entry_points.add_entry_point(EntryPoint::new(
         "unpause_contract",
         vec![],
@@ -252,6 +3147,40 @@ pub extern "C" fn call() {
         EntryPointType::Contract,
     ));
 
+    #[cfg(feature = "admin")]
+    entry_points.add_entry_point(EntryPoint::new(
+        "renounce_admin",
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points
+}
+
+/// Contract installation entry point.
+///
+/// This function is called when the contract is first deployed.
+/// It stores the contract and then calls `init` so that all storage is
+/// created under the contract's own context rather than the installer's.
+/// An optional `max_fee_ppm` session argument is forwarded to `init` to cap
+/// the platform fee below `MAX_FEE_PPM`, and an optional `fee_model` to
+/// charge fees on contribution instead of on release.
+///
+/// Reverts with `AlreadyInitialized` if the installing account already holds
+/// an installation, so re-sending the install deploy cannot orphan the
+/// existing contract and its purse. Settings are changed through the admin
+/// entry points instead.
+#[no_mangle]
+pub extern "C" fn call() {
+    if runtime::has_key(CONTRACT_HASH_KEY) {
+        runtime::revert(errors::Error::AlreadyInitialized);
+    }
+
+    // Define entry points
+    let entry_points = contract_entry_points();
+
     // Create named keys for contract access
     let named_keys = NamedKeys::new();
 
@@ -260,9 +3189,28 @@ pub extern "C" fn call() {
         entry_points,
         Some(named_keys),
         Some("casperflow_contract_package".to_string()),
-        Some("casperflow_access_token".to_string()),
+        Some(ACCESS_TOKEN_KEY.to_string()),
     );
 
     // Store contract hash for easy access
-    runtime::put_key("casperflow_contract_hash", contract_hash.into());
+    runtime::put_key(CONTRACT_HASH_KEY, contract_hash.into());
+
+    // Hand the package access token to the contract instead of leaving it
+    // with the installing account
+    let access_token: URef = runtime::get_key(ACCESS_TOKEN_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    runtime::remove_key(ACCESS_TOKEN_KEY);
+
+    // Initialize contract storage in the contract context
+    let mut init_args = RuntimeArgs::new();
+    init_args.insert("access_token", access_token).unwrap_or_revert();
+    if let Some(max_fee_ppm) = args::get_optional::<u64>("max_fee_ppm") {
+        init_args.insert("max_fee_ppm", max_fee_ppm).unwrap_or_revert();
+    }
+    if let Some(fee_model) = args::get_optional::<u8>("fee_model") {
+        init_args.insert("fee_model", fee_model).unwrap_or_revert();
+    }
+    runtime::call_contract::<()>(contract_hash, "init", init_args);
 }
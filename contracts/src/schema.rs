@@ -0,0 +1,6 @@
+//! Machine-readable description of the contract interface.
+//!
+//! Defined in `casperflow-types` so explorers and code generators share the
+//! same encoding.
+
+pub use casperflow_types::schema::*;
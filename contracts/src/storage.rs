@@ -15,33 +15,267 @@ use casper_contract::{
 };
 use casper_types::{account::AccountHash, URef, U512};
 
-use crate::{errors::Error, remittance::Remittance};
+#[cfg(any(feature = "views", feature = "admin"))]
+use crate::remittance::Branding;
+#[cfg(feature = "admin")]
+use crate::remittance::RemittanceFormatVersion;
+#[cfg(feature = "stats")]
+use crate::remittance::{AccountingSnapshot, PeriodStats};
+use crate::{
+    errors::{Error, MAX_FEE_PPM, PPM_PER_BPS},
+    ledger::LedgerEntry,
+    remittance::{
+        contributor_key, rate_window_key, BackupRecipient, BridgePayout, BuildInfo,
+        CategoryTemplate, ContributionSnapshot, ContributionWeight, DocumentRef, FeeCover,
+        GasLimits, InstantSplit, InsurancePolicy, LegacyRemittance, LocalizedPurpose, Namespace,
+        PaymentGuards, PayoutRoute, PayoutSchedule, PayoutTarget, PotDeposit, RateLimitedAction,
+        RateLimits, RateWindow, ReceiptAcknowledgement, RecipientMultisig, RefundPolicy,
+        ReleaseBreakdown, ReleaseSla, Remittance, RemittanceAnalytics, RemittanceUpdate,
+        ReviewPolicy, RiskEngine, SavingsPool, ScheduledStart, SettlementPartner, TravelRuleData,
+    },
+    schema::ContractSchema,
+    utils::{FeeModel, FeeRounding},
+};
 
 // Storage key constants
 pub const REMITTANCE_COUNTER: &str = "remittance_counter";
-pub const REMITTANCES_DICT: &str = "remittances";
+pub const REMITTANCES_DICT: &str = "remittance_records";
+pub const LEGACY_REMITTANCES_DICT: &str = "remittances";
 pub const CONTRIBUTIONS_DICT: &str = "contributions";
 pub const CONTRIBUTORS_DICT: &str = "contributors";
 pub const REFUND_CLAIMED_DICT: &str = "refund_claimed";
 pub const USER_REMITTANCES_DICT: &str = "user_remittances";
 pub const RECIPIENT_REMITTANCES_DICT: &str = "recipient_remittances";
 pub const PLATFORM_FEE_BPS: &str = "platform_fee_bps";
+pub const PLATFORM_FEE_PPM: &str = "platform_fee_ppm";
+pub const MAX_FEE_PPM_KEY: &str = "max_fee_ppm";
 pub const FEE_COLLECTOR: &str = "fee_collector";
 pub const CONTRACT_OWNER: &str = "contract_owner";
 pub const IS_PAUSED: &str = "is_paused";
 pub const CONTRACT_PURSE: &str = "contract_purse";
+pub const FEE_ROUNDING: &str = "fee_rounding";
+pub const FEE_MODEL: &str = "fee_model";
+pub const CREATOR_FEE_SHARE_BPS: &str = "creator_fee_share_bps";
+pub const CREATOR_REWARDS_DICT: &str = "creator_rewards";
+pub const ACCESS_TOKEN: &str = "access_token";
+pub const REMITTANCE_KEYS_DICT: &str = "remittance_keys";
+pub const HASHED_REMITTANCES_DICT: &str = "hashed_remittances";
+pub const CONTRIBUTION_COMMITMENTS_DICT: &str = "contribution_commitments";
+pub const DOCUMENTS_DICT: &str = "remittance_documents";
+pub const REFERENCE_INDEX_DICT: &str = "reference_index";
+pub const COMPLIANCE_OFFICER: &str = "compliance_officer";
+pub const VERIFIED_CHARITIES_DICT: &str = "verified_charities";
+pub const TRAVEL_RULE_DICT: &str = "travel_rule_data";
+pub const TRAVEL_RULE_THRESHOLD: &str = "travel_rule_threshold";
+pub const PERIOD_STATS_DICT: &str = "period_stats";
+pub const CORRIDORS_DICT: &str = "remittance_corridors";
+pub const ESCROW_LIABILITIES: &str = "escrow_liabilities";
+pub const ACCRUED_CREATOR_REWARDS: &str = "accrued_creator_rewards";
+pub const SNAPSHOTS_DICT: &str = "accounting_snapshots";
+pub const SNAPSHOT_COUNT: &str = "snapshot_count";
+pub const CONTRACT_SCHEMA: &str = "contract_schema";
+pub const BUILD_INFO: &str = "build_info";
+pub const RATE_LIMITS: &str = "rate_limits";
+pub const RATE_WINDOWS_DICT: &str = "rate_windows";
+pub const RISK_ENGINE: &str = "risk_engine";
+pub const REVIEW_POLICY: &str = "review_policy";
+pub const RELEASE_REVIEWS_DICT: &str = "release_reviews";
+pub const MAX_DEADLINE_EXTENSION: &str = "max_deadline_extension";
+pub const DEADLINE_EXTENSIONS_DICT: &str = "deadline_extensions";
+pub const LEDGER_DICT: &str = "remittance_ledger";
+pub const LEDGER_LENGTHS_DICT: &str = "remittance_ledger_lengths";
+pub const BRIDGE_OPERATOR: &str = "bridge_operator";
+pub const BRIDGE_PAYOUTS_DICT: &str = "bridge_payouts";
+pub const SETTLEMENT_PARTNERS_DICT: &str = "settlement_partners";
+pub const PAYOUT_ROUTES_DICT: &str = "payout_routes";
+pub const CONTRIBUTOR_COUNTS_DICT: &str = "contributor_counts";
+pub const CONTRIBUTOR_CAPS_DICT: &str = "contributor_caps";
+pub const CREATION_INDEX_DICT: &str = "creation_index";
+pub const HANDLES_DICT: &str = "remittance_handles";
+pub const HANDLE_BY_REMITTANCE_DICT: &str = "handle_by_remittance";
+pub const INSTANT_SPLITS_DICT: &str = "instant_splits";
+pub const BACKUP_RECIPIENTS_DICT: &str = "backup_recipients";
+pub const INSURANCE_PREMIUM_BPS: &str = "insurance_premium_bps";
+pub const INSURANCE_POOL: &str = "insurance_pool";
+pub const INSURANCE_POLICIES_DICT: &str = "insurance_policies";
+pub const INSURANCE_CLAIMS_DICT: &str = "insurance_claims";
+pub const ANALYTICS_DICT: &str = "remittance_analytics";
+pub const UPDATES_DICT: &str = "remittance_updates";
+pub const ACKNOWLEDGEMENTS_DICT: &str = "receipt_acknowledgements";
+pub const MAX_TARGET_AMOUNT: &str = "max_target_amount";
+pub const MIN_TARGET_AMOUNT: &str = "min_target_amount";
+pub const IDEMPOTENCY_KEYS_DICT: &str = "idempotency_keys";
+pub const REFUND_POLICIES_DICT: &str = "refund_policies";
+pub const PAYOUT_TARGETS_DICT: &str = "payout_targets";
+pub const PAYOUT_SELECTIONS_DICT: &str = "payout_selections";
+#[cfg(any(feature = "views", feature = "admin"))]
+pub const BRANDING: &str = "branding";
+pub const NAMESPACES_DICT: &str = "namespaces";
+pub const REMITTANCE_NAMESPACES_DICT: &str = "remittance_namespaces";
+pub const SCHEDULED_STARTS_DICT: &str = "scheduled_starts";
+pub const CATEGORIES_DICT: &str = "categories";
+pub const REMITTANCE_CATEGORIES_DICT: &str = "remittance_categories";
+pub const REMITTANCE_FEE_RATES_DICT: &str = "remittance_fee_rates";
+pub const SLA_INTERVALS: &str = "sla_intervals";
+pub const RELEASE_SLAS_DICT: &str = "release_slas";
+pub const DECLINE_REASONS_DICT: &str = "decline_reasons";
+pub const FEE_COVERS_DICT: &str = "fee_covers";
+pub const RECIPIENT_MULTISIGS_DICT: &str = "recipient_multisigs";
+pub const EVENT_SCHEMA_VERSION_KEY: &str = "event_schema_version";
+pub const CONTRIBUTION_SNAPSHOTS_DICT: &str = "contribution_snapshots";
+pub const CONTRIBUTION_SNAPSHOT_COUNT: &str = "contribution_snapshot_count";
+pub const CONTRIBUTION_WEIGHTS_DICT: &str = "contribution_weights";
+pub const REMITTANCE_WEIGHTS_DICT: &str = "remittance_weights";
+pub const ESCROW_CLOSED_AT_DICT: &str = "escrow_closed_at";
+pub const CONTRIBUTED_REMITTANCES_DICT: &str = "contributed_remittances";
+pub const CLAIMABLE_REFUNDS_DICT: &str = "claimable_refunds";
+pub const REFUND_DELEGATES_DICT: &str = "refund_delegates";
+pub const VERIFIED_BUSINESSES_DICT: &str = "verified_businesses";
+pub const KYB_MAX_TARGET_AMOUNT: &str = "kyb_max_target_amount";
+pub const ADMIN_RENOUNCED: &str = "admin_renounced";
+pub const GAS_LIMITS: &str = "gas_limits";
+pub const INITIALIZED: &str = "initialized";
+pub const PAYMENT_GUARDS: &str = "payment_guards";
+pub const BLOCKED_PURPOSES_DICT: &str = "blocked_purposes";
+pub const RELEASE_BREAKDOWNS_DICT: &str = "release_breakdowns";
+pub const LOCALIZED_PURPOSES_DICT: &str = "localized_purposes";
+pub const PAYOUT_SCHEDULES_DICT: &str = "payout_schedules";
+pub const SAVINGS_POOLS_DICT: &str = "savings_pools";
+pub const SAVINGS_POOL_COUNT: &str = "savings_pool_count";
+pub const POOL_CYCLES_DICT: &str = "pool_cycles";
+pub const POT_DEPOSITS_DICT: &str = "pot_deposits";
+pub const REFUNDED_AMOUNTS_DICT: &str = "refunded_amounts";
+
+/// Named keys holding live contract state; `remove_named_key` refuses these.
+///
+/// Every storage key constant above must be listed here.
+#[cfg(feature = "admin")]
+pub const PROTECTED_NAMED_KEYS: &[&str] = &[
+    REMITTANCE_COUNTER,
+    REMITTANCES_DICT,
+    LEGACY_REMITTANCES_DICT,
+    CONTRIBUTIONS_DICT,
+    CONTRIBUTORS_DICT,
+    REFUND_CLAIMED_DICT,
+    USER_REMITTANCES_DICT,
+    RECIPIENT_REMITTANCES_DICT,
+    PLATFORM_FEE_BPS,
+    PLATFORM_FEE_PPM,
+    MAX_FEE_PPM_KEY,
+    FEE_COLLECTOR,
+    CONTRACT_OWNER,
+    IS_PAUSED,
+    CONTRACT_PURSE,
+    FEE_ROUNDING,
+    FEE_MODEL,
+    CREATOR_FEE_SHARE_BPS,
+    CREATOR_REWARDS_DICT,
+    ACCESS_TOKEN,
+    REMITTANCE_KEYS_DICT,
+    HASHED_REMITTANCES_DICT,
+    CONTRIBUTION_COMMITMENTS_DICT,
+    DOCUMENTS_DICT,
+    REFERENCE_INDEX_DICT,
+    COMPLIANCE_OFFICER,
+    VERIFIED_CHARITIES_DICT,
+    TRAVEL_RULE_DICT,
+    TRAVEL_RULE_THRESHOLD,
+    PERIOD_STATS_DICT,
+    CORRIDORS_DICT,
+    ESCROW_LIABILITIES,
+    ACCRUED_CREATOR_REWARDS,
+    SNAPSHOTS_DICT,
+    SNAPSHOT_COUNT,
+    CONTRACT_SCHEMA,
+    BUILD_INFO,
+    RATE_LIMITS,
+    RATE_WINDOWS_DICT,
+    RISK_ENGINE,
+    REVIEW_POLICY,
+    RELEASE_REVIEWS_DICT,
+    MAX_DEADLINE_EXTENSION,
+    DEADLINE_EXTENSIONS_DICT,
+    LEDGER_DICT,
+    LEDGER_LENGTHS_DICT,
+    BRIDGE_OPERATOR,
+    BRIDGE_PAYOUTS_DICT,
+    SETTLEMENT_PARTNERS_DICT,
+    PAYOUT_ROUTES_DICT,
+    CONTRIBUTOR_COUNTS_DICT,
+    CONTRIBUTOR_CAPS_DICT,
+    CREATION_INDEX_DICT,
+    HANDLES_DICT,
+    HANDLE_BY_REMITTANCE_DICT,
+    INSTANT_SPLITS_DICT,
+    BACKUP_RECIPIENTS_DICT,
+    INSURANCE_PREMIUM_BPS,
+    INSURANCE_POOL,
+    INSURANCE_POLICIES_DICT,
+    INSURANCE_CLAIMS_DICT,
+    ANALYTICS_DICT,
+    UPDATES_DICT,
+    ACKNOWLEDGEMENTS_DICT,
+    MAX_TARGET_AMOUNT,
+    MIN_TARGET_AMOUNT,
+    IDEMPOTENCY_KEYS_DICT,
+    REFUND_POLICIES_DICT,
+    PAYOUT_TARGETS_DICT,
+    PAYOUT_SELECTIONS_DICT,
+    BRANDING,
+    NAMESPACES_DICT,
+    REMITTANCE_NAMESPACES_DICT,
+    SCHEDULED_STARTS_DICT,
+    CATEGORIES_DICT,
+    REMITTANCE_CATEGORIES_DICT,
+    REMITTANCE_FEE_RATES_DICT,
+    SLA_INTERVALS,
+    RELEASE_SLAS_DICT,
+    DECLINE_REASONS_DICT,
+    FEE_COVERS_DICT,
+    RECIPIENT_MULTISIGS_DICT,
+    EVENT_SCHEMA_VERSION_KEY,
+    CONTRIBUTION_SNAPSHOTS_DICT,
+    CONTRIBUTION_SNAPSHOT_COUNT,
+    CONTRIBUTION_WEIGHTS_DICT,
+    REMITTANCE_WEIGHTS_DICT,
+    ESCROW_CLOSED_AT_DICT,
+    CONTRIBUTED_REMITTANCES_DICT,
+    CLAIMABLE_REFUNDS_DICT,
+    REFUND_DELEGATES_DICT,
+    VERIFIED_BUSINESSES_DICT,
+    KYB_MAX_TARGET_AMOUNT,
+    ADMIN_RENOUNCED,
+    GAS_LIMITS,
+    INITIALIZED,
+    PAYMENT_GUARDS,
+    BLOCKED_PURPOSES_DICT,
+    RELEASE_BREAKDOWNS_DICT,
+    LOCALIZED_PURPOSES_DICT,
+    PAYOUT_SCHEDULES_DICT,
+    SAVINGS_POOLS_DICT,
+    SAVINGS_POOL_COUNT,
+    POOL_CYCLES_DICT,
+    POT_DEPOSITS_DICT,
+    REFUNDED_AMOUNTS_DICT,
+    casper_event_standard::EVENTS_DICT,
+    casper_event_standard::EVENTS_LENGTH,
+    casper_event_standard::EVENTS_SCHEMA,
+    casper_event_standard::CES_VERSION_KEY,
+];
 
 /// Initializes the contract storage with default values.
 ///
-/// This function must be called once during contract installation.
-pub fn initialize_contract() {
+/// This function must be called once during contract installation, from the
+/// `init` entry point, so that dictionaries, named keys, and the purse are
+/// owned by the contract rather than the installing account. `max_fee_ppm`
+/// must already be checked against `MAX_FEE_PPM`.
+pub fn initialize_contract(max_fee_ppm: u64, fee_model: FeeModel) {
     // Create contract purse for holding escrowed funds
     let purse = system::create_purse();
     runtime::put_key(CONTRACT_PURSE, purse.into());
 
     // Initialize counter
-    storage::new_dictionary(REMITTANCE_COUNTER)
-        .unwrap_or_revert_with(Error::StorageError);
+    runtime::put_key(REMITTANCE_COUNTER, storage::new_uref(0u64).into());
 
     // Initialize dictionaries
     storage::new_dictionary(REMITTANCES_DICT)
@@ -56,9 +290,113 @@ pub fn initialize_contract() {
         .unwrap_or_revert_with(Error::StorageError);
     storage::new_dictionary(RECIPIENT_REMITTANCES_DICT)
         .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(CREATOR_REWARDS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(REMITTANCE_KEYS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(HASHED_REMITTANCES_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(CONTRIBUTION_COMMITMENTS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(DOCUMENTS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(REFERENCE_INDEX_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(VERIFIED_CHARITIES_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(TRAVEL_RULE_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(PERIOD_STATS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(CORRIDORS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(SNAPSHOTS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(RATE_WINDOWS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(RELEASE_REVIEWS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(DEADLINE_EXTENSIONS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(LEDGER_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(LEDGER_LENGTHS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(BRIDGE_PAYOUTS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(SETTLEMENT_PARTNERS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(PAYOUT_ROUTES_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(CONTRIBUTOR_COUNTS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(CONTRIBUTOR_CAPS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(CREATION_INDEX_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(HANDLES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(HANDLE_BY_REMITTANCE_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(INSTANT_SPLITS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(BACKUP_RECIPIENTS_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(INSURANCE_POLICIES_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(INSURANCE_CLAIMS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(ANALYTICS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(UPDATES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(ACKNOWLEDGEMENTS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(IDEMPOTENCY_KEYS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(REFUND_POLICIES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(PAYOUT_TARGETS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(PAYOUT_SELECTIONS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(NAMESPACES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(REMITTANCE_NAMESPACES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(SCHEDULED_STARTS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(CATEGORIES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(REMITTANCE_CATEGORIES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(REMITTANCE_FEE_RATES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(RELEASE_SLAS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(DECLINE_REASONS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(FEE_COVERS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(RECIPIENT_MULTISIGS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(CONTRIBUTION_SNAPSHOTS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(CONTRIBUTION_WEIGHTS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(REMITTANCE_WEIGHTS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(ESCROW_CLOSED_AT_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(CONTRIBUTED_REMITTANCES_DICT)
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(CLAIMABLE_REFUNDS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(REFUND_DELEGATES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(VERIFIED_BUSINESSES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(BLOCKED_PURPOSES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(RELEASE_BREAKDOWNS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(LOCALIZED_PURPOSES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(PAYOUT_SCHEDULES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(SAVINGS_POOLS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(POOL_CYCLES_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(POT_DEPOSITS_DICT).unwrap_or_revert_with(Error::StorageError);
+    storage::new_dictionary(REFUNDED_AMOUNTS_DICT).unwrap_or_revert_with(Error::StorageError);
+
+    // Initialize CES event storage
+    crate::events::init_events();
+
+    // Store the fee cap for this installation
+    runtime::put_key(MAX_FEE_PPM_KEY, storage::new_uref(max_fee_ppm).into());
 
-    // Set default platform fee (50 bps = 0.5%)
-    runtime::put_key(PLATFORM_FEE_BPS, storage::new_uref(50u64).into());
+    // Set default platform fee (50 bps = 0.5%), clamped to the cap
+    let fee_ppm = core::cmp::min(50u64 * PPM_PER_BPS, max_fee_ppm);
+    runtime::put_key(PLATFORM_FEE_BPS, storage::new_uref(fee_ppm / PPM_PER_BPS).into());
+    runtime::put_key(PLATFORM_FEE_PPM, storage::new_uref(fee_ppm).into());
+
+    // Fees round down by default
+    runtime::put_key(FEE_ROUNDING, storage::new_uref(FeeRounding::Floor as u8).into());
+
+    // The fee model is fixed for the life of the installation
+    runtime::put_key(FEE_MODEL, storage::new_uref(fee_model as u8).into());
+
+    // No creator fee rebate by default
+    runtime::put_key(CREATOR_FEE_SHARE_BPS, storage::new_uref(0u64).into());
 
     // Set contract owner
     let caller = runtime::get_caller();
@@ -67,8 +405,77 @@ pub fn initialize_contract() {
     // Set fee collector (initially the owner)
     runtime::put_key(FEE_COLLECTOR, storage::new_uref(caller).into());
 
-    // Contract starts unpaused
+    // Set compliance officer (initially the owner)
+    runtime::put_key(COMPLIANCE_OFFICER, storage::new_uref(caller).into());
+
+    // Set bridge operator (initially the owner)
+    runtime::put_key(BRIDGE_OPERATOR, storage::new_uref(caller).into());
+
+    // Travel-rule data is not required until a threshold is set
+    runtime::put_key(TRAVEL_RULE_THRESHOLD, storage::new_uref(U512::zero()).into());
+
+    // Accounting ledger starts empty
+    runtime::put_key(ESCROW_LIABILITIES, storage::new_uref(U512::zero()).into());
+    runtime::put_key(ACCRUED_CREATOR_REWARDS, storage::new_uref(U512::zero()).into());
+    runtime::put_key(SNAPSHOT_COUNT, storage::new_uref(0u64).into());
+
+    // No rate limits until the owner sets them
+    runtime::put_key(RATE_LIMITS, storage::new_uref(RateLimits::default()).into());
+
+    // Batch calls and pages are bounded by the built-in maximum until the
+    // owner lowers it
+    runtime::put_key(GAS_LIMITS, storage::new_uref(GasLimits::default()).into());
+
+    // Self-remittances and recipient contributions stay allowed until the
+    // owner tightens them
+    runtime::put_key(PAYMENT_GUARDS, storage::new_uref(PaymentGuards::default()).into());
+
+    // Releases are not risk-scored until compliance registers an engine
+    runtime::put_key(RISK_ENGINE, storage::new_uref(Option::<RiskEngine>::None).into());
+
+    // Releases are not held for review until compliance sets a threshold
+    runtime::put_key(REVIEW_POLICY, storage::new_uref(ReviewPolicy::default()).into());
+
+    // Deadlines cannot be extended until the owner allows it
+    runtime::put_key(MAX_DEADLINE_EXTENSION, storage::new_uref(0u64).into());
+
+    // Insurance is free until the owner sets a premium, and the pool starts
+    // empty
+    runtime::put_key(INSURANCE_PREMIUM_BPS, storage::new_uref(0u64).into());
+    runtime::put_key(INSURANCE_POOL, storage::new_uref(U512::zero()).into());
+
+    // Target amounts are unbounded until the owner sets a minimum or maximum
+    runtime::put_key(MAX_TARGET_AMOUNT, storage::new_uref(U512::zero()).into());
+    runtime::put_key(MIN_TARGET_AMOUNT, storage::new_uref(U512::zero()).into());
+    runtime::put_key(KYB_MAX_TARGET_AMOUNT, storage::new_uref(U512::zero()).into());
+
+    // Contract starts unpaused, with the owner in full control
     runtime::put_key(IS_PAUSED, storage::new_uref(false).into());
+    runtime::put_key(ADMIN_RENOUNCED, storage::new_uref(false).into());
+
+    // Written last, so a contract only counts as initialized once every key
+    // above exists
+    runtime::put_key(INITIALIZED, storage::new_uref(true).into());
+}
+
+/// Checks whether contract storage has already been initialized.
+///
+/// Installs that predate the `initialized` sentinel are recognized by their
+/// owner key.
+pub fn is_initialized() -> bool {
+    runtime::has_key(INITIALIZED) || runtime::has_key(CONTRACT_OWNER)
+}
+
+/// Gets the number of remittances created so far (the highest issued ID).
+pub fn get_remittance_count() -> u64 {
+    let counter_uref: URef = runtime::get_key(REMITTANCE_COUNTER)
+        .unwrap_or_revert_with(Error::StorageError)
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+
+    storage::read(counter_uref)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or(0u64)
 }
 
 /// Gets the next remittance ID and increments the counter.
@@ -99,19 +506,104 @@ pub fn store_remittance(remittance: &Remittance) {
 }
 
 /// Retrieves a remittance from storage.
+///
+/// Falls back to the legacy dictionary for records that have not been
+/// migrated to the versioned encoding yet.
 pub fn get_remittance(id: u64) -> Result<Remittance, Error> {
     let dict_uref = get_dict_uref(REMITTANCES_DICT);
     let key = id.to_string();
 
+    let remittance: Option<Remittance> = storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError);
+
+    match remittance {
+        Some(remittance) => Ok(remittance),
+        None => get_legacy_remittance(id).ok_or(Error::RemittanceNotFound),
+    }
+}
+
+/// Retrieves a remittance stored in the legacy unversioned layout, if any.
+pub fn get_legacy_remittance(id: u64) -> Option<Remittance> {
+    let dict_uref = runtime::get_key(LEGACY_REMITTANCES_DICT)?
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+    let key = id.to_string();
+
+    let legacy: Option<LegacyRemittance> = storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError);
+
+    legacy.map(|LegacyRemittance(remittance)| remittance)
+}
+
+/// Gets the encoding version of a remittance in the versioned dictionary.
+///
+/// Returns `None` if the remittance is absent or only stored in the legacy
+/// dictionary.
+#[cfg(feature = "admin")]
+pub fn get_stored_format_version(id: u64) -> Option<u8> {
+    let dict_uref = get_dict_uref(REMITTANCES_DICT);
+    let key = id.to_string();
+
+    let version: Option<RemittanceFormatVersion> = storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError);
+
+    version.map(|RemittanceFormatVersion(version)| version)
+}
+
+/// Maps a private remittance's hash key to its internal ID.
+pub fn register_remittance_key(remittance_key: [u8; 32], remittance_id: u64) {
+    let dict_uref = get_dict_uref(REMITTANCE_KEYS_DICT);
+    let key = hex_key(&remittance_key);
+
+    storage::dictionary_put(dict_uref, &key, remittance_id);
+}
+
+/// Resolves a private remittance's hash key to its internal ID.
+pub fn resolve_remittance_key(remittance_key: [u8; 32]) -> Option<u64> {
+    let dict_uref = get_dict_uref(REMITTANCE_KEYS_DICT);
+    let key = hex_key(&remittance_key);
+
+    storage::dictionary_get(dict_uref, &key).unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Indexes a remittance under its creator-scoped external reference key.
+pub fn index_external_reference(reference_key: [u8; 32], remittance_id: u64) {
+    let dict_uref = get_dict_uref(REFERENCE_INDEX_DICT);
+    let key = hex_key(&reference_key);
+
+    storage::dictionary_put(dict_uref, &key, remittance_id);
+}
+
+/// Looks up a remittance ID by its creator-scoped external reference key.
+pub fn get_remittance_by_reference(reference_key: [u8; 32]) -> Option<u64> {
+    let dict_uref = get_dict_uref(REFERENCE_INDEX_DICT);
+    let key = hex_key(&reference_key);
+
+    storage::dictionary_get(dict_uref, &key).unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Marks a remittance as only addressable by its hash key.
+pub fn mark_hashed_remittance(remittance_id: u64) {
+    let dict_uref = get_dict_uref(HASHED_REMITTANCES_DICT);
+    let key = remittance_id.to_string();
+
+    storage::dictionary_put(dict_uref, &key, true);
+}
+
+/// Checks if a remittance is only addressable by its hash key.
+pub fn is_hashed_remittance(remittance_id: u64) -> bool {
+    let dict_uref = get_dict_uref(HASHED_REMITTANCES_DICT);
+    let key = remittance_id.to_string();
+
     storage::dictionary_get(dict_uref, &key)
         .unwrap_or_revert_with(Error::StorageError)
-        .ok_or(Error::RemittanceNotFound)
+        .unwrap_or(false)
 }
 
 /// Stores a contribution amount for a specific remittance and contributor.
 pub fn store_contribution(remittance_id: u64, contributor: AccountHash, amount: U512) {
     let dict_uref = get_dict_uref(CONTRIBUTIONS_DICT);
-    let key = format!("{}_{}", remittance_id, contributor);
+    let key = hex_key(&contributor_key(remittance_id, &contributor));
 
     // Get existing contribution if any
     let existing: U512 = storage::dictionary_get(dict_uref, &key)
@@ -129,13 +621,122 @@ pub fn store_contribution(remittance_id: u64, contributor: AccountHash, amount:
 /// Retrieves the contribution amount for a specific remittance and contributor.
 pub fn get_contribution(remittance_id: u64, contributor: AccountHash) -> U512 {
     let dict_uref = get_dict_uref(CONTRIBUTIONS_DICT);
-    let key = format!("{}_{}", remittance_id, contributor);
+    let key = hex_key(&contributor_key(remittance_id, &contributor));
 
     storage::dictionary_get(dict_uref, &key)
         .unwrap_or_revert_with(Error::StorageError)
         .unwrap_or(U512::zero())
 }
 
+/// Clears a contributor's recorded contribution after it was refunded.
+#[cfg(feature = "admin")]
+pub fn clear_contribution(remittance_id: u64, contributor: AccountHash) {
+    let dict_uref = get_dict_uref(CONTRIBUTIONS_DICT);
+    let key = hex_key(&contributor_key(remittance_id, &contributor));
+
+    storage::dictionary_put(dict_uref, &key, U512::zero());
+}
+
+/// Applies `update` to a contributor's time weight and to the remittance's,
+/// creating their storage if needed.
+pub fn update_contribution_weights(
+    remittance_id: u64,
+    contributor: AccountHash,
+    update: impl Fn(&mut ContributionWeight),
+) {
+    for dict_name in [CONTRIBUTION_WEIGHTS_DICT, REMITTANCE_WEIGHTS_DICT] {
+        if runtime::get_key(dict_name).is_none() {
+            storage::new_dictionary(dict_name).unwrap_or_revert_with(Error::StorageError);
+        }
+    }
+
+    let keys = [
+        (CONTRIBUTION_WEIGHTS_DICT, hex_key(&contributor_key(remittance_id, &contributor))),
+        (REMITTANCE_WEIGHTS_DICT, remittance_id.to_string()),
+    ];
+
+    for (dict_name, key) in keys {
+        let dict_uref = get_dict_uref(dict_name);
+        let mut weight: ContributionWeight = storage::dictionary_get(dict_uref, &key)
+            .unwrap_or_revert_with(Error::StorageError)
+            .unwrap_or_default();
+
+        update(&mut weight);
+        storage::dictionary_put(dict_uref, &key, weight);
+    }
+}
+
+/// Gets a contributor's time weight; zero for contributions made before
+/// weights were tracked.
+#[cfg(feature = "views")]
+pub fn get_contribution_weight(remittance_id: u64, contributor: AccountHash) -> ContributionWeight {
+    let key = hex_key(&contributor_key(remittance_id, &contributor));
+    get_weight(CONTRIBUTION_WEIGHTS_DICT, &key)
+}
+
+/// Gets the combined time weight of all contributions to a remittance.
+#[cfg(feature = "views")]
+pub fn get_remittance_weight(remittance_id: u64) -> ContributionWeight {
+    get_weight(REMITTANCE_WEIGHTS_DICT, &remittance_id.to_string())
+}
+
+#[cfg(feature = "views")]
+fn get_weight(dict_name: &str, key: &str) -> ContributionWeight {
+    if runtime::get_key(dict_name).is_none() {
+        return ContributionWeight::default();
+    }
+
+    storage::dictionary_get(get_dict_uref(dict_name), key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
+/// Records when a remittance stopped being active, creating its storage if
+/// needed. Time weights stop accruing at this block.
+pub fn set_escrow_closed_at(remittance_id: u64, timestamp: u64) {
+    if runtime::get_key(ESCROW_CLOSED_AT_DICT).is_none() {
+        storage::new_dictionary(ESCROW_CLOSED_AT_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(ESCROW_CLOSED_AT_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), timestamp);
+}
+
+/// Gets when a remittance stopped being active, if it has.
+#[cfg(feature = "views")]
+pub fn get_escrow_closed_at(remittance_id: u64) -> Option<u64> {
+    if runtime::get_key(ESCROW_CLOSED_AT_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(ESCROW_CLOSED_AT_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores a contributor's amount commitment for a confidential remittance.
+pub fn store_contribution_commitment(
+    remittance_id: u64,
+    contributor: AccountHash,
+    commitment: [u8; 32],
+) {
+    let dict_uref = get_dict_uref(CONTRIBUTION_COMMITMENTS_DICT);
+    let key = hex_key(&contributor_key(remittance_id, &contributor));
+
+    storage::dictionary_put(dict_uref, &key, commitment);
+}
+
+/// Retrieves a contributor's amount commitment for a confidential remittance.
+pub fn get_contribution_commitment(
+    remittance_id: u64,
+    contributor: AccountHash,
+) -> Option<[u8; 32]> {
+    let dict_uref = get_dict_uref(CONTRIBUTION_COMMITMENTS_DICT);
+    let key = hex_key(&contributor_key(remittance_id, &contributor));
+
+    storage::dictionary_get(dict_uref, &key).unwrap_or_revert_with(Error::StorageError)
+}
+
 /// Adds a contributor to the list of contributors for a remittance.
 pub fn add_contributor(remittance_id: u64, contributor: AccountHash) {
     let dict_uref = get_dict_uref(CONTRIBUTORS_DICT);
@@ -149,28 +750,184 @@ pub fn add_contributor(remittance_id: u64, contributor: AccountHash) {
     // Add if not already present
     if !contributors.contains(&contributor) {
         contributors.push(contributor);
+        let count = contributors.len() as u64;
         storage::dictionary_put(dict_uref, &key, contributors);
+
+        // Keep the count separately so reading it does not load the list
+        if runtime::get_key(CONTRIBUTOR_COUNTS_DICT).is_none() {
+            storage::new_dictionary(CONTRIBUTOR_COUNTS_DICT)
+                .unwrap_or_revert_with(Error::StorageError);
+        }
+        storage::dictionary_put(get_dict_uref(CONTRIBUTOR_COUNTS_DICT), &key, count);
+
+        // A private remittance must not show up in its contributors' indexes
+        if !is_hashed_remittance(remittance_id) {
+            add_contributed_remittance(contributor, remittance_id);
+        }
+    }
+}
+
+/// Adds a remittance ID to a contributor's list, creating its storage if
+/// needed.
+fn add_contributed_remittance(contributor: AccountHash, remittance_id: u64) {
+    if runtime::get_key(CONTRIBUTED_REMITTANCES_DICT).is_none() {
+        storage::new_dictionary(CONTRIBUTED_REMITTANCES_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(CONTRIBUTED_REMITTANCES_DICT);
+    let key = contributor.to_string();
+
+    let mut remittances: Vec<u64> = storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_else(Vec::new);
+
+    remittances.push(remittance_id);
+    storage::dictionary_put(dict_uref, &key, remittances);
+}
+
+/// Gets the IDs of remittances an account contributed to, in order of
+/// first contribution.
+///
+/// Contributions made before the index existed are not listed.
+#[cfg(feature = "views")]
+pub fn get_contributed_remittances(contributor: AccountHash) -> Vec<u64> {
+    if runtime::get_key(CONTRIBUTED_REMITTANCES_DICT).is_none() {
+        return Vec::new();
+    }
+    let dict_uref = get_dict_uref(CONTRIBUTED_REMITTANCES_DICT);
+
+    storage::dictionary_get(dict_uref, &contributor.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
+/// Gets the contributors to a remittance, in order of first contribution.
+pub fn get_contributors(remittance_id: u64) -> Vec<AccountHash> {
+    let dict_uref = get_dict_uref(CONTRIBUTORS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
+/// Gets the number of unique contributors to a remittance.
+///
+/// Remittances last contributed to before the counter existed fall back to
+/// the length of the contributor list.
+pub fn get_contributor_count(remittance_id: u64) -> u64 {
+    let key = remittance_id.to_string();
+
+    let count = match runtime::get_key(CONTRIBUTOR_COUNTS_DICT) {
+        Some(_) => storage::dictionary_get(get_dict_uref(CONTRIBUTOR_COUNTS_DICT), &key)
+            .unwrap_or_revert_with(Error::StorageError),
+        None => None,
+    };
+
+    count.unwrap_or_else(|| {
+        storage::dictionary_get::<Vec<AccountHash>>(get_dict_uref(CONTRIBUTORS_DICT), &key)
+            .unwrap_or_revert_with(Error::StorageError)
+            .map_or(0, |contributors| contributors.len() as u64)
+    })
+}
+
+/// Caps the number of unique contributors to a remittance, creating its
+/// storage if needed.
+pub fn set_contributor_cap(remittance_id: u64, max_contributors: u64) {
+    if runtime::get_key(CONTRIBUTOR_CAPS_DICT).is_none() {
+        storage::new_dictionary(CONTRIBUTOR_CAPS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(CONTRIBUTOR_CAPS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), max_contributors);
+}
+
+/// Gets the contributor cap of a remittance, if it has one.
+pub fn get_contributor_cap(remittance_id: u64) -> Option<u64> {
+    if runtime::get_key(CONTRIBUTOR_CAPS_DICT).is_none() {
+        return None;
     }
+    let dict_uref = get_dict_uref(CONTRIBUTOR_CAPS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
 }
 
 /// Marks a refund as claimed for a specific remittance and contributor.
 pub fn mark_refund_claimed(remittance_id: u64, contributor: AccountHash) {
     let dict_uref = get_dict_uref(REFUND_CLAIMED_DICT);
-    let key = format!("{}_{}", remittance_id, contributor);
+    let key = hex_key(&contributor_key(remittance_id, &contributor));
 
     storage::dictionary_put(dict_uref, &key, true);
 }
 
+/// Adds `amount` to the total refunded from a remittance, creating its
+/// storage if needed.
+pub fn add_refunded_amount(remittance_id: u64, amount: U512) {
+    if runtime::get_key(REFUNDED_AMOUNTS_DICT).is_none() {
+        storage::new_dictionary(REFUNDED_AMOUNTS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let total = get_refunded_amount(remittance_id)
+        .checked_add(amount)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    let dict_uref = get_dict_uref(REFUNDED_AMOUNTS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), total);
+}
+
+/// Gets the total refunded from a remittance's escrow so far.
+pub fn get_refunded_amount(remittance_id: u64) -> U512 {
+    if runtime::get_key(REFUNDED_AMOUNTS_DICT).is_none() {
+        return U512::zero();
+    }
+    let dict_uref = get_dict_uref(REFUNDED_AMOUNTS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
 /// Checks if a refund has been claimed.
 pub fn is_refund_claimed(remittance_id: u64, contributor: AccountHash) -> bool {
     let dict_uref = get_dict_uref(REFUND_CLAIMED_DICT);
-    let key = format!("{}_{}", remittance_id, contributor);
+    let key = hex_key(&contributor_key(remittance_id, &contributor));
 
     storage::dictionary_get(dict_uref, &key)
         .unwrap_or_revert_with(Error::StorageError)
         .unwrap_or(false)
 }
 
+/// Records a contributor's idempotency key for a remittance.
+///
+/// Returns `false` without changes if the key was already used.
+pub fn record_idempotency_key(
+    remittance_id: u64,
+    contributor: AccountHash,
+    idempotency_key: [u8; 32],
+) -> bool {
+    if runtime::get_key(IDEMPOTENCY_KEYS_DICT).is_none() {
+        storage::new_dictionary(IDEMPOTENCY_KEYS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+    let dict_uref = get_dict_uref(IDEMPOTENCY_KEYS_DICT);
+
+    // Key on (remittance, contributor, idempotency key); a hash keeps it
+    // within the 64-byte item key limit
+    let mut preimage = [0u8; 64];
+    preimage[..32].copy_from_slice(&contributor_key(remittance_id, &contributor));
+    preimage[32..].copy_from_slice(&idempotency_key);
+    let key = hex_key(&runtime::blake2b(preimage));
+
+    let used: bool = storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or(false);
+    if used {
+        return false;
+    }
+
+    storage::dictionary_put(dict_uref, &key, true);
+    true
+}
+
 /// Adds a remittance ID to a user's list of created remittances.
 pub fn add_user_remittance(user: AccountHash, remittance_id: u64) {
     let dict_uref = get_dict_uref(USER_REMITTANCES_DICT);
@@ -197,74 +954,2009 @@ pub fn add_recipient_remittance(recipient: AccountHash, remittance_id: u64) {
     storage::dictionary_put(dict_uref, &key, remittances);
 }
 
-/// Gets the platform fee in basis points.
-pub fn get_platform_fee_bps() -> u64 {
-    let uref: URef = runtime::get_key(PLATFORM_FEE_BPS)
-        .unwrap_or_revert_with(Error::StorageError)
-        .into_uref()
-        .unwrap_or_revert_with(Error::StorageError);
+/// Adds a remittance ID to a contributor's claimable refunds, creating its
+/// storage if needed.
+pub fn add_claimable_refund(contributor: AccountHash, remittance_id: u64) {
+    if runtime::get_key(CLAIMABLE_REFUNDS_DICT).is_none() {
+        storage::new_dictionary(CLAIMABLE_REFUNDS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
 
-    storage::read(uref)
+    let dict_uref = get_dict_uref(CLAIMABLE_REFUNDS_DICT);
+    let key = contributor.to_string();
+
+    let mut remittances: Vec<u64> = storage::dictionary_get(dict_uref, &key)
         .unwrap_or_revert_with(Error::StorageError)
-        .unwrap_or(50u64)
+        .unwrap_or_else(Vec::new);
+
+    if !remittances.contains(&remittance_id) {
+        remittances.push(remittance_id);
+        storage::dictionary_put(dict_uref, &key, remittances);
+    }
 }
 
-/// Sets the platform fee in basis points.
-pub fn set_platform_fee_bps(fee_bps: u64) {
-    let uref: URef = runtime::get_key(PLATFORM_FEE_BPS)
+/// Removes a remittance ID from a contributor's claimable refunds.
+pub fn remove_claimable_refund(contributor: AccountHash, remittance_id: u64) {
+    if runtime::get_key(CLAIMABLE_REFUNDS_DICT).is_none() {
+        return;
+    }
+
+    let dict_uref = get_dict_uref(CLAIMABLE_REFUNDS_DICT);
+    let key = contributor.to_string();
+
+    let mut remittances: Vec<u64> = storage::dictionary_get(dict_uref, &key)
         .unwrap_or_revert_with(Error::StorageError)
-        .into_uref()
-        .unwrap_or_revert_with(Error::StorageError);
+        .unwrap_or_else(Vec::new);
 
-    storage::write(uref, fee_bps);
+    let before = remittances.len();
+    remittances.retain(|&id| id != remittance_id);
+    if remittances.len() != before {
+        storage::dictionary_put(dict_uref, &key, remittances);
+    }
 }
 
-/// Gets the fee collector account.
-pub fn get_fee_collector() -> AccountHash {
-    let uref: URef = runtime::get_key(FEE_COLLECTOR)
-        .unwrap_or_revert_with(Error::StorageError)
-        .into_uref()
-        .unwrap_or_revert_with(Error::StorageError);
+/// Gets the IDs of cancelled or expired remittances a contributor has not
+/// claimed a refund from yet, in order of closing.
+///
+/// Remittances closed before the index existed are not listed.
+pub fn get_claimable_refunds(contributor: AccountHash) -> Vec<u64> {
+    if runtime::get_key(CLAIMABLE_REFUNDS_DICT).is_none() {
+        return Vec::new();
+    }
+    let dict_uref = get_dict_uref(CLAIMABLE_REFUNDS_DICT);
 
-    storage::read(uref)
-        .unwrap_or_revert_with(Error::StorageError)
+    storage::dictionary_get(dict_uref, &contributor.to_string())
         .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
 }
 
-/// Gets the contract owner account.
-pub fn get_contract_owner() -> AccountHash {
-    let uref: URef = runtime::get_key(CONTRACT_OWNER)
-        .unwrap_or_revert_with(Error::StorageError)
-        .into_uref()
-        .unwrap_or_revert_with(Error::StorageError);
+/// Sets or clears (`None`) the account allowed to claim a contributor's
+/// refunds, creating its storage if needed.
+pub fn set_refund_delegate(contributor: AccountHash, delegate: Option<AccountHash>) {
+    if runtime::get_key(REFUND_DELEGATES_DICT).is_none() {
+        storage::new_dictionary(REFUND_DELEGATES_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
 
-    storage::read(uref)
-        .unwrap_or_revert_with(Error::StorageError)
-        .unwrap_or_revert_with(Error::StorageError)
+    let dict_uref = get_dict_uref(REFUND_DELEGATES_DICT);
+    storage::dictionary_put(dict_uref, &contributor.to_string(), delegate);
 }
 
-/// Checks if the contract is paused.
-pub fn is_contract_paused() -> bool {
-    let uref: URef = runtime::get_key(IS_PAUSED)
-        .unwrap_or_revert_with(Error::StorageError)
-        .into_uref()
-        .unwrap_or_revert_with(Error::StorageError);
+/// Gets the account allowed to claim a contributor's refunds, if any.
+pub fn get_refund_delegate(contributor: AccountHash) -> Option<AccountHash> {
+    if runtime::get_key(REFUND_DELEGATES_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(REFUND_DELEGATES_DICT);
 
-    storage::read(uref)
+    storage::dictionary_get::<Option<AccountHash>>(dict_uref, &contributor.to_string())
         .unwrap_or_revert_with(Error::StorageError)
-        .unwrap_or(false)
+        .flatten()
 }
 
-/// Sets the contract paused state.
-pub fn set_contract_paused(paused: bool) {
-    let uref: URef = runtime::get_key(IS_PAUSED)
+/// Gets the IDs of remittances a user created, oldest first.
+#[cfg(feature = "views")]
+pub fn get_user_remittances(user: AccountHash) -> Vec<u64> {
+    let dict_uref = get_dict_uref(USER_REMITTANCES_DICT);
+
+    storage::dictionary_get(dict_uref, &user.to_string())
         .unwrap_or_revert_with(Error::StorageError)
-        .into_uref()
-        .unwrap_or_revert_with(Error::StorageError);
+        .unwrap_or_default()
+}
+
+/// Gets the IDs of remittances addressed to a recipient, oldest first.
+#[cfg(feature = "views")]
+pub fn get_recipient_remittances(recipient: AccountHash) -> Vec<u64> {
+    let dict_uref = get_dict_uref(RECIPIENT_REMITTANCES_DICT);
+
+    storage::dictionary_get(dict_uref, &recipient.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
+/// Adds a remittance ID to the list of remittances created on a day.
+///
+/// Installs that predate the index get its dictionary on first use.
+pub fn add_day_remittance(day_index: u64, remittance_id: u64) {
+    if runtime::get_key(CREATION_INDEX_DICT).is_none() {
+        storage::new_dictionary(CREATION_INDEX_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(CREATION_INDEX_DICT);
+    let key = day_index.to_string();
+
+    let mut remittances: Vec<u64> = storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_else(Vec::new);
+
+    remittances.push(remittance_id);
+    storage::dictionary_put(dict_uref, &key, remittances);
+}
+
+/// Gets the IDs of remittances created on a day, oldest first.
+#[cfg(feature = "views")]
+pub fn get_day_remittances(day_index: u64) -> Vec<u64> {
+    if runtime::get_key(CREATION_INDEX_DICT).is_none() {
+        return Vec::new();
+    }
+    let dict_uref = get_dict_uref(CREATION_INDEX_DICT);
+
+    storage::dictionary_get(dict_uref, &day_index.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
+/// Maps a handle to a remittance in both directions.
+///
+/// Installs that predate handles get their dictionaries on first use.
+pub fn register_handle(handle: &str, remittance_id: u64) {
+    for name in [HANDLES_DICT, HANDLE_BY_REMITTANCE_DICT] {
+        if runtime::get_key(name).is_none() {
+            storage::new_dictionary(name).unwrap_or_revert_with(Error::StorageError);
+        }
+    }
+
+    storage::dictionary_put(get_dict_uref(HANDLES_DICT), handle, remittance_id);
+    storage::dictionary_put(
+        get_dict_uref(HANDLE_BY_REMITTANCE_DICT),
+        &remittance_id.to_string(),
+        String::from(handle),
+    );
+}
+
+/// Resolves a handle to the ID of the remittance it names.
+pub fn resolve_handle(handle: &str) -> Option<u64> {
+    if runtime::get_key(HANDLES_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(HANDLES_DICT);
+
+    storage::dictionary_get(dict_uref, handle).unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Gets the handle registered for a remittance, if any.
+pub fn get_handle(remittance_id: u64) -> Option<String> {
+    if runtime::get_key(HANDLE_BY_REMITTANCE_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(HANDLE_BY_REMITTANCE_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores the instant split of a remittance, creating its storage if needed.
+pub fn store_instant_split(remittance_id: u64, split: InstantSplit) {
+    if runtime::get_key(INSTANT_SPLITS_DICT).is_none() {
+        storage::new_dictionary(INSTANT_SPLITS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(INSTANT_SPLITS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), split);
+}
+
+/// Gets the instant split of a remittance, if it forwards contributions.
+pub fn get_instant_split(remittance_id: u64) -> Option<InstantSplit> {
+    if runtime::get_key(INSTANT_SPLITS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(INSTANT_SPLITS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores the backup recipient of a remittance, creating its storage if
+/// needed.
+pub fn store_backup_recipient(remittance_id: u64, backup: BackupRecipient) {
+    if runtime::get_key(BACKUP_RECIPIENTS_DICT).is_none() {
+        storage::new_dictionary(BACKUP_RECIPIENTS_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(BACKUP_RECIPIENTS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), backup);
+}
+
+/// Gets the backup recipient of a remittance, if the creator nominated one.
+pub fn get_backup_recipient(remittance_id: u64) -> Option<BackupRecipient> {
+    if runtime::get_key(BACKUP_RECIPIENTS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(BACKUP_RECIPIENTS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores the installment schedule of a remittance, creating its storage if
+/// needed.
+pub fn store_payout_schedule(remittance_id: u64, schedule: PayoutSchedule) {
+    if runtime::get_key(PAYOUT_SCHEDULES_DICT).is_none() {
+        storage::new_dictionary(PAYOUT_SCHEDULES_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(PAYOUT_SCHEDULES_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), schedule);
+}
+
+/// Gets the installment schedule of a remittance, if it pays out in
+/// installments.
+pub fn get_payout_schedule(remittance_id: u64) -> Option<PayoutSchedule> {
+    if runtime::get_key(PAYOUT_SCHEDULES_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(PAYOUT_SCHEDULES_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores the contribution analytics of a remittance, creating its storage if
+/// needed.
+pub fn store_analytics(remittance_id: u64, analytics: RemittanceAnalytics) {
+    if runtime::get_key(ANALYTICS_DICT).is_none() {
+        storage::new_dictionary(ANALYTICS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(ANALYTICS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), analytics);
+}
+
+/// Gets the contribution analytics of a remittance; empty before its first
+/// public contribution.
+pub fn get_analytics(remittance_id: u64) -> RemittanceAnalytics {
+    if runtime::get_key(ANALYTICS_DICT).is_none() {
+        return RemittanceAnalytics::default();
+    }
+    let dict_uref = get_dict_uref(ANALYTICS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
+/// Stores the insurance policy of a remittance, creating its storage if
+/// needed.
+pub fn store_insurance_policy(remittance_id: u64, policy: InsurancePolicy) {
+    if runtime::get_key(INSURANCE_POLICIES_DICT).is_none() {
+        storage::new_dictionary(INSURANCE_POLICIES_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(INSURANCE_POLICIES_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), policy);
+}
+
+/// Gets the insurance policy of a remittance, if it is insured.
+pub fn get_insurance_policy(remittance_id: u64) -> Option<InsurancePolicy> {
+    if runtime::get_key(INSURANCE_POLICIES_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(INSURANCE_POLICIES_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores a remittance's refund policy, creating its storage if needed.
+pub fn store_refund_policy(remittance_id: u64, policy: RefundPolicy) {
+    if runtime::get_key(REFUND_POLICIES_DICT).is_none() {
+        storage::new_dictionary(REFUND_POLICIES_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(REFUND_POLICIES_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), policy);
+}
+
+/// Gets a remittance's refund policy; full refunds if none was chosen.
+pub fn get_refund_policy(remittance_id: u64) -> RefundPolicy {
+    if runtime::get_key(REFUND_POLICIES_DICT).is_none() {
+        return RefundPolicy::default();
+    }
+    let dict_uref = get_dict_uref(REFUND_POLICIES_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
+/// Marks a contributor's insurance top-up as claimed, creating its storage if
+/// needed.
+pub fn mark_insurance_claimed(remittance_id: u64, contributor: AccountHash) {
+    if runtime::get_key(INSURANCE_CLAIMS_DICT).is_none() {
+        storage::new_dictionary(INSURANCE_CLAIMS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(INSURANCE_CLAIMS_DICT);
+    let key = hex_key(&contributor_key(remittance_id, &contributor));
+    storage::dictionary_put(dict_uref, &key, true);
+}
+
+/// Checks if a contributor has claimed their insurance top-up.
+pub fn is_insurance_claimed(remittance_id: u64, contributor: AccountHash) -> bool {
+    if runtime::get_key(INSURANCE_CLAIMS_DICT).is_none() {
+        return false;
+    }
+    let dict_uref = get_dict_uref(INSURANCE_CLAIMS_DICT);
+    let key = hex_key(&contributor_key(remittance_id, &contributor));
+
+    storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or(false)
+}
+
+/// Gets the insurance premium charged on new insured remittances, in basis
+/// points.
+///
+/// Returns zero if it was never set.
+pub fn get_insurance_premium_bps() -> u64 {
+    match runtime::get_key(INSURANCE_PREMIUM_BPS) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or_default()
+        }
+        None => 0,
+    }
+}
+
+/// Sets the insurance premium, creating its storage if needed.
+#[cfg(feature = "admin")]
+pub fn set_insurance_premium_bps(premium_bps: u64) {
+    match runtime::get_key(INSURANCE_PREMIUM_BPS) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, premium_bps);
+        }
+        None => runtime::put_key(INSURANCE_PREMIUM_BPS, storage::new_uref(premium_bps).into()),
+    }
+}
+
+/// Gets the documents attached to a remittance, oldest first.
+pub fn get_documents(remittance_id: u64) -> Vec<DocumentRef> {
+    let dict_uref = get_dict_uref(DOCUMENTS_DICT);
+    let key = remittance_id.to_string();
+
+    storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_else(Vec::new)
+}
+
+/// Stores the full document list for a remittance.
+pub fn store_documents(remittance_id: u64, documents: Vec<DocumentRef>) {
+    let dict_uref = get_dict_uref(DOCUMENTS_DICT);
+    let key = remittance_id.to_string();
+
+    storage::dictionary_put(dict_uref, &key, documents);
+}
+
+/// Gets the updates posted on a remittance, oldest first.
+pub fn get_updates(remittance_id: u64) -> Vec<RemittanceUpdate> {
+    if runtime::get_key(UPDATES_DICT).is_none() {
+        return Vec::new();
+    }
+    let dict_uref = get_dict_uref(UPDATES_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_else(Vec::new)
+}
+
+/// Stores the full update list for a remittance, creating its storage if
+/// needed.
+pub fn store_updates(remittance_id: u64, updates: Vec<RemittanceUpdate>) {
+    if runtime::get_key(UPDATES_DICT).is_none() {
+        storage::new_dictionary(UPDATES_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(UPDATES_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), updates);
+}
+
+/// Gets the localized purposes of a remittance, in the order first set.
+pub fn get_localized_purposes(remittance_id: u64) -> Vec<LocalizedPurpose> {
+    if runtime::get_key(LOCALIZED_PURPOSES_DICT).is_none() {
+        return Vec::new();
+    }
+    let dict_uref = get_dict_uref(LOCALIZED_PURPOSES_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_else(Vec::new)
+}
+
+/// Stores the full list of localized purposes for a remittance, creating its
+/// storage if needed.
+pub fn store_localized_purposes(remittance_id: u64, purposes: Vec<LocalizedPurpose>) {
+    if runtime::get_key(LOCALIZED_PURPOSES_DICT).is_none() {
+        storage::new_dictionary(LOCALIZED_PURPOSES_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(LOCALIZED_PURPOSES_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), purposes);
+}
+
+/// Gets the recipient's acknowledgement of a released remittance, if any.
+pub fn get_acknowledgement(remittance_id: u64) -> Option<ReceiptAcknowledgement> {
+    if runtime::get_key(ACKNOWLEDGEMENTS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(ACKNOWLEDGEMENTS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Records the recipient's acknowledgement, creating its storage if needed.
+pub fn store_acknowledgement(remittance_id: u64, acknowledgement: ReceiptAcknowledgement) {
+    if runtime::get_key(ACKNOWLEDGEMENTS_DICT).is_none() {
+        storage::new_dictionary(ACKNOWLEDGEMENTS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(ACKNOWLEDGEMENTS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), acknowledgement);
+}
+
+/// Gets the platform fee in basis points.
+///
+/// Sub-basis-point rates are floored; use `get_platform_fee_ppm` for the
+/// exact rate.
+#[cfg(any(feature = "views", feature = "admin"))]
+pub fn get_platform_fee_bps() -> u64 {
+    get_platform_fee_ppm() / PPM_PER_BPS
+}
+
+/// Gets the platform fee in parts-per-million.
+///
+/// Installations that predate the ppm key fall back to the stored basis
+/// point value until the fee is next updated.
+pub fn get_platform_fee_ppm() -> u64 {
+    match runtime::get_key(PLATFORM_FEE_PPM) {
+        Some(key) => {
+            let uref = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or(50u64 * PPM_PER_BPS)
+        }
+        None => read_legacy_fee_bps()
+            .checked_mul(PPM_PER_BPS)
+            .unwrap_or_revert_with(Error::ArithmeticOverflow),
+    }
+}
+
+/// Sets the platform fee in parts-per-million.
+///
+/// The basis point key is kept in sync (floored) for older clients, and the
+/// ppm key is created on first write for installations that predate it.
+#[cfg(feature = "admin")]
+pub fn set_platform_fee_ppm(fee_ppm: u64) {
+    match runtime::get_key(PLATFORM_FEE_PPM) {
+        Some(key) => {
+            let uref = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, fee_ppm);
+        }
+        None => runtime::put_key(PLATFORM_FEE_PPM, storage::new_uref(fee_ppm).into()),
+    }
+
+    let uref: URef = runtime::get_key(PLATFORM_FEE_BPS)
+        .unwrap_or_revert_with(Error::StorageError)
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+
+    storage::write(uref, fee_ppm / PPM_PER_BPS);
+}
+
+/// Gets the highest platform fee this installation accepts, in ppm.
+///
+/// Installations that predate the key fall back to `MAX_FEE_PPM`.
+pub fn get_max_fee_ppm() -> u64 {
+    match runtime::get_key(MAX_FEE_PPM_KEY) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or(MAX_FEE_PPM)
+        }
+        None => MAX_FEE_PPM,
+    }
+}
+
+/// Sets the platform fee cap in ppm, creating the key if needed.
+#[cfg(feature = "admin")]
+pub fn set_max_fee_ppm(max_fee_ppm: u64) {
+    match runtime::get_key(MAX_FEE_PPM_KEY) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, max_fee_ppm);
+        }
+        None => runtime::put_key(MAX_FEE_PPM_KEY, storage::new_uref(max_fee_ppm).into()),
+    }
+}
+
+/// Reads the basis point fee written by installations without a ppm key.
+fn read_legacy_fee_bps() -> u64 {
+    let uref: URef = runtime::get_key(PLATFORM_FEE_BPS)
+        .unwrap_or_revert_with(Error::StorageError)
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+
+    storage::read(uref)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or(50u64)
+}
+
+/// Gets the fee rounding mode.
+pub fn get_fee_rounding() -> FeeRounding {
+    let uref: URef = runtime::get_key(FEE_ROUNDING)
+        .unwrap_or_revert_with(Error::StorageError)
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+
+    let mode: u8 = storage::read(uref)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or(FeeRounding::Floor as u8);
+
+    FeeRounding::from_u8(mode).unwrap_or_revert()
+}
+
+/// Sets the fee rounding mode.
+#[cfg(feature = "admin")]
+pub fn set_fee_rounding(rounding: FeeRounding) {
+    let uref: URef = runtime::get_key(FEE_ROUNDING)
+        .unwrap_or_revert_with(Error::StorageError)
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+
+    storage::write(uref, rounding as u8);
+}
+
+/// Gets the fee model; installations that predate it charge on release.
+pub fn get_fee_model() -> FeeModel {
+    match runtime::get_key(FEE_MODEL) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            let model: u8 = storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or(FeeModel::OnRelease as u8);
+            FeeModel::from_u8(model).unwrap_or_revert()
+        }
+        None => FeeModel::OnRelease,
+    }
+}
+
+/// Gets the share of the platform fee rebated to creators, in basis points.
+pub fn get_creator_fee_share_bps() -> u64 {
+    let uref: URef = runtime::get_key(CREATOR_FEE_SHARE_BPS)
+        .unwrap_or_revert_with(Error::StorageError)
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+
+    storage::read(uref)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or(0u64)
+}
+
+/// Sets the share of the platform fee rebated to creators, in basis points.
+#[cfg(feature = "admin")]
+pub fn set_creator_fee_share_bps(share_bps: u64) {
+    let uref: URef = runtime::get_key(CREATOR_FEE_SHARE_BPS)
+        .unwrap_or_revert_with(Error::StorageError)
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+
+    storage::write(uref, share_bps);
+}
+
+/// Gets the claimable creator reward balance for an account.
+pub fn get_creator_rewards(creator: AccountHash) -> U512 {
+    let dict_uref = get_dict_uref(CREATOR_REWARDS_DICT);
+    let key = creator.to_string();
+
+    storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or(U512::zero())
+}
+
+/// Adds to the claimable creator reward balance for an account.
+pub fn add_creator_rewards(creator: AccountHash, amount: U512) {
+    let dict_uref = get_dict_uref(CREATOR_REWARDS_DICT);
+    let key = creator.to_string();
+
+    let new_amount = get_creator_rewards(creator)
+        .checked_add(amount)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+
+    storage::dictionary_put(dict_uref, &key, new_amount);
+}
+
+/// Clears the claimable creator reward balance for an account.
+pub fn clear_creator_rewards(creator: AccountHash) {
+    let dict_uref = get_dict_uref(CREATOR_REWARDS_DICT);
+    let key = creator.to_string();
+
+    storage::dictionary_put(dict_uref, &key, U512::zero());
+}
+
+/// Gets the fee collector account.
+pub fn get_fee_collector() -> AccountHash {
+    let uref: URef = runtime::get_key(FEE_COLLECTOR)
+        .unwrap_or_revert_with(Error::StorageError)
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+
+    storage::read(uref)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Gets the contract owner account.
+pub fn get_contract_owner() -> AccountHash {
+    let uref: URef = runtime::get_key(CONTRACT_OWNER)
+        .unwrap_or_revert_with(Error::StorageError)
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+
+    storage::read(uref)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Sets the contract owner account.
+#[cfg(feature = "admin")]
+pub fn set_contract_owner(owner: AccountHash) {
+    let uref: URef = runtime::get_key(CONTRACT_OWNER)
+        .unwrap_or_revert_with(Error::StorageError)
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+
+    storage::write(uref, owner);
+}
+
+/// Gets the compliance officer account.
+///
+/// Falls back to the contract owner for installs that predate the role.
+pub fn get_compliance_officer() -> AccountHash {
+    match runtime::get_key(COMPLIANCE_OFFICER) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or_revert_with(Error::StorageError)
+        }
+        None => get_contract_owner(),
+    }
+}
+
+/// Sets the compliance officer account, creating the key if needed.
+#[cfg(feature = "admin")]
+pub fn set_compliance_officer(officer: AccountHash) {
+    match runtime::get_key(COMPLIANCE_OFFICER) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, officer);
+        }
+        None => runtime::put_key(COMPLIANCE_OFFICER, storage::new_uref(officer).into()),
+    }
+}
+
+/// Gets the bridge operator account.
+///
+/// Falls back to the contract owner for installs that predate the role.
+pub fn get_bridge_operator() -> AccountHash {
+    match runtime::get_key(BRIDGE_OPERATOR) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or_revert_with(Error::StorageError)
+        }
+        None => get_contract_owner(),
+    }
+}
+
+/// Sets the bridge operator account, creating the key if needed.
+#[cfg(feature = "admin")]
+pub fn set_bridge_operator(operator: AccountHash) {
+    match runtime::get_key(BRIDGE_OPERATOR) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, operator);
+        }
+        None => runtime::put_key(BRIDGE_OPERATOR, storage::new_uref(operator).into()),
+    }
+}
+
+/// Records or (with `None`) removes a bridge payout request, creating its
+/// storage if needed.
+pub fn set_bridge_payout(remittance_id: u64, payout: Option<BridgePayout>) {
+    if runtime::get_key(BRIDGE_PAYOUTS_DICT).is_none() {
+        storage::new_dictionary(BRIDGE_PAYOUTS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(BRIDGE_PAYOUTS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), payout);
+}
+
+/// Gets the bridge payout requested for a remittance, if any.
+pub fn get_bridge_payout(remittance_id: u64) -> Option<BridgePayout> {
+    if runtime::get_key(BRIDGE_PAYOUTS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(BRIDGE_PAYOUTS_DICT);
+
+    storage::dictionary_get::<Option<BridgePayout>>(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .flatten()
+}
+
+/// Adds, updates, or (with `None`) removes a settlement partner, creating
+/// the registry if needed.
+#[cfg(feature = "admin")]
+pub fn set_settlement_partner(partner: AccountHash, entry: Option<SettlementPartner>) {
+    if runtime::get_key(SETTLEMENT_PARTNERS_DICT).is_none() {
+        storage::new_dictionary(SETTLEMENT_PARTNERS_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(SETTLEMENT_PARTNERS_DICT);
+    storage::dictionary_put(dict_uref, &partner.to_string(), entry);
+}
+
+/// Gets a registered settlement partner.
+pub fn get_settlement_partner(partner: AccountHash) -> Option<SettlementPartner> {
+    if runtime::get_key(SETTLEMENT_PARTNERS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(SETTLEMENT_PARTNERS_DICT);
+
+    storage::dictionary_get::<Option<SettlementPartner>>(dict_uref, &partner.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .flatten()
+}
+
+/// Records the settlement partner a release is routed to, creating its
+/// storage if needed.
+pub fn set_payout_route(remittance_id: u64, route: PayoutRoute) {
+    if runtime::get_key(PAYOUT_ROUTES_DICT).is_none() {
+        storage::new_dictionary(PAYOUT_ROUTES_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(PAYOUT_ROUTES_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), route);
+}
+
+/// Gets the settlement partner a release is routed to, if any.
+pub fn get_payout_route(remittance_id: u64) -> Option<PayoutRoute> {
+    if runtime::get_key(PAYOUT_ROUTES_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(PAYOUT_ROUTES_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores a recipient's payout address book, creating its storage if needed.
+pub fn set_payout_targets(owner: AccountHash, targets: Vec<PayoutTarget>) {
+    if runtime::get_key(PAYOUT_TARGETS_DICT).is_none() {
+        storage::new_dictionary(PAYOUT_TARGETS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(PAYOUT_TARGETS_DICT);
+    storage::dictionary_put(dict_uref, &owner.to_string(), targets);
+}
+
+/// Gets a recipient's payout address book; empty if they have none.
+pub fn get_payout_targets(owner: AccountHash) -> Vec<PayoutTarget> {
+    if runtime::get_key(PAYOUT_TARGETS_DICT).is_none() {
+        return Vec::new();
+    }
+    let dict_uref = get_dict_uref(PAYOUT_TARGETS_DICT);
+
+    storage::dictionary_get(dict_uref, &owner.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
+/// Records the payout target a release was sent to, creating its storage if
+/// needed.
+pub fn set_payout_selection(remittance_id: u64, target: PayoutTarget) {
+    if runtime::get_key(PAYOUT_SELECTIONS_DICT).is_none() {
+        storage::new_dictionary(PAYOUT_SELECTIONS_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(PAYOUT_SELECTIONS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), target);
+}
+
+/// Gets the payout target a release was sent to, if any.
+pub fn get_payout_selection(remittance_id: u64) -> Option<PayoutTarget> {
+    if runtime::get_key(PAYOUT_SELECTIONS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(PAYOUT_SELECTIONS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Adds or removes an account from the verified charity registry.
+#[cfg(feature = "admin")]
+pub fn set_verified_charity(charity: AccountHash, verified: bool) {
+    let dict_uref = get_dict_uref(VERIFIED_CHARITIES_DICT);
+    let key = charity.to_string();
+
+    storage::dictionary_put(dict_uref, &key, verified);
+}
+
+/// Checks if an account is a verified charity.
+pub fn is_verified_charity(account: AccountHash) -> bool {
+    let dict_uref = get_dict_uref(VERIFIED_CHARITIES_DICT);
+    let key = account.to_string();
+
+    storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or(false)
+}
+
+/// Adds or removes a KYB-verified business, creating the registry if needed.
+#[cfg(feature = "admin")]
+pub fn set_verified_business(business: AccountHash, verified: bool) {
+    if runtime::get_key(VERIFIED_BUSINESSES_DICT).is_none() {
+        storage::new_dictionary(VERIFIED_BUSINESSES_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(VERIFIED_BUSINESSES_DICT);
+    storage::dictionary_put(dict_uref, &business.to_string(), verified);
+}
+
+/// Checks if an account is a KYB-verified business.
+pub fn is_verified_business(account: AccountHash) -> bool {
+    if runtime::get_key(VERIFIED_BUSINESSES_DICT).is_none() {
+        return false;
+    }
+    let dict_uref = get_dict_uref(VERIFIED_BUSINESSES_DICT);
+
+    storage::dictionary_get(dict_uref, &account.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or(false)
+}
+
+/// Blocks or unblocks a purpose hash, creating the blocklist on installs
+/// that predate it.
+#[cfg(feature = "admin")]
+pub fn set_purpose_blocked(purpose_hash: [u8; 32], blocked: bool) {
+    if runtime::get_key(BLOCKED_PURPOSES_DICT).is_none() {
+        storage::new_dictionary(BLOCKED_PURPOSES_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(BLOCKED_PURPOSES_DICT);
+    storage::dictionary_put(dict_uref, &hex_key(&purpose_hash), blocked);
+}
+
+/// Checks if a purpose hash is blocklisted.
+pub fn is_purpose_blocked(purpose_hash: [u8; 32]) -> bool {
+    if runtime::get_key(BLOCKED_PURPOSES_DICT).is_none() {
+        return false;
+    }
+    let dict_uref = get_dict_uref(BLOCKED_PURPOSES_DICT);
+
+    storage::dictionary_get(dict_uref, &hex_key(&purpose_hash))
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or(false)
+}
+
+/// Stores the travel-rule data for a remittance, replacing any previous record.
+pub fn store_travel_rule_data(remittance_id: u64, data: TravelRuleData) {
+    let dict_uref = get_dict_uref(TRAVEL_RULE_DICT);
+    let key = remittance_id.to_string();
+
+    storage::dictionary_put(dict_uref, &key, data);
+}
+
+/// Gets the travel-rule data for a remittance, if attached.
+pub fn get_travel_rule_data(remittance_id: u64) -> Option<TravelRuleData> {
+    let dict_uref = get_dict_uref(TRAVEL_RULE_DICT);
+    let key = remittance_id.to_string();
+
+    storage::dictionary_get(dict_uref, &key).unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Gets the amount at or above which releases require travel-rule data.
+///
+/// Zero (or a missing key on older installs) disables the requirement.
+pub fn get_travel_rule_threshold() -> U512 {
+    match runtime::get_key(TRAVEL_RULE_THRESHOLD) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or(U512::zero())
+        }
+        None => U512::zero(),
+    }
+}
+
+/// Sets the travel-rule threshold, creating the key if needed.
+#[cfg(feature = "admin")]
+pub fn set_travel_rule_threshold(threshold: U512) {
+    match runtime::get_key(TRAVEL_RULE_THRESHOLD) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, threshold);
+        }
+        None => runtime::put_key(TRAVEL_RULE_THRESHOLD, storage::new_uref(threshold).into()),
+    }
+}
+
+/// Stores the corridor code a remittance is reported under.
+pub fn set_corridor(remittance_id: u64, corridor: &str) {
+    let dict_uref = get_dict_uref(CORRIDORS_DICT);
+    let key = remittance_id.to_string();
+
+    storage::dictionary_put(dict_uref, &key, String::from(corridor));
+}
+
+/// Gets the corridor code a remittance is reported under, if any.
+pub fn get_corridor(remittance_id: u64) -> Option<String> {
+    let dict_uref = get_dict_uref(CORRIDORS_DICT);
+    let key = remittance_id.to_string();
+
+    storage::dictionary_get(dict_uref, &key).unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Gets the aggregates for a day, or for one corridor on that day.
+#[cfg(all(feature = "views", feature = "stats"))]
+pub fn get_period_stats(day_index: u64, corridor: Option<&str>) -> PeriodStats {
+    let dict_uref = get_dict_uref(PERIOD_STATS_DICT);
+    let key = period_stats_key(day_index, corridor);
+
+    storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
+/// Applies `update` to the day bucket and, if given, the corridor bucket.
+#[cfg(feature = "stats")]
+pub fn update_period_stats(
+    day_index: u64,
+    corridor: Option<&str>,
+    update: impl Fn(&mut PeriodStats),
+) {
+    let dict_uref = get_dict_uref(PERIOD_STATS_DICT);
+
+    let mut keys = Vec::with_capacity(2);
+    keys.push(period_stats_key(day_index, None));
+    if corridor.is_some() {
+        keys.push(period_stats_key(day_index, corridor));
+    }
+
+    for key in keys {
+        let mut stats: PeriodStats = storage::dictionary_get(dict_uref, &key)
+            .unwrap_or_revert_with(Error::StorageError)
+            .unwrap_or_default();
+
+        update(&mut stats);
+        storage::dictionary_put(dict_uref, &key, stats);
+    }
+}
+
+/// Gets a ledger total (`ESCROW_LIABILITIES`, `ACCRUED_CREATOR_REWARDS`, or
+/// `INSURANCE_POOL`).
+///
+/// Missing keys on older installs read as zero.
+pub fn get_ledger_total(name: &str) -> U512 {
+    match runtime::get_key(name) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or(U512::zero())
+        }
+        None => U512::zero(),
+    }
+}
+
+/// Adds to a ledger total.
+pub fn increase_ledger_total(name: &str, amount: U512) {
+    let total = get_ledger_total(name)
+        .checked_add(amount)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    write_ledger_total(name, total);
+}
+
+/// Subtracts from a ledger total, saturating at zero.
+///
+/// Installs that predate the ledger hold funds it never counted, so an
+/// underflow here is expected drift rather than a reason to block payouts.
+pub fn decrease_ledger_total(name: &str, amount: U512) {
+    let total = get_ledger_total(name).saturating_sub(amount);
+    write_ledger_total(name, total);
+}
+
+/// Records an accounting snapshot and returns its index.
+#[cfg(feature = "stats")]
+pub fn store_snapshot(snapshot: AccountingSnapshot) -> u64 {
+    let index = get_snapshot_count();
+    let dict_uref = get_dict_uref(SNAPSHOTS_DICT);
+    storage::dictionary_put(dict_uref, &index.to_string(), snapshot);
+
+    let next = index
+        .checked_add(1)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    match runtime::get_key(SNAPSHOT_COUNT) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, next);
+        }
+        None => runtime::put_key(SNAPSHOT_COUNT, storage::new_uref(next).into()),
+    }
+
+    index
+}
+
+/// Gets an accounting snapshot by index.
+#[cfg(all(feature = "views", feature = "stats"))]
+pub fn get_snapshot(index: u64) -> Option<AccountingSnapshot> {
+    let dict_uref = get_dict_uref(SNAPSHOTS_DICT);
+
+    storage::dictionary_get(dict_uref, &index.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Gets the number of accounting snapshots taken.
+#[cfg(feature = "stats")]
+pub fn get_snapshot_count() -> u64 {
+    match runtime::get_key(SNAPSHOT_COUNT) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or(0u64)
+        }
+        None => 0,
+    }
+}
+
+/// Records a contribution snapshot, creating its storage if needed, and
+/// returns its ID.
+pub fn store_contribution_snapshot(snapshot: ContributionSnapshot) -> u64 {
+    if runtime::get_key(CONTRIBUTION_SNAPSHOTS_DICT).is_none() {
+        storage::new_dictionary(CONTRIBUTION_SNAPSHOTS_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let snapshot_id = get_contribution_snapshot_count();
+    let dict_uref = get_dict_uref(CONTRIBUTION_SNAPSHOTS_DICT);
+    storage::dictionary_put(dict_uref, &snapshot_id.to_string(), snapshot);
+
+    let next = snapshot_id
+        .checked_add(1)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    match runtime::get_key(CONTRIBUTION_SNAPSHOT_COUNT) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, next);
+        }
+        None => runtime::put_key(CONTRIBUTION_SNAPSHOT_COUNT, storage::new_uref(next).into()),
+    }
+
+    snapshot_id
+}
+
+/// Gets a contribution snapshot by ID.
+#[cfg(feature = "views")]
+pub fn get_contribution_snapshot(snapshot_id: u64) -> Option<ContributionSnapshot> {
+    if runtime::get_key(CONTRIBUTION_SNAPSHOTS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(CONTRIBUTION_SNAPSHOTS_DICT);
+
+    storage::dictionary_get(dict_uref, &snapshot_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Records a savings pool, creating its storage if needed, and returns its
+/// ID.
+pub fn store_savings_pool(pool: SavingsPool) -> u64 {
+    if runtime::get_key(SAVINGS_POOLS_DICT).is_none() {
+        storage::new_dictionary(SAVINGS_POOLS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let pool_id = get_savings_pool_count();
+    let dict_uref = get_dict_uref(SAVINGS_POOLS_DICT);
+    storage::dictionary_put(dict_uref, &pool_id.to_string(), pool);
+
+    let next = pool_id
+        .checked_add(1)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    match runtime::get_key(SAVINGS_POOL_COUNT) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, next);
+        }
+        None => runtime::put_key(SAVINGS_POOL_COUNT, storage::new_uref(next).into()),
+    }
+
+    pool_id
+}
+
+/// Gets a savings pool by ID.
+pub fn get_savings_pool(pool_id: u64) -> Option<SavingsPool> {
+    if runtime::get_key(SAVINGS_POOLS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(SAVINGS_POOLS_DICT);
+
+    storage::dictionary_get(dict_uref, &pool_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Gets the number of savings pools created.
+pub fn get_savings_pool_count() -> u64 {
+    match runtime::get_key(SAVINGS_POOL_COUNT) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or(0u64)
+        }
+        None => 0,
+    }
+}
+
+/// Records that a remittance is a cycle of a savings pool, creating its
+/// storage if needed.
+pub fn set_remittance_pool(remittance_id: u64, pool_id: u64) {
+    if runtime::get_key(POOL_CYCLES_DICT).is_none() {
+        storage::new_dictionary(POOL_CYCLES_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(POOL_CYCLES_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), pool_id);
+}
+
+/// Gets the savings pool a remittance is a cycle of, if any.
+pub fn get_remittance_pool(remittance_id: u64) -> Option<u64> {
+    if runtime::get_key(POOL_CYCLES_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(POOL_CYCLES_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores what a contributor has paid into an open-ended pot since its last
+/// withdrawal, creating its storage if needed.
+pub fn store_pot_deposit(remittance_id: u64, contributor: AccountHash, deposit: PotDeposit) {
+    if runtime::get_key(POT_DEPOSITS_DICT).is_none() {
+        storage::new_dictionary(POT_DEPOSITS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(POT_DEPOSITS_DICT);
+    let key = hex_key(&contributor_key(remittance_id, &contributor));
+    storage::dictionary_put(dict_uref, &key, deposit);
+}
+
+/// Gets a contributor's deposits into an open-ended pot; empty if they never
+/// paid in.
+pub fn get_pot_deposit(remittance_id: u64, contributor: AccountHash) -> PotDeposit {
+    if runtime::get_key(POT_DEPOSITS_DICT).is_none() {
+        return PotDeposit::default();
+    }
+    let dict_uref = get_dict_uref(POT_DEPOSITS_DICT);
+    let key = hex_key(&contributor_key(remittance_id, &contributor));
+
+    storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
+/// Gets the number of contribution snapshots taken.
+pub fn get_contribution_snapshot_count() -> u64 {
+    match runtime::get_key(CONTRIBUTION_SNAPSHOT_COUNT) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or(0u64)
+        }
+        None => 0,
+    }
+}
+
+/// Appends an entry to a remittance's ledger and returns its index.
+///
+/// Installs that predate the ledger get its dictionaries on first use.
+pub fn append_ledger_entry(remittance_id: u64, entry: LedgerEntry) -> u64 {
+    for name in [LEDGER_DICT, LEDGER_LENGTHS_DICT] {
+        if runtime::get_key(name).is_none() {
+            storage::new_dictionary(name).unwrap_or_revert_with(Error::StorageError);
+        }
+    }
+
+    let index = get_ledger_length(remittance_id);
+    let dict_uref = get_dict_uref(LEDGER_DICT);
+    storage::dictionary_put(dict_uref, &format!("{}_{}", remittance_id, index), entry);
+
+    let next = index
+        .checked_add(1)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    let lengths_uref = get_dict_uref(LEDGER_LENGTHS_DICT);
+    storage::dictionary_put(lengths_uref, &remittance_id.to_string(), next);
+
+    index
+}
+
+/// Gets the number of entries in a remittance's ledger.
+pub fn get_ledger_length(remittance_id: u64) -> u64 {
+    if runtime::get_key(LEDGER_LENGTHS_DICT).is_none() {
+        return 0;
+    }
+    let dict_uref = get_dict_uref(LEDGER_LENGTHS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
+/// Gets a remittance's ledger entry by index.
+#[cfg(feature = "views")]
+pub fn get_ledger_entry(remittance_id: u64, index: u64) -> Option<LedgerEntry> {
+    let dict_uref = get_dict_uref(LEDGER_DICT);
+
+    storage::dictionary_get(dict_uref, &format!("{}_{}", remittance_id, index))
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores the contract schema under its named key.
+pub fn store_schema(schema: ContractSchema) {
+    runtime::put_key(CONTRACT_SCHEMA, storage::new_uref(schema).into());
+}
+
+/// Gets the stored contract schema, or `None` on installs that predate it.
+#[cfg(feature = "views")]
+pub fn get_schema() -> Option<ContractSchema> {
+    let uref: URef = runtime::get_key(CONTRACT_SCHEMA)?
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::read(uref).unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Records the event schema version the installed code emits.
+pub fn store_event_schema_version(version: u32) {
+    match runtime::get_key(EVENT_SCHEMA_VERSION_KEY) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, version);
+        }
+        None => runtime::put_key(EVENT_SCHEMA_VERSION_KEY, storage::new_uref(version).into()),
+    }
+}
+
+/// Gets the recorded event schema version, or 0 on installs that predate
+/// versioned events.
+#[cfg(feature = "views")]
+pub fn get_event_schema_version() -> u32 {
+    match runtime::get_key(EVENT_SCHEMA_VERSION_KEY) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or_default()
+        }
+        None => 0,
+    }
+}
+
+/// Stores the version and git commit of the installed build.
+pub fn store_build_info(build_info: BuildInfo) {
+    runtime::put_key(BUILD_INFO, storage::new_uref(build_info).into());
+}
+
+/// Gets the stored build info, or `None` on installs that predate it.
+#[cfg(feature = "views")]
+pub fn get_build_info() -> Option<BuildInfo> {
+    let uref: URef = runtime::get_key(BUILD_INFO)?
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+    storage::read(uref).unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Gets the per-account rate limits; none on installs that predate them.
+pub fn get_rate_limits() -> RateLimits {
+    match runtime::get_key(RATE_LIMITS) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or_default()
+        }
+        None => RateLimits::default(),
+    }
+}
+
+/// Sets the per-account rate limits, creating their storage if needed.
+#[cfg(feature = "admin")]
+pub fn set_rate_limits(limits: RateLimits) {
+    match runtime::get_key(RATE_LIMITS) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, limits);
+        }
+        None => runtime::put_key(RATE_LIMITS, storage::new_uref(limits).into()),
+    }
+
+    if runtime::get_key(RATE_WINDOWS_DICT).is_none() {
+        storage::new_dictionary(RATE_WINDOWS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+}
+
+/// Gets the per-call gas-sanity limits; the built-in maximums on installs
+/// that predate them.
+pub fn get_gas_limits() -> GasLimits {
+    match runtime::get_key(GAS_LIMITS) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or_default()
+        }
+        None => GasLimits::default(),
+    }
+}
+
+/// Sets the per-call gas-sanity limits, creating their storage if needed.
+#[cfg(feature = "admin")]
+pub fn set_gas_limits(limits: GasLimits) {
+    match runtime::get_key(GAS_LIMITS) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, limits);
+        }
+        None => runtime::put_key(GAS_LIMITS, storage::new_uref(limits).into()),
+    }
+}
+
+/// Gets the payment guards; the permissive defaults on installs that
+/// predate them.
+pub fn get_payment_guards() -> PaymentGuards {
+    match runtime::get_key(PAYMENT_GUARDS) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or_default()
+        }
+        None => PaymentGuards::default(),
+    }
+}
+
+/// Sets the payment guards, creating their storage if needed.
+#[cfg(feature = "admin")]
+pub fn set_payment_guards(guards: PaymentGuards) {
+    match runtime::get_key(PAYMENT_GUARDS) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, guards);
+        }
+        None => runtime::put_key(PAYMENT_GUARDS, storage::new_uref(guards).into()),
+    }
+}
+
+/// Records `action` by `account` at block time `now` against the rate limits.
+///
+/// Returns `false`, recording nothing, if the action would exceed its limit.
+/// Always succeeds for actions without a limit.
+pub fn record_rate_limited_action(
+    account: AccountHash,
+    action: RateLimitedAction,
+    now: u64,
+) -> bool {
+    let limits = get_rate_limits();
+    let limit = limits.limit(action);
+    if limit == 0 {
+        return true;
+    }
+
+    let dict_uref = get_dict_uref(RATE_WINDOWS_DICT);
+    let key = hex_key(&rate_window_key(&account, action));
+
+    let mut window: RateWindow = storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default();
+
+    if !window.try_record(now, limits.window_ms, limit) {
+        return false;
+    }
+    storage::dictionary_put(dict_uref, &key, window);
+    true
+}
+
+/// Gets the registered risk engine, if any.
+pub fn get_risk_engine() -> Option<RiskEngine> {
+    match runtime::get_key(RISK_ENGINE) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .flatten()
+        }
+        None => None,
+    }
+}
+
+/// Registers (or, with `None`, removes) the risk engine.
+#[cfg(feature = "admin")]
+pub fn set_risk_engine(engine: Option<RiskEngine>) {
+    match runtime::get_key(RISK_ENGINE) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, engine);
+        }
+        None => runtime::put_key(RISK_ENGINE, storage::new_uref(engine).into()),
+    }
+}
+
+/// Stores a namespace, creating the registry if needed.
+pub fn set_namespace(name: &str, namespace: Namespace) {
+    if runtime::get_key(NAMESPACES_DICT).is_none() {
+        storage::new_dictionary(NAMESPACES_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(NAMESPACES_DICT);
+    storage::dictionary_put(dict_uref, name, namespace);
+}
+
+/// Gets a namespace by name.
+pub fn get_namespace(name: &str) -> Option<Namespace> {
+    if runtime::get_key(NAMESPACES_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(NAMESPACES_DICT);
+
+    storage::dictionary_get(dict_uref, name).unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Records the namespace a remittance belongs to, creating its storage if
+/// needed.
+pub fn set_remittance_namespace(remittance_id: u64, name: &str) {
+    if runtime::get_key(REMITTANCE_NAMESPACES_DICT).is_none() {
+        storage::new_dictionary(REMITTANCE_NAMESPACES_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(REMITTANCE_NAMESPACES_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), String::from(name));
+}
+
+/// Gets the name of the namespace a remittance belongs to, if any.
+pub fn get_remittance_namespace(remittance_id: u64) -> Option<String> {
+    if runtime::get_key(REMITTANCE_NAMESPACES_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(REMITTANCE_NAMESPACES_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores the scheduled start of a remittance, creating its storage if
+/// needed.
+pub fn store_scheduled_start(remittance_id: u64, start: ScheduledStart) {
+    if runtime::get_key(SCHEDULED_STARTS_DICT).is_none() {
+        storage::new_dictionary(SCHEDULED_STARTS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(SCHEDULED_STARTS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), start);
+}
+
+/// Gets the scheduled start of a remittance, if the creator set one.
+pub fn get_scheduled_start(remittance_id: u64) -> Option<ScheduledStart> {
+    if runtime::get_key(SCHEDULED_STARTS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(SCHEDULED_STARTS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores a category template, creating the registry if needed.
+#[cfg(feature = "admin")]
+pub fn set_category(name: &str, template: CategoryTemplate) {
+    if runtime::get_key(CATEGORIES_DICT).is_none() {
+        storage::new_dictionary(CATEGORIES_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(CATEGORIES_DICT);
+    storage::dictionary_put(dict_uref, name, template);
+}
+
+/// Gets a category template by name.
+pub fn get_category(name: &str) -> Option<CategoryTemplate> {
+    if runtime::get_key(CATEGORIES_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(CATEGORIES_DICT);
+
+    storage::dictionary_get(dict_uref, name).unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Records the category a remittance was created in, creating its storage
+/// if needed.
+pub fn set_remittance_category(remittance_id: u64, name: &str) {
+    if runtime::get_key(REMITTANCE_CATEGORIES_DICT).is_none() {
+        storage::new_dictionary(REMITTANCE_CATEGORIES_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(REMITTANCE_CATEGORIES_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), String::from(name));
+}
+
+/// Gets the name of the category a remittance was created in, if any.
+#[cfg(feature = "views")]
+pub fn get_remittance_category(remittance_id: u64) -> Option<String> {
+    if runtime::get_key(REMITTANCE_CATEGORIES_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(REMITTANCE_CATEGORIES_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Fixes the platform fee rate of a remittance, creating its storage if
+/// needed.
+pub fn set_remittance_fee_ppm(remittance_id: u64, fee_ppm: u64) {
+    if runtime::get_key(REMITTANCE_FEE_RATES_DICT).is_none() {
+        storage::new_dictionary(REMITTANCE_FEE_RATES_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(REMITTANCE_FEE_RATES_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), fee_ppm);
+}
+
+/// Gets the platform fee rate fixed for a remittance, if any.
+pub fn get_remittance_fee_ppm(remittance_id: u64) -> Option<u64> {
+    if runtime::get_key(REMITTANCE_FEE_RATES_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(REMITTANCE_FEE_RATES_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Gets the release escalation intervals in milliseconds; empty if they
+/// were never set.
+pub fn get_sla_intervals() -> Vec<u64> {
+    match runtime::get_key(SLA_INTERVALS) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or_default()
+        }
+        None => Vec::new(),
+    }
+}
+
+/// Sets the release escalation intervals, creating their storage if needed.
+#[cfg(feature = "admin")]
+pub fn set_sla_intervals(intervals_ms: Vec<u64>) {
+    match runtime::get_key(SLA_INTERVALS) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, intervals_ms);
+        }
+        None => runtime::put_key(SLA_INTERVALS, storage::new_uref(intervals_ms).into()),
+    }
+}
+
+/// Stores the release SLA of a remittance, creating its storage if needed.
+pub fn store_release_sla(remittance_id: u64, sla: ReleaseSla) {
+    if runtime::get_key(RELEASE_SLAS_DICT).is_none() {
+        storage::new_dictionary(RELEASE_SLAS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(RELEASE_SLAS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), sla);
+}
+
+/// Gets the release SLA of a remittance, if its target has been met.
+pub fn get_release_sla(remittance_id: u64) -> Option<ReleaseSla> {
+    if runtime::get_key(RELEASE_SLAS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(RELEASE_SLAS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Records why the recipient declined a remittance, creating its storage if
+/// needed.
+pub fn set_decline_reason(remittance_id: u64, reason: &str) {
+    if runtime::get_key(DECLINE_REASONS_DICT).is_none() {
+        storage::new_dictionary(DECLINE_REASONS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(DECLINE_REASONS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), String::from(reason));
+}
+
+/// Gets why the recipient declined a remittance, if they did.
+#[cfg(feature = "views")]
+pub fn get_decline_reason(remittance_id: u64) -> Option<String> {
+    if runtime::get_key(DECLINE_REASONS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(DECLINE_REASONS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores the fee cover of a remittance, creating its storage if needed.
+pub fn store_fee_cover(remittance_id: u64, cover: FeeCover) {
+    if runtime::get_key(FEE_COVERS_DICT).is_none() {
+        storage::new_dictionary(FEE_COVERS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(FEE_COVERS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), cover);
+}
+
+/// Gets the fee cover of a remittance, if anyone pre-funded its fee.
+pub fn get_fee_cover(remittance_id: u64) -> Option<FeeCover> {
+    if runtime::get_key(FEE_COVERS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(FEE_COVERS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores the itemized split of a remittance's release, creating its storage
+/// if needed.
+pub fn store_release_breakdown(remittance_id: u64, breakdown: ReleaseBreakdown) {
+    if runtime::get_key(RELEASE_BREAKDOWNS_DICT).is_none() {
+        storage::new_dictionary(RELEASE_BREAKDOWNS_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(RELEASE_BREAKDOWNS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), breakdown);
+}
+
+/// Gets the itemized split of a remittance's release; `None` until it is
+/// released, or if it was released before breakdowns were recorded.
+pub fn get_release_breakdown(remittance_id: u64) -> Option<ReleaseBreakdown> {
+    if runtime::get_key(RELEASE_BREAKDOWNS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(RELEASE_BREAKDOWNS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Stores the signer group of a multi-signature recipient, creating its
+/// storage if needed.
+pub fn store_recipient_multisig(remittance_id: u64, multisig: RecipientMultisig) {
+    if runtime::get_key(RECIPIENT_MULTISIGS_DICT).is_none() {
+        storage::new_dictionary(RECIPIENT_MULTISIGS_DICT)
+            .unwrap_or_revert_with(Error::StorageError);
+    }
+
+    let dict_uref = get_dict_uref(RECIPIENT_MULTISIGS_DICT);
+    storage::dictionary_put(dict_uref, &remittance_id.to_string(), multisig);
+}
+
+/// Gets the signer group of a remittance, if its recipient is multi-signature.
+pub fn get_recipient_multisig(remittance_id: u64) -> Option<RecipientMultisig> {
+    if runtime::get_key(RECIPIENT_MULTISIGS_DICT).is_none() {
+        return None;
+    }
+    let dict_uref = get_dict_uref(RECIPIENT_MULTISIGS_DICT);
+
+    storage::dictionary_get(dict_uref, &remittance_id.to_string())
+        .unwrap_or_revert_with(Error::StorageError)
+}
+
+/// Gets the instance branding; empty if it was never set.
+#[cfg(feature = "views")]
+pub fn get_branding() -> Branding {
+    match runtime::get_key(BRANDING) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or_default()
+        }
+        None => Branding::default(),
+    }
+}
+
+/// Sets the instance branding, creating its storage if needed.
+#[cfg(feature = "admin")]
+pub fn set_branding(branding: Branding) {
+    match runtime::get_key(BRANDING) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, branding);
+        }
+        None => runtime::put_key(BRANDING, storage::new_uref(branding).into()),
+    }
+}
+
+/// Gets the release review policy; review is disabled if it was never set.
+pub fn get_review_policy() -> ReviewPolicy {
+    match runtime::get_key(REVIEW_POLICY) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or_default()
+        }
+        None => ReviewPolicy::default(),
+    }
+}
+
+/// Sets the release review policy, creating its storage if needed.
+#[cfg(feature = "admin")]
+pub fn set_review_policy(policy: ReviewPolicy) {
+    match runtime::get_key(REVIEW_POLICY) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, policy);
+        }
+        None => runtime::put_key(REVIEW_POLICY, storage::new_uref(policy).into()),
+    }
+
+    if runtime::get_key(RELEASE_REVIEWS_DICT).is_none() {
+        storage::new_dictionary(RELEASE_REVIEWS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+}
+
+/// Records the block time by which a held release must be reviewed.
+pub fn set_review_deadline(remittance_id: u64, review_deadline: u64) {
+    let dict_uref = get_dict_uref(RELEASE_REVIEWS_DICT);
+    let key = remittance_id.to_string();
+
+    storage::dictionary_put(dict_uref, &key, review_deadline);
+}
+
+/// Gets the review deadline of a held release.
+#[cfg(feature = "admin")]
+pub fn get_review_deadline(remittance_id: u64) -> Result<u64, Error> {
+    let dict_uref = get_dict_uref(RELEASE_REVIEWS_DICT);
+    let key = remittance_id.to_string();
+
+    storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .ok_or(Error::NotPendingReview)
+}
+
+/// Gets the most a creator may extend a deadline in total, in milliseconds.
+///
+/// Returns zero (extensions disabled) if it was never set.
+pub fn get_max_deadline_extension() -> u64 {
+    match runtime::get_key(MAX_DEADLINE_EXTENSION) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or_default()
+        }
+        None => 0,
+    }
+}
+
+/// Gets the largest target amount a new remittance may have.
+///
+/// Zero (or a missing key on older installs) means no cap.
+pub fn get_max_target_amount() -> U512 {
+    match runtime::get_key(MAX_TARGET_AMOUNT) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or(U512::zero())
+        }
+        None => U512::zero(),
+    }
+}
+
+/// Sets the maximum target amount, creating the key if needed.
+#[cfg(feature = "admin")]
+pub fn set_max_target_amount(max_target_amount: U512) {
+    match runtime::get_key(MAX_TARGET_AMOUNT) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, max_target_amount);
+        }
+        None => runtime::put_key(MAX_TARGET_AMOUNT, storage::new_uref(max_target_amount).into()),
+    }
+}
+
+/// Gets the smallest target amount a new remittance may have.
+///
+/// Zero (or a missing key on older installs) means no minimum.
+pub fn get_min_target_amount() -> U512 {
+    match runtime::get_key(MIN_TARGET_AMOUNT) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or(U512::zero())
+        }
+        None => U512::zero(),
+    }
+}
+
+/// Sets the minimum target amount, creating the key if needed.
+#[cfg(feature = "admin")]
+pub fn set_min_target_amount(min_target_amount: U512) {
+    match runtime::get_key(MIN_TARGET_AMOUNT) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, min_target_amount);
+        }
+        None => runtime::put_key(MIN_TARGET_AMOUNT, storage::new_uref(min_target_amount).into()),
+    }
+}
+
+/// Gets the largest target amount a new remittance to a KYB-verified
+/// business may have.
+///
+/// Zero (or a missing key on older installs) means such remittances get the
+/// standard maximum.
+pub fn get_kyb_max_target_amount() -> U512 {
+    match runtime::get_key(KYB_MAX_TARGET_AMOUNT) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or(U512::zero())
+        }
+        None => U512::zero(),
+    }
+}
+
+/// Sets the KYB maximum target amount, creating the key if needed.
+#[cfg(feature = "admin")]
+pub fn set_kyb_max_target_amount(kyb_max_target_amount: U512) {
+    match runtime::get_key(KYB_MAX_TARGET_AMOUNT) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, kyb_max_target_amount);
+        }
+        None => runtime::put_key(
+            KYB_MAX_TARGET_AMOUNT,
+            storage::new_uref(kyb_max_target_amount).into(),
+        ),
+    }
+}
+
+/// Sets the maximum total deadline extension, creating its storage if needed.
+#[cfg(feature = "admin")]
+pub fn set_max_deadline_extension(max_extension_ms: u64) {
+    match runtime::get_key(MAX_DEADLINE_EXTENSION) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, max_extension_ms);
+        }
+        None => runtime::put_key(MAX_DEADLINE_EXTENSION, storage::new_uref(max_extension_ms).into()),
+    }
+
+    if runtime::get_key(DEADLINE_EXTENSIONS_DICT).is_none() {
+        storage::new_dictionary(DEADLINE_EXTENSIONS_DICT).unwrap_or_revert_with(Error::StorageError);
+    }
+}
+
+/// Gets how far a remittance's deadline has been extended in total, in
+/// milliseconds.
+pub fn get_deadline_extension(remittance_id: u64) -> u64 {
+    let dict_uref = get_dict_uref(DEADLINE_EXTENSIONS_DICT);
+    let key = remittance_id.to_string();
+
+    storage::dictionary_get(dict_uref, &key)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or_default()
+}
+
+/// Records the total extension of a remittance's deadline.
+pub fn set_deadline_extension(remittance_id: u64, extension_ms: u64) {
+    let dict_uref = get_dict_uref(DEADLINE_EXTENSIONS_DICT);
+    let key = remittance_id.to_string();
+
+    storage::dictionary_put(dict_uref, &key, extension_ms);
+}
+
+/// Takes custody of the contract package access token.
+pub fn store_access_token(access_token: URef) {
+    runtime::put_key(ACCESS_TOKEN, access_token.into());
+}
+
+/// Releases the contract package access token from custody, if still held.
+#[cfg(feature = "admin")]
+pub fn take_access_token() -> Option<URef> {
+    let access_token = runtime::get_key(ACCESS_TOKEN)?
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+
+    runtime::remove_key(ACCESS_TOKEN);
+    Some(access_token)
+}
+
+/// Checks if a named key holds live contract state and must not be removed.
+#[cfg(feature = "admin")]
+pub fn is_protected_named_key(name: &str) -> bool {
+    PROTECTED_NAMED_KEYS.contains(&name)
+}
+
+/// Checks if the contract is paused.
+pub fn is_contract_paused() -> bool {
+    let uref: URef = runtime::get_key(IS_PAUSED)
+        .unwrap_or_revert_with(Error::StorageError)
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
+
+    storage::read(uref)
+        .unwrap_or_revert_with(Error::StorageError)
+        .unwrap_or(false)
+}
+
+/// Sets the contract paused state.
+#[cfg(feature = "admin")]
+pub fn set_contract_paused(paused: bool) {
+    let uref: URef = runtime::get_key(IS_PAUSED)
+        .unwrap_or_revert_with(Error::StorageError)
+        .into_uref()
+        .unwrap_or_revert_with(Error::StorageError);
 
     storage::write(uref, paused);
 }
 
+/// Checks whether the owner has renounced admin control.
+///
+/// A missing key (on older installs) means control has not been renounced.
+#[cfg(any(feature = "views", feature = "admin"))]
+pub fn is_admin_renounced() -> bool {
+    match runtime::get_key(ADMIN_RENOUNCED) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::read(uref)
+                .unwrap_or_revert_with(Error::StorageError)
+                .unwrap_or(false)
+        }
+        None => false,
+    }
+}
+
+/// Permanently records that the owner renounced admin control, creating the
+/// key if needed.
+#[cfg(feature = "admin")]
+pub fn set_admin_renounced() {
+    match runtime::get_key(ADMIN_RENOUNCED) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, true);
+        }
+        None => runtime::put_key(ADMIN_RENOUNCED, storage::new_uref(true).into()),
+    }
+}
+
 /// Gets the contract's purse URef.
 pub fn get_contract_purse() -> URef {
     runtime::get_key(CONTRACT_PURSE)
@@ -273,6 +2965,39 @@ pub fn get_contract_purse() -> URef {
         .unwrap_or_revert_with(Error::StorageError)
 }
 
+/// Helper function to encode a 32-byte key as a 64-character dictionary key.
+fn hex_key(bytes: &[u8; 32]) -> String {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    let mut key = String::with_capacity(64);
+    for byte in bytes {
+        key.push(HEX[(byte >> 4) as usize] as char);
+        key.push(HEX[(byte & 0x0f) as usize] as char);
+    }
+    key
+}
+
+/// Writes a ledger total, creating the key if needed.
+fn write_ledger_total(name: &str, total: U512) {
+    match runtime::get_key(name) {
+        Some(key) => {
+            let uref: URef = key.into_uref().unwrap_or_revert_with(Error::StorageError);
+            storage::write(uref, total);
+        }
+        None => runtime::put_key(name, storage::new_uref(total).into()),
+    }
+}
+
+/// Builds the dictionary key for a period bucket: `"<day>"` or
+/// `"<day>_<corridor>"`.
+#[cfg(feature = "stats")]
+fn period_stats_key(day_index: u64, corridor: Option<&str>) -> String {
+    match corridor {
+        Some(corridor) => format!("{}_{}", day_index, corridor),
+        None => day_index.to_string(),
+    }
+}
+
 /// Helper function to get dictionary URef by name.
 fn get_dict_uref(dict_name: &str) -> URef {
     runtime::get_key(dict_name)
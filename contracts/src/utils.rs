@@ -1,9 +1,21 @@
 //! Utility functions for the CasperFlow remittance contract.
 
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
-use casper_types::{account::AccountHash, runtime_args, system::CallStackElement, RuntimeArgs, U512};
+use alloc::vec::Vec;
 
-use crate::errors::Error;
+use casper_types::{
+    account::AccountHash, bytesrepr::ToBytes, runtime_args, system::CallStackElement, ContractHash,
+    RuntimeArgs, U512,
+};
+
+use crate::args;
+use crate::errors::{
+    Error, BASIS_POINTS, MAX_CORRIDOR_LENGTH, MAX_HANDLE_LENGTH, MAX_LANGUAGE_CODE_LENGTH,
+    MAX_POOL_MEMBERS, MAX_PURPOSE_LENGTH, MAX_RECIPIENT_SIGNERS, MILLIS_PER_DAY, MIN_HANDLE_LENGTH,
+    PPM_DENOMINATOR,
+};
+#[cfg(feature = "admin")]
+use crate::errors::{MAX_CATEGORY_LENGTH, MAX_NAMESPACE_LENGTH, MAX_SLA_INTERVALS};
 
 /// Validates that an account hash is not the zero address.
 pub fn validate_account_hash(account: &AccountHash) -> Result<(), Error> {
@@ -29,6 +41,227 @@ pub fn validate_string_length(s: &str, max_length: usize) -> Result<(), Error> {
     Ok(())
 }
 
+/// Validates a plaintext remittance purpose (non-blank, within max length).
+pub fn validate_purpose(purpose: &str) -> Result<(), Error> {
+    validate_string_length(purpose, MAX_PURPOSE_LENGTH)?;
+
+    if purpose.trim().is_empty() {
+        return Err(Error::PurposeMaxLength);
+    }
+    Ok(())
+}
+
+/// Computes the commitment to a purpose: `blake2b(purpose || salt)`.
+pub fn compute_purpose_commitment(purpose: &str, salt: &[u8; 32]) -> [u8; 32] {
+    let mut preimage = Vec::with_capacity(purpose.len() + salt.len());
+    preimage.extend_from_slice(purpose.as_bytes());
+    preimage.extend_from_slice(salt);
+
+    runtime::blake2b(preimage)
+}
+
+/// Computes the commitment to a contribution: `blake2b(amount || blinding)`.
+///
+/// `amount` is encoded with its `bytesrepr` serialization. This is a hash
+/// commitment, not a Pedersen one: it hides the amount only while the
+/// blinding stays secret and binds it as far as blake2b resists collisions,
+/// but commitments cannot be added together or proven in range without
+/// opening them. Each contributor therefore commits once, and the contract
+/// checks every opening against the plain amount.
+pub fn compute_amount_commitment(amount: &U512, blinding: &[u8; 32]) -> [u8; 32] {
+    let mut preimage = amount.to_bytes().unwrap_or_revert();
+    preimage.extend_from_slice(blinding);
+
+    runtime::blake2b(preimage)
+}
+
+/// Resolves the remittance addressed by the current call.
+///
+/// Accepts either a `remittance_key` (32-byte hash key of a private
+/// remittance) or a numeric `remittance_id`. Numeric lookups of private
+/// remittances revert with `RemittanceNotFound` so they cannot be enumerated.
+pub fn get_remittance_id_arg() -> u64 {
+    if let Some(remittance_key) = args::get_optional::<[u8; 32]>("remittance_key") {
+        return crate::storage::resolve_remittance_key(remittance_key)
+            .unwrap_or_revert_with(Error::RemittanceNotFound);
+    }
+
+    let remittance_id: u64 = args::get("remittance_id");
+    if crate::storage::is_hashed_remittance(remittance_id) {
+        runtime::revert(Error::RemittanceNotFound);
+    }
+
+    remittance_id
+}
+
+/// Derives the non-enumerable key of a private remittance.
+///
+/// The key is `blake2b(creator || nonce || timestamp || salt)`, where the
+/// nonce is the internal remittance counter value. The creator, nonce, and
+/// timestamp are guessable, so the caller-supplied `salt` is what keeps the
+/// key secret. It is never stored or emitted, but like any argument it is
+/// visible in the creating deploy.
+pub fn derive_remittance_key(
+    creator: &AccountHash,
+    nonce: u64,
+    timestamp: u64,
+    salt: &[u8; 32],
+) -> [u8; 32] {
+    let mut preimage = Vec::with_capacity(32 + 8 + 8 + 32);
+    preimage.extend_from_slice(creator.as_bytes());
+    preimage.extend_from_slice(&nonce.to_le_bytes());
+    preimage.extend_from_slice(&timestamp.to_le_bytes());
+    preimage.extend_from_slice(salt);
+
+    runtime::blake2b(preimage)
+}
+
+/// Derives the index key for a creator's external reference.
+///
+/// References are namespaced per creator so one account cannot claim
+/// another's invoice numbers.
+pub fn derive_reference_key(creator: &AccountHash, reference: &str) -> [u8; 32] {
+    let mut preimage = Vec::with_capacity(32 + reference.len());
+    preimage.extend_from_slice(creator.as_bytes());
+    preimage.extend_from_slice(reference.as_bytes());
+
+    runtime::blake2b(preimage)
+}
+
+/// Gets the reporting day index (days since the Unix epoch) for a block time.
+pub fn day_index(timestamp: u64) -> u64 {
+    timestamp / MILLIS_PER_DAY
+}
+
+/// Validates a corridor code: 1-16 ASCII letters, digits, or hyphens.
+pub fn validate_corridor(corridor: &str) -> Result<(), Error> {
+    let well_formed = !corridor.is_empty()
+        && corridor.len() <= MAX_CORRIDOR_LENGTH
+        && corridor.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'-');
+
+    if !well_formed {
+        return Err(Error::InvalidCorridor);
+    }
+    Ok(())
+}
+
+/// Validates a namespace name: 1-32 lowercase ASCII letters, digits, or
+/// hyphens.
+#[cfg(feature = "admin")]
+pub fn validate_namespace(namespace: &str) -> Result<(), Error> {
+    let well_formed = !namespace.is_empty()
+        && namespace.len() <= MAX_NAMESPACE_LENGTH
+        && namespace
+            .bytes()
+            .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-');
+
+    if !well_formed {
+        return Err(Error::InvalidNamespace);
+    }
+    Ok(())
+}
+
+/// Validates a category name: 1-32 lowercase ASCII letters, digits, or
+/// hyphens.
+#[cfg(feature = "admin")]
+pub fn validate_category(category: &str) -> Result<(), Error> {
+    let well_formed = !category.is_empty()
+        && category.len() <= MAX_CATEGORY_LENGTH
+        && category
+            .bytes()
+            .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-');
+
+    if !well_formed {
+        return Err(Error::InvalidCategory);
+    }
+    Ok(())
+}
+
+/// Validates release escalation intervals: at most `MAX_SLA_INTERVALS`,
+/// each non-zero and longer than the one before.
+#[cfg(feature = "admin")]
+pub fn validate_sla_intervals(intervals_ms: &[u64]) -> Result<(), Error> {
+    let well_formed = intervals_ms.len() <= MAX_SLA_INTERVALS
+        && intervals_ms.first().map_or(true, |first| *first > 0)
+        && intervals_ms.windows(2).all(|pair| pair[0] < pair[1]);
+
+    if !well_formed {
+        return Err(Error::InvalidSlaIntervals);
+    }
+    Ok(())
+}
+
+/// Validates a multi-signature recipient group: 2 to `MAX_RECIPIENT_SIGNERS`
+/// distinct, non-zero accounts including the recipient, with a threshold
+/// between 1 and the number of signers.
+pub fn validate_recipient_signers(
+    recipient: AccountHash,
+    signers: &[AccountHash],
+    threshold: u64,
+) -> Result<(), Error> {
+    let well_formed = (2..=MAX_RECIPIENT_SIGNERS).contains(&signers.len())
+        && signers.contains(&recipient)
+        && signers.iter().all(|signer| validate_account_hash(signer).is_ok())
+        && signers
+            .iter()
+            .enumerate()
+            .all(|(i, signer)| !signers[..i].contains(signer))
+        && threshold >= 1
+        && threshold <= signers.len() as u64;
+
+    if !well_formed {
+        return Err(Error::InvalidRecipientSigners);
+    }
+    Ok(())
+}
+
+/// Validates the members of a savings pool: 2 to `MAX_POOL_MEMBERS`
+/// distinct, non-zero accounts.
+pub fn validate_pool_members(members: &[AccountHash]) -> Result<(), Error> {
+    let well_formed = (2..=MAX_POOL_MEMBERS).contains(&members.len())
+        && members.iter().all(|member| validate_account_hash(member).is_ok())
+        && members
+            .iter()
+            .enumerate()
+            .all(|(i, member)| !members[..i].contains(member));
+
+    if !well_formed {
+        return Err(Error::InvalidSavingsPool);
+    }
+    Ok(())
+}
+
+/// Validates a remittance handle: 3-64 lowercase ASCII letters, digits, or
+/// hyphens, neither starting nor ending with a hyphen.
+pub fn validate_handle(handle: &str) -> Result<(), Error> {
+    let well_formed = (MIN_HANDLE_LENGTH..=MAX_HANDLE_LENGTH).contains(&handle.len())
+        && handle
+            .bytes()
+            .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-')
+        && !handle.starts_with('-')
+        && !handle.ends_with('-');
+
+    if !well_formed {
+        return Err(Error::InvalidHandle);
+    }
+    Ok(())
+}
+
+/// Validates a localized purpose's language code: 2-16 lowercase ASCII
+/// letters, digits, or hyphens, starting with a letter (e.g. "pt-br").
+pub fn validate_language_code(lang: &str) -> Result<(), Error> {
+    let well_formed = (2..=MAX_LANGUAGE_CODE_LENGTH).contains(&lang.len())
+        && lang.starts_with(|c: char| c.is_ascii_lowercase())
+        && lang
+            .bytes()
+            .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-');
+
+    if !well_formed {
+        return Err(Error::InvalidLanguageCode);
+    }
+    Ok(())
+}
+
 /// Gets the account hash of the current caller.
 ///
 /// This function determines who is calling the contract entry point.
@@ -55,12 +288,70 @@ pub fn get_caller() -> AccountHash {
     }
 }
 
-/// Calculates the platform fee from an amount given the fee in basis points.
+/// Gets the hash of this contract from within a session entry point.
+///
+/// Session entry points run in the caller's account context, so the
+/// contract's own hash is only available from the call stack.
+pub fn get_session_contract_hash() -> ContractHash {
+    match runtime::get_call_stack().last() {
+        Some(CallStackElement::StoredSession { contract_hash, .. }) => *contract_hash,
+        _ => runtime::revert(Error::Unauthorized),
+    }
+}
+
+/// Rounding policy applied when a fee does not divide evenly.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeRounding {
+    /// Round down (truncate), favouring the recipient
+    Floor = 0,
+    /// Round up, so any non-zero fee rate never yields a zero fee
+    Ceil = 1,
+    /// Round to the nearest mote, with exact halves rounded up
+    HalfUp = 2,
+}
+
+impl FeeRounding {
+    /// Decodes a stored rounding mode.
+    pub fn from_u8(value: u8) -> Result<Self, Error> {
+        match value {
+            0 => Ok(FeeRounding::Floor),
+            1 => Ok(FeeRounding::Ceil),
+            2 => Ok(FeeRounding::HalfUp),
+            _ => Err(Error::InvalidFeeRounding),
+        }
+    }
+}
+
+/// When the platform fee is taken, fixed per deployment at install.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeModel {
+    /// Charged on the amount released
+    OnRelease = 0,
+    /// Charged on each contribution as it is made; contributions and
+    /// refunds are recorded net of the fee
+    OnContribute = 1,
+}
+
+impl FeeModel {
+    /// Decodes a stored fee model.
+    pub fn from_u8(value: u8) -> Result<Self, Error> {
+        match value {
+            0 => Ok(FeeModel::OnRelease),
+            1 => Ok(FeeModel::OnContribute),
+            _ => Err(Error::InvalidFeeModel),
+        }
+    }
+}
+
+/// Calculates the platform fee from an amount given the fee in parts-per-million.
 ///
 /// # Arguments
 ///
 /// * `amount` - The total amount
-/// * `fee_bps` - Fee in basis points (1 bps = 0.01%)
+/// * `fee_ppm` - Fee in parts-per-million (1 ppm = 0.0001%, 100 ppm = 1 bps)
+/// * `rounding` - How to round a fractional fee
 ///
 /// # Returns
 ///
@@ -68,22 +359,95 @@ pub fn get_caller() -> AccountHash {
 ///
 /// # Example
 ///
-/// ```
+/// ```ignore
 /// let amount = U512::from(10000);
-/// let fee = calculate_fee(&amount, 50); // 50 bps = 0.5%
+/// let fee = calculate_fee(&amount, 5_000, FeeRounding::Floor); // 5000 ppm = 0.5%
 /// // fee = 50
 /// ```
-pub fn calculate_fee(amount: &U512, fee_bps: u64) -> U512 {
-    // Fee = (amount * fee_bps) / 10000
+pub fn calculate_fee(amount: &U512, fee_ppm: u64, rounding: FeeRounding) -> U512 {
+    // Fee = (amount * fee_ppm) / 1_000_000, rounded per policy
     // Using checked operations to prevent overflow
 
-    let fee_bps_u512 = U512::from(fee_bps);
-    let basis_points = U512::from(10000);
+    let fee_ppm_u512 = U512::from(fee_ppm);
+    let parts_per_million = U512::from(PPM_DENOMINATOR);
+
+    let numerator = amount
+        .checked_mul(fee_ppm_u512)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+
+    let (quotient, remainder) = numerator.div_mod(parts_per_million);
+
+    let round_up = match rounding {
+        FeeRounding::Floor => false,
+        FeeRounding::Ceil => !remainder.is_zero(),
+        FeeRounding::HalfUp => remainder * 2 >= parts_per_million,
+    };
+
+    if round_up {
+        quotient
+            .checked_add(U512::one())
+            .unwrap_or_revert_with(Error::ArithmeticOverflow)
+    } else {
+        quotient
+    }
+}
+
+/// Calculates the smallest amount that leaves at least `net` once the fee is
+/// taken from it.
+///
+/// Starts from the exact gross-up, `net / (1 - fee)`, rounded up; rounding
+/// the fee moves the answer by at most a mote either way, which is then
+/// corrected by checking the neighbouring amounts.
+///
+/// # Arguments
+///
+/// * `net` - Amount that must be left after the fee
+/// * `fee_ppm` - Fee in parts-per-million, below `PPM_DENOMINATOR`
+/// * `rounding` - How the fee is rounded
+#[cfg(feature = "views")]
+pub fn gross_up(net: &U512, fee_ppm: u64, rounding: FeeRounding) -> U512 {
+    let parts_per_million = U512::from(PPM_DENOMINATOR);
+    let net_parts = U512::from(PPM_DENOMINATOR.saturating_sub(fee_ppm).max(1));
+
+    let numerator = net
+        .checked_mul(parts_per_million)
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+    let (quotient, remainder) = numerator.div_mod(net_parts);
+    let mut gross = if remainder.is_zero() {
+        quotient
+    } else {
+        quotient + U512::one()
+    };
 
-    amount
-        .checked_mul(fee_bps_u512)
-        .and_then(|result| result.checked_div(basis_points))
-        .unwrap_or_revert_with(Error::ArithmeticOverflow)
+    let net_of = |gross: U512| gross - calculate_fee(&gross, fee_ppm, rounding);
+    while net_of(gross) < *net {
+        gross += U512::one();
+    }
+    while !gross.is_zero() && net_of(gross - U512::one()) >= *net {
+        gross -= U512::one();
+    }
+    gross
+}
+
+/// Splits a platform fee into the fee collector's part and the creator rebate.
+///
+/// # Arguments
+///
+/// * `fee` - The platform fee charged on release
+/// * `creator_share_bps` - Share of the fee rebated to the creator, in basis
+///   points of the fee (10000 = the whole fee)
+///
+/// # Returns
+///
+/// `(collector_amount, creator_rebate)`, with the rebate rounded down so the
+/// two parts always sum to `fee`
+pub fn split_fee(fee: &U512, creator_share_bps: u64) -> (U512, U512) {
+    let rebate = fee
+        .checked_mul(U512::from(creator_share_bps))
+        .and_then(|result| result.checked_div(U512::from(BASIS_POINTS)))
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+
+    (*fee - rebate, rebate)
 }
 
 /// Transfers CSPR tokens from one purse to another.
@@ -113,6 +477,49 @@ pub fn transfer_cspr(
     .map_err(|_| Error::TransferFailed)
 }
 
+/// Pays several accounts out of the contract purse through a fresh purse.
+///
+/// The combined payout is first moved into a temporary purse, so the escrow
+/// is debited exactly once, and each payment is then drawn from it. Returns
+/// an error if any transfer fails or the payout purse is not fully drained;
+/// callers revert on error, which also rolls back the release flag.
+///
+/// # Arguments
+///
+/// * `payments` - `(account, amount)` pairs; zero amounts are skipped
+pub fn pay_out(payments: &[(AccountHash, U512)]) -> Result<(), Error> {
+    let mut total = U512::zero();
+    for (_, amount) in payments {
+        total = total.checked_add(*amount).ok_or(Error::ArithmeticOverflow)?;
+    }
+
+    if total.is_zero() {
+        return Ok(());
+    }
+
+    // Debit the escrow once, into a purse used only for this payout
+    let payout_purse = casper_contract::contract_api::system::create_purse();
+    casper_contract::contract_api::system::transfer_from_purse_to_purse(
+        crate::storage::get_contract_purse(),
+        payout_purse,
+        total,
+        None,
+    )
+    .map_err(|_| Error::TransferFailed)?;
+
+    for (account, amount) in payments {
+        transfer_cspr(payout_purse, *account, *amount)?;
+    }
+
+    // Nothing may be left behind in the temporary purse
+    let remaining = casper_contract::contract_api::system::get_purse_balance(payout_purse)
+        .ok_or(Error::StorageError)?;
+    if !remaining.is_zero() {
+        return Err(Error::PayoutIncomplete);
+    }
+    Ok(())
+}
+
 /// Transfers CSPR tokens from caller to contract purse.
 ///
 /// This is used when contributors send funds to the contract.
@@ -120,7 +527,7 @@ pub fn receive_payment(amount: U512) -> Result<(), Error> {
     validate_non_zero_amount(&amount)?;
 
     // Get the main purse of the caller
-    let caller_purse = runtime::get_named_arg::<casper_types::URef>("purse");
+    let caller_purse = args::get::<casper_types::URef>("purse");
 
     // Get the contract purse
     let contract_purse = crate::storage::get_contract_purse();
@@ -135,6 +542,34 @@ pub fn receive_payment(amount: U512) -> Result<(), Error> {
     .map_err(|_| Error::TransferFailed)
 }
 
+/// Receives a payment and checks that the contract purse grew by exactly
+/// `amount`.
+///
+/// Used for confidential contributions, where the contract keeps no
+/// per-contributor amount to reconcile against later.
+pub fn receive_payment_verified(amount: U512) -> Result<(), Error> {
+    let balance_before = get_contract_purse_balance()?;
+
+    receive_payment(amount)?;
+
+    let balance_after = get_contract_purse_balance()?;
+    let delta = balance_after
+        .checked_sub(balance_before)
+        .ok_or(Error::PurseDeltaMismatch)?;
+
+    if delta != amount {
+        return Err(Error::PurseDeltaMismatch);
+    }
+    Ok(())
+}
+
+/// Gets the current balance of the contract purse.
+pub fn get_contract_purse_balance() -> Result<U512, Error> {
+    let contract_purse = crate::storage::get_contract_purse();
+    casper_contract::contract_api::system::get_purse_balance(contract_purse)
+        .ok_or(Error::StorageError)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,31 +601,320 @@ mod tests {
 
     #[test]
     fn test_calculate_fee() {
-        // Test 0.5% fee (50 bps)
+        // Test 0.5% fee (5000 ppm)
         let amount = U512::from(10000);
-        let fee = calculate_fee(&amount, 50);
+        let fee = calculate_fee(&amount, 5_000, FeeRounding::Floor);
         assert_eq!(fee, U512::from(50));
 
-        // Test 1% fee (100 bps)
+        // Test 1% fee (10000 ppm)
         let amount = U512::from(10000);
-        let fee = calculate_fee(&amount, 100);
+        let fee = calculate_fee(&amount, 10_000, FeeRounding::Floor);
         assert_eq!(fee, U512::from(100));
 
-        // Test 5% fee (500 bps)
+        // Test 5% fee (50000 ppm)
         let amount = U512::from(10000);
-        let fee = calculate_fee(&amount, 500);
+        let fee = calculate_fee(&amount, 50_000, FeeRounding::Floor);
         assert_eq!(fee, U512::from(500));
 
         // Test large amount
         let amount = U512::from(1_000_000_000u64); // 1 CSPR in motes
-        let fee = calculate_fee(&amount, 50);
+        let fee = calculate_fee(&amount, 5_000, FeeRounding::Floor);
         assert_eq!(fee, U512::from(5_000_000u64)); // 0.005 CSPR
     }
 
+    #[cfg(feature = "views")]
+    #[test]
+    fn test_gross_up() {
+        let net = U512::from(9950);
+
+        // 9999 pays a 49.995 fee: rounded down it nets 9950, up it nets 9949
+        assert_eq!(gross_up(&net, 5_000, FeeRounding::Floor), U512::from(9999));
+        assert_eq!(gross_up(&net, 5_000, FeeRounding::Ceil), U512::from(10000));
+        assert_eq!(gross_up(&net, 5_000, FeeRounding::HalfUp), U512::from(10000));
+
+        assert_eq!(gross_up(&net, 0, FeeRounding::Ceil), net);
+        assert_eq!(gross_up(&U512::zero(), 5_000, FeeRounding::Ceil), U512::zero());
+
+        let gross = gross_up(&U512::from(1_000_000_007u64), 12_345, FeeRounding::HalfUp);
+        let fee = calculate_fee(&gross, 12_345, FeeRounding::HalfUp);
+        assert!(gross - fee >= U512::from(1_000_000_007u64));
+        let fee = calculate_fee(&(gross - 1), 12_345, FeeRounding::HalfUp);
+        assert!(gross - 1 - fee < U512::from(1_000_000_007u64));
+    }
+
     #[test]
     fn test_zero_fee() {
         let amount = U512::from(10000);
-        let fee = calculate_fee(&amount, 0);
+        let fee = calculate_fee(&amount, 0, FeeRounding::Floor);
         assert_eq!(fee, U512::zero());
     }
+
+    #[test]
+    fn test_fee_rounding_floor() {
+        // 199 * 50 / 10000 = 0.995 -> 0
+        assert_eq!(calculate_fee(&U512::from(199), 5_000, FeeRounding::Floor), U512::zero());
+        // 200 * 50 / 10000 = 1.0 -> 1
+        assert_eq!(calculate_fee(&U512::from(200), 5_000, FeeRounding::Floor), U512::from(1));
+    }
+
+    #[test]
+    fn test_fee_rounding_ceil() {
+        // Smallest amount never rounds to zero
+        assert_eq!(calculate_fee(&U512::from(1), 5_000, FeeRounding::Ceil), U512::from(1));
+        assert_eq!(calculate_fee(&U512::from(199), 5_000, FeeRounding::Ceil), U512::from(1));
+        // Exact division is not bumped
+        assert_eq!(calculate_fee(&U512::from(200), 5_000, FeeRounding::Ceil), U512::from(1));
+        assert_eq!(calculate_fee(&U512::from(201), 5_000, FeeRounding::Ceil), U512::from(2));
+        // Zero rate stays zero
+        assert_eq!(calculate_fee(&U512::from(201), 0, FeeRounding::Ceil), U512::zero());
+    }
+
+    #[test]
+    fn test_fee_rounding_half_up() {
+        // 99 * 50 / 10000 = 0.495 -> 0
+        assert_eq!(calculate_fee(&U512::from(99), 5_000, FeeRounding::HalfUp), U512::zero());
+        // 100 * 50 / 10000 = 0.5 -> 1
+        assert_eq!(calculate_fee(&U512::from(100), 5_000, FeeRounding::HalfUp), U512::from(1));
+        // 299 * 50 / 10000 = 1.495 -> 1
+        assert_eq!(calculate_fee(&U512::from(299), 5_000, FeeRounding::HalfUp), U512::from(1));
+        // 300 * 50 / 10000 = 1.5 -> 2
+        assert_eq!(calculate_fee(&U512::from(300), 5_000, FeeRounding::HalfUp), U512::from(2));
+    }
+
+    #[test]
+    fn test_fee_rounding_from_u8() {
+        assert_eq!(FeeRounding::from_u8(0).ok(), Some(FeeRounding::Floor));
+        assert_eq!(FeeRounding::from_u8(1).ok(), Some(FeeRounding::Ceil));
+        assert_eq!(FeeRounding::from_u8(2).ok(), Some(FeeRounding::HalfUp));
+        assert!(FeeRounding::from_u8(3).is_err());
+    }
+
+    #[test]
+    fn test_fee_model_from_u8() {
+        assert_eq!(FeeModel::from_u8(0).ok(), Some(FeeModel::OnRelease));
+        assert_eq!(FeeModel::from_u8(1).ok(), Some(FeeModel::OnContribute));
+        assert_eq!(FeeModel::from_u8(2).err(), Some(Error::InvalidFeeModel));
+    }
+
+    #[test]
+    fn test_sub_basis_point_fees() {
+        let amount = U512::from(1_000_000_000u64); // 1 CSPR in motes
+
+        // 0.05% (500 ppm)
+        assert_eq!(calculate_fee(&amount, 500, FeeRounding::Floor), U512::from(500_000u64));
+
+        // 0.025% (250 ppm), not expressible in whole basis points
+        assert_eq!(calculate_fee(&amount, 250, FeeRounding::Floor), U512::from(250_000u64));
+    }
+
+    #[test]
+    fn test_split_fee() {
+        let fee = U512::from(1000);
+
+        // No share keeps the whole fee with the collector
+        assert_eq!(split_fee(&fee, 0), (fee, U512::zero()));
+
+        // 20% share
+        assert_eq!(split_fee(&fee, 2_000), (U512::from(800), U512::from(200)));
+
+        // Full share
+        assert_eq!(split_fee(&fee, 10_000), (U512::zero(), fee));
+
+        // Rebate rounds down, collector keeps the remainder
+        let fee = U512::from(3);
+        assert_eq!(split_fee(&fee, 5_000), (U512::from(2), U512::from(1)));
+    }
+
+    #[test]
+    fn test_validate_purpose() {
+        assert!(validate_purpose("School fees").is_ok());
+        assert!(validate_purpose("").is_err());
+        assert!(validate_purpose("   ").is_err());
+        assert!(validate_purpose(&"a".repeat(MAX_PURPOSE_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_day_index() {
+        assert_eq!(day_index(0), 0);
+        assert_eq!(day_index(MILLIS_PER_DAY - 1), 0);
+        assert_eq!(day_index(MILLIS_PER_DAY), 1);
+        // 2024-01-01T00:00:00Z
+        assert_eq!(day_index(1_704_067_200_000), 19_723);
+    }
+
+    #[test]
+    fn test_validate_corridor() {
+        assert!(validate_corridor("US-NG").is_ok());
+        assert!(validate_corridor("").is_err());
+        assert!(validate_corridor("US NG").is_err());
+        assert!(validate_corridor("US_NG").is_err());
+        assert!(validate_corridor(&"A".repeat(MAX_CORRIDOR_LENGTH + 1)).is_err());
+    }
+
+    #[cfg(feature = "admin")]
+    #[test]
+    fn test_validate_namespace() {
+        assert!(validate_namespace("lagos-hometown").is_ok());
+        assert!(validate_namespace("").is_err());
+        assert!(validate_namespace("Lagos").is_err());
+        assert!(validate_namespace("lagos hometown").is_err());
+        assert!(validate_namespace(&"a".repeat(MAX_NAMESPACE_LENGTH + 1)).is_err());
+    }
+
+    #[cfg(feature = "admin")]
+    #[test]
+    fn test_validate_sla_intervals() {
+        assert!(validate_sla_intervals(&[]).is_ok());
+        assert!(validate_sla_intervals(&[259_200_000, 604_800_000]).is_ok());
+        assert!(validate_sla_intervals(&[0, 604_800_000]).is_err());
+        assert!(validate_sla_intervals(&[604_800_000, 259_200_000]).is_err());
+        assert!(validate_sla_intervals(&[1_000, 1_000]).is_err());
+        assert!(validate_sla_intervals(&[1; MAX_SLA_INTERVALS + 1]).is_err());
+    }
+
+    #[test]
+    fn test_validate_recipient_signers() {
+        let alice = AccountHash::new([1u8; 32]);
+        let bob = AccountHash::new([2u8; 32]);
+        let carol = AccountHash::new([3u8; 32]);
+
+        assert!(validate_recipient_signers(alice, &[alice, bob], 2).is_ok());
+        assert!(validate_recipient_signers(alice, &[alice, bob, carol], 1).is_ok());
+        assert!(validate_recipient_signers(alice, &[alice], 1).is_err());
+        assert!(validate_recipient_signers(alice, &[bob, carol], 1).is_err());
+        assert!(validate_recipient_signers(alice, &[alice, alice], 1).is_err());
+        assert!(validate_recipient_signers(alice, &[alice, bob], 0).is_err());
+        assert!(validate_recipient_signers(alice, &[alice, bob], 3).is_err());
+        assert!(
+            validate_recipient_signers(alice, &[alice, AccountHash::new([0u8; 32])], 1).is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_pool_members() {
+        let alice = AccountHash::new([1u8; 32]);
+        let bob = AccountHash::new([2u8; 32]);
+
+        assert!(validate_pool_members(&[alice, bob]).is_ok());
+        assert!(validate_pool_members(&[alice]).is_err());
+        assert!(validate_pool_members(&[alice, alice]).is_err());
+        assert!(validate_pool_members(&[alice, AccountHash::new([0u8; 32])]).is_err());
+
+        let members: Vec<AccountHash> = (1..=MAX_POOL_MEMBERS as u8 + 1)
+            .map(|i| AccountHash::new([i; 32]))
+            .collect();
+        assert!(validate_pool_members(&members[..MAX_POOL_MEMBERS]).is_ok());
+        assert!(validate_pool_members(&members).is_err());
+    }
+
+    #[cfg(feature = "admin")]
+    #[test]
+    fn test_validate_category() {
+        assert!(validate_category("tuition").is_ok());
+        assert!(validate_category("medical-bills").is_ok());
+        assert!(validate_category("").is_err());
+        assert!(validate_category("Medical").is_err());
+        assert!(validate_category(&"a".repeat(MAX_CATEGORY_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_validate_handle() {
+        assert!(validate_handle("maria-surgery-2025").is_ok());
+        assert!(validate_handle("abc").is_ok());
+        assert!(validate_handle("ab").is_err());
+        assert!(validate_handle("Maria-Surgery").is_err());
+        assert!(validate_handle("maria_surgery").is_err());
+        assert!(validate_handle("-maria").is_err());
+        assert!(validate_handle("maria-").is_err());
+        assert!(validate_handle(&"a".repeat(MAX_HANDLE_LENGTH)).is_ok());
+        assert!(validate_handle(&"a".repeat(MAX_HANDLE_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_validate_language_code() {
+        assert!(validate_language_code("fr").is_ok());
+        assert!(validate_language_code("pt-br").is_ok());
+        assert!(validate_language_code("f").is_err());
+        assert!(validate_language_code("pt-BR").is_err());
+        assert!(validate_language_code("-fr").is_err());
+        assert!(validate_language_code(&"a".repeat(MAX_LANGUAGE_CODE_LENGTH + 1)).is_err());
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const ROUNDINGS: [FeeRounding; 3] =
+        [FeeRounding::Floor, FeeRounding::Ceil, FeeRounding::HalfUp];
+
+    fn rounding() -> impl Strategy<Value = FeeRounding> {
+        prop::sample::select(&ROUNDINGS[..])
+    }
+
+    proptest! {
+        #[test]
+        fn fee_never_exceeds_amount(
+            amount in any::<u128>(),
+            fee_ppm in 0..=PPM_DENOMINATOR,
+            rounding in rounding(),
+        ) {
+            let amount = U512::from(amount);
+            prop_assert!(calculate_fee(&amount, fee_ppm, rounding) <= amount);
+        }
+
+        #[test]
+        fn fee_is_monotonic_in_amount(
+            a in any::<u128>(),
+            b in any::<u128>(),
+            fee_ppm in 0..=PPM_DENOMINATOR,
+            rounding in rounding(),
+        ) {
+            let (low, high) = (U512::from(a.min(b)), U512::from(a.max(b)));
+            prop_assert!(
+                calculate_fee(&low, fee_ppm, rounding) <= calculate_fee(&high, fee_ppm, rounding)
+            );
+        }
+
+        #[test]
+        fn fee_is_monotonic_in_rate(
+            amount in any::<u128>(),
+            a in 0..=PPM_DENOMINATOR,
+            b in 0..=PPM_DENOMINATOR,
+            rounding in rounding(),
+        ) {
+            let amount = U512::from(amount);
+            prop_assert!(
+                calculate_fee(&amount, a.min(b), rounding)
+                    <= calculate_fee(&amount, a.max(b), rounding)
+            );
+        }
+
+        #[test]
+        fn fee_rounding_brackets_exact_fee(amount in any::<u64>(), fee_ppm in 0..=PPM_DENOMINATOR) {
+            let exact = amount as u128 * fee_ppm as u128;
+            let floor = U512::from(exact / PPM_DENOMINATOR as u128);
+            let amount = U512::from(amount);
+
+            let half_up = calculate_fee(&amount, fee_ppm, FeeRounding::HalfUp);
+            let ceil = calculate_fee(&amount, fee_ppm, FeeRounding::Ceil);
+            prop_assert_eq!(calculate_fee(&amount, fee_ppm, FeeRounding::Floor), floor);
+            prop_assert!(floor <= half_up && half_up <= ceil);
+
+            if exact % PPM_DENOMINATOR as u128 == 0 {
+                prop_assert_eq!(ceil, floor);
+            } else {
+                prop_assert_eq!(ceil, floor + 1);
+            }
+        }
+
+        #[test]
+        fn split_fee_sums_to_fee(fee in any::<u128>(), creator_share_bps in 0..=BASIS_POINTS) {
+            let fee = U512::from(fee);
+            let (collector, rebate) = split_fee(&fee, creator_share_bps);
+            prop_assert_eq!(collector + rebate, fee);
+            prop_assert!(rebate <= fee);
+        }
+    }
 }
@@ -1,80 +0,0 @@
-//! Integration tests for CasperFlow remittance contract.
-//!
-//! These tests verify the complete workflows of the contract
-//! including multiple user interactions.
-
-#![cfg(test)]
-
-use casper_types::{account::AccountHash, U512};
-
-// Note: Full integration tests require casper-engine-test-support
-// This is a template for the test structure
-
-#[test]
-fn test_complete_remittance_workflow() {
-    // TODO: Implement using casper-engine-test-support
-    // 1. Deploy contract
-    // 2. Create remittance
-    // 3. Multiple contributions
-    // 4. Release funds
-    // 5. Verify balances
-}
-
-#[test]
-fn test_cancel_and_refund_workflow() {
-    // TODO: Implement
-    // 1. Create remittance
-    // 2. Multiple contributions
-    // 3. Cancel remittance
-    // 4. Each contributor claims refund
-    // 5. Verify all refunds claimed
-}
-
-#[test]
-fn test_unauthorized_access() {
-    // TODO: Implement
-    // 1. Create remittance as user A
-    // 2. Try to release as user B (should fail)
-    // 3. Try to cancel as user B (should fail)
-}
-
-#[test]
-fn test_target_not_met_release_fails() {
-    // TODO: Implement
-    // 1. Create remittance with target 1000
-    // 2. Contribute 500
-    // 3. Try to release (should fail)
-}
-
-#[test]
-fn test_platform_fee_calculation() {
-    // TODO: Implement
-    // 1. Create remittance
-    // 2. Contribute exact target amount
-    // 3. Release funds
-    // 4. Verify recipient received (target - fee)
-    // 5. Verify fee collector received fee
-}
-
-#[test]
-fn test_contract_pause_functionality() {
-    // TODO: Implement
-    // 1. Pause contract as owner
-    // 2. Try operations (should all fail)
-    // 3. Unpause contract
-    // 4. Operations should work again
-}
-
-#[test]
-fn test_multiple_contributors() {
-    // TODO: Implement
-    // Test with 10+ contributors to verify scalability
-}
-
-#[test]
-fn test_refund_double_claim_prevention() {
-    // TODO: Implement
-    // 1. Create and cancel remittance
-    // 2. Claim refund as contributor
-    // 3. Try to claim again (should fail)
-}
@@ -0,0 +1,757 @@
+//! Test fixture: an in-memory network with the contract installed.
+//!
+//! [`TestContext::new`] runs genesis with a handful of funded accounts and
+//! installs the release wasm from the installer account, which becomes the
+//! contract owner and fee collector. Entry points are called through typed
+//! helpers that commit the deploy and return contract reverts as
+//! [`Error`]s, and contributions go through the contribute session wasm the
+//! same way a wallet would send them.
+//!
+//! Every deploy is paid with [`DEFAULT_PAYMENT`], which the engine consumes
+//! in full. The fixture keeps a tally per sender so balance assertions can
+//! use [`TestContext::net_balance`], the main purse balance with those
+//! payments added back.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use casper_engine_test_support::{
+    utils::create_run_genesis_request, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+    DEFAULT_ACCOUNTS, DEFAULT_ACCOUNT_ADDR, DEFAULT_BLOCK_TIME, DEFAULT_PAYMENT,
+};
+use casper_execution_engine::core::{
+    engine_state::{self, genesis::GenesisAccount},
+    execution,
+};
+use casper_types::{
+    account::AccountHash, runtime_args, system::mint::ARG_AMOUNT, ApiError, ContractHash, Key,
+    Motes, PublicKey, RuntimeArgs, SecretKey, StoredValue, URef, U512,
+};
+use casperflow_types::{
+    casper_types::{
+        self as types_v4,
+        bytesrepr::{self, ToBytes},
+    },
+    contributor_key,
+    remittance::{
+        Branding, BuildInfo, ContributionSnapshot, ContributionWeight, LocalizedPurpose,
+        PayoutSchedule, ReceiptAcknowledgement, ReleaseBreakdown, ReleaseSla, RemittanceAnalytics,
+        RemittanceUpdate, SavingsPool, ScheduledStart,
+    },
+    ContractSchema, Error, LedgerEntry, Remittance,
+};
+
+/// Balance each test account starts with (100,000 CSPR)
+pub const INITIAL_BALANCE: u64 = 100_000_000_000_000;
+
+/// Installer named key holding the contract hash
+const CONTRACT_HASH_KEY: &str = "casperflow_contract_hash";
+
+/// Directory the contract workspace writes release wasm to
+const WASM_DIR: &str = "../target/wasm32-unknown-unknown/release";
+
+/// Contract wasm, as built by `make build`
+pub const CONTRACT_WASM: &str = "casperflow_escrow.wasm";
+
+/// Session wasm that contributes from the sender's main purse
+pub const CONTRIBUTE_SESSION_WASM: &str = "contribute_session.wasm";
+
+/// Session wasm that calls `release_funds`
+pub const RELEASE_SESSION_WASM: &str = "release_session.wasm";
+
+/// Session wasm that calls `claim_refund`
+pub const REFUND_SESSION_WASM: &str = "refund_session.wasm";
+
+/// Reads a release wasm from the contract workspace's target directory
+pub fn read_wasm(name: &str) -> Vec<u8> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join(WASM_DIR)
+        .join(name);
+    std::fs::read(&path).unwrap_or_else(|error| {
+        panic!(
+            "failed to read {} ({}); run `make build` first",
+            path.display(),
+            error
+        )
+    })
+}
+
+/// An in-memory network with the contract installed
+pub struct TestContext {
+    pub builder: InMemoryWasmTestBuilder,
+    pub contract_hash: ContractHash,
+    /// Installer; contract owner, fee collector, and compliance officer
+    pub owner: AccountHash,
+    /// Creates remittances
+    pub alice: AccountHash,
+    /// Receives remittances
+    pub bob: AccountHash,
+    /// Contributes to remittances
+    pub carol: AccountHash,
+    /// Contributes to remittances
+    pub dave: AccountHash,
+    /// Extra funded accounts, see [`TestContext::with_accounts`]
+    pub accounts: Vec<AccountHash>,
+    /// Block time (ms) given to every deploy, see [`TestContext::advance_time`]
+    pub block_time: u64,
+    payments: BTreeMap<AccountHash, U512>,
+}
+
+impl TestContext {
+    /// Runs genesis with funded test accounts and installs the contract
+    pub fn new() -> Self {
+        Self::with_accounts(0)
+    }
+
+    /// Like [`TestContext::new`], also funding `count` extra accounts
+    pub fn with_accounts(count: usize) -> Self {
+        Self::setup(count, RuntimeArgs::new())
+    }
+
+    /// Like [`TestContext::new`], passing `args` to the install session
+    pub fn with_install_args(args: RuntimeArgs) -> Self {
+        Self::setup(0, args)
+    }
+
+    fn setup(count: usize, install_args: RuntimeArgs) -> Self {
+        let alice = test_account([1; SecretKey::ED25519_LENGTH]);
+        let bob = test_account([2; SecretKey::ED25519_LENGTH]);
+        let carol = test_account([3; SecretKey::ED25519_LENGTH]);
+        let dave = test_account([4; SecretKey::ED25519_LENGTH]);
+        let extra: Vec<PublicKey> = (0..count as u64).map(extra_account).collect();
+
+        let mut accounts = DEFAULT_ACCOUNTS.clone();
+        for public_key in [&alice, &bob, &carol, &dave].into_iter().chain(&extra) {
+            accounts.push(GenesisAccount::account(
+                public_key.clone(),
+                Motes::new(U512::from(INITIAL_BALANCE)),
+                None,
+            ));
+        }
+
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder.run_genesis(&create_run_genesis_request(accounts));
+
+        let mut context = TestContext {
+            builder,
+            contract_hash: ContractHash::default(),
+            owner: *DEFAULT_ACCOUNT_ADDR,
+            alice: alice.to_account_hash(),
+            bob: bob.to_account_hash(),
+            carol: carol.to_account_hash(),
+            dave: dave.to_account_hash(),
+            accounts: extra.iter().map(PublicKey::to_account_hash).collect(),
+            block_time: DEFAULT_BLOCK_TIME,
+            payments: BTreeMap::new(),
+        };
+
+        let owner = context.owner;
+        context
+            .session(owner, CONTRACT_WASM, install_args)
+            .expect("contract should install");
+        context.contract_hash = context
+            .builder
+            .get_expected_account(owner)
+            .named_keys()
+            .get(CONTRACT_HASH_KEY)
+            .and_then(|key| key.into_hash())
+            .map(ContractHash::new)
+            .expect("installer should hold the contract hash");
+
+        context
+    }
+
+    // ============================================================================
+    // Deploys
+    // ============================================================================
+
+    /// Calls a contract entry point from `sender`
+    pub fn call(
+        &mut self,
+        sender: AccountHash,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) -> Result<(), Error> {
+        let request = ExecuteRequestBuilder::contract_call_by_hash(
+            sender,
+            self.contract_hash,
+            entry_point,
+            args,
+        )
+        .with_block_time(self.block_time)
+        .build();
+        self.execute(sender, request)
+    }
+
+    /// Runs one of the workspace's session wasm from `sender`
+    pub fn session(
+        &mut self,
+        sender: AccountHash,
+        wasm: &str,
+        args: RuntimeArgs,
+    ) -> Result<(), Error> {
+        let request = ExecuteRequestBuilder::module_bytes(sender, read_wasm(wasm), args)
+            .with_block_time(self.block_time)
+            .build();
+        self.execute(sender, request)
+    }
+
+    /// Moves the block time of later deploys forward by `millis`
+    pub fn advance_time(&mut self, millis: u64) {
+        self.block_time += millis;
+    }
+
+    fn execute(
+        &mut self,
+        sender: AccountHash,
+        request: engine_state::ExecuteRequest,
+    ) -> Result<(), Error> {
+        self.builder.exec(request).commit();
+        *self.payments.entry(sender).or_default() += *DEFAULT_PAYMENT;
+
+        match self.builder.get_error() {
+            None => Ok(()),
+            Some(engine_state::Error::Exec(execution::Error::Revert(ApiError::User(code)))) => {
+                Err(Error::try_from(code).unwrap_or_else(|_| {
+                    panic!("deploy reverted with unknown contract error {}", code)
+                }))
+            }
+            Some(error) => panic!("deploy failed outside the contract: {:?}", error),
+        }
+    }
+
+    // ============================================================================
+    // Typed Calls
+    // ============================================================================
+
+    /// Creates a public remittance and returns its ID
+    pub fn create_remittance(
+        &mut self,
+        creator: AccountHash,
+        recipient: AccountHash,
+        target_amount: u64,
+        purpose: &str,
+    ) -> u64 {
+        let args = runtime_args! {
+            "recipient" => recipient,
+            "target_amount" => U512::from(target_amount),
+            "purpose" => purpose.to_string(),
+        };
+        self.call(creator, "create_remittance", args)
+            .expect("remittance should be created");
+        self.remittance_count()
+    }
+
+    /// Contributes through the contribute session wasm
+    pub fn contribute(
+        &mut self,
+        contributor: AccountHash,
+        remittance_id: u64,
+        amount: u64,
+    ) -> Result<(), Error> {
+        let args = runtime_args! {
+            "contract_hash" => self.contract_hash,
+            "remittance_id" => remittance_id,
+            ARG_AMOUNT => U512::from(amount),
+        };
+        self.session(contributor, CONTRIBUTE_SESSION_WASM, args)
+    }
+
+    /// Contributes through the contribute session wasm with an idempotency key
+    pub fn contribute_once(
+        &mut self,
+        contributor: AccountHash,
+        remittance_id: u64,
+        amount: u64,
+        idempotency_key: [u8; 32],
+    ) -> Result<(), Error> {
+        let args = runtime_args! {
+            "contract_hash" => self.contract_hash,
+            "remittance_id" => remittance_id,
+            ARG_AMOUNT => U512::from(amount),
+            "idempotency_key" => idempotency_key,
+        };
+        self.session(contributor, CONTRIBUTE_SESSION_WASM, args)
+    }
+
+    /// Contributes to a confidential remittance through the contribute
+    /// session wasm, recording `commitment` with its `blinding`
+    pub fn contribute_committed(
+        &mut self,
+        contributor: AccountHash,
+        remittance_id: u64,
+        amount: u64,
+        commitment: [u8; 32],
+        blinding: [u8; 32],
+    ) -> Result<(), Error> {
+        let args = runtime_args! {
+            "contract_hash" => self.contract_hash,
+            "remittance_id" => remittance_id,
+            ARG_AMOUNT => U512::from(amount),
+            "amount_commitment" => commitment,
+            "blinding" => blinding,
+        };
+        self.session(contributor, CONTRIBUTE_SESSION_WASM, args)
+    }
+
+    /// Pre-funds the platform fee through the contribute session wasm
+    pub fn top_up_fee_cover(
+        &mut self,
+        sponsor: AccountHash,
+        remittance_id: u64,
+        amount: u64,
+    ) -> Result<(), Error> {
+        let args = runtime_args! {
+            "contract_hash" => self.contract_hash,
+            "remittance_id" => remittance_id,
+            ARG_AMOUNT => U512::from(amount),
+            "fee_cover" => true,
+        };
+        self.session(sponsor, CONTRIBUTE_SESSION_WASM, args)
+    }
+
+    /// Releases a remittance through the release session wasm
+    pub fn release_funds(&mut self, sender: AccountHash, remittance_id: u64) -> Result<(), Error> {
+        let args = runtime_args! {
+            "contract_hash" => self.contract_hash,
+            "remittance_id" => remittance_id,
+        };
+        self.session(sender, RELEASE_SESSION_WASM, args)
+    }
+
+    /// Cancels a remittance
+    pub fn cancel_remittance(
+        &mut self,
+        sender: AccountHash,
+        remittance_id: u64,
+    ) -> Result<(), Error> {
+        self.call(
+            sender,
+            "cancel_remittance",
+            runtime_args! { "remittance_id" => remittance_id },
+        )
+    }
+
+    /// Claims a refund through the refund session wasm
+    pub fn claim_refund(&mut self, sender: AccountHash, remittance_id: u64) -> Result<(), Error> {
+        let args = runtime_args! {
+            "contract_hash" => self.contract_hash,
+            "remittance_id" => remittance_id,
+        };
+        self.session(sender, REFUND_SESSION_WASM, args)
+    }
+
+    /// Claims a refund of a confidential contribution through the refund
+    /// session wasm, opening its commitment with `amount` and `blinding`
+    pub fn claim_refund_opening(
+        &mut self,
+        sender: AccountHash,
+        remittance_id: u64,
+        amount: u64,
+        blinding: [u8; 32],
+    ) -> Result<(), Error> {
+        let args = runtime_args! {
+            "contract_hash" => self.contract_hash,
+            "remittance_id" => remittance_id,
+            "amount" => U512::from(amount),
+            "blinding" => blinding,
+        };
+        self.session(sender, REFUND_SESSION_WASM, args)
+    }
+
+    /// Expires a remittance past its deadline
+    pub fn expire_remittance(
+        &mut self,
+        sender: AccountHash,
+        remittance_id: u64,
+    ) -> Result<(), Error> {
+        self.call(
+            sender,
+            "expire_remittance",
+            runtime_args! { "remittance_id" => remittance_id },
+        )
+    }
+
+    /// Claims a share of a released remittance's excess over target
+    pub fn claim_excess_refund(
+        &mut self,
+        sender: AccountHash,
+        remittance_id: u64,
+    ) -> Result<(), Error> {
+        self.call(
+            sender,
+            "claim_excess_refund",
+            runtime_args! { "remittance_id" => remittance_id },
+        )
+    }
+
+    /// Sets the platform fee in basis points
+    pub fn set_platform_fee(&mut self, sender: AccountHash, fee_bps: u64) -> Result<(), Error> {
+        self.call(
+            sender,
+            "set_platform_fee",
+            runtime_args! { "fee_bps" => fee_bps },
+        )
+    }
+
+    /// Lowers the platform fee cap, in parts per million
+    pub fn lower_max_fee(&mut self, sender: AccountHash, max_fee_ppm: u64) -> Result<(), Error> {
+        self.call(
+            sender,
+            "lower_max_fee",
+            runtime_args! { "max_fee_ppm" => max_fee_ppm },
+        )
+    }
+
+    /// Pauses the contract
+    pub fn pause(&mut self, sender: AccountHash) -> Result<(), Error> {
+        self.call(sender, "pause_contract", RuntimeArgs::new())
+    }
+
+    /// Unpauses the contract
+    pub fn unpause(&mut self, sender: AccountHash) -> Result<(), Error> {
+        self.call(sender, "unpause_contract", RuntimeArgs::new())
+    }
+
+    // ============================================================================
+    // State
+    // ============================================================================
+
+    /// Gas used by the session of the last deploy
+    pub fn last_gas_cost(&self) -> U512 {
+        self.builder.last_exec_gas_cost().value()
+    }
+
+    /// Number of remittances created so far
+    pub fn remittance_count(&self) -> u64 {
+        let counter = self.named_uref("remittance_counter");
+        match self.builder.query(None, Key::URef(counter), &[]) {
+            Ok(StoredValue::CLValue(value)) => value.into_t().expect("counter should be a u64"),
+            other => panic!("unexpected remittance counter: {:?}", other),
+        }
+    }
+
+    /// Platform fee cap of the installation, in parts per million
+    pub fn max_fee_ppm(&self) -> u64 {
+        let cap = self.named_uref("max_fee_ppm");
+        match self.builder.query(None, Key::URef(cap), &[]) {
+            Ok(StoredValue::CLValue(value)) => value.into_t().expect("cap should be a u64"),
+            other => panic!("unexpected fee cap: {:?}", other),
+        }
+    }
+
+    /// Entry point and event schema recorded at install
+    pub fn schema(&self) -> ContractSchema {
+        self.named_value("contract_schema")
+    }
+
+    /// Event layout version recorded at install
+    pub fn event_schema_version(&self) -> u32 {
+        self.named_value("event_schema_version")
+    }
+
+    /// Number of events the contract has emitted
+    pub fn events_count(&self) -> u32 {
+        self.named_value("__events_length")
+    }
+
+    /// Crate version and git commit recorded at install
+    pub fn build_info(&self) -> BuildInfo {
+        self.named_value("build_info")
+    }
+
+    /// Branding set by the owner
+    pub fn branding(&self) -> Branding {
+        self.named_value("branding")
+    }
+
+    /// Gets a remittance by ID
+    pub fn remittance(&self, remittance_id: u64) -> Remittance {
+        self.dictionary_item("remittance_records", &remittance_id.to_string())
+            .unwrap_or_else(|| panic!("remittance {} should exist", remittance_id))
+    }
+
+    /// Gets the amount `contributor` has put into a remittance
+    pub fn contribution(&self, remittance_id: u64, contributor: AccountHash) -> types_v4::U512 {
+        let key = contribution_dictionary_key(remittance_id, contributor);
+        self.dictionary_item("contributions", &key)
+            .unwrap_or_default()
+    }
+
+    /// Gets the time weight of `contributor`'s funds in a remittance
+    pub fn contribution_weight(
+        &self,
+        remittance_id: u64,
+        contributor: AccountHash,
+    ) -> ContributionWeight {
+        let key = contribution_dictionary_key(remittance_id, contributor);
+        self.dictionary_item("contribution_weights", &key)
+            .unwrap_or_default()
+    }
+
+    /// Gets the combined time weight of all funds in a remittance
+    pub fn remittance_weight(&self, remittance_id: u64) -> ContributionWeight {
+        self.dictionary_item("remittance_weights", &remittance_id.to_string())
+            .unwrap_or_default()
+    }
+
+    /// IDs of remittances `creator` has created
+    pub fn user_remittances(&self, creator: AccountHash) -> Vec<u64> {
+        let creator = types_v4::account::AccountHash::new(creator.value());
+        self.dictionary_item("user_remittances", &creator.to_string())
+            .unwrap_or_default()
+    }
+
+    /// IDs of remittances paying out to `recipient`
+    pub fn recipient_remittances(&self, recipient: AccountHash) -> Vec<u64> {
+        let recipient = types_v4::account::AccountHash::new(recipient.value());
+        self.dictionary_item("recipient_remittances", &recipient.to_string())
+            .unwrap_or_default()
+    }
+
+    /// IDs of remittances `contributor` has put funds into
+    pub fn contributed_remittances(&self, contributor: AccountHash) -> Vec<u64> {
+        let contributor = types_v4::account::AccountHash::new(contributor.value());
+        self.dictionary_item("contributed_remittances", &contributor.to_string())
+            .unwrap_or_default()
+    }
+
+    /// IDs of closed remittances `contributor` has not claimed a refund from
+    pub fn claimable_refunds(&self, contributor: AccountHash) -> Vec<u64> {
+        let contributor = types_v4::account::AccountHash::new(contributor.value());
+        self.dictionary_item("claimable_refunds", &contributor.to_string())
+            .unwrap_or_default()
+    }
+
+    /// Whether `account` is in the KYB-verified business registry
+    pub fn is_verified_business(&self, account: AccountHash) -> bool {
+        let account = types_v4::account::AccountHash::new(account.value());
+        self.dictionary_item("verified_businesses", &account.to_string())
+            .unwrap_or(false)
+    }
+
+    /// Block time a remittance stopped accruing time weight, once closed
+    pub fn escrow_closed_at(&self, remittance_id: u64) -> Option<u64> {
+        self.dictionary_item("escrow_closed_at", &remittance_id.to_string())
+    }
+
+    /// Number of unique contributors to a remittance
+    pub fn contributor_count(&self, remittance_id: u64) -> u64 {
+        self.dictionary_item("contributor_counts", &remittance_id.to_string())
+            .unwrap_or_default()
+    }
+
+    /// IDs of public remittances created on a day
+    pub fn day_remittances(&self, day: u64) -> Vec<u64> {
+        self.dictionary_item("creation_index", &day.to_string())
+            .unwrap_or_default()
+    }
+
+    /// ID of the remittance a handle names
+    pub fn resolve_handle(&self, handle: &str) -> Option<u64> {
+        self.dictionary_item("remittance_handles", handle)
+    }
+
+    /// Contribution analytics of a remittance, as last stored
+    pub fn analytics(&self, remittance_id: u64) -> RemittanceAnalytics {
+        self.dictionary_item("remittance_analytics", &remittance_id.to_string())
+            .unwrap_or_default()
+    }
+
+    /// Updates posted on a remittance, oldest first
+    pub fn updates(&self, remittance_id: u64) -> Vec<RemittanceUpdate> {
+        self.dictionary_item("remittance_updates", &remittance_id.to_string())
+            .unwrap_or_default()
+    }
+
+    /// The recipient's acknowledgement of a released remittance, if any
+    pub fn acknowledgement(&self, remittance_id: u64) -> Option<ReceiptAcknowledgement> {
+        self.dictionary_item("receipt_acknowledgements", &remittance_id.to_string())
+    }
+
+    /// The scheduled start of a remittance, if it has one
+    pub fn scheduled_start(&self, remittance_id: u64) -> Option<ScheduledStart> {
+        self.dictionary_item("scheduled_starts", &remittance_id.to_string())
+    }
+
+    /// Why the recipient declined a remittance, if they did
+    pub fn decline_reason(&self, remittance_id: u64) -> Option<String> {
+        self.dictionary_item("decline_reasons", &remittance_id.to_string())
+    }
+
+    /// The release SLA of a remittance, once its target has been met
+    pub fn release_sla(&self, remittance_id: u64) -> Option<ReleaseSla> {
+        self.dictionary_item("release_slas", &remittance_id.to_string())
+    }
+
+    /// Translations of a remittance's purpose, in the order first set
+    pub fn localized_purposes(&self, remittance_id: u64) -> Vec<LocalizedPurpose> {
+        self.dictionary_item("localized_purposes", &remittance_id.to_string())
+            .unwrap_or_default()
+    }
+
+    /// The installment schedule of a remittance, if it pays out in installments
+    pub fn payout_schedule(&self, remittance_id: u64) -> Option<PayoutSchedule> {
+        self.dictionary_item("payout_schedules", &remittance_id.to_string())
+    }
+
+    /// A savings pool by ID
+    pub fn savings_pool(&self, pool_id: u64) -> Option<SavingsPool> {
+        self.dictionary_item("savings_pools", &pool_id.to_string())
+    }
+
+    /// The itemized split of a remittance's release, once released
+    pub fn release_breakdown(&self, remittance_id: u64) -> Option<ReleaseBreakdown> {
+        self.dictionary_item("release_breakdowns", &remittance_id.to_string())
+    }
+
+    /// A frozen contributor snapshot, if one was taken under this ID
+    pub fn contribution_snapshot(&self, snapshot_id: u64) -> Option<ContributionSnapshot> {
+        self.dictionary_item("contribution_snapshots", &snapshot_id.to_string())
+    }
+
+    /// Balance of the insurance pool
+    pub fn insurance_pool(&self) -> types_v4::U512 {
+        self.named_value("insurance_pool")
+    }
+
+    /// Gets a remittance's ledger entries, oldest first
+    pub fn ledger(&self, remittance_id: u64) -> Vec<LedgerEntry> {
+        let length: u64 = self
+            .dictionary_item("remittance_ledger_lengths", &remittance_id.to_string())
+            .unwrap_or_default();
+        (0..length)
+            .map(|index| {
+                self.dictionary_item("remittance_ledger", &format!("{}_{}", remittance_id, index))
+                    .expect("ledger entry should exist")
+            })
+            .collect()
+    }
+
+    /// Balance of the contract's escrow purse
+    pub fn contract_balance(&self) -> U512 {
+        self.builder
+            .get_purse_balance(self.named_uref("contract_purse"))
+    }
+
+    /// Main purse balance of `account`
+    pub fn balance(&self, account: AccountHash) -> U512 {
+        let main_purse = self.builder.get_expected_account(account).main_purse();
+        self.builder.get_purse_balance(main_purse)
+    }
+
+    /// Main purse balance of `account` with its deploy payments added back
+    pub fn net_balance(&self, account: AccountHash) -> U512 {
+        self.balance(account) + self.payments.get(&account).copied().unwrap_or_default()
+    }
+
+    /// Asserts that `account` has gained `amount` since `before`, ignoring gas
+    pub fn assert_received(&self, account: AccountHash, before: U512, amount: u64) {
+        assert_eq!(
+            self.net_balance(account),
+            before + U512::from(amount),
+            "unexpected balance change for {}",
+            account
+        );
+    }
+
+    /// Asserts that `account` has spent `amount` since `before`, ignoring gas
+    pub fn assert_spent(&self, account: AccountHash, before: U512, amount: u64) {
+        assert_eq!(
+            self.net_balance(account),
+            before - U512::from(amount),
+            "unexpected balance change for {}",
+            account
+        );
+    }
+
+    fn named_uref(&self, name: &str) -> URef {
+        self.builder
+            .get_contract(self.contract_hash)
+            .expect("contract should exist")
+            .named_keys()
+            .get(name)
+            .and_then(Key::as_uref)
+            .copied()
+            .unwrap_or_else(|| panic!("contract should hold named key {}", name))
+    }
+
+    fn named_value<T: bytesrepr::FromBytes>(&self, name: &str) -> T {
+        match self
+            .builder
+            .query(None, Key::URef(self.named_uref(name)), &[])
+        {
+            Ok(StoredValue::CLValue(value)) => {
+                bytesrepr::deserialize_from_slice(value.inner_bytes())
+                    .unwrap_or_else(|_| panic!("named key {} should decode", name))
+            }
+            other => panic!("unexpected value under {}: {:?}", name, other),
+        }
+    }
+
+    fn dictionary_item<T: bytesrepr::FromBytes>(&self, dictionary: &str, key: &str) -> Option<T> {
+        let seed = self.named_uref(dictionary);
+        let value = match self.builder.query_dictionary_item(None, seed, key) {
+            Ok(StoredValue::CLValue(value)) => value,
+            Ok(other) => panic!("unexpected dictionary value: {:?}", other),
+            Err(_) => return None,
+        };
+
+        Some(
+            bytesrepr::deserialize_from_slice(value.inner_bytes())
+                .expect("dictionary value should decode"),
+        )
+    }
+}
+
+impl Default for TestContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Commitment to a confidential contribution, `blake2b(amount || blinding)`
+/// as the contract computes it
+pub fn amount_commitment(amount: u64, blinding: &[u8; 32]) -> [u8; 32] {
+    let mut preimage = types_v4::U512::from(amount)
+        .to_bytes()
+        .expect("amount should serialize");
+    preimage.extend_from_slice(blinding);
+    types_v4::crypto::blake2b(preimage)
+}
+
+/// Key of a private remittance, `blake2b(creator || id || created_at ||
+/// salt)` as the contract derives it
+pub fn remittance_key(creator: AccountHash, id: u64, created_at: u64, salt: &[u8]) -> [u8; 32] {
+    let mut preimage = creator.value().to_vec();
+    preimage.extend_from_slice(&id.to_le_bytes());
+    preimage.extend_from_slice(&created_at.to_le_bytes());
+    preimage.extend_from_slice(salt);
+    types_v4::crypto::blake2b(preimage)
+}
+
+/// Public key of a test account derived from `seed`
+fn test_account(seed: [u8; SecretKey::ED25519_LENGTH]) -> PublicKey {
+    let secret_key = SecretKey::ed25519_from_bytes(seed).expect("seed should make a valid key");
+    PublicKey::from(&secret_key)
+}
+
+/// Public key of the `index`th extra account
+fn extra_account(index: u64) -> PublicKey {
+    let mut seed = [0xcf; SecretKey::ED25519_LENGTH];
+    seed[..8].copy_from_slice(&index.to_le_bytes());
+    test_account(seed)
+}
+
+/// Dictionary key of a contributor's per-remittance records, as the
+/// contract hex-encodes it
+fn contribution_dictionary_key(remittance_id: u64, contributor: AccountHash) -> String {
+    let contributor = types_v4::account::AccountHash::new(contributor.value());
+    contributor_key(remittance_id, &contributor)
+        .iter()
+        .fold(String::new(), |mut key, byte| {
+            let _ = write!(key, "{:02x}", byte);
+            key
+        })
+}
@@ -0,0 +1,2472 @@
+//! Integration tests for CasperFlow remittance contract.
+//!
+//! These tests verify the complete workflows of the contract
+//! including multiple user interactions, running the release wasm in the
+//! execution engine.
+
+use casper_types::{runtime_args, RuntimeArgs};
+use casperflow_types::{
+    casper_types::{account::AccountHash, U512},
+    errors::{BRIDGE_PAYOUT_WINDOW_MS, MILLIS_PER_DAY},
+    events::EVENT_SCHEMA_VERSION,
+    ledger::{ledger_balance, LedgerEntryKind},
+    purpose_hash, ContractEvent, Error,
+};
+
+use crate::fixture::{amount_commitment, remittance_key, TestContext, CONTRACT_WASM};
+
+/// 10 CSPR in motes
+const TARGET: u64 = 10_000_000_000;
+
+/// Default platform fee (50 bps) on `TARGET`
+const TARGET_FEE: u64 = TARGET / 200;
+
+#[test]
+fn test_complete_remittance_workflow() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave, ctx.owner);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Family support");
+    assert_eq!(id, 1);
+
+    let carol_before = ctx.net_balance(carol);
+    let dave_before = ctx.net_balance(dave);
+    ctx.contribute(carol, id, 6_000_000_000).unwrap();
+    ctx.contribute(dave, id, 4_000_000_000).unwrap();
+    ctx.assert_spent(carol, carol_before, 6_000_000_000);
+    ctx.assert_spent(dave, dave_before, 4_000_000_000);
+
+    let remittance = ctx.remittance(id);
+    assert_eq!(remittance.current_amount, U512::from(TARGET));
+    assert_eq!(ctx.contribution(id, carol), U512::from(6_000_000_000u64));
+    assert_eq!(ctx.contract_balance(), TARGET.into());
+
+    let bob_before = ctx.net_balance(bob);
+    let owner_before = ctx.net_balance(owner);
+    ctx.release_funds(bob, id).unwrap();
+
+    ctx.assert_received(bob, bob_before, TARGET - TARGET_FEE);
+    ctx.assert_received(owner, owner_before, TARGET_FEE);
+    assert!(ctx.remittance(id).is_released());
+    assert_eq!(ctx.contract_balance(), 0.into());
+
+    assert_eq!(ctx.release_funds(bob, id), Err(Error::AlreadyReleased));
+    assert_eq!(
+        ctx.contribute(carol, id, 1_000_000_000),
+        Err(Error::AlreadyReleased)
+    );
+}
+
+#[test]
+fn test_release_requires_target() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Tuition");
+    ctx.contribute(carol, id, TARGET - 1).unwrap();
+
+    assert_eq!(ctx.release_funds(bob, id), Err(Error::TargetNotMet));
+    assert_eq!(ctx.contract_balance(), (TARGET - 1).into());
+}
+
+#[test]
+fn test_cancel_and_refund_workflow() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Medical bills");
+    ctx.contribute(carol, id, 3_000_000_000).unwrap();
+    ctx.contribute(dave, id, 2_000_000_000).unwrap();
+
+    assert_eq!(ctx.claim_refund(carol, id), Err(Error::NotCancelled));
+    ctx.cancel_remittance(alice, id).unwrap();
+    assert!(ctx.remittance(id).is_cancelled());
+
+    let carol_before = ctx.net_balance(carol);
+    ctx.claim_refund(carol, id).unwrap();
+    ctx.assert_received(carol, carol_before, 3_000_000_000);
+    assert_eq!(
+        ctx.claim_refund(carol, id),
+        Err(Error::RefundAlreadyClaimed)
+    );
+
+    let dave_before = ctx.net_balance(dave);
+    ctx.claim_refund(dave, id).unwrap();
+    ctx.assert_received(dave, dave_before, 2_000_000_000);
+
+    assert_eq!(ctx.claim_refund(bob, id), Err(Error::NoContribution));
+    assert_eq!(ctx.release_funds(bob, id), Err(Error::RemittanceCancelled));
+    assert_eq!(ctx.contract_balance(), 0.into());
+}
+
+#[test]
+fn test_recipient_rejection_enables_refunds() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Wrong account");
+    ctx.contribute(carol, id, TARGET).unwrap();
+
+    let args = runtime_args! { "remittance_id" => id };
+    assert_eq!(
+        ctx.call(alice, "reject_remittance", args.clone()),
+        Err(Error::Unauthorized)
+    );
+    ctx.call(bob, "reject_remittance", args.clone()).unwrap();
+    assert!(ctx.remittance(id).is_cancelled());
+    assert_eq!(
+        ctx.call(bob, "reject_remittance", args),
+        Err(Error::RemittanceCancelled)
+    );
+    assert_eq!(ctx.release_funds(bob, id), Err(Error::RemittanceCancelled));
+
+    let carol_before = ctx.net_balance(carol);
+    ctx.claim_refund(carol, id).unwrap();
+    ctx.assert_received(carol, carol_before, TARGET);
+    assert_eq!(ctx.contract_balance(), 0.into());
+}
+
+#[test]
+fn test_bridge_payout_reimburses_operator() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave, ctx.owner);
+    ctx.call(owner, "set_bridge_operator", runtime_args! { "operator" => dave }).unwrap();
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Off-ramp abroad");
+    let request = runtime_args! {
+        "remittance_id" => id,
+        "chain_id" => "eip155:1".to_string(),
+        "foreign_address" => "0x52908400098527886E0F7030069857D2E4169EE7".to_string(),
+    };
+    assert_eq!(
+        ctx.call(bob, "request_bridge_payout", request.clone()),
+        Err(Error::TargetNotMet)
+    );
+    ctx.contribute(carol, id, TARGET).unwrap();
+    assert_eq!(
+        ctx.call(alice, "request_bridge_payout", request.clone()),
+        Err(Error::Unauthorized)
+    );
+    ctx.call(bob, "request_bridge_payout", request.clone()).unwrap();
+
+    // The remittance is locked until the operator confirms
+    assert_eq!(
+        ctx.call(bob, "request_bridge_payout", request.clone()),
+        Err(Error::BridgePayoutPending)
+    );
+    assert_eq!(ctx.release_funds(bob, id), Err(Error::BridgePayoutPending));
+    assert_eq!(ctx.cancel_remittance(alice, id), Err(Error::BridgePayoutPending));
+    assert_eq!(ctx.contribute(carol, id, TARGET), Err(Error::BridgePayoutPending));
+
+    // ...or the recipient withdraws the payout
+    let cancel = runtime_args! { "remittance_id" => id };
+    assert_eq!(ctx.call(carol, "cancel_bridge_payout", cancel.clone()), Err(Error::Unauthorized));
+    ctx.call(bob, "cancel_bridge_payout", cancel.clone()).unwrap();
+    assert_eq!(ctx.call(bob, "cancel_bridge_payout", cancel.clone()), Err(Error::NoBridgePayout));
+
+    // Past its deadline, anyone can unlock an unconfirmed payout
+    ctx.call(bob, "request_bridge_payout", request.clone()).unwrap();
+    ctx.advance_time(BRIDGE_PAYOUT_WINDOW_MS + 1);
+    ctx.call(carol, "cancel_bridge_payout", cancel.clone()).unwrap();
+    ctx.call(bob, "request_bridge_payout", request).unwrap();
+
+    let confirm = runtime_args! {
+        "remittance_id" => id,
+        "foreign_tx_hash" => "0xabc123".to_string(),
+    };
+    assert_eq!(
+        ctx.call(bob, "confirm_bridge_payout", confirm.clone()),
+        Err(Error::Unauthorized)
+    );
+    let bob_before = ctx.net_balance(bob);
+    let dave_before = ctx.net_balance(dave);
+    ctx.call(dave, "confirm_bridge_payout", confirm.clone()).unwrap();
+    ctx.assert_received(dave, dave_before, TARGET - TARGET_FEE);
+    ctx.assert_received(bob, bob_before, 0);
+    assert!(ctx.remittance(id).is_released());
+    assert_eq!(ctx.contract_balance(), 0.into());
+    assert_eq!(
+        ctx.call(dave, "confirm_bridge_payout", confirm),
+        Err(Error::AlreadyReleased)
+    );
+    assert_eq!(ctx.call(bob, "cancel_bridge_payout", cancel), Err(Error::AlreadyReleased));
+}
+
+#[test]
+fn test_release_routed_to_settlement_partner() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave, ctx.owner);
+
+    let register = runtime_args! {
+        "partner" => dave,
+        "name" => "Lagos Cash Express".to_string(),
+        "corridors" => vec!["US-NG".to_string()],
+    };
+    assert_eq!(
+        ctx.call(alice, "register_settlement_partner", register.clone()),
+        Err(Error::Unauthorized)
+    );
+    ctx.call(owner, "register_settlement_partner", register).unwrap();
+
+    let create = |corridor: &str| {
+        runtime_args! {
+            "recipient" => bob,
+            "target_amount" => U512::from(TARGET),
+            "purpose" => "Cash pickup".to_string(),
+            "corridor" => corridor.to_string(),
+        }
+    };
+    ctx.call(alice, "create_remittance", create("US-GH")).unwrap();
+    let other_corridor = ctx.remittance_count();
+    ctx.call(alice, "create_remittance", create("US-NG")).unwrap();
+    let id = ctx.remittance_count();
+    ctx.contribute(carol, other_corridor, TARGET).unwrap();
+    ctx.contribute(carol, id, TARGET).unwrap();
+
+    let release = |remittance_id: u64, partner| {
+        runtime_args! {
+            "remittance_id" => remittance_id,
+            "partner" => partner,
+            "reference_code" => "PICKUP-7731".to_string(),
+        }
+    };
+    assert_eq!(
+        ctx.call(bob, "release_funds", release(id, carol)),
+        Err(Error::PartnerNotRegistered)
+    );
+    assert_eq!(
+        ctx.call(bob, "release_funds", release(other_corridor, dave)),
+        Err(Error::PartnerCorridorUnsupported)
+    );
+
+    let bob_before = ctx.net_balance(bob);
+    let dave_before = ctx.net_balance(dave);
+    ctx.call(bob, "release_funds", release(id, dave)).unwrap();
+    ctx.assert_received(dave, dave_before, TARGET - TARGET_FEE);
+    ctx.assert_received(bob, bob_before, 0);
+    assert!(ctx.remittance(id).is_released());
+}
+
+#[test]
+fn test_excess_refund_after_release() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave, ctx.owner);
+
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "School fees".to_string(),
+        "refund_excess" => true,
+    };
+    ctx.call(alice, "create_remittance", args).unwrap();
+    let id = ctx.remittance_count();
+
+    ctx.contribute(carol, id, 9_000_000_000).unwrap();
+    ctx.contribute(dave, id, 6_000_000_000).unwrap();
+    assert_eq!(ctx.claim_excess_refund(carol, id), Err(Error::NoExcessRefund));
+
+    // Only the target and its fee leave escrow
+    let bob_before = ctx.net_balance(bob);
+    let owner_before = ctx.net_balance(owner);
+    ctx.release_funds(bob, id).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET - TARGET_FEE);
+    ctx.assert_received(owner, owner_before, TARGET_FEE);
+    assert_eq!(ctx.remittance(id).released_amount, U512::from(TARGET));
+    assert_eq!(ctx.contract_balance(), 5_000_000_000u64.into());
+
+    // The 5 CSPR excess is shared 9:6
+    let carol_before = ctx.net_balance(carol);
+    ctx.claim_excess_refund(carol, id).unwrap();
+    ctx.assert_received(carol, carol_before, 3_000_000_000);
+    assert_eq!(
+        ctx.claim_excess_refund(carol, id),
+        Err(Error::RefundAlreadyClaimed)
+    );
+
+    let dave_before = ctx.net_balance(dave);
+    ctx.claim_excess_refund(dave, id).unwrap();
+    ctx.assert_received(dave, dave_before, 2_000_000_000);
+
+    assert_eq!(ctx.claim_excess_refund(bob, id), Err(Error::NoContribution));
+    assert_eq!(ctx.contract_balance(), 0.into());
+}
+
+#[test]
+fn test_excess_released_without_refund_mode() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Rent");
+    ctx.contribute(carol, id, TARGET * 2).unwrap();
+
+    let bob_before = ctx.net_balance(bob);
+    ctx.release_funds(bob, id).unwrap();
+    ctx.assert_received(bob, bob_before, 2 * (TARGET - TARGET_FEE));
+
+    assert_eq!(ctx.claim_excess_refund(carol, id), Err(Error::NoExcessRefund));
+    assert_eq!(ctx.contract_balance(), 0.into());
+}
+
+#[test]
+fn test_expiry_enables_refunds() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let deadline = ctx.block_time + 86_400_000;
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Harvest loan".to_string(),
+        "deadline" => deadline,
+    };
+    ctx.call(alice, "create_remittance", args).unwrap();
+    let id = ctx.remittance_count();
+    ctx.contribute(carol, id, 4_000_000_000).unwrap();
+
+    assert_eq!(ctx.expire_remittance(dave, id), Err(Error::NotExpirable));
+
+    ctx.advance_time(86_400_000);
+    assert_eq!(
+        ctx.contribute(dave, id, 1_000_000_000),
+        Err(Error::RemittanceExpired)
+    );
+
+    // Anyone may expire it once the deadline passes
+    ctx.expire_remittance(dave, id).unwrap();
+    assert!(ctx.remittance(id).is_expired());
+    assert_eq!(ctx.expire_remittance(dave, id), Err(Error::NotExpirable));
+    assert_eq!(ctx.cancel_remittance(alice, id), Err(Error::RemittanceExpired));
+    assert_eq!(ctx.release_funds(bob, id), Err(Error::RemittanceExpired));
+
+    let carol_before = ctx.net_balance(carol);
+    ctx.claim_refund(carol, id).unwrap();
+    ctx.assert_received(carol, carol_before, 4_000_000_000);
+    assert_eq!(ctx.contract_balance(), 0.into());
+}
+
+#[test]
+fn test_extend_deadline_up_to_maximum() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.owner);
+
+    let deadline = ctx.block_time + 86_400_000;
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Clinic roof".to_string(),
+        "deadline" => deadline,
+    };
+    ctx.call(alice, "create_remittance", args).unwrap();
+    let id = ctx.remittance_count();
+
+    let extend = |new_deadline: u64| {
+        runtime_args! { "remittance_id" => id, "new_deadline" => new_deadline }
+    };
+
+    // Extensions are disabled until the owner allows them
+    assert_eq!(
+        ctx.call(alice, "extend_deadline", extend(deadline + 1)),
+        Err(Error::DeadlineExtensionTooLong)
+    );
+    ctx.call(
+        owner,
+        "set_max_deadline_extension",
+        runtime_args! { "max_extension_ms" => 2 * 86_400_000u64 },
+    )
+    .unwrap();
+
+    assert_eq!(
+        ctx.call(bob, "extend_deadline", extend(deadline + 86_400_000)),
+        Err(Error::Unauthorized)
+    );
+    assert_eq!(
+        ctx.call(alice, "extend_deadline", extend(deadline)),
+        Err(Error::InvalidDeadline)
+    );
+    ctx.call(alice, "extend_deadline", extend(deadline + 86_400_000)).unwrap();
+    assert_eq!(ctx.remittance(id).deadline, Some(deadline + 86_400_000));
+
+    // The cap applies to all extensions together
+    assert_eq!(
+        ctx.call(alice, "extend_deadline", extend(deadline + 3 * 86_400_000)),
+        Err(Error::DeadlineExtensionTooLong)
+    );
+    ctx.call(alice, "extend_deadline", extend(deadline + 2 * 86_400_000)).unwrap();
+
+    // The original deadline no longer stops contributions
+    ctx.advance_time(86_400_000);
+    ctx.contribute(carol, id, 1_000_000_000).unwrap();
+    assert_eq!(ctx.expire_remittance(carol, id), Err(Error::NotExpirable));
+}
+
+#[test]
+fn test_expire_batch_skips_live_remittances() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let deadline = ctx.block_time + 1_000;
+    for purpose in ["Stalled", "Funded"] {
+        let args = runtime_args! {
+            "recipient" => bob,
+            "target_amount" => U512::from(TARGET),
+            "purpose" => purpose.to_string(),
+            "deadline" => deadline,
+        };
+        ctx.call(alice, "create_remittance", args).unwrap();
+    }
+    let open = ctx.create_remittance(alice, bob, TARGET, "No deadline");
+    ctx.contribute(carol, 2, TARGET).unwrap();
+
+    ctx.advance_time(1_000);
+    let args = runtime_args! { "start_id" => 1u64, "count" => 10u64 };
+    ctx.call(carol, "expire_batch", args).unwrap();
+
+    assert!(ctx.remittance(1).is_expired());
+    assert!(ctx.remittance(2).is_active());
+    assert!(ctx.remittance(open).is_active());
+}
+
+#[test]
+fn test_rate_limits_block_bursts() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.owner);
+
+    let limits = runtime_args! {
+        "window_ms" => 3_600_000u64,
+        "max_creations" => 2u64,
+        "max_contributions" => 0u64,
+    };
+    assert_eq!(
+        ctx.call(alice, "set_rate_limits", limits.clone()),
+        Err(Error::Unauthorized)
+    );
+    ctx.call(owner, "set_rate_limits", limits).unwrap();
+
+    ctx.create_remittance(alice, bob, TARGET, "First");
+    ctx.create_remittance(alice, bob, TARGET, "Second");
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Third".to_string(),
+    };
+    assert_eq!(
+        ctx.call(alice, "create_remittance", args.clone()),
+        Err(Error::RateLimited)
+    );
+
+    // Limits are per account, and contributions are not limited
+    ctx.create_remittance(bob, alice, TARGET, "Other account");
+    ctx.contribute(carol, 1, TARGET).unwrap();
+
+    // The earlier creations age out of the rolling window
+    ctx.advance_time(2 * 3_600_000);
+    ctx.call(alice, "create_remittance", args).unwrap();
+}
+
+#[test]
+fn test_large_releases_wait_for_review() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave, ctx.owner);
+
+    let policy = runtime_args! {
+        "threshold" => U512::from(TARGET),
+        "window_ms" => 86_400_000u64,
+    };
+    assert_eq!(
+        ctx.call(alice, "set_review_policy", policy.clone()),
+        Err(Error::Unauthorized)
+    );
+    ctx.call(owner, "set_review_policy", policy).unwrap();
+
+    // Releasing holds the funds instead of paying out
+    let approved = ctx.create_remittance(alice, bob, TARGET, "School fees");
+    ctx.contribute(carol, approved, TARGET).unwrap();
+    let bob_before = ctx.net_balance(bob);
+    ctx.release_funds(bob, approved).unwrap();
+    assert!(ctx.remittance(approved).is_pending_review());
+    assert_eq!(ctx.contract_balance(), TARGET.into());
+    assert_eq!(
+        ctx.contribute(dave, approved, 1_000_000_000),
+        Err(Error::ReleasePendingReview)
+    );
+    assert_eq!(
+        ctx.release_funds(bob, approved),
+        Err(Error::ReleasePendingReview)
+    );
+
+    let args = runtime_args! { "remittance_id" => approved };
+    assert_eq!(
+        ctx.call(alice, "approve_release", args.clone()),
+        Err(Error::Unauthorized)
+    );
+    assert_eq!(
+        ctx.call(dave, "reject_release", args.clone()),
+        Err(Error::Unauthorized)
+    );
+    ctx.call(owner, "approve_release", args.clone()).unwrap();
+    assert!(ctx.remittance(approved).is_released());
+    ctx.assert_received(bob, bob_before, TARGET - TARGET_FEE);
+    assert_eq!(
+        ctx.call(owner, "approve_release", args),
+        Err(Error::NotPendingReview)
+    );
+
+    // An unanswered review lapses; anyone may then reject it
+    let lapsed = ctx.create_remittance(alice, bob, TARGET, "Rent");
+    ctx.contribute(carol, lapsed, TARGET).unwrap();
+    ctx.release_funds(bob, lapsed).unwrap();
+    ctx.advance_time(86_400_001);
+
+    let args = runtime_args! { "remittance_id" => lapsed };
+    assert_eq!(
+        ctx.call(owner, "approve_release", args.clone()),
+        Err(Error::ReviewWindowElapsed)
+    );
+    ctx.call(dave, "reject_release", args).unwrap();
+    assert!(ctx.remittance(lapsed).is_refundable());
+
+    let carol_before = ctx.net_balance(carol);
+    ctx.claim_refund(carol, lapsed).unwrap();
+    ctx.assert_received(carol, carol_before, TARGET);
+}
+
+#[test]
+fn test_unauthorized_access() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Rent");
+    ctx.contribute(carol, id, TARGET).unwrap();
+
+    // Only the recipient releases and only the creator cancels
+    assert_eq!(ctx.release_funds(carol, id), Err(Error::Unauthorized));
+    assert_eq!(ctx.release_funds(alice, id), Err(Error::Unauthorized));
+    assert_eq!(ctx.cancel_remittance(bob, id), Err(Error::Unauthorized));
+
+    // Admin entry points are owner only
+    assert_eq!(ctx.set_platform_fee(alice, 100), Err(Error::Unauthorized));
+    assert_eq!(ctx.pause(carol), Err(Error::Unauthorized));
+
+    assert_eq!(ctx.contract_balance(), TARGET.into());
+    assert!(ctx.remittance(id).is_active());
+}
+
+#[test]
+fn test_pause_blocks_operations() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.owner);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Groceries");
+    ctx.pause(owner).unwrap();
+
+    assert_eq!(
+        ctx.contribute(carol, id, TARGET),
+        Err(Error::ContractPaused)
+    );
+    assert_eq!(ctx.cancel_remittance(alice, id), Err(Error::ContractPaused));
+    assert_eq!(ctx.unpause(alice), Err(Error::Unauthorized));
+
+    ctx.unpause(owner).unwrap();
+    ctx.contribute(carol, id, TARGET).unwrap();
+    ctx.pause(owner).unwrap();
+    assert_eq!(ctx.release_funds(bob, id), Err(Error::ContractPaused));
+
+    ctx.unpause(owner).unwrap();
+    ctx.release_funds(bob, id).unwrap();
+}
+
+#[test]
+fn test_fee_math() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.owner);
+
+    assert_eq!(ctx.set_platform_fee(owner, 501), Err(Error::FeeTooHigh));
+    ctx.set_platform_fee(owner, 250).unwrap();
+
+    // 2.5% of 1_234_567_891 is 30_864_197.275; fees round down by default
+    let target = 1_234_567_891;
+    let fee = 30_864_197;
+    let id = ctx.create_remittance(alice, bob, target, "Invoice 42");
+    ctx.contribute(carol, id, target).unwrap();
+
+    let bob_before = ctx.net_balance(bob);
+    let owner_before = ctx.net_balance(owner);
+    ctx.release_funds(bob, id).unwrap();
+
+    ctx.assert_received(bob, bob_before, target - fee);
+    ctx.assert_received(owner, owner_before, fee);
+    assert_eq!(ctx.contract_balance(), 0.into());
+}
+
+#[test]
+fn test_fee_cap() {
+    // A community instance capped at 1% (10,000 ppm)
+    let mut ctx = TestContext::with_install_args(runtime_args! { "max_fee_ppm" => 10_000u64 });
+    let (alice, owner) = (ctx.alice, ctx.owner);
+    assert_eq!(ctx.max_fee_ppm(), 10_000);
+
+    assert_eq!(ctx.set_platform_fee(owner, 101), Err(Error::FeeTooHigh));
+    ctx.set_platform_fee(owner, 100).unwrap();
+
+    // The fee in force must be lowered before the cap
+    assert_eq!(ctx.lower_max_fee(owner, 5_000), Err(Error::FeeTooHigh));
+    ctx.set_platform_fee(owner, 25).unwrap();
+    assert_eq!(ctx.lower_max_fee(alice, 5_000), Err(Error::Unauthorized));
+    ctx.lower_max_fee(owner, 5_000).unwrap();
+    assert_eq!(ctx.max_fee_ppm(), 5_000);
+
+    assert_eq!(ctx.lower_max_fee(owner, 5_001), Err(Error::MaxFeeRaised));
+    assert_eq!(ctx.set_platform_fee(owner, 51), Err(Error::FeeTooHigh));
+    ctx.set_platform_fee(owner, 50).unwrap();
+}
+
+#[test]
+fn test_default_fee_cap() {
+    let mut ctx = TestContext::new();
+    assert_eq!(ctx.max_fee_ppm(), 50_000);
+
+    let owner = ctx.owner;
+    assert_eq!(ctx.lower_max_fee(owner, 50_001), Err(Error::MaxFeeRaised));
+}
+
+#[test]
+fn test_fee_on_contribution() {
+    let mut ctx = TestContext::with_install_args(runtime_args! { "fee_model" => 1u8 });
+    let (alice, bob, carol, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.owner);
+
+    // The fee is taken as funds arrive; only the net amount counts
+    let id = ctx.create_remittance(alice, bob, TARGET - TARGET_FEE, "Net target");
+    let owner_before = ctx.net_balance(owner);
+    ctx.contribute(carol, id, TARGET).unwrap();
+    ctx.assert_received(owner, owner_before, TARGET_FEE);
+    assert_eq!(ctx.contribution(id, carol), U512::from(TARGET - TARGET_FEE));
+    assert!(ctx.remittance(id).is_target_met());
+
+    // Release pays out the rest without charging again
+    let bob_before = ctx.net_balance(bob);
+    let owner_before = ctx.net_balance(owner);
+    ctx.release_funds(bob, id).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET - TARGET_FEE);
+    ctx.assert_received(owner, owner_before, 0);
+    assert_eq!(ctx.contract_balance(), 0.into());
+
+    // Refunds return the net amount
+    let id = ctx.create_remittance(alice, bob, TARGET, "Cancelled");
+    ctx.contribute(carol, id, TARGET).unwrap();
+    ctx.cancel_remittance(alice, id).unwrap();
+    let carol_before = ctx.net_balance(carol);
+    ctx.claim_refund(carol, id).unwrap();
+    ctx.assert_received(carol, carol_before, TARGET - TARGET_FEE);
+    assert_eq!(ctx.contract_balance(), 0.into());
+}
+
+#[test]
+fn test_ledger_records_escrow_movements() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave, ctx.owner);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Ledgered");
+    ctx.contribute(carol, id, 6_000_000_000).unwrap();
+    ctx.contribute(dave, id, 4_000_000_000).unwrap();
+
+    let ledger = ctx.ledger(id);
+    assert_eq!(ledger.len(), 2);
+    assert_eq!(ledger[0].kind, LedgerEntryKind::Contribution);
+    assert_eq!(ledger[0].account.value(), carol.value());
+    assert_eq!(ledger[1].amount, U512::from(4_000_000_000u64));
+    assert_eq!(ledger_balance(&ledger), Some(ctx.remittance(id).current_amount));
+
+    // Release debits the fee and the payout, leaving nothing in escrow
+    ctx.release_funds(bob, id).unwrap();
+    let ledger = ctx.ledger(id);
+    let kinds: Vec<_> = ledger.iter().map(|entry| entry.kind).collect();
+    assert_eq!(kinds[2..], [LedgerEntryKind::Fee, LedgerEntryKind::Release]);
+    assert_eq!(ledger[2].account.value(), owner.value());
+    assert_eq!(ledger[2].amount, U512::from(TARGET_FEE));
+    assert_eq!(ledger[3].amount, U512::from(TARGET - TARGET_FEE));
+    assert_eq!(ledger_balance(&ledger), Some(U512::zero()));
+
+    // Refunds are debited to the contributor
+    let id = ctx.create_remittance(alice, bob, TARGET, "Refunded");
+    ctx.contribute(carol, id, TARGET).unwrap();
+    ctx.cancel_remittance(alice, id).unwrap();
+    ctx.claim_refund(carol, id).unwrap();
+    let ledger = ctx.ledger(id);
+    assert_eq!(ledger[1].kind, LedgerEntryKind::Refund);
+    assert_eq!(ledger_balance(&ledger), Some(U512::zero()));
+}
+
+#[test]
+fn test_contributor_cap() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave, ctx.owner);
+
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Pool".to_string(),
+        "max_contributors" => 0u64,
+    };
+    assert_eq!(
+        ctx.call(alice, "create_remittance", args),
+        Err(Error::InvalidContributorCap)
+    );
+
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Pool".to_string(),
+        "max_contributors" => 2u64,
+    };
+    ctx.call(alice, "create_remittance", args).unwrap();
+    let id = ctx.remittance_count();
+
+    ctx.contribute(carol, id, 1_000_000_000).unwrap();
+    ctx.contribute(dave, id, 1_000_000_000).unwrap();
+    assert_eq!(ctx.contributor_count(id), 2);
+
+    // A third contributor is turned away, but existing ones may add more
+    assert_eq!(
+        ctx.contribute(owner, id, 1_000_000_000),
+        Err(Error::ContributorCapReached)
+    );
+    ctx.contribute(carol, id, 1_000_000_000).unwrap();
+    assert_eq!(ctx.contributor_count(id), 2);
+    assert_eq!(ctx.remittance(id).current_amount, U512::from(3_000_000_000u64));
+}
+
+#[test]
+fn test_creation_index_buckets_by_day() {
+    let mut ctx = TestContext::new();
+    let (alice, bob) = (ctx.alice, ctx.bob);
+    let day = ctx.block_time / 86_400_000;
+
+    let first = ctx.create_remittance(alice, bob, TARGET, "Monday");
+    let second = ctx.create_remittance(bob, alice, TARGET, "Also Monday");
+
+    // Private remittances stay out of the index
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Hidden".to_string(),
+        "key_salt" => [9u8; 32],
+    };
+    ctx.call(alice, "create_private_remittance", args).unwrap();
+
+    ctx.advance_time(86_400_000);
+    let third = ctx.create_remittance(alice, bob, TARGET, "Tuesday");
+
+    assert_eq!(ctx.day_remittances(day), vec![first, second]);
+    assert_eq!(ctx.day_remittances(day + 1), vec![third]);
+    assert!(ctx.day_remittances(day + 2).is_empty());
+}
+
+#[test]
+fn test_private_remittance_key_is_salted() {
+    let mut ctx = TestContext::new();
+    let (alice, bob) = (ctx.alice, ctx.bob);
+    let salt = [9u8; 32];
+    let events = ctx.events_count();
+
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Hidden".to_string(),
+        "key_salt" => salt,
+    };
+    ctx.call(alice, "create_private_remittance", args).unwrap();
+    let id = ctx.remittance_count();
+    let created_at = ctx.remittance(id).created_at;
+
+    // Nothing ties the remittance to its ID or parties on chain
+    assert_eq!(ctx.events_count(), events);
+    assert!(ctx.user_remittances(alice).is_empty());
+    assert!(ctx.recipient_remittances(bob).is_empty());
+
+    // The creator, ID, and creation time alone do not give the key
+    let cancel = |key: [u8; 32]| runtime_args! { "remittance_key" => key };
+    let guessed = remittance_key(alice, id, created_at, &[]);
+    assert_eq!(
+        ctx.call(alice, "cancel_remittance", cancel(guessed)),
+        Err(Error::RemittanceNotFound)
+    );
+
+    // Nor is the numeric ID accepted in place of the key
+    assert_eq!(ctx.cancel_remittance(alice, id), Err(Error::RemittanceNotFound));
+
+    let key = remittance_key(alice, id, created_at, &salt);
+    ctx.call(alice, "cancel_remittance", cancel(key)).unwrap();
+    assert!(ctx.remittance(id).is_cancelled());
+    assert_eq!(ctx.events_count(), events);
+}
+
+#[test]
+fn test_register_handle() {
+    let mut ctx = TestContext::new();
+    let (alice, bob) = (ctx.alice, ctx.bob);
+    let register = |id: u64, handle: &str| {
+        runtime_args! { "remittance_id" => id, "handle" => handle.to_string() }
+    };
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Surgery");
+    let other = ctx.create_remittance(alice, bob, TARGET, "Rent");
+
+    assert_eq!(
+        ctx.call(bob, "register_handle", register(id, "maria-surgery-2025")),
+        Err(Error::Unauthorized)
+    );
+    assert_eq!(
+        ctx.call(alice, "register_handle", register(id, "Maria Surgery")),
+        Err(Error::InvalidHandle)
+    );
+
+    ctx.call(alice, "register_handle", register(id, "maria-surgery-2025"))
+        .unwrap();
+    assert_eq!(ctx.resolve_handle("maria-surgery-2025"), Some(id));
+
+    // Handles are unique and fixed once set
+    assert_eq!(
+        ctx.call(alice, "register_handle", register(other, "maria-surgery-2025")),
+        Err(Error::HandleTaken)
+    );
+    assert_eq!(
+        ctx.call(alice, "register_handle", register(id, "maria-surgery")),
+        Err(Error::HandleAlreadySet)
+    );
+}
+
+#[test]
+fn test_instant_share_forwarded_on_contribution() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+    let create = |purpose: &str| {
+        runtime_args! {
+            "recipient" => bob,
+            "target_amount" => U512::from(TARGET),
+            "purpose" => purpose.to_string(),
+            "instant_share_bps" => 2_500u64,
+        }
+    };
+
+    // A quarter of each contribution reaches the recipient at once, less fee
+    ctx.call(alice, "create_remittance", create("Urgent")).unwrap();
+    let id = ctx.remittance_count();
+    let bob_before = ctx.net_balance(bob);
+    ctx.contribute(carol, id, TARGET).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET / 4 - TARGET_FEE / 4);
+    assert_eq!(ctx.remittance(id).current_amount, U512::from(TARGET));
+    assert_eq!(ctx.remittance(id).released_amount, U512::from(TARGET / 4));
+
+    // Release pays only what is still escrowed
+    let bob_before = ctx.net_balance(bob);
+    ctx.release_funds(bob, id).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET * 3 / 4 - TARGET_FEE * 3 / 4);
+    assert_eq!(ctx.remittance(id).released_amount, U512::from(TARGET));
+
+    // Cancelling refunds the escrowed part only
+    ctx.call(alice, "create_remittance", create("Urgent too")).unwrap();
+    let id = ctx.remittance_count();
+    ctx.contribute(carol, id, TARGET / 2).unwrap();
+    ctx.cancel_remittance(alice, id).unwrap();
+    let carol_before = ctx.net_balance(carol);
+    ctx.claim_refund(carol, id).unwrap();
+    ctx.assert_received(carol, carol_before, TARGET / 2 * 3 / 4);
+
+    // Escrow must be able to refund excess over the target
+    let mut args = create("Excess");
+    args.insert("refund_excess", true).unwrap();
+    assert_eq!(
+        ctx.call(alice, "create_remittance", args),
+        Err(Error::InvalidInstantShare)
+    );
+}
+
+#[test]
+fn test_backup_recipient_release() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Backed up".to_string(),
+        "backup_recipient" => bob,
+        "backup_grace_days" => 3u64,
+    };
+    assert_eq!(
+        ctx.call(alice, "create_remittance", args),
+        Err(Error::InvalidBackupRecipient)
+    );
+
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Backed up".to_string(),
+        "backup_recipient" => dave,
+        "backup_grace_days" => 3u64,
+    };
+    ctx.call(alice, "create_remittance", args).unwrap();
+    let id = ctx.remittance_count();
+    let release = runtime_args! { "remittance_id" => id };
+
+    // The grace period only starts once the target is met
+    ctx.advance_time(5 * 86_400_000);
+    ctx.contribute(carol, id, TARGET).unwrap();
+    assert_eq!(
+        ctx.call(dave, "release_to_backup", release.clone()),
+        Err(Error::BackupNotEligible)
+    );
+    assert_eq!(
+        ctx.call(carol, "release_to_backup", release.clone()),
+        Err(Error::Unauthorized)
+    );
+
+    ctx.advance_time(3 * 86_400_000);
+    let dave_before = ctx.net_balance(dave);
+    ctx.call(dave, "release_to_backup", release).unwrap();
+    ctx.assert_received(dave, dave_before, TARGET - TARGET_FEE);
+    assert!(ctx.remittance(id).is_released());
+    assert_eq!(ctx.release_funds(bob, id), Err(Error::AlreadyReleased));
+}
+
+#[test]
+fn test_recipient_multisig_release() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let create = |signers: Vec<AccountHash>, threshold: u64| {
+        runtime_args! {
+            "recipient" => bob,
+            "target_amount" => U512::from(TARGET),
+            "purpose" => "Family account".to_string(),
+            "recipient_signers" => signers,
+            "recipient_threshold" => threshold,
+        }
+    };
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(vec![carol, dave], 1)),
+        Err(Error::InvalidRecipientSigners)
+    );
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(vec![bob, carol], 3)),
+        Err(Error::InvalidRecipientSigners)
+    );
+    ctx.call(alice, "create_remittance", create(vec![bob, carol, dave], 2)).unwrap();
+    let id = ctx.remittance_count();
+    let approve = runtime_args! { "remittance_id" => id };
+
+    assert_eq!(
+        ctx.call(carol, "approve_release_as_recipient", approve.clone()),
+        Err(Error::TargetNotMet)
+    );
+    ctx.contribute(carol, id, TARGET).unwrap();
+    assert_eq!(ctx.release_funds(bob, id), Err(Error::RecipientApprovalRequired));
+    assert_eq!(
+        ctx.call(alice, "approve_release_as_recipient", approve.clone()),
+        Err(Error::Unauthorized)
+    );
+
+    ctx.call(carol, "approve_release_as_recipient", approve.clone()).unwrap();
+    assert_eq!(
+        ctx.call(carol, "approve_release_as_recipient", approve.clone()),
+        Err(Error::AlreadyApproved)
+    );
+    assert_eq!(ctx.release_funds(bob, id), Err(Error::RecipientApprovalRequired));
+
+    ctx.call(dave, "approve_release_as_recipient", approve).unwrap();
+    let bob_before = ctx.net_balance(bob);
+    ctx.release_funds(bob, id).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET - TARGET_FEE);
+}
+
+#[test]
+fn test_insurance_top_up_after_cancellation() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave, ctx.owner);
+    let quarter = TARGET / 4;
+    let premium = quarter / 100;
+    let contribution = quarter - premium;
+
+    assert_eq!(
+        ctx.call(alice, "set_insurance_premium", runtime_args! { "premium_bps" => 100u64 }),
+        Err(Error::Unauthorized)
+    );
+    ctx.call(owner, "set_insurance_premium", runtime_args! { "premium_bps" => 100u64 }).unwrap();
+
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Insured".to_string(),
+        "insured" => true,
+    };
+    ctx.call(alice, "create_remittance", args).unwrap();
+    let id = ctx.remittance_count();
+
+    // Each contribution pays its premium into the pool
+    ctx.contribute(carol, id, quarter).unwrap();
+    ctx.contribute(dave, id, quarter).unwrap();
+    assert_eq!(ctx.contribution(id, carol), U512::from(contribution));
+    assert_eq!(ctx.insurance_pool(), U512::from(2 * premium));
+    assert_eq!(ledger_balance(&ctx.ledger(id)), Some(U512::from(2 * contribution)));
+
+    let claim = runtime_args! { "remittance_id" => id };
+    let approve = runtime_args! { "remittance_id" => id, "coverage_bps" => 100u64 };
+    assert_eq!(
+        ctx.call(owner, "approve_insurance_claims", approve.clone()),
+        Err(Error::NotCancelled)
+    );
+
+    ctx.cancel_remittance(alice, id).unwrap();
+    assert_eq!(
+        ctx.call(carol, "claim_insurance", claim.clone()),
+        Err(Error::InsuranceNotApproved)
+    );
+    assert_eq!(
+        ctx.call(alice, "approve_insurance_claims", approve.clone()),
+        Err(Error::Unauthorized)
+    );
+    ctx.call(owner, "approve_insurance_claims", approve.clone()).unwrap();
+    assert_eq!(
+        ctx.call(owner, "approve_insurance_claims", approve),
+        Err(Error::InvalidInsuranceTerms)
+    );
+
+    // The refund returns the escrowed contribution; the top-up comes from the pool
+    let carol_before = ctx.net_balance(carol);
+    ctx.claim_refund(carol, id).unwrap();
+    ctx.call(carol, "claim_insurance", claim.clone()).unwrap();
+    ctx.assert_received(carol, carol_before, contribution + contribution / 100);
+    assert_eq!(ctx.insurance_pool(), U512::from(2 * premium - contribution / 100));
+    assert_eq!(
+        ctx.call(carol, "claim_insurance", claim.clone()),
+        Err(Error::InsuranceAlreadyClaimed)
+    );
+    assert_eq!(ctx.call(alice, "claim_insurance", claim), Err(Error::NoContribution));
+}
+
+#[test]
+fn test_remittance_analytics() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Momentum");
+    ctx.contribute(carol, id, 1_000_000_000).unwrap();
+    ctx.contribute(dave, id, 3_000_000_000).unwrap();
+
+    let analytics = ctx.analytics(id);
+    assert_eq!(analytics.contribution_count, 2);
+    assert_eq!(analytics.recent_contributions(), 2);
+    assert_eq!(analytics.largest_contribution, U512::from(3_000_000_000u64));
+
+    // Contributions older than a day drop out of the recent count only
+    ctx.advance_time(25 * 3_600_000);
+    ctx.contribute(carol, id, 2_000_000_000).unwrap();
+
+    let analytics = ctx.analytics(id);
+    assert_eq!(analytics.contribution_count, 3);
+    assert_eq!(analytics.recent_contributions(), 1);
+    assert_eq!(analytics.largest_contribution, U512::from(3_000_000_000u64));
+    assert_eq!(analytics.average_contribution(), U512::from(2_000_000_000u64));
+}
+
+#[test]
+fn test_post_update() {
+    let mut ctx = TestContext::new();
+    let (alice, bob) = (ctx.alice, ctx.bob);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Wheelchair");
+    let update = |message: &str| {
+        runtime_args! { "remittance_id" => id, "message" => message.to_string() }
+    };
+
+    assert_eq!(
+        ctx.call(bob, "post_update", update("Not mine to post")),
+        Err(Error::Unauthorized)
+    );
+    assert_eq!(
+        ctx.call(alice, "post_update", update("  ")),
+        Err(Error::InvalidUpdateMessage)
+    );
+    assert_eq!(
+        ctx.call(alice, "post_update", update(&"a".repeat(281))),
+        Err(Error::InvalidUpdateMessage)
+    );
+
+    ctx.call(alice, "post_update", update("Halfway there")).unwrap();
+    ctx.advance_time(86_400_000);
+    ctx.call(alice, "post_update", update("Funds will buy the wheelchair Friday")).unwrap();
+
+    let updates = ctx.updates(id);
+    assert_eq!(updates.len(), 2);
+    assert_eq!(updates[0].message, "Halfway there");
+    assert_eq!(updates[1].posted_at, ctx.block_time);
+}
+
+#[test]
+fn test_acknowledge_receipt() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "School fees");
+    let acknowledge = |message: &str| {
+        runtime_args! { "remittance_id" => id, "message" => message.to_string() }
+    };
+
+    ctx.contribute(carol, id, TARGET).unwrap();
+    assert_eq!(
+        ctx.call(bob, "acknowledge_receipt", acknowledge("Too early")),
+        Err(Error::NotReleased)
+    );
+
+    ctx.release_funds(bob, id).unwrap();
+    assert_eq!(
+        ctx.call(alice, "acknowledge_receipt", acknowledge("Not mine")),
+        Err(Error::Unauthorized)
+    );
+    assert_eq!(
+        ctx.call(bob, "acknowledge_receipt", acknowledge("")),
+        Err(Error::InvalidAcknowledgement)
+    );
+
+    ctx.call(bob, "acknowledge_receipt", acknowledge("Fees paid, thank you all")).unwrap();
+    assert_eq!(
+        ctx.call(bob, "acknowledge_receipt", acknowledge("Again")),
+        Err(Error::AlreadyAcknowledged)
+    );
+
+    let acknowledgement = ctx.acknowledgement(id).expect("acknowledgement");
+    assert_eq!(acknowledgement.message, "Fees paid, thank you all");
+    assert_eq!(acknowledgement.acknowledged_at, ctx.block_time);
+}
+
+#[test]
+fn test_max_target_amount() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, owner) = (ctx.alice, ctx.bob, ctx.owner);
+
+    let create = |target: u64| {
+        runtime_args! {
+            "recipient" => bob,
+            "target_amount" => U512::from(target),
+            "purpose" => "Rent".to_string(),
+        }
+    };
+    let set_max = |max: u64| runtime_args! { "max_target_amount" => U512::from(max) };
+
+    assert_eq!(
+        ctx.call(alice, "set_max_target_amount", set_max(TARGET)),
+        Err(Error::Unauthorized)
+    );
+    ctx.call(owner, "set_max_target_amount", set_max(TARGET)).unwrap();
+
+    ctx.call(alice, "create_remittance", create(TARGET)).unwrap();
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(TARGET + 1)),
+        Err(Error::TargetTooLarge)
+    );
+
+    // Zero removes the cap
+    ctx.call(owner, "set_max_target_amount", set_max(0)).unwrap();
+    ctx.call(alice, "create_remittance", create(TARGET + 1)).unwrap();
+}
+
+#[test]
+fn test_min_target_amount() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, owner) = (ctx.alice, ctx.bob, ctx.owner);
+
+    let create = |target: u64| {
+        runtime_args! {
+            "recipient" => bob,
+            "target_amount" => U512::from(target),
+            "purpose" => "Rent".to_string(),
+        }
+    };
+    let set_min = |min: u64| runtime_args! { "min_target_amount" => U512::from(min) };
+    let set_max = |max: u64| runtime_args! { "max_target_amount" => U512::from(max) };
+
+    ctx.call(owner, "set_min_target_amount", set_min(TARGET)).unwrap();
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(1)),
+        Err(Error::TargetTooSmall)
+    );
+    ctx.call(alice, "create_remittance", create(TARGET)).unwrap();
+
+    // The minimum can never exceed a configured maximum
+    assert_eq!(
+        ctx.call(owner, "set_max_target_amount", set_max(TARGET - 1)),
+        Err(Error::InvalidTargetAmount)
+    );
+    ctx.call(owner, "set_max_target_amount", set_max(TARGET)).unwrap();
+    assert_eq!(
+        ctx.call(owner, "set_min_target_amount", set_min(TARGET + 1)),
+        Err(Error::InvalidTargetAmount)
+    );
+}
+
+#[test]
+fn test_contribution_idempotency_key() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Rent");
+    ctx.contribute_once(carol, id, 1_000_000_000, [1u8; 32]).unwrap();
+
+    // A retried deploy is rejected and moves no funds
+    let carol_before = ctx.net_balance(carol);
+    assert_eq!(
+        ctx.contribute_once(carol, id, 1_000_000_000, [1u8; 32]),
+        Err(Error::DuplicateContribution)
+    );
+    assert_eq!(ctx.net_balance(carol), carol_before);
+    assert_eq!(ctx.contribution(id, carol), U512::from(1_000_000_000u64));
+
+    // Keys are scoped to the contributor and the remittance
+    ctx.contribute_once(carol, id, 1_000_000_000, [2u8; 32]).unwrap();
+    ctx.contribute_once(dave, id, 1_000_000_000, [1u8; 32]).unwrap();
+    let other = ctx.create_remittance(alice, bob, TARGET, "Groceries");
+    ctx.contribute_once(carol, other, 1_000_000_000, [1u8; 32]).unwrap();
+}
+
+#[test]
+fn test_refund_policy_retains_processing_fee() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.owner);
+
+    let create = |policy: u8, rate_bps: u64| {
+        runtime_args! {
+            "recipient" => bob,
+            "target_amount" => U512::from(TARGET),
+            "purpose" => "Clinic bill".to_string(),
+            "refund_policy" => policy,
+            "refund_policy_bps" => rate_bps,
+        }
+    };
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(1, 2_001)),
+        Err(Error::InvalidRefundPolicy)
+    );
+    ctx.call(alice, "create_remittance", create(1, 500)).unwrap();
+    let id = ctx.remittance_count();
+
+    ctx.contribute(carol, id, 4_000_000_000).unwrap();
+    ctx.cancel_remittance(alice, id).unwrap();
+
+    // 5% of the refund goes to the fee collector
+    let carol_before = ctx.net_balance(carol);
+    let owner_before = ctx.net_balance(owner);
+    ctx.claim_refund(carol, id).unwrap();
+    ctx.assert_received(carol, carol_before, 3_800_000_000);
+    ctx.assert_received(owner, owner_before, 200_000_000);
+    assert_eq!(ctx.contract_balance(), U512::zero());
+}
+
+#[test]
+fn test_force_cancel() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.owner);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Fraudulent appeal");
+    ctx.contribute(carol, id, 1_000_000_000).unwrap();
+
+    let force = |reason: &str| {
+        runtime_args! { "remittance_id" => id, "reason" => reason.to_string() }
+    };
+    assert_eq!(ctx.call(alice, "force_cancel", force("Fraud")), Err(Error::Unauthorized));
+    assert_eq!(ctx.call(owner, "force_cancel", force(" ")), Err(Error::InvalidReason));
+    ctx.call(owner, "force_cancel", force("Fraudulent campaign")).unwrap();
+    assert_eq!(
+        ctx.call(owner, "force_cancel", force("Fraud")),
+        Err(Error::RemittanceCancelled)
+    );
+
+    // Contributors can reclaim their funds
+    let carol_before = ctx.net_balance(carol);
+    ctx.claim_refund(carol, id).unwrap();
+    ctx.assert_received(carol, carol_before, 1_000_000_000);
+    assert_eq!(ctx.contract_balance(), U512::zero());
+}
+
+#[test]
+fn test_force_refund() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave, ctx.owner);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Chargeback");
+    ctx.contribute(carol, id, 1_000_000_000).unwrap();
+    ctx.contribute(dave, id, 2_000_000_000).unwrap();
+
+    let force = |contributor| {
+        runtime_args! {
+            "remittance_id" => id,
+            "contributor" => contributor,
+            "reason" => "Card chargeback".to_string(),
+        }
+    };
+    assert_eq!(ctx.call(alice, "force_refund", force(carol)), Err(Error::Unauthorized));
+    assert_eq!(ctx.call(owner, "force_refund", force(bob)), Err(Error::NoContribution));
+
+    // Only the refunded contribution leaves escrow
+    let carol_before = ctx.net_balance(carol);
+    ctx.call(owner, "force_refund", force(carol)).unwrap();
+    ctx.assert_received(carol, carol_before, 1_000_000_000);
+    assert_eq!(ctx.contribution(id, carol), U512::zero());
+    assert_eq!(ctx.remittance(id).current_amount, U512::from(2_000_000_000u64));
+    assert_eq!(ctx.contract_balance(), U512::from(2_000_000_000u64));
+    assert_eq!(ctx.call(owner, "force_refund", force(carol)), Err(Error::NoContribution));
+}
+
+#[test]
+fn test_release_to_payout_target() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let set = |label: &str, target| {
+        runtime_args! { "label" => label.to_string(), "target" => target }
+    };
+    assert_eq!(
+        ctx.call(bob, "set_payout_target", set("", dave)),
+        Err(Error::InvalidPayoutLabel)
+    );
+    ctx.call(bob, "set_payout_target", set("savings", carol)).unwrap();
+    ctx.call(bob, "set_payout_target", set("savings", dave)).unwrap();
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "School fees");
+    ctx.contribute(carol, id, TARGET).unwrap();
+
+    let release = |label: &str| {
+        runtime_args! { "remittance_id" => id, "payout_target" => label.to_string() }
+    };
+    assert_eq!(
+        ctx.call(bob, "release_funds", release("agent")),
+        Err(Error::PayoutTargetNotFound)
+    );
+    let mut conflicting = release("savings");
+    conflicting.insert("partner", dave).unwrap();
+    assert_eq!(
+        ctx.call(bob, "release_funds", conflicting),
+        Err(Error::ConflictingPayoutRoute)
+    );
+
+    let bob_before = ctx.net_balance(bob);
+    let dave_before = ctx.net_balance(dave);
+    ctx.call(bob, "release_funds", release("savings")).unwrap();
+    ctx.assert_received(dave, dave_before, TARGET - TARGET_FEE);
+    ctx.assert_received(bob, bob_before, 0);
+
+    let remove = runtime_args! { "label" => "savings".to_string() };
+    ctx.call(bob, "remove_payout_target", remove.clone()).unwrap();
+    assert_eq!(
+        ctx.call(bob, "remove_payout_target", remove),
+        Err(Error::PayoutTargetNotFound)
+    );
+}
+
+#[test]
+fn test_set_branding() {
+    let mut ctx = TestContext::new();
+    let (alice, owner) = (ctx.alice, ctx.owner);
+
+    let branding = |platform_name: &str| {
+        runtime_args! {
+            "platform_name" => platform_name.to_string(),
+            "logo_cid" => "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+            "support_url" => "https://help.example.org".to_string(),
+            "currency_display" => "CSPR".to_string(),
+        }
+    };
+    assert_eq!(
+        ctx.call(alice, "set_branding", branding("Harbor Remit")),
+        Err(Error::Unauthorized)
+    );
+    assert_eq!(ctx.call(owner, "set_branding", branding("")), Err(Error::InvalidBranding));
+
+    ctx.call(owner, "set_branding", branding("Harbor Remit")).unwrap();
+    let stored = ctx.branding();
+    assert_eq!(stored.platform_name, "Harbor Remit");
+    assert_eq!(stored.currency_display, "CSPR");
+}
+
+#[test]
+fn test_namespaces() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave, ctx.owner);
+    let namespace_fee = TARGET / 100;
+
+    let create_namespace = |name: &str| {
+        runtime_args! {
+            "namespace" => name.to_string(),
+            "namespace_owner" => dave,
+            "fee_ppm" => 10_000u64,
+        }
+    };
+    assert_eq!(
+        ctx.call(alice, "create_namespace", create_namespace("lagos-coop")),
+        Err(Error::Unauthorized)
+    );
+    assert_eq!(
+        ctx.call(owner, "create_namespace", create_namespace("Lagos Coop")),
+        Err(Error::InvalidNamespace)
+    );
+    ctx.call(owner, "create_namespace", create_namespace("lagos-coop")).unwrap();
+    assert_eq!(
+        ctx.call(owner, "create_namespace", create_namespace("lagos-coop")),
+        Err(Error::NamespaceExists)
+    );
+
+    let create = |name: &str| {
+        runtime_args! {
+            "recipient" => bob,
+            "target_amount" => U512::from(TARGET),
+            "purpose" => "Rent".to_string(),
+            "namespace" => name.to_string(),
+        }
+    };
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create("abuja-coop")),
+        Err(Error::NamespaceNotFound)
+    );
+    ctx.call(alice, "create_remittance", create("lagos-coop")).unwrap();
+    let id = ctx.remittance_count();
+
+    let pause = runtime_args! { "namespace" => "lagos-coop".to_string() };
+    assert_eq!(ctx.call(owner, "pause_namespace", pause.clone()), Err(Error::Unauthorized));
+    ctx.call(dave, "pause_namespace", pause.clone()).unwrap();
+    assert_eq!(ctx.contribute(carol, id, TARGET), Err(Error::NamespacePaused));
+    ctx.call(dave, "unpause_namespace", pause).unwrap();
+    ctx.contribute(carol, id, TARGET).unwrap();
+
+    let bob_before = ctx.net_balance(bob);
+    let dave_before = ctx.net_balance(dave);
+    ctx.call(bob, "release_funds", runtime_args! { "remittance_id" => id }).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET - namespace_fee);
+    ctx.assert_received(dave, dave_before, namespace_fee);
+}
+
+#[test]
+fn test_scheduled_start() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let now = ctx.block_time;
+    let starts_at = now + 86_400_000;
+    let create = |starts_at: u64, deadline: u64| {
+        runtime_args! {
+            "recipient" => bob,
+            "target_amount" => U512::from(TARGET),
+            "purpose" => "Christmas drive".to_string(),
+            "starts_at" => starts_at,
+            "deadline" => deadline,
+        }
+    };
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(now, starts_at)),
+        Err(Error::InvalidStartTime)
+    );
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(starts_at, starts_at)),
+        Err(Error::InvalidStartTime)
+    );
+    ctx.call(alice, "create_remittance", create(starts_at, starts_at + 86_400_000)).unwrap();
+    let id = ctx.remittance_count();
+
+    assert_eq!(ctx.contribute(carol, id, TARGET), Err(Error::RemittanceNotStarted));
+
+    ctx.advance_time(86_400_000);
+    ctx.contribute(carol, id, 1_000_000_000).unwrap();
+    let start = ctx.scheduled_start(id).unwrap();
+    assert_eq!(start.activated_at, Some(ctx.block_time));
+
+    // Only the first contribution activates the remittance
+    ctx.advance_time(1_000);
+    ctx.contribute(carol, id, 1_000_000_000).unwrap();
+    assert_eq!(ctx.scheduled_start(id), Some(start));
+}
+
+#[test]
+fn test_category_defaults() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.owner);
+
+    let template = runtime_args! {
+        "category" => "tuition".to_string(),
+        "deadline_days" => 30u64,
+        "fee_bps" => 100u64,
+        "refund_excess" => true,
+    };
+    assert_eq!(ctx.call(alice, "set_category", template.clone()), Err(Error::Unauthorized));
+    ctx.call(owner, "set_category", template).unwrap();
+
+    let create = |category: &str| {
+        runtime_args! {
+            "recipient" => bob,
+            "target_amount" => U512::from(TARGET),
+            "purpose" => "Spring semester".to_string(),
+            "category" => category.to_string(),
+        }
+    };
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create("medical")),
+        Err(Error::CategoryNotFound)
+    );
+    ctx.call(alice, "create_remittance", create("tuition")).unwrap();
+    let id = ctx.remittance_count();
+
+    let remittance = ctx.remittance(id);
+    assert_eq!(remittance.deadline, Some(ctx.block_time + 30 * MILLIS_PER_DAY));
+    assert!(remittance.refunds_excess());
+
+    // The category fee of 1% replaces the 0.5% platform fee
+    ctx.contribute(carol, id, TARGET).unwrap();
+    let bob_before = ctx.net_balance(bob);
+    ctx.release_funds(bob, id).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET - TARGET / 100);
+}
+
+#[test]
+fn test_recipient_declines_release_with_reason() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Tuition in EUR");
+    let decline = |reason: &str| {
+        runtime_args! { "remittance_id" => id, "reason" => reason.to_string() }
+    };
+    let reason = "Expected the fee in EUR, not CSPR";
+    assert_eq!(ctx.call(bob, "decline_release", decline(reason)), Err(Error::TargetNotMet));
+
+    ctx.contribute(carol, id, TARGET).unwrap();
+    assert_eq!(ctx.call(alice, "decline_release", decline(reason)), Err(Error::Unauthorized));
+    assert_eq!(ctx.call(bob, "decline_release", decline(" ")), Err(Error::InvalidReason));
+    ctx.call(bob, "decline_release", decline(reason)).unwrap();
+    assert!(ctx.remittance(id).is_cancelled());
+    assert_eq!(ctx.decline_reason(id).as_deref(), Some(reason));
+
+    let carol_before = ctx.net_balance(carol);
+    ctx.claim_refund(carol, id).unwrap();
+    ctx.assert_received(carol, carol_before, TARGET);
+}
+
+#[test]
+fn test_fee_cover_pays_recipient_in_full() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "School fees");
+    assert_eq!(ctx.top_up_fee_cover(carol, id, TARGET_FEE), Err(Error::Unauthorized));
+    ctx.top_up_fee_cover(alice, id, TARGET_FEE + 1_000).unwrap();
+    assert_eq!(ctx.top_up_fee_cover(bob, id, 1_000), Err(Error::Unauthorized));
+
+    // The fee comes out of the cover and the unused rest goes back to alice
+    ctx.contribute(carol, id, TARGET).unwrap();
+    let (alice_before, bob_before) = (ctx.net_balance(alice), ctx.net_balance(bob));
+    ctx.release_funds(bob, id).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET);
+    ctx.assert_received(alice, alice_before, 1_000);
+    assert_eq!(ctx.contract_balance(), 0.into());
+}
+
+#[test]
+fn test_fee_cover_claimed_back_after_cancellation() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "School fees");
+    ctx.top_up_fee_cover(bob, id, TARGET_FEE).unwrap();
+    ctx.contribute(carol, id, TARGET).unwrap();
+
+    let args = runtime_args! { "remittance_id" => id };
+    assert_eq!(ctx.call(bob, "claim_fee_cover", args.clone()), Err(Error::NotCancelled));
+    ctx.cancel_remittance(alice, id).unwrap();
+    assert_eq!(ctx.call(alice, "claim_fee_cover", args.clone()), Err(Error::NoFeeCover));
+
+    let bob_before = ctx.net_balance(bob);
+    ctx.call(bob, "claim_fee_cover", args.clone()).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET_FEE);
+    assert_eq!(ctx.call(bob, "claim_fee_cover", args), Err(Error::NoFeeCover));
+}
+
+#[test]
+fn test_simple_entry_points() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Rent");
+    let contribute = runtime_args! { "remittance_id" => id, "amount" => U512::from(TARGET) };
+    let carol_before = ctx.net_balance(carol);
+    ctx.call(carol, "contribute_simple", contribute).unwrap();
+    ctx.assert_spent(carol, carol_before, TARGET);
+    assert!(ctx.remittance(id).is_target_met());
+
+    let release = runtime_args! { "remittance_id" => id };
+    assert_eq!(ctx.call(alice, "release_simple", release.clone()), Err(Error::Unauthorized));
+    let bob_before = ctx.net_balance(bob);
+    ctx.call(bob, "release_simple", release).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET - TARGET_FEE);
+}
+
+#[test]
+fn test_contribution_snapshot_is_frozen() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Airdrop");
+    ctx.contribute(carol, id, 3_000_000_000).unwrap();
+    ctx.contribute(dave, id, 2_000_000_000).unwrap();
+    ctx.contribute(carol, id, 1_000_000_000).unwrap();
+
+    // Anyone can take the snapshot
+    ctx.call(bob, "snapshot_contributions", runtime_args! { "remittance_id" => id }).unwrap();
+
+    ctx.cancel_remittance(alice, id).unwrap();
+    ctx.claim_refund(carol, id).unwrap();
+
+    let snapshot = ctx.contribution_snapshot(0).expect("snapshot");
+    assert_eq!(snapshot.remittance_id, id);
+    assert_eq!(snapshot.contributions.len(), 2);
+    assert_eq!(snapshot.contributions[0].0.value(), carol.value());
+    assert_eq!(snapshot.contributions[0].1, U512::from(4_000_000_000u64));
+    assert_eq!(snapshot.contributions[1].0.value(), dave.value());
+    assert_eq!(snapshot.total(), U512::from(6_000_000_000u64));
+    assert!(ctx.contribution_snapshot(1).is_none());
+}
+
+#[test]
+fn test_time_weighted_contributions() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Weighted");
+    ctx.contribute(carol, id, 6_000_000_000).unwrap();
+    ctx.advance_time(1_000);
+    ctx.contribute(dave, id, 4_000_000_000).unwrap();
+    ctx.advance_time(2_000);
+    ctx.release_funds(bob, id).unwrap();
+
+    // Weights stop at release, however long after they are read
+    let closed_at = ctx.escrow_closed_at(id).expect("escrow closed");
+    assert_eq!(closed_at, ctx.block_time);
+    // 6 CSPR for 3 s and 4 CSPR for 2 s
+    let carol_weight = ctx.contribution_weight(id, carol).weight_at(closed_at);
+    let dave_weight = ctx.contribution_weight(id, dave).weight_at(closed_at);
+    assert_eq!(carol_weight, U512::from(18_000_000_000_000u64));
+    assert_eq!(dave_weight, U512::from(8_000_000_000_000u64));
+    assert_eq!(ctx.remittance_weight(id).weight_at(closed_at), carol_weight + dave_weight);
+}
+
+#[test]
+fn test_contributed_remittances_index() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let first = ctx.create_remittance(alice, bob, TARGET, "First");
+    let second = ctx.create_remittance(bob, alice, TARGET, "Second");
+    ctx.contribute(carol, second, 1_000_000_000).unwrap();
+    ctx.contribute(carol, first, 1_000_000_000).unwrap();
+    ctx.contribute(carol, second, 1_000_000_000).unwrap();
+
+    // Listed once each, in order of first contribution
+    assert_eq!(ctx.contributed_remittances(carol), vec![second, first]);
+    assert!(ctx.contributed_remittances(dave).is_empty());
+}
+
+#[test]
+fn test_claimable_refunds_index() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let cancelled = ctx.create_remittance(alice, bob, TARGET, "Cancelled");
+    let open = ctx.create_remittance(alice, bob, TARGET, "Open");
+    ctx.contribute(carol, cancelled, 3_000_000_000).unwrap();
+    ctx.contribute(dave, cancelled, 2_000_000_000).unwrap();
+    ctx.contribute(carol, open, 1_000_000_000).unwrap();
+    assert!(ctx.claimable_refunds(carol).is_empty());
+
+    ctx.cancel_remittance(alice, cancelled).unwrap();
+    assert_eq!(ctx.claimable_refunds(carol), vec![cancelled]);
+    assert_eq!(ctx.claimable_refunds(dave), vec![cancelled]);
+
+    ctx.claim_refund(carol, cancelled).unwrap();
+    assert!(ctx.claimable_refunds(carol).is_empty());
+    assert_eq!(ctx.claimable_refunds(dave), vec![cancelled]);
+}
+
+#[test]
+fn test_claim_all_refunds() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let mut ids = Vec::new();
+    for purpose in ["First", "Second", "Third"] {
+        let id = ctx.create_remittance(alice, bob, TARGET, purpose);
+        ctx.contribute(carol, id, 1_000_000_000).unwrap();
+        ctx.cancel_remittance(alice, id).unwrap();
+        ids.push(id);
+    }
+
+    let max_items = |max_items: u64| runtime_args! { "max_items" => max_items };
+    assert_eq!(
+        ctx.call(carol, "claim_all_refunds", max_items(0)),
+        Err(Error::InvalidBatchSize)
+    );
+
+    // Oldest first, up to the limit
+    let carol_before = ctx.net_balance(carol);
+    ctx.call(carol, "claim_all_refunds", max_items(2)).unwrap();
+    ctx.assert_received(carol, carol_before, 2_000_000_000);
+    assert_eq!(ctx.claimable_refunds(carol), vec![ids[2]]);
+    assert_eq!(
+        ctx.claim_refund(carol, ids[0]),
+        Err(Error::RefundAlreadyClaimed)
+    );
+
+    let carol_before = ctx.net_balance(carol);
+    ctx.call(carol, "claim_all_refunds", max_items(10)).unwrap();
+    ctx.assert_received(carol, carol_before, 1_000_000_000);
+    assert!(ctx.claimable_refunds(carol).is_empty());
+    assert_eq!(ctx.contract_balance(), 0.into());
+}
+
+#[test]
+fn test_refund_delegate() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let first = ctx.create_remittance(alice, bob, TARGET, "First");
+    let second = ctx.create_remittance(alice, bob, TARGET, "Second");
+    for id in [first, second] {
+        ctx.contribute(carol, id, 3_000_000_000).unwrap();
+        ctx.cancel_remittance(alice, id).unwrap();
+    }
+
+    let claim_for = |id: u64| runtime_args! { "remittance_id" => id, "contributor" => carol };
+    assert_eq!(
+        ctx.call(dave, "claim_refund", claim_for(first)),
+        Err(Error::Unauthorized)
+    );
+
+    // The delegate claims, the contributor is paid
+    ctx.call(carol, "set_refund_delegate", runtime_args! { "delegate" => dave }).unwrap();
+    let carol_before = ctx.net_balance(carol);
+    let dave_before = ctx.net_balance(dave);
+    ctx.call(dave, "claim_refund", claim_for(first)).unwrap();
+    ctx.assert_received(carol, carol_before, 3_000_000_000);
+    assert_eq!(ctx.net_balance(dave), dave_before);
+
+    ctx.call(carol, "set_refund_delegate", RuntimeArgs::new()).unwrap();
+    assert_eq!(
+        ctx.call(dave, "claim_refund", claim_for(second)),
+        Err(Error::Unauthorized)
+    );
+}
+
+#[test]
+fn test_kyb_business_target_cap() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.owner);
+
+    let create = |recipient: AccountHash, target: u64| {
+        runtime_args! {
+            "recipient" => recipient,
+            "target_amount" => U512::from(target),
+            "purpose" => "Stock order".to_string(),
+        }
+    };
+    let business = runtime_args! { "business" => bob };
+
+    let max = runtime_args! { "max_target_amount" => U512::from(TARGET) };
+    ctx.call(owner, "set_max_target_amount", max).unwrap();
+    let kyb_max = runtime_args! { "kyb_max_target_amount" => U512::from(2 * TARGET) };
+    ctx.call(owner, "set_kyb_max_target_amount", kyb_max).unwrap();
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(bob, TARGET + 1)),
+        Err(Error::TargetTooLarge)
+    );
+
+    assert_eq!(
+        ctx.call(alice, "register_business", business.clone()),
+        Err(Error::Unauthorized)
+    );
+    ctx.call(owner, "register_business", business.clone()).unwrap();
+    assert!(ctx.is_verified_business(bob));
+
+    // Only the verified business gets the higher cap
+    ctx.call(alice, "create_remittance", create(bob, 2 * TARGET)).unwrap();
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(bob, 2 * TARGET + 1)),
+        Err(Error::TargetTooLarge)
+    );
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(carol, TARGET + 1)),
+        Err(Error::TargetTooLarge)
+    );
+
+    ctx.call(owner, "unregister_business", business).unwrap();
+    assert!(!ctx.is_verified_business(bob));
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(bob, TARGET + 1)),
+        Err(Error::TargetTooLarge)
+    );
+}
+
+#[test]
+fn test_renounce_admin() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, owner) = (ctx.alice, ctx.bob, ctx.owner);
+
+    assert_eq!(
+        ctx.call(alice, "renounce_admin", RuntimeArgs::new()),
+        Err(Error::Unauthorized)
+    );
+
+    // A paused contract could never be unpaused again
+    ctx.pause(owner).unwrap();
+    assert_eq!(
+        ctx.call(owner, "renounce_admin", RuntimeArgs::new()),
+        Err(Error::ContractPaused)
+    );
+    ctx.unpause(owner).unwrap();
+
+    ctx.call(owner, "renounce_admin", RuntimeArgs::new()).unwrap();
+    assert_eq!(ctx.set_platform_fee(owner, 100), Err(Error::AdminRenounced));
+    assert_eq!(ctx.pause(owner), Err(Error::AdminRenounced));
+
+    // Every owner-only entry point is locked, before reading its arguments
+    let gated = [
+        "set_platform_fee_ppm",
+        "lower_max_fee",
+        "set_max_deadline_extension",
+        "set_max_target_amount",
+        "set_min_target_amount",
+        "set_kyb_max_target_amount",
+        "set_branding",
+        "create_namespace",
+        "set_sla_intervals",
+        "set_category",
+        "set_insurance_premium",
+        "set_fee_rounding",
+        "set_creator_fee_share",
+        "set_rate_limits",
+        "set_payment_guards",
+        "set_gas_limits",
+        "migrate_records",
+        "rotate_owner",
+        "set_compliance_officer",
+        "set_bridge_operator",
+        "withdraw_access_token",
+        "revoke_access_token",
+        "remove_named_key",
+        "renounce_admin",
+        "unpause_contract",
+    ];
+    for entry_point in gated {
+        assert_eq!(
+            ctx.call(owner, entry_point, RuntimeArgs::new()),
+            Err(Error::AdminRenounced),
+            "{}",
+            entry_point
+        );
+    }
+
+    // Users are unaffected
+    let id = ctx.create_remittance(alice, bob, TARGET, "Rent");
+    ctx.contribute(alice, id, TARGET).unwrap();
+    ctx.release_funds(bob, id).unwrap();
+}
+
+#[test]
+fn test_gas_limits() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave, ctx.owner);
+
+    let limits = |batch: u64, page: u64, contributors: u64| {
+        runtime_args! {
+            "max_batch_size" => batch,
+            "max_page_size" => page,
+            "max_contributors" => contributors,
+        }
+    };
+    assert_eq!(
+        ctx.call(alice, "set_gas_limits", limits(10, 10, 1)),
+        Err(Error::Unauthorized)
+    );
+    assert_eq!(
+        ctx.call(owner, "set_gas_limits", limits(101, 10, 1)),
+        Err(Error::InvalidBatchSize)
+    );
+    ctx.call(owner, "set_gas_limits", limits(10, 10, 1)).unwrap();
+
+    let expire = |count: u64| runtime_args! { "start_id" => 1u64, "count" => count };
+    ctx.call(alice, "expire_batch", expire(10)).unwrap();
+    assert_eq!(
+        ctx.call(alice, "expire_batch", expire(11)),
+        Err(Error::InvalidBatchSize)
+    );
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Airdrop");
+    ctx.contribute(carol, id, 1_000_000_000).unwrap();
+    let snapshot = runtime_args! { "remittance_id" => id };
+    ctx.call(alice, "snapshot_contributions", snapshot.clone()).unwrap();
+    ctx.contribute(dave, id, 1_000_000_000).unwrap();
+    assert_eq!(
+        ctx.call(alice, "snapshot_contributions", snapshot.clone()),
+        Err(Error::TooManyContributors)
+    );
+
+    // Zero restores the built-in maximums and lifts the contributor limit
+    ctx.call(owner, "set_gas_limits", limits(0, 0, 0)).unwrap();
+    ctx.call(alice, "expire_batch", expire(100)).unwrap();
+    ctx.call(alice, "snapshot_contributions", snapshot).unwrap();
+}
+
+#[test]
+fn test_payment_guards() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, owner) = (ctx.alice, ctx.bob, ctx.owner);
+
+    let create = |recipient: AccountHash| {
+        runtime_args! {
+            "recipient" => recipient,
+            "target_amount" => U512::from(TARGET),
+            "purpose" => "Rent".to_string(),
+        }
+    };
+    // The installer collects fees, so it can never be paid out
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(owner)),
+        Err(Error::RecipientIsFeeCollector)
+    );
+
+    // Self-remittances and recipient contributions are allowed by default
+    let id = ctx.create_remittance(alice, alice, TARGET, "Savings");
+    ctx.contribute(alice, id, 1_000_000_000).unwrap();
+
+    let guards = |reject_self: bool, policy: u8| {
+        runtime_args! {
+            "reject_self_remittance" => reject_self,
+            "recipient_contributions" => policy,
+        }
+    };
+    assert_eq!(
+        ctx.call(alice, "set_payment_guards", guards(true, 2)),
+        Err(Error::Unauthorized)
+    );
+    assert_eq!(
+        ctx.call(owner, "set_payment_guards", guards(true, 3)),
+        Err(Error::InvalidPaymentGuards)
+    );
+    ctx.call(owner, "set_payment_guards", guards(true, 2)).unwrap();
+
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(alice)),
+        Err(Error::SelfRemittance)
+    );
+    let id = ctx.create_remittance(alice, bob, TARGET, "Rent");
+    assert_eq!(
+        ctx.contribute(bob, id, 1_000_000_000),
+        Err(Error::RecipientContribution)
+    );
+
+    // Flagged contributions are still accepted
+    ctx.call(owner, "set_payment_guards", guards(true, 1)).unwrap();
+    ctx.contribute(bob, id, 1_000_000_000).unwrap();
+}
+
+#[test]
+fn test_purpose_blocklist() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, owner) = (ctx.alice, ctx.bob, ctx.owner);
+
+    let scam = "Pay the release fee to unlock your inheritance";
+    let blocked = runtime_args! { "purpose_hash" => purpose_hash(scam) };
+    assert_eq!(
+        ctx.call(alice, "block_purpose", blocked.clone()),
+        Err(Error::Unauthorized)
+    );
+    ctx.call(owner, "block_purpose", blocked.clone()).unwrap();
+
+    // Case and spacing changes still match the blocked template
+    let create = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "pay the release fee  to unlock your INHERITANCE".to_string(),
+    };
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create.clone()),
+        Err(Error::PurposeBlocked)
+    );
+
+    ctx.call(owner, "unblock_purpose", blocked).unwrap();
+    ctx.call(alice, "create_remittance", create).unwrap();
+}
+
+#[test]
+fn test_release_breakdown() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.owner);
+
+    // A fifth of the fee is rebated to the creator
+    let share = runtime_args! { "share_bps" => 2_000u64 };
+    ctx.call(owner, "set_creator_fee_share", share).unwrap();
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Tuition");
+    ctx.contribute(carol, id, TARGET).unwrap();
+    assert_eq!(ctx.release_breakdown(id), None);
+
+    let bob_before = ctx.net_balance(bob);
+    ctx.release_funds(bob, id).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET - TARGET_FEE);
+
+    let breakdown = ctx.release_breakdown(id).unwrap();
+    assert_eq!(breakdown.gross, U512::from(TARGET));
+    assert_eq!(breakdown.platform_fee, U512::from(TARGET_FEE));
+    assert_eq!(breakdown.creator_rebate, U512::from(TARGET_FEE / 5));
+    assert_eq!(breakdown.collector_fee, U512::from(TARGET_FEE - TARGET_FEE / 5));
+    assert_eq!(breakdown.fee_covered, U512::zero());
+    assert_eq!(breakdown.net, U512::from(TARGET - TARGET_FEE));
+}
+
+#[test]
+fn test_localized_purpose() {
+    let mut ctx = TestContext::new();
+    let (alice, bob) = (ctx.alice, ctx.bob);
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "School fees");
+    let set = |lang: &str, purpose: &str| {
+        runtime_args! {
+            "remittance_id" => id,
+            "lang" => lang.to_string(),
+            "purpose" => purpose.to_string(),
+        }
+    };
+
+    assert_eq!(
+        ctx.call(bob, "set_localized_purpose", set("fr", "Frais de scolarité")),
+        Err(Error::Unauthorized)
+    );
+    assert_eq!(
+        ctx.call(alice, "set_localized_purpose", set("FR", "Frais de scolarité")),
+        Err(Error::InvalidLanguageCode)
+    );
+
+    ctx.call(alice, "set_localized_purpose", set("fr", "Frais scolaires")).unwrap();
+    ctx.call(alice, "set_localized_purpose", set("fr", "Frais de scolarité")).unwrap();
+    let purposes = ctx.localized_purposes(id);
+    assert_eq!(purposes.len(), 1);
+    assert_eq!(purposes[0].purpose, "Frais de scolarité");
+
+    // An empty purpose removes the translation
+    ctx.call(alice, "set_localized_purpose", set("fr", "")).unwrap();
+    assert!(ctx.localized_purposes(id).is_empty());
+
+    for lang in ["fr", "pt", "es", "de", "sw", "yo", "ha", "ig"] {
+        ctx.call(alice, "set_localized_purpose", set(lang, "School fees")).unwrap();
+    }
+    assert_eq!(
+        ctx.call(alice, "set_localized_purpose", set("wo", "School fees")),
+        Err(Error::TooManyLocalizedPurposes)
+    );
+}
+
+#[test]
+fn test_installment_payout() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let installment = TARGET * 2 / 5;
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Monthly stipend".to_string(),
+        "installment_amount" => U512::from(installment),
+        "installment_interval_days" => 30u64,
+    };
+    ctx.call(alice, "create_remittance", args).unwrap();
+    let id = ctx.remittance_count();
+    let process = runtime_args! { "remittance_id" => id };
+
+    assert_eq!(
+        ctx.call(dave, "process_installment", process.clone()),
+        Err(Error::TargetNotMet)
+    );
+    ctx.contribute(carol, id, TARGET).unwrap();
+    assert_eq!(ctx.release_funds(bob, id), Err(Error::PayoutScheduled));
+
+    // Anyone may pay an installment once it is due
+    let bob_before = ctx.net_balance(bob);
+    ctx.call(dave, "process_installment", process.clone()).unwrap();
+    ctx.assert_received(bob, bob_before, installment - installment / 200);
+    assert_eq!(
+        ctx.call(dave, "process_installment", process.clone()),
+        Err(Error::InstallmentNotDue)
+    );
+
+    ctx.advance_time(30 * MILLIS_PER_DAY);
+    ctx.call(dave, "process_installment", process.clone()).unwrap();
+    assert!(!ctx.remittance(id).is_released());
+
+    // The last installment pays what remains and completes the release
+    ctx.advance_time(30 * MILLIS_PER_DAY);
+    let bob_before = ctx.net_balance(bob);
+    ctx.call(dave, "process_installment", process).unwrap();
+    let rest = TARGET - 2 * installment;
+    ctx.assert_received(bob, bob_before, rest - rest / 200);
+    assert!(ctx.remittance(id).is_released());
+    assert_eq!(ctx.payout_schedule(id).unwrap().paid_count, 3);
+}
+
+#[test]
+fn test_installment_multisig_approval() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let installment = TARGET / 2;
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Family stipend".to_string(),
+        "installment_amount" => U512::from(installment),
+        "installment_interval_days" => 30u64,
+        "recipient_signers" => vec![bob, carol],
+        "recipient_threshold" => 2u64,
+    };
+    ctx.call(alice, "create_remittance", args).unwrap();
+    let id = ctx.remittance_count();
+    let process = runtime_args! { "remittance_id" => id };
+    ctx.contribute(alice, id, TARGET).unwrap();
+
+    // Keepers cannot pay out before the signers approve
+    assert_eq!(
+        ctx.call(dave, "process_installment", process.clone()),
+        Err(Error::RecipientApprovalRequired)
+    );
+    let approve = runtime_args! { "remittance_id" => id };
+    ctx.call(bob, "approve_release_as_recipient", approve.clone()).unwrap();
+    assert_eq!(
+        ctx.call(dave, "process_installment", process.clone()),
+        Err(Error::RecipientApprovalRequired)
+    );
+    ctx.call(carol, "approve_release_as_recipient", approve).unwrap();
+
+    let bob_before = ctx.net_balance(bob);
+    ctx.call(dave, "process_installment", process).unwrap();
+    ctx.assert_received(bob, bob_before, installment - installment / 200);
+}
+
+#[test]
+fn test_installment_review_settled_at_creation() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave, ctx.owner);
+    let review = |threshold: u64| {
+        runtime_args! { "threshold" => U512::from(threshold), "window_ms" => MILLIS_PER_DAY }
+    };
+    let create = |installment: u64| {
+        runtime_args! {
+            "recipient" => bob,
+            "target_amount" => U512::from(TARGET),
+            "purpose" => "Monthly stipend".to_string(),
+            "installment_amount" => U512::from(installment),
+            "installment_interval_days" => 30u64,
+        }
+    };
+    ctx.call(owner, "set_review_policy", review(TARGET / 2)).unwrap();
+
+    // Installments that would need review are refused up front
+    assert_eq!(
+        ctx.call(alice, "create_remittance", create(TARGET / 2)),
+        Err(Error::InvalidPayoutSchedule)
+    );
+    let installment = TARGET * 2 / 5;
+    ctx.call(alice, "create_remittance", create(installment)).unwrap();
+    let id = ctx.remittance_count();
+    ctx.contribute(carol, id, TARGET).unwrap();
+
+    // Lowering the threshold mid-schedule does not strand the payout
+    ctx.call(owner, "set_review_policy", review(TARGET / 10)).unwrap();
+    let process = runtime_args! { "remittance_id" => id };
+    let bob_before = ctx.net_balance(bob);
+    ctx.call(dave, "process_installment", process.clone()).unwrap();
+    ctx.assert_received(bob, bob_before, installment - installment / 200);
+
+    ctx.advance_time(30 * MILLIS_PER_DAY);
+    ctx.call(dave, "process_installment", process).unwrap();
+    assert_eq!(ctx.payout_schedule(id).unwrap().paid_count, 2);
+}
+
+#[test]
+fn test_savings_pool() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let create = |members: Vec<AccountHash>| {
+        runtime_args! {
+            "members" => members,
+            "contribution" => U512::from(TARGET / 2),
+            "cycle_days" => 30u64,
+            "purpose" => "Family susu".to_string(),
+        }
+    };
+    assert_eq!(
+        ctx.call(alice, "create_savings_pool", create(vec![bob])),
+        Err(Error::InvalidSavingsPool)
+    );
+    assert_eq!(
+        ctx.call(alice, "create_savings_pool", create(vec![bob, bob])),
+        Err(Error::InvalidSavingsPool)
+    );
+    ctx.call(alice, "create_savings_pool", create(vec![bob, carol])).unwrap();
+
+    // One remittance per member, each paying out the whole pot
+    let pool = ctx.savings_pool(0).unwrap();
+    let (first, second) = (pool.first_remittance_id, pool.first_remittance_id + 1);
+    assert_eq!(ctx.remittance_count(), second);
+    assert_eq!(ctx.remittance(first).recipient, bob);
+    assert_eq!(ctx.remittance(second).recipient, carol);
+    assert_eq!(ctx.remittance(first).target_amount, Some(U512::from(TARGET)));
+
+    // Only members contribute, and only their share per cycle
+    assert_eq!(ctx.contribute(dave, first, TARGET / 2), Err(Error::NotPoolMember));
+    assert_eq!(
+        ctx.contribute(carol, first, TARGET / 2 + 1),
+        Err(Error::PoolContributionExceeded)
+    );
+    ctx.contribute(bob, first, TARGET / 2).unwrap();
+    ctx.contribute(carol, first, TARGET / 2).unwrap();
+
+    let bob_before = ctx.net_balance(bob);
+    ctx.release_funds(bob, first).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET - TARGET_FEE);
+
+    // The next cycle opens once the first has run its course
+    assert_eq!(ctx.contribute(bob, second, TARGET / 2), Err(Error::RemittanceNotStarted));
+    ctx.advance_time(30 * MILLIS_PER_DAY);
+    ctx.contribute(bob, second, TARGET / 2).unwrap();
+}
+
+#[test]
+fn test_savings_pool_fee_on_contribution() {
+    let mut ctx = TestContext::with_install_args(runtime_args! { "fee_model" => 1u8 });
+    let (alice, bob, carol) = (ctx.alice, ctx.bob, ctx.carol);
+
+    let args = runtime_args! {
+        "members" => vec![bob, carol],
+        "contribution" => U512::from(TARGET / 2),
+        "cycle_days" => 30u64,
+        "purpose" => "Family susu".to_string(),
+    };
+    ctx.call(alice, "create_savings_pool", args).unwrap();
+    let first = ctx.savings_pool(0).unwrap().first_remittance_id;
+
+    // Shares are escrowed amounts, so members pay their share plus the fee;
+    // this gross amount nets exactly TARGET / 2 at the 0.5% fee
+    let gross = 5_025_125_628;
+    assert_eq!(ctx.contribute(bob, first, gross + 1), Err(Error::PoolContributionExceeded));
+    ctx.contribute(bob, first, gross).unwrap();
+    ctx.contribute(carol, first, gross).unwrap();
+    assert_eq!(ctx.contribution(first, bob), U512::from(TARGET / 2));
+    assert!(ctx.remittance(first).is_target_met());
+
+    let bob_before = ctx.net_balance(bob);
+    ctx.release_funds(bob, first).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET);
+}
+
+#[test]
+fn test_confidential_contributions() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let args = runtime_args! {
+        "recipient" => bob,
+        "target_amount" => U512::from(TARGET),
+        "purpose" => "Family support".to_string(),
+        "confidential" => true,
+    };
+    ctx.call(alice, "create_remittance", args).unwrap();
+    let id = ctx.remittance_count();
+    let (carol_blinding, dave_blinding) = ([7u8; 32], [8u8; 32]);
+
+    // A commitment must open to the amount actually paid
+    let inflated = amount_commitment(TARGET, &carol_blinding);
+    assert_eq!(
+        ctx.contribute_committed(carol, id, 1, inflated, carol_blinding),
+        Err(Error::InvalidAmountCommitment)
+    );
+
+    let commitment = amount_commitment(TARGET / 4, &carol_blinding);
+    ctx.contribute_committed(carol, id, TARGET / 4, commitment, carol_blinding).unwrap();
+    assert_eq!(
+        ctx.contribute_committed(carol, id, TARGET / 4, commitment, carol_blinding),
+        Err(Error::CommitmentAlreadyRecorded)
+    );
+    let commitment = amount_commitment(TARGET / 2, &dave_blinding);
+    ctx.contribute_committed(dave, id, TARGET / 2, commitment, dave_blinding).unwrap();
+
+    // Only the aggregate is kept in contract state
+    assert_eq!(ctx.remittance(id).current_amount, U512::from(TARGET / 4 * 3));
+    assert_eq!(ctx.contribution(id, carol), U512::zero());
+
+    // Refunds pay only what a valid opening shows was paid in
+    ctx.cancel_remittance(alice, id).unwrap();
+    assert_eq!(ctx.claim_refund(carol, id), Err(Error::MissingArgument));
+    assert_eq!(
+        ctx.claim_refund_opening(carol, id, TARGET, carol_blinding),
+        Err(Error::InvalidAmountCommitment)
+    );
+    assert_eq!(
+        ctx.claim_refund_opening(carol, id, TARGET / 2, dave_blinding),
+        Err(Error::InvalidAmountCommitment)
+    );
+
+    let carol_before = ctx.net_balance(carol);
+    ctx.claim_refund_opening(carol, id, TARGET / 4, carol_blinding).unwrap();
+    ctx.assert_received(carol, carol_before, TARGET / 4);
+
+    let dave_before = ctx.net_balance(dave);
+    ctx.claim_refund_opening(dave, id, TARGET / 2, dave_blinding).unwrap();
+    ctx.assert_received(dave, dave_before, TARGET / 2);
+    assert_eq!(
+        ctx.claim_refund_opening(dave, id, TARGET / 2, dave_blinding),
+        Err(Error::RefundAlreadyClaimed)
+    );
+}
+
+#[test]
+fn test_open_ended_pot() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, dave) = (ctx.alice, ctx.bob, ctx.carol, ctx.dave);
+
+    let args = runtime_args! {
+        "recipient" => bob,
+        "purpose" => "Family support".to_string(),
+    };
+    ctx.call(alice, "create_remittance", args).unwrap();
+    let id = ctx.remittance_count();
+    let withdraw = runtime_args! { "remittance_id" => id };
+    assert_eq!(ctx.remittance(id).target_amount, None);
+    assert_eq!(ctx.call(bob, "withdraw_pot", withdraw.clone()), Err(Error::TargetNotMet));
+
+    // The recipient withdraws whatever has come in, and the pot stays open
+    ctx.contribute(carol, id, TARGET).unwrap();
+    assert_eq!(ctx.call(carol, "withdraw_pot", withdraw.clone()), Err(Error::Unauthorized));
+    let bob_before = ctx.net_balance(bob);
+    ctx.call(bob, "withdraw_pot", withdraw.clone()).unwrap();
+    ctx.assert_received(bob, bob_before, TARGET - TARGET_FEE);
+    assert!(ctx.remittance(id).is_active());
+    assert_eq!(ctx.call(bob, "withdraw_pot", withdraw), Err(Error::TargetNotMet));
+
+    // Cancelling refunds what was paid in since the last withdrawal, in full
+    ctx.contribute(dave, id, TARGET).unwrap();
+    ctx.cancel_remittance(alice, id).unwrap();
+    let dave_before = ctx.net_balance(dave);
+    ctx.claim_refund(dave, id).unwrap();
+    ctx.assert_received(dave, dave_before, TARGET);
+    assert_eq!(ctx.claim_refund(carol, id), Err(Error::NoContribution));
+
+    // Forwarding part of each contribution would pay out deposits without
+    // a withdrawal
+    let args = runtime_args! {
+        "recipient" => bob,
+        "purpose" => "Family support".to_string(),
+        "instant_share_bps" => 2_000u64,
+    };
+    assert_eq!(
+        ctx.call(alice, "create_remittance", args),
+        Err(Error::InvalidInstantShare)
+    );
+
+    // Remittances with a target are released, not withdrawn from
+    let id = ctx.create_remittance(alice, bob, TARGET, "Rent");
+    ctx.contribute(carol, id, TARGET).unwrap();
+    assert_eq!(
+        ctx.call(bob, "withdraw_pot", runtime_args! { "remittance_id" => id }),
+        Err(Error::NotOpenEnded)
+    );
+}
+
+#[test]
+fn test_release_escalation() {
+    let mut ctx = TestContext::new();
+    let (alice, bob, carol, owner) = (ctx.alice, ctx.bob, ctx.carol, ctx.owner);
+
+    let intervals = runtime_args! {
+        "intervals_ms" => vec![3 * MILLIS_PER_DAY, 7 * MILLIS_PER_DAY],
+    };
+    assert_eq!(ctx.call(alice, "set_sla_intervals", intervals.clone()), Err(Error::Unauthorized));
+    assert_eq!(
+        ctx.call(owner, "set_sla_intervals", runtime_args! { "intervals_ms" => vec![0u64] }),
+        Err(Error::InvalidSlaIntervals)
+    );
+    ctx.call(owner, "set_sla_intervals", intervals).unwrap();
+
+    let id = ctx.create_remittance(alice, bob, TARGET, "Rent");
+    assert_eq!(ctx.release_sla(id), None);
+    ctx.contribute(carol, id, TARGET).unwrap();
+    let funded_at = ctx.block_time;
+
+    let sweep = runtime_args! { "start_id" => 1u64, "count" => 10u64 };
+    let level = |ctx: &TestContext| ctx.release_sla(id).unwrap().escalation_level;
+    ctx.call(carol, "escalate_overdue", sweep.clone()).unwrap();
+    assert_eq!(level(&ctx), 0);
+
+    ctx.advance_time(3 * MILLIS_PER_DAY);
+    ctx.call(carol, "escalate_overdue", sweep.clone()).unwrap();
+    assert_eq!(level(&ctx), 1);
+
+    // Each level is reported once
+    ctx.call(carol, "escalate_overdue", sweep.clone()).unwrap();
+    assert_eq!(level(&ctx), 1);
+
+    ctx.advance_time(4 * MILLIS_PER_DAY);
+    ctx.call(carol, "escalate_overdue", sweep.clone()).unwrap();
+    assert_eq!(ctx.release_sla(id).unwrap().funded_at, funded_at);
+    assert_eq!(level(&ctx), 2);
+
+    // Released remittances are no longer tracked
+    ctx.release_funds(bob, id).unwrap();
+    ctx.advance_time(7 * MILLIS_PER_DAY);
+    ctx.call(carol, "escalate_overdue", sweep).unwrap();
+    assert_eq!(level(&ctx), 2);
+}
+
+#[test]
+fn test_schema_recorded_at_install() {
+    let ctx = TestContext::new();
+    let schema = ctx.schema();
+
+    let contribute = schema
+        .entry_point("contribute")
+        .expect("contribute entry point");
+    assert!(contribute
+        .args()
+        .iter()
+        .any(|arg| arg.name() == "remittance_id"));
+    assert!(schema.entry_point("get_schema").is_some());
+    assert!(schema.event("RemittanceCreated").is_some());
+    assert_eq!(schema.events.len(), ContractEvent::schemas().len());
+    assert_eq!(ctx.event_schema_version(), EVENT_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_reinstall_is_refused() {
+    let mut ctx = TestContext::new();
+    let owner = ctx.owner;
+
+    assert_eq!(
+        ctx.session(owner, CONTRACT_WASM, RuntimeArgs::new()),
+        Err(Error::AlreadyInitialized)
+    );
+    assert_eq!(
+        ctx.call(owner, "init", RuntimeArgs::new()),
+        Err(Error::AlreadyInitialized)
+    );
+}
+
+#[test]
+fn test_build_info_recorded_at_install() {
+    let ctx = TestContext::new();
+    let build = ctx.build_info();
+
+    assert_eq!(build.version, "1.0.0");
+    assert!(!build.git_hash.is_empty());
+}
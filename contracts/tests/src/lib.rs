@@ -0,0 +1,16 @@
+//! Execution-engine tests for the CasperFlow contract.
+//!
+//! The tests install the release wasm into an in-memory network, so build it
+//! first and enable the `test-support` feature on a host target:
+//!
+//! ```bash
+//! make build
+//! cargo test -p casperflow-tests --features test-support --target x86_64-unknown-linux-gnu
+//! ```
+
+#![cfg(feature = "test-support")]
+
+pub mod fixture;
+
+#[cfg(test)]
+mod integration_tests;
@@ -0,0 +1,605 @@
+//! Subcommand implementations.
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use casper_client::types::Deploy;
+use casper_types::{PublicKey, SecretKey, U512};
+use casperflow_client::{
+    calls::{
+        AcknowledgeReceipt, ApproveReleaseAsRecipient, BlockPurpose, CancelBridgePayout,
+        ClaimAllRefunds, ClaimFeeCover, ClaimInsurance, ClaimRefund, ContributeSession,
+        ContributeSimple, CreateNamespace, CreateRemittance, CreateSavingsPool, DeclineRelease,
+        ExpireRemittance, ExtendDeadline, LowerMaxFee, PauseContract, PostUpdate,
+        ProcessInstallment, RegisterHandle, RejectRemittance, ReleaseFunds, ReleaseSimple,
+        ReleaseToBackup, ReleaseToPartner, ReleaseToPayoutTarget, RemovePayoutTarget,
+        RenounceAdmin, RequestBridgePayout, SetBranding, SetCategory, SetGasLimits,
+        SetInsurancePremium, SetKybMaxTargetAmount, SetLocalizedPurpose, SetMaxDeadlineExtension,
+        SetMaxTargetAmount, SetMinTargetAmount, SetPaymentGuards, SetPayoutTarget, SetPlatformFee,
+        SetPlatformFeePpm, SetRateLimits, SetRefundDelegate, SetSlaIntervals,
+        SnapshotContributions, UnblockPurpose, UnpauseContract, WithdrawPot,
+    },
+    CasperFlowClient, Installer,
+};
+use casperflow_types::{
+    errors::{MILLIS_PER_DAY, MILLIS_PER_HOUR},
+    purpose_hash,
+    remittance::RefundPolicyKind,
+};
+
+use crate::{parse, profile, AdminCommand, Cli, Command, CreateArgs, Result};
+
+/// Interval between execution status polls
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long to wait for a deploy to execute
+const EXECUTION_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Runs the parsed command line
+pub async fn run(cli: Cli) -> Result<()> {
+    let config = cli.config.unwrap_or_else(profile::default_path);
+    let profile = profile::load(&config, &cli.profile)?;
+    let secret_key = SecretKey::from_file(&profile.secret_key).map_err(|error| {
+        format!(
+            "failed to read secret key {}: {}",
+            profile.secret_key.display(),
+            error
+        )
+    })?;
+    let payment = U512::from(profile.payment_amount);
+
+    if let Command::Deploy {
+        wasm,
+        payment,
+        max_fee_ppm,
+        fee_on_contribution,
+    } = &cli.command
+    {
+        let mut installer = Installer::new(&profile.node_address, &profile.chain_name);
+        if let Some(max_fee_ppm) = *max_fee_ppm {
+            installer = installer.with_max_fee_ppm(max_fee_ppm);
+        }
+        if *fee_on_contribution {
+            installer = installer.with_fee_on_contribution();
+        }
+        let deploy = installer.install_deploy(read_file(wasm)?, *payment, &secret_key)?;
+        println!("deploy hash: {}", deploy.id());
+        if cli.no_wait {
+            return Ok(());
+        }
+
+        let cost = installer
+            .send_and_wait(deploy, POLL_INTERVAL, EXECUTION_TIMEOUT)
+            .await?;
+        println!("cost: {} motes", cost);
+        match installer
+            .installed_contract_hash(PublicKey::from(&secret_key))
+            .await?
+        {
+            Some(contract_hash) => println!("contract hash: {}", contract_hash),
+            None => println!("contract hash not found in installer named keys"),
+        }
+        return Ok(());
+    }
+
+    let contract_hash = profile
+        .contract_hash
+        .as_deref()
+        .ok_or_else(|| format!("profile '{}' has no contract_hash", cli.profile))?;
+    let client = CasperFlowClient::new(
+        &profile.node_address,
+        &profile.chain_name,
+        parse::contract_hash(contract_hash)?,
+    );
+
+    let deploy = match cli.command {
+        Command::Deploy { .. } => unreachable!("handled above"),
+        Command::Create(args) => client.call_deploy(&create_call(args), payment, &secret_key)?,
+        Command::CreatePool {
+            members,
+            contribution,
+            cycle_days,
+            purpose,
+        } => {
+            let call = CreateSavingsPool {
+                members,
+                contribution,
+                cycle_days,
+                purpose,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Contribute {
+            id,
+            amount,
+            simple: true,
+            ..
+        } => {
+            let call = ContributeSimple {
+                remittance_id: id,
+                amount,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Contribute {
+            id,
+            amount,
+            commitment,
+            blinding,
+            idempotency_key,
+            fee_cover,
+            session_wasm,
+            ..
+        } => {
+            let session_wasm = session_wasm.or(profile.contribute_session).ok_or(
+                "no contribute session wasm; set contribute_session or pass --session-wasm",
+            )?;
+            let mut contribution = ContributeSession::new(
+                read_file(&session_wasm)?,
+                client.contract_hash(),
+                id,
+                amount,
+            );
+            if let (Some(commitment), Some(blinding)) = (commitment, blinding) {
+                contribution = contribution.with_amount_commitment(commitment, blinding);
+            }
+            if let Some(idempotency_key) = idempotency_key {
+                contribution = contribution.with_idempotency_key(idempotency_key);
+            }
+            if fee_cover {
+                contribution = contribution.fee_cover();
+            }
+            client.contribute_deploy(&contribution, payment, &secret_key)?
+        }
+        Command::Handle { id, handle } => {
+            let call = RegisterHandle {
+                remittance: id.into(),
+                handle,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Update { id, message } => {
+            let call = PostUpdate {
+                remittance: id.into(),
+                message,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Translate { id, lang, purpose } => {
+            let call = SetLocalizedPurpose {
+                remittance: id.into(),
+                lang,
+                purpose,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Release {
+            id,
+            partner,
+            reference,
+            payout_target,
+            simple,
+        } => match (partner, reference, payout_target) {
+            (Some(partner), Some(reference_code), _) => {
+                let call = ReleaseToPartner {
+                    remittance: id.into(),
+                    partner,
+                    reference_code,
+                };
+                client.call_deploy(&call, payment, &secret_key)?
+            }
+            (_, _, Some(label)) => {
+                let call = ReleaseToPayoutTarget {
+                    remittance: id.into(),
+                    label,
+                };
+                client.call_deploy(&call, payment, &secret_key)?
+            }
+            _ if simple => {
+                let call = ReleaseSimple { remittance_id: id };
+                client.call_deploy(&call, payment, &secret_key)?
+            }
+            _ => {
+                let call = ReleaseFunds {
+                    remittance: id.into(),
+                };
+                client.call_deploy(&call, payment, &secret_key)?
+            }
+        },
+        Command::Acknowledge { id, message } => {
+            let call = AcknowledgeReceipt {
+                remittance: id.into(),
+                message,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::SetPayoutTarget { label, target } => {
+            let call = SetPayoutTarget { label, target };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::RemovePayoutTarget { label } => {
+            let call = RemovePayoutTarget { label };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::ReleaseBackup { id } => {
+            let call = ReleaseToBackup {
+                remittance: id.into(),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::ApproveRelease { id } => {
+            let call = ApproveReleaseAsRecipient {
+                remittance: id.into(),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Reject { id } => {
+            let call = RejectRemittance {
+                remittance: id.into(),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Decline { id, reason } => {
+            let call = DeclineRelease {
+                remittance: id.into(),
+                reason,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Bridge { id, chain, address } => {
+            let call = RequestBridgePayout {
+                remittance: id.into(),
+                chain_id: chain,
+                foreign_address: address,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::CancelBridge { id } => {
+            let call = CancelBridgePayout {
+                remittance: id.into(),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Extend { id, deadline } => {
+            let call = ExtendDeadline {
+                remittance: id.into(),
+                new_deadline: deadline,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Expire { id } => {
+            let call = ExpireRemittance {
+                remittance: id.into(),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Installment { id } => {
+            let call = ProcessInstallment {
+                remittance: id.into(),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Withdraw { id } => {
+            let call = WithdrawPot {
+                remittance: id.into(),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Refund {
+            id,
+            excess,
+            amount,
+            blinding,
+            contributor,
+        } => {
+            let mut call = ClaimRefund::new(id);
+            if excess {
+                call = call.excess();
+            }
+            if let (Some(amount), Some(blinding)) = (amount, blinding) {
+                call = call.with_opening(amount, blinding);
+            }
+            if let Some(contributor) = contributor {
+                call = call.on_behalf_of(contributor);
+            }
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::SetRefundDelegate { delegate } => {
+            client.call_deploy(&SetRefundDelegate { delegate }, payment, &secret_key)?
+        }
+        Command::RefundAll { max } => {
+            client.call_deploy(&ClaimAllRefunds { max_items: max }, payment, &secret_key)?
+        }
+        Command::ClaimInsurance { id } => {
+            let call = ClaimInsurance {
+                remittance: id.into(),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::ClaimFeeCover { id } => {
+            let call = ClaimFeeCover {
+                remittance: id.into(),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Snapshot { id } => {
+            let call = SnapshotContributions {
+                remittance: id.into(),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Stats { day, corridor } => {
+            return print_stats(&client, day, corridor.as_deref()).await;
+        }
+        Command::Version => return print_version(&client).await,
+        Command::Admin(AdminCommand::SetFee { bps, ppm }) => match (bps, ppm) {
+            (Some(fee_bps), _) => {
+                client.call_deploy(&SetPlatformFee { fee_bps }, payment, &secret_key)?
+            }
+            (None, Some(fee_ppm)) => {
+                client.call_deploy(&SetPlatformFeePpm { fee_ppm }, payment, &secret_key)?
+            }
+            (None, None) => return Err("pass --bps or --ppm".into()),
+        },
+        Command::Admin(AdminCommand::LowerMaxFee { ppm }) => {
+            client.call_deploy(&LowerMaxFee { max_fee_ppm: ppm }, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::SetRateLimits {
+            window_secs,
+            creations,
+            contributions,
+        }) => {
+            let call = SetRateLimits {
+                window_ms: window_secs.saturating_mul(1_000),
+                max_creations: creations,
+                max_contributions: contributions,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::SetGasLimits {
+            batch,
+            page,
+            contributors,
+        }) => {
+            let call = SetGasLimits {
+                max_batch_size: batch,
+                max_page_size: page,
+                max_contributors: contributors,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::SetPaymentGuards {
+            reject_self,
+            recipient_contributions,
+        }) => {
+            let call = SetPaymentGuards {
+                reject_self_remittance: reject_self,
+                recipient_contributions,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::SetMaxExtension { days }) => {
+            let call = SetMaxDeadlineExtension {
+                max_extension_ms: days.saturating_mul(MILLIS_PER_DAY),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::SetMaxTarget { amount }) => {
+            let call = SetMaxTargetAmount {
+                max_target_amount: amount,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::SetMinTarget { amount }) => {
+            let call = SetMinTargetAmount {
+                min_target_amount: amount,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::SetKybMaxTarget { amount }) => {
+            let call = SetKybMaxTargetAmount {
+                kyb_max_target_amount: amount,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::BlockPurpose { purpose }) => {
+            let call = BlockPurpose {
+                purpose_hash: purpose_hash(&purpose),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::UnblockPurpose { purpose }) => {
+            let call = UnblockPurpose {
+                purpose_hash: purpose_hash(&purpose),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::SetBranding {
+            name,
+            logo_cid,
+            support_url,
+            currency,
+        }) => {
+            let call = SetBranding {
+                platform_name: name,
+                logo_cid,
+                support_url,
+                currency_display: currency,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::CreateNamespace {
+            name,
+            owner,
+            fee_ppm,
+        }) => {
+            let call = CreateNamespace {
+                namespace: name,
+                namespace_owner: owner,
+                fee_ppm,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::SetCategory {
+            name,
+            deadline_days,
+            fee_bps,
+            refund_excess,
+        }) => {
+            let call = SetCategory {
+                category: name,
+                deadline_days,
+                fee_bps,
+                refund_excess,
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::SetSlaIntervals { hours }) => {
+            let call = SetSlaIntervals {
+                intervals_ms: hours
+                    .into_iter()
+                    .map(|h| h.saturating_mul(MILLIS_PER_HOUR))
+                    .collect(),
+            };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::SetInsurancePremium { bps }) => {
+            let call = SetInsurancePremium { premium_bps: bps };
+            client.call_deploy(&call, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::Pause) => {
+            client.call_deploy(&PauseContract {}, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::Unpause) => {
+            client.call_deploy(&UnpauseContract {}, payment, &secret_key)?
+        }
+        Command::Admin(AdminCommand::RenounceAdmin) => {
+            client.call_deploy(&RenounceAdmin {}, payment, &secret_key)?
+        }
+    };
+
+    send(&client, deploy, cli.no_wait).await
+}
+
+fn create_call(args: CreateArgs) -> CreateRemittance {
+    let mut call = match args.amount {
+        Some(amount) => CreateRemittance::new(args.recipient, amount, args.purpose),
+        None => CreateRemittance::open_ended(args.recipient, args.purpose),
+    };
+    if let Some(key_salt) = args.private {
+        call = call.private(key_salt);
+    }
+    if args.confidential {
+        call = call.confidential();
+    }
+    if args.refund_excess {
+        call = call.refund_excess();
+    }
+    if let Some(commitment) = args.purpose_commitment {
+        call = call.with_purpose_commitment(commitment);
+    }
+    if let Some(reference) = args.reference {
+        call = call.with_external_reference(reference);
+    }
+    if let Some(corridor) = args.corridor {
+        call = call.with_corridor(corridor);
+    }
+    if let Some(deadline) = args.deadline {
+        call = call.with_deadline(deadline);
+    }
+    if let Some(starts_at) = args.starts_at {
+        call = call.with_start_time(starts_at);
+    }
+    if let Some(max_contributors) = args.max_contributors {
+        call = call.with_max_contributors(max_contributors);
+    }
+    if let Some(share_bps) = args.instant_share_bps {
+        call = call.with_instant_share(share_bps);
+    }
+    if let (Some(backup), Some(grace_days)) = (args.backup, args.backup_grace_days) {
+        call = call.with_backup_recipient(backup, grace_days);
+    }
+    if let (Some(amount), Some(days)) = (args.installment, args.installment_interval_days) {
+        call = call.with_installments(amount, days);
+    }
+    if let Some(threshold) = args.recipient_threshold {
+        call = call.with_recipient_signers(args.recipient_signers, threshold);
+    }
+    if args.insured {
+        call = call.insured();
+    }
+    if let Some(fee_bps) = args.refund_fee_bps {
+        call = call.with_refund_policy(RefundPolicyKind::ProcessingFee, fee_bps);
+    }
+    if let Some(decay_bps) = args.refund_decay_bps {
+        call = call.with_refund_policy(RefundPolicyKind::TimeDecayed, decay_bps);
+    }
+    if let Some(namespace) = args.namespace {
+        call = call.in_namespace(namespace);
+    }
+    if let Some(category) = args.category {
+        call = call.in_category(category);
+    }
+    call
+}
+
+/// Sends a deploy, then unless `no_wait` waits for it and prints its events
+async fn send(client: &CasperFlowClient, deploy: Deploy, no_wait: bool) -> Result<()> {
+    let deploy_hash = client.put_deploy(deploy).await?;
+    println!("deploy hash: {}", deploy_hash);
+    if no_wait {
+        return Ok(());
+    }
+
+    let cost = client
+        .wait_for_deploy(deploy_hash, POLL_INTERVAL, EXECUTION_TIMEOUT)
+        .await?;
+    println!("cost: {} motes", cost);
+    for event in client.get_deploy_events(deploy_hash).await? {
+        println!("event: {:?}", event);
+    }
+    Ok(())
+}
+
+async fn print_stats(
+    client: &CasperFlowClient,
+    day: Option<u64>,
+    corridor: Option<&str>,
+) -> Result<()> {
+    let day = match day {
+        Some(day) => day,
+        None => SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64 / MILLIS_PER_DAY,
+    };
+    let stats = client.get_period_stats(day, corridor).await?;
+
+    println!("day: {}", day);
+    if let Some(corridor) = corridor {
+        println!("corridor: {}", corridor);
+    }
+    println!(
+        "created: {} ({} motes)",
+        stats.created_count, stats.created_volume
+    );
+    println!(
+        "released: {} ({} motes)",
+        stats.released_count, stats.released_volume
+    );
+    println!("fees: {} motes", stats.fees);
+    Ok(())
+}
+
+async fn print_version(client: &CasperFlowClient) -> Result<()> {
+    match client.get_version().await? {
+        Some(build) => {
+            println!("version: {}", build.version);
+            println!("git commit: {}", build.git_hash);
+        }
+        None => println!("installed contract predates build metadata"),
+    }
+    Ok(())
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>> {
+    fs::read(path).map_err(|error| format!("failed to read {}: {}", path.display(), error).into())
+}
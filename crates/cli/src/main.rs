@@ -0,0 +1,663 @@
+//! `casperflow` - command-line interface for the CasperFlow remittance contract.
+//!
+//! Network, key, and contract settings come from a profile file (see
+//! [`profile`]); each subcommand builds a deploy with `casperflow-client`,
+//! sends it, and by default waits for it to execute.
+
+mod commands;
+mod parse;
+mod profile;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use casper_types::{account::AccountHash, U512};
+use clap::{Args, Parser, Subcommand};
+
+/// Result type for CLI commands
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[derive(Parser)]
+#[command(
+    name = "casperflow",
+    version,
+    about = "Operate the CasperFlow remittance contract"
+)]
+struct Cli {
+    /// Profile file [default: ~/.casperflow/profiles.toml]
+    #[arg(long, global = true, env = "CASPERFLOW_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// Profile to use from the profile file
+    #[arg(long, short, global = true, default_value = "default")]
+    profile: String,
+
+    /// Print the deploy hash without waiting for execution
+    #[arg(long, global = true)]
+    no_wait: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Install the contract and print its hash
+    Deploy {
+        /// Compiled contract wasm
+        #[arg(long)]
+        wasm: PathBuf,
+
+        /// Gas payment in motes
+        #[arg(long, value_parser = parse::motes, default_value = "150000000000")]
+        payment: U512,
+
+        /// Cap on the platform fee in parts per million (default 50000, 5%)
+        #[arg(long)]
+        max_fee_ppm: Option<u64>,
+
+        /// Charge the platform fee on each contribution instead of on release
+        #[arg(long)]
+        fee_on_contribution: bool,
+    },
+
+    /// Create a remittance
+    Create(CreateArgs),
+
+    /// Create a rotating savings pool that pays each member in turn
+    CreatePool {
+        /// Member account (repeat for each member, in payout order)
+        #[arg(long = "member", value_parser = parse::account, required = true)]
+        members: Vec<AccountHash>,
+
+        /// Amount each member pays per cycle, in motes
+        #[arg(long, value_parser = parse::motes)]
+        contribution: U512,
+
+        /// Length of each cycle in days
+        #[arg(long)]
+        cycle_days: u64,
+
+        /// Purpose shown on every cycle
+        #[arg(long)]
+        purpose: String,
+    },
+
+    /// Contribute to a remittance from the profile account
+    Contribute {
+        /// Remittance ID
+        id: u64,
+
+        /// Amount in motes
+        #[arg(long, value_parser = parse::motes)]
+        amount: U512,
+
+        /// Amount commitment for a confidential remittance (hex)
+        #[arg(long, value_parser = parse::bytes32, requires = "blinding")]
+        commitment: Option<[u8; 32]>,
+
+        /// Blinding factor of the commitment (hex)
+        #[arg(long, value_parser = parse::bytes32, requires = "commitment")]
+        blinding: Option<[u8; 32]>,
+
+        /// Key rejecting a retry of this contribution (hex); reuse it when
+        /// resending
+        #[arg(long, value_parser = parse::bytes32)]
+        idempotency_key: Option<[u8; 32]>,
+
+        /// Pre-fund the platform fee instead, so the recipient receives the
+        /// full amount (creator or recipient only)
+        #[arg(long, conflicts_with_all = ["commitment", "blinding", "idempotency_key"])]
+        fee_cover: bool,
+
+        /// Send through `contribute_simple`, without session wasm, so a
+        /// hardware wallet can show every argument
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "commitment",
+                "blinding",
+                "idempotency_key",
+                "fee_cover",
+                "session_wasm",
+            ]
+        )]
+        simple: bool,
+
+        /// Contribute session wasm, overriding the profile's
+        #[arg(long)]
+        session_wasm: Option<PathBuf>,
+    },
+
+    /// Register a shareable handle for a remittance you created
+    Handle {
+        /// Remittance ID
+        id: u64,
+
+        /// Handle, e.g. maria-surgery-2025
+        handle: String,
+    },
+
+    /// Post a progress update on a remittance you created
+    Update {
+        /// Remittance ID
+        id: u64,
+
+        /// Update text, e.g. "funds will buy the wheelchair Friday"
+        message: String,
+    },
+
+    /// Add a translation of a remittance's purpose
+    Translate {
+        /// Remittance ID
+        id: u64,
+
+        /// Language code, e.g. "fr" or "pt-br"
+        lang: String,
+
+        /// Translated purpose; leave empty to remove the translation
+        purpose: String,
+    },
+
+    /// Release a funded remittance to its recipient
+    Release {
+        /// Remittance ID
+        id: u64,
+
+        /// Settlement partner to collect the payout from in cash
+        #[arg(long, value_parser = parse::account, requires = "reference")]
+        partner: Option<AccountHash>,
+
+        /// Pickup code to present to the settlement partner
+        #[arg(long, requires = "partner")]
+        reference: Option<String>,
+
+        /// Label of a payout address book entry to pay instead
+        #[arg(long, conflicts_with = "partner")]
+        payout_target: Option<String>,
+
+        /// Send through `release_simple`, with the ID as the only argument
+        #[arg(long, conflicts_with_all = ["partner", "payout_target"])]
+        simple: bool,
+    },
+
+    /// Confirm that a released remittance addressed to you arrived
+    Acknowledge {
+        /// Remittance ID
+        id: u64,
+
+        /// Thank-you or usage note, e.g. "surgery done, thank you all"
+        message: String,
+    },
+
+    /// Add or repoint an entry in your payout address book
+    SetPayoutTarget {
+        /// Name of the entry, e.g. "savings"
+        label: String,
+
+        /// Account paid when the label is chosen at release
+        #[arg(value_parser = parse::account)]
+        target: AccountHash,
+    },
+
+    /// Remove an entry from your payout address book
+    RemovePayoutTarget {
+        /// Name of the entry
+        label: String,
+    },
+
+    /// Release a remittance you are the backup recipient of to yourself
+    ReleaseBackup {
+        /// Remittance ID
+        id: u64,
+    },
+
+    /// Approve a release as one of a multi-signature recipient's signers
+    ApproveRelease {
+        /// Remittance ID
+        id: u64,
+    },
+
+    /// Decline a remittance addressed to you and enable refunds
+    Reject {
+        /// Remittance ID
+        id: u64,
+    },
+
+    /// Refuse a funded remittance addressed to you, telling contributors
+    /// why, and enable refunds
+    Decline {
+        /// Remittance ID
+        id: u64,
+
+        /// Why the funds are refused (max 280 characters)
+        #[arg(long)]
+        reason: String,
+    },
+
+    /// Ask for a funded remittance addressed to you to be paid out on
+    /// another chain
+    Bridge {
+        /// Remittance ID
+        id: u64,
+
+        /// Destination chain ID (e.g. "eip155:1")
+        #[arg(long)]
+        chain: String,
+
+        /// Payout address on the destination chain
+        #[arg(long)]
+        address: String,
+    },
+
+    /// Withdraw a bridge payout the operator has not confirmed, unlocking
+    /// the remittance
+    CancelBridge {
+        /// Remittance ID
+        id: u64,
+    },
+
+    /// Push back the deadline of a remittance you created
+    Extend {
+        /// Remittance ID
+        id: u64,
+
+        /// New block time (Unix ms) after which the remittance can be expired
+        #[arg(long)]
+        deadline: u64,
+    },
+
+    /// Expire a remittance past its deadline with its target unmet
+    Expire {
+        /// Remittance ID
+        id: u64,
+    },
+
+    /// Pay the next due installment of a scheduled payout
+    Installment {
+        /// Remittance ID
+        id: u64,
+    },
+
+    /// Withdraw what an open-ended pot holds, leaving it open
+    Withdraw {
+        /// Remittance ID
+        id: u64,
+    },
+
+    /// Claim a refund from a cancelled or expired remittance
+    Refund {
+        /// Remittance ID
+        id: u64,
+
+        /// Claim the share of the excess over target of a released remittance
+        #[arg(long)]
+        excess: bool,
+
+        /// Contributed amount, to open a confidential commitment
+        #[arg(long, value_parser = parse::motes, requires = "blinding")]
+        amount: Option<U512>,
+
+        /// Blinding factor of the commitment (hex)
+        #[arg(long, value_parser = parse::bytes32, requires = "amount")]
+        blinding: Option<[u8; 32]>,
+
+        /// Claim for this contributor as their refund delegate
+        #[arg(long = "for", value_parser = parse::account, conflicts_with = "excess")]
+        contributor: Option<AccountHash>,
+    },
+
+    /// Let another account claim your refunds for you; the refunds are still
+    /// paid to you
+    SetRefundDelegate {
+        /// Account to authorize; omit to revoke the current delegate
+        #[arg(value_parser = parse::account)]
+        delegate: Option<AccountHash>,
+    },
+
+    /// Claim your refunds from every cancelled or expired remittance, except
+    /// confidential ones
+    RefundAll {
+        /// Most refunds to claim in this deploy (at most 100)
+        #[arg(long, default_value_t = 20)]
+        max: u64,
+    },
+
+    /// Claim an approved insurance top-up from a failed insured remittance
+    ClaimInsurance {
+        /// Remittance ID
+        id: u64,
+    },
+
+    /// Claim back the unused fee cover you pre-funded on a cancelled or
+    /// expired remittance
+    ClaimFeeCover {
+        /// Remittance ID
+        id: u64,
+    },
+
+    /// Freeze the contributor amounts of a remittance for an airdrop
+    Snapshot {
+        /// Remittance ID
+        id: u64,
+    },
+
+    /// Show created and released totals for a day
+    Stats {
+        /// Day index (days since the Unix epoch, UTC) [default: today]
+        #[arg(long)]
+        day: Option<u64>,
+
+        /// Narrow the totals to one corridor
+        #[arg(long)]
+        corridor: Option<String>,
+    },
+
+    /// Show the version and git commit of the installed contract
+    Version,
+
+    /// Owner operations
+    #[command(subcommand)]
+    Admin(AdminCommand),
+}
+
+#[derive(Args)]
+struct CreateArgs {
+    /// Recipient account hash or public key
+    #[arg(long, value_parser = parse::account)]
+    recipient: AccountHash,
+
+    /// Target amount in motes; omit for an open-ended pot
+    #[arg(long, value_parser = parse::motes)]
+    amount: Option<U512>,
+
+    /// Purpose of the remittance
+    #[arg(long, default_value = "")]
+    purpose: String,
+
+    /// Create under a non-enumerable key, salted with this random secret
+    /// (hex)
+    #[arg(long, value_parser = parse::bytes32)]
+    private: Option<[u8; 32]>,
+
+    /// Record contributions as amount commitments only
+    #[arg(long)]
+    confidential: bool,
+
+    /// Release only the target and let contributors reclaim the excess
+    #[arg(long)]
+    refund_excess: bool,
+
+    /// Commitment to a purpose revealed later (hex)
+    #[arg(long, value_parser = parse::bytes32)]
+    purpose_commitment: Option<[u8; 32]>,
+
+    /// Creator-scoped external reference, e.g. an invoice number
+    #[arg(long)]
+    reference: Option<String>,
+
+    /// Corridor code for reporting, e.g. US-PH
+    #[arg(long)]
+    corridor: Option<String>,
+
+    /// Block time (Unix ms) after which the remittance can be expired
+    #[arg(long)]
+    deadline: Option<u64>,
+
+    /// Block time (Unix ms) before which contributions are rejected
+    #[arg(long)]
+    starts_at: Option<u64>,
+
+    /// Maximum number of unique contributors
+    #[arg(long)]
+    max_contributors: Option<u64>,
+
+    /// Share of each contribution paid to the recipient immediately, in
+    /// basis points; the rest is escrowed
+    #[arg(long)]
+    instant_share_bps: Option<u64>,
+
+    /// Backup recipient who may release if the recipient has not
+    #[arg(long, value_parser = parse::account, requires = "backup_grace_days")]
+    backup: Option<AccountHash>,
+
+    /// Days after funding before the backup recipient may release
+    #[arg(long, requires = "backup")]
+    backup_grace_days: Option<u64>,
+
+    /// Pay the recipient this much per installment once funded, instead of
+    /// a single release
+    #[arg(long, value_parser = parse::motes, requires = "installment_interval_days")]
+    installment: Option<U512>,
+
+    /// Days between installments
+    #[arg(long, requires = "installment")]
+    installment_interval_days: Option<u64>,
+
+    /// Account that must approve releases (repeat for each signer,
+    /// including the recipient)
+    #[arg(
+        long = "recipient-signer",
+        value_parser = parse::account,
+        requires = "recipient_threshold"
+    )]
+    recipient_signers: Vec<AccountHash>,
+
+    /// Number of signer approvals a release needs
+    #[arg(long, requires = "recipient_signers")]
+    recipient_threshold: Option<u64>,
+
+    /// Pay the insurance premium on each contribution
+    #[arg(long)]
+    insured: bool,
+
+    /// Keep a flat processing fee from each refund, in basis points
+    #[arg(long, conflicts_with = "refund_decay_bps")]
+    refund_fee_bps: Option<u64>,
+
+    /// Keep this many basis points of each refund per day since creation
+    #[arg(long)]
+    refund_decay_bps: Option<u64>,
+
+    /// Namespace to create the remittance in
+    #[arg(long)]
+    namespace: Option<String>,
+
+    /// Category whose defaults fill in options not given here
+    #[arg(long)]
+    category: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum AdminCommand {
+    /// Set the platform fee
+    SetFee {
+        /// Fee in basis points
+        #[arg(long, conflicts_with = "ppm", required_unless_present = "ppm")]
+        bps: Option<u64>,
+
+        /// Fee in parts per million
+        #[arg(long)]
+        ppm: Option<u64>,
+    },
+
+    /// Lower the platform fee cap; it can never be raised again
+    LowerMaxFee {
+        /// New cap in parts per million
+        #[arg(long)]
+        ppm: u64,
+    },
+
+    /// Limit creations and contributions per account; 0 disables a limit
+    SetRateLimits {
+        /// Rolling window in seconds
+        #[arg(long)]
+        window_secs: u64,
+
+        /// Remittances one account may create per window
+        #[arg(long, default_value_t = 0)]
+        creations: u64,
+
+        /// Contributions one account may make per window
+        #[arg(long, default_value_t = 0)]
+        contributions: u64,
+    },
+
+    /// Bound the work one call may do; 0 restores a default
+    SetGasLimits {
+        /// Most remittances or refunds one batch call may process (max 100)
+        #[arg(long, default_value_t = 0)]
+        batch: u64,
+
+        /// Most items one paginated view may return (max 100)
+        #[arg(long, default_value_t = 0)]
+        page: u64,
+
+        /// Most contributors one call may process for a remittance
+        #[arg(long, default_value_t = 0)]
+        contributors: u64,
+    },
+
+    /// Guard against self-remittances and recipients funding their own remittances
+    SetPaymentGuards {
+        /// Reject remittances whose creator is also the recipient
+        #[arg(long)]
+        reject_self: bool,
+
+        /// Contributions from the recipient: 0 = allow, 1 = flag, 2 = reject
+        #[arg(long, default_value_t = 0)]
+        recipient_contributions: u8,
+    },
+
+    /// Cap how far creators may extend a deadline in total; 0 disables
+    SetMaxExtension {
+        /// Maximum total extension in days
+        #[arg(long)]
+        days: u64,
+    },
+
+    /// Cap the target amount of new remittances; 0 removes the cap
+    SetMaxTarget {
+        /// Maximum target in motes
+        #[arg(long, value_parser = parse::motes)]
+        amount: U512,
+    },
+
+    /// Reject new remittances with a smaller target; 0 removes the minimum
+    SetMinTarget {
+        /// Minimum target in motes
+        #[arg(long, value_parser = parse::motes)]
+        amount: U512,
+    },
+
+    /// Let remittances to KYB-verified businesses exceed the target cap; 0
+    /// removes the higher cap
+    SetKybMaxTarget {
+        /// Maximum target in motes
+        #[arg(long, value_parser = parse::motes)]
+        amount: U512,
+    },
+
+    /// Reject new remittances with this purpose, ignoring case and spacing
+    /// (compliance officer only)
+    BlockPurpose {
+        /// Purpose text of the abusive template
+        purpose: String,
+    },
+
+    /// Lift a purpose block (compliance officer only)
+    UnblockPurpose {
+        /// Purpose text of the blocked template
+        purpose: String,
+    },
+
+    /// Set how frontends should present this instance
+    SetBranding {
+        /// Name shown for the platform
+        #[arg(long)]
+        name: String,
+
+        /// IPFS CID of the platform logo
+        #[arg(long, default_value = "")]
+        logo_cid: String,
+
+        /// Where users can get help
+        #[arg(long, default_value = "")]
+        support_url: String,
+
+        /// How amounts are labelled, e.g. "CSPR"
+        #[arg(long, default_value = "")]
+        currency: String,
+    },
+
+    /// Create a namespace with its own owner and fee
+    CreateNamespace {
+        /// Namespace name: lowercase letters, digits and hyphens
+        #[arg(long)]
+        name: String,
+
+        /// Account hash or public key of the namespace owner
+        #[arg(long, value_parser = parse::account)]
+        owner: AccountHash,
+
+        /// Fee in parts per million, paid to the namespace owner
+        #[arg(long, default_value_t = 0)]
+        fee_ppm: u64,
+    },
+
+    /// Set the defaults applied to remittances created in a category
+    SetCategory {
+        /// Category name: lowercase letters, digits and hyphens
+        #[arg(long)]
+        name: String,
+
+        /// Days from creation until the deadline; 0 for none
+        #[arg(long, default_value_t = 0)]
+        deadline_days: u64,
+
+        /// Fee in basis points; the global or namespace fee applies if omitted
+        #[arg(long)]
+        fee_bps: Option<u64>,
+
+        /// Refund contributions beyond the target by default
+        #[arg(long)]
+        refund_excess: bool,
+    },
+
+    /// Set when funded remittances left unreleased are escalated
+    SetSlaIntervals {
+        /// Hours after funding for each escalation, comma-separated; empty
+        /// to disable
+        #[arg(long, value_delimiter = ',')]
+        hours: Vec<u64>,
+    },
+
+    /// Set the premium charged on new insured remittances
+    SetInsurancePremium {
+        /// Premium in basis points
+        #[arg(long)]
+        bps: u64,
+    },
+
+    /// Pause the contract
+    Pause,
+
+    /// Unpause the contract
+    Unpause,
+
+    /// Permanently give up fee changes, pausing, and upgrades
+    RenounceAdmin,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match commands::run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Parsers for command-line values.
+
+use casper_types::{account::AccountHash, AsymmetricType, ContractHash, Key, PublicKey, U512};
+
+/// Parses an amount in motes
+pub fn motes(value: &str) -> Result<U512, String> {
+    U512::from_dec_str(value).map_err(|_| format!("invalid amount in motes: {}", value))
+}
+
+/// Parses an `account-hash-...` string or a hex public key
+pub fn account(value: &str) -> Result<AccountHash, String> {
+    if let Ok(account_hash) = AccountHash::from_formatted_str(value) {
+        return Ok(account_hash);
+    }
+    PublicKey::from_hex(value)
+        .map(|public_key| public_key.to_account_hash())
+        .map_err(|_| format!("expected account-hash-... or a hex public key: {}", value))
+}
+
+/// Parses a `hash-...` or `contract-...` contract hash
+pub fn contract_hash(value: &str) -> Result<ContractHash, String> {
+    if let Some(hash) = Key::from_formatted_str(value)
+        .ok()
+        .and_then(|key| key.into_hash())
+    {
+        return Ok(ContractHash::new(hash));
+    }
+    ContractHash::from_formatted_str(value)
+        .map_err(|_| format!("expected hash-... or contract-...: {}", value))
+}
+
+/// Parses 32 hex-encoded bytes
+pub fn bytes32(value: &str) -> Result<[u8; 32], String> {
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    if value.len() != 64 || !value.is_ascii() {
+        return Err(format!("expected 32 hex-encoded bytes: {}", value));
+    }
+
+    let mut bytes = [0u8; 32];
+    for (index, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[index * 2..index * 2 + 2], 16)
+            .map_err(|_| format!("expected 32 hex-encoded bytes: {}", value))?;
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEX: &str = "0101010101010101010101010101010101010101010101010101010101010101";
+
+    #[test]
+    fn test_account() {
+        let formatted = format!("account-hash-{}", HEX);
+        assert_eq!(account(&formatted).unwrap(), AccountHash::new([1u8; 32]));
+        assert!(account("alice").is_err());
+    }
+
+    #[test]
+    fn test_contract_hash() {
+        let expected = ContractHash::new([1u8; 32]);
+        assert_eq!(contract_hash(&format!("hash-{}", HEX)).unwrap(), expected);
+        assert_eq!(
+            contract_hash(&format!("contract-{}", HEX)).unwrap(),
+            expected
+        );
+        assert!(contract_hash(HEX).is_err());
+    }
+
+    #[test]
+    fn test_bytes32() {
+        assert_eq!(bytes32(HEX).unwrap(), [1u8; 32]);
+        assert_eq!(bytes32(&format!("0x{}", HEX)).unwrap(), [1u8; 32]);
+        assert!(bytes32("01").is_err());
+    }
+
+    #[test]
+    fn test_motes() {
+        assert_eq!(motes("2500000000").unwrap(), U512::from(2_500_000_000u64));
+        assert!(motes("-1").is_err());
+    }
+}
@@ -0,0 +1,116 @@
+//! Network and key configuration read from a profile file.
+//!
+//! The file holds one TOML table per profile:
+//!
+//! ```toml
+//! [default]
+//! node_address = "https://node.testnet.casper.network"
+//! chain_name = "casper-test"
+//! secret_key = "keys/secret_key.pem"
+//! contract_hash = "hash-0123..."
+//! contribute_session = "contracts/target/wasm32-unknown-unknown/release/contribute_session.wasm"
+//! payment_amount = 5000000000
+//! ```
+//!
+//! Relative paths are resolved against the directory holding the file.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::Result;
+
+/// Default gas payment per call, in motes (5 CSPR)
+const DEFAULT_PAYMENT_AMOUNT: u64 = 5_000_000_000;
+
+/// One named network and key configuration
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    /// Node RPC root, without the `/rpc` suffix
+    pub node_address: String,
+    /// Chain name deploys are signed for
+    pub chain_name: String,
+    /// Path to the signing key in PEM format
+    pub secret_key: PathBuf,
+    /// Installed contract hash; required for everything but `deploy`
+    pub contract_hash: Option<String>,
+    /// Path to the compiled contribute session wasm
+    pub contribute_session: Option<PathBuf>,
+    /// Gas payment per call, in motes
+    #[serde(default = "default_payment_amount")]
+    pub payment_amount: u64,
+}
+
+fn default_payment_amount() -> u64 {
+    DEFAULT_PAYMENT_AMOUNT
+}
+
+/// Default profile file: `~/.casperflow/profiles.toml`
+pub fn default_path() -> PathBuf {
+    let home = std::env::var_os("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    home.join(".casperflow").join("profiles.toml")
+}
+
+/// Loads profile `name` from the file at `path`
+pub fn load(path: &Path, name: &str) -> Result<Profile> {
+    let contents = fs::read_to_string(path)
+        .map_err(|error| format!("failed to read profile file {}: {}", path.display(), error))?;
+    let base = path.parent().unwrap_or_else(|| Path::new("."));
+    parse(&contents, name, base)
+}
+
+fn parse(contents: &str, name: &str, base: &Path) -> Result<Profile> {
+    let mut profiles: BTreeMap<String, Profile> = toml::from_str(contents)?;
+    let mut profile = profiles
+        .remove(name)
+        .ok_or_else(|| format!("profile '{}' not found", name))?;
+
+    profile.secret_key = base.join(&profile.secret_key);
+    profile.contribute_session = profile.contribute_session.map(|path| base.join(path));
+    Ok(profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILES: &str = r#"
+        [default]
+        node_address = "http://localhost:11101"
+        chain_name = "casper-net-1"
+        secret_key = "keys/secret_key.pem"
+
+        [testnet]
+        node_address = "https://node.testnet.casper.network"
+        chain_name = "casper-test"
+        secret_key = "/keys/testnet.pem"
+        contract_hash = "hash-0101010101010101010101010101010101010101010101010101010101010101"
+        payment_amount = 3000000000
+    "#;
+
+    #[test]
+    fn test_parse_profile() {
+        let profile = parse(PROFILES, "default", Path::new("/home/ops/.casperflow")).unwrap();
+        assert_eq!(profile.chain_name, "casper-net-1");
+        assert_eq!(
+            profile.secret_key,
+            PathBuf::from("/home/ops/.casperflow/keys/secret_key.pem")
+        );
+        assert_eq!(profile.payment_amount, DEFAULT_PAYMENT_AMOUNT);
+        assert!(profile.contract_hash.is_none());
+
+        let profile = parse(PROFILES, "testnet", Path::new("/home/ops/.casperflow")).unwrap();
+        assert_eq!(profile.secret_key, PathBuf::from("/keys/testnet.pem"));
+        assert_eq!(profile.payment_amount, 3_000_000_000);
+    }
+
+    #[test]
+    fn test_missing_profile() {
+        assert!(parse(PROFILES, "mainnet", Path::new(".")).is_err());
+    }
+}
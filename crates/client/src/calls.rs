@@ -0,0 +1,1585 @@
+//! Typed builders for the contract's entry points.
+//!
+//! Each builder produces the entry point name and the runtime args the
+//! contract expects, so callers never spell argument names by hand.
+//! Contributions need a session that moves funds from the caller's purse and
+//! are built by [`ContributeSession`] instead, or sent through
+//! [`ContributeSimple`].
+
+use casper_types::{
+    account::AccountHash, bytesrepr::Bytes, CLTyped, ContractHash, RuntimeArgs, U512,
+};
+use casperflow_types::remittance::{DocumentType, RefundPolicyKind};
+
+/// A call to a stored entry point on the CasperFlow contract
+pub trait EntryPointCall {
+    /// Name of the entry point
+    fn entry_point(&self) -> &'static str;
+
+    /// Runtime args for the call
+    fn runtime_args(&self) -> RuntimeArgs;
+}
+
+/// Identifies a remittance by public ID or by private remittance key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RemittanceRef {
+    /// Sequential ID of a public remittance
+    Id(u64),
+    /// Hash key returned when a private remittance is created
+    Key([u8; 32]),
+}
+
+impl RemittanceRef {
+    fn insert_into(&self, args: &mut RuntimeArgs) {
+        match self {
+            RemittanceRef::Id(id) => insert(args, "remittance_id", *id),
+            RemittanceRef::Key(key) => insert(args, "remittance_key", *key),
+        }
+    }
+}
+
+impl From<u64> for RemittanceRef {
+    fn from(id: u64) -> Self {
+        RemittanceRef::Id(id)
+    }
+}
+
+impl From<[u8; 32]> for RemittanceRef {
+    fn from(key: [u8; 32]) -> Self {
+        RemittanceRef::Key(key)
+    }
+}
+
+/// Inserts a runtime arg; values built here always serialize.
+fn insert<T: CLTyped + casper_types::bytesrepr::ToBytes>(
+    args: &mut RuntimeArgs,
+    name: &str,
+    value: T,
+) {
+    args.insert(name, value)
+        .expect("runtime arg should serialize");
+}
+
+/// Creates a public or private remittance
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateRemittance {
+    recipient: AccountHash,
+    target_amount: Option<U512>,
+    purpose: String,
+    key_salt: Option<[u8; 32]>,
+    purpose_commitment: Option<[u8; 32]>,
+    confidential: bool,
+    refund_excess: bool,
+    external_reference: Option<String>,
+    corridor: Option<String>,
+    deadline: Option<u64>,
+    starts_at: Option<u64>,
+    max_contributors: Option<u64>,
+    instant_share_bps: Option<u64>,
+    backup_recipient: Option<(AccountHash, u64)>,
+    installments: Option<(U512, u64)>,
+    recipient_signers: Option<(Vec<AccountHash>, u64)>,
+    insured: bool,
+    refund_policy: Option<(RefundPolicyKind, u64)>,
+    namespace: Option<String>,
+    category: Option<String>,
+}
+
+impl CreateRemittance {
+    /// Creates a public remittance for `recipient`
+    pub fn new(recipient: AccountHash, target_amount: U512, purpose: impl Into<String>) -> Self {
+        CreateRemittance {
+            recipient,
+            target_amount: Some(target_amount),
+            purpose: purpose.into(),
+            key_salt: None,
+            purpose_commitment: None,
+            confidential: false,
+            refund_excess: false,
+            external_reference: None,
+            corridor: None,
+            deadline: None,
+            starts_at: None,
+            max_contributors: None,
+            instant_share_bps: None,
+            backup_recipient: None,
+            installments: None,
+            recipient_signers: None,
+            insured: false,
+            refund_policy: None,
+            namespace: None,
+            category: None,
+        }
+    }
+
+    /// Creates an open-ended pot for `recipient`, with no target; the
+    /// recipient withdraws from it at any time with [`WithdrawPot`]
+    pub fn open_ended(recipient: AccountHash, purpose: impl Into<String>) -> Self {
+        CreateRemittance {
+            target_amount: None,
+            ..CreateRemittance::new(recipient, U512::zero(), purpose)
+        }
+    }
+
+    /// Creates the remittance under a non-enumerable key instead of an ID.
+    ///
+    /// `key_salt` must be random and kept secret: it is what stops others
+    /// deriving the key. The contract rejects external references on
+    /// private remittances.
+    pub fn private(mut self, key_salt: [u8; 32]) -> Self {
+        self.key_salt = Some(key_salt);
+        self
+    }
+
+    /// Commits to the purpose instead of storing it in the clear.
+    ///
+    /// `purpose` should be empty; reveal it later with [`RevealPurpose`].
+    pub fn with_purpose_commitment(mut self, commitment: [u8; 32]) -> Self {
+        self.purpose_commitment = Some(commitment);
+        self
+    }
+
+    /// Records contributions as amount commitments only
+    pub fn confidential(mut self) -> Self {
+        self.confidential = true;
+        self
+    }
+
+    /// Releases only the target; contributors reclaim any excess with
+    /// [`ClaimRefund::excess`]
+    pub fn refund_excess(mut self) -> Self {
+        self.refund_excess = true;
+        self
+    }
+
+    /// Indexes the remittance under a creator-scoped external reference
+    pub fn with_external_reference(mut self, reference: impl Into<String>) -> Self {
+        self.external_reference = Some(reference.into());
+        self
+    }
+
+    /// Reports the remittance under a corridor code, such as `"US-PH"`
+    pub fn with_corridor(mut self, corridor: impl Into<String>) -> Self {
+        self.corridor = Some(corridor.into());
+        self
+    }
+
+    /// Lets anyone expire the remittance once block time reaches `deadline`
+    /// (ms) with the target unmet
+    pub fn with_deadline(mut self, deadline: u64) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Rejects contributions until block time reaches `starts_at` (ms), e.g.
+    /// for a coordinated launch
+    pub fn with_start_time(mut self, starts_at: u64) -> Self {
+        self.starts_at = Some(starts_at);
+        self
+    }
+
+    /// Caps the number of unique contributors, e.g. for a private pool
+    pub fn with_max_contributors(mut self, max_contributors: u64) -> Self {
+        self.max_contributors = Some(max_contributors);
+        self
+    }
+
+    /// Forwards `share_bps` of each contribution to the recipient immediately
+    /// and escrows the rest until release
+    pub fn with_instant_share(mut self, share_bps: u64) -> Self {
+        self.instant_share_bps = Some(share_bps);
+        self
+    }
+
+    /// Lets `backup` release to themselves if the recipient has not released
+    /// within `grace_days` of the target being met
+    pub fn with_backup_recipient(mut self, backup: AccountHash, grace_days: u64) -> Self {
+        self.backup_recipient = Some((backup, grace_days));
+        self
+    }
+
+    /// Pays the recipient `amount` every `interval_days` once funded, in
+    /// place of a single release
+    pub fn with_installments(mut self, amount: U512, interval_days: u64) -> Self {
+        self.installments = Some((amount, interval_days));
+        self
+    }
+
+    /// Requires `threshold` of `signers` (which must include the recipient)
+    /// to approve with [`ApproveReleaseAsRecipient`] before release
+    pub fn with_recipient_signers(mut self, signers: Vec<AccountHash>, threshold: u64) -> Self {
+        self.recipient_signers = Some((signers, threshold));
+        self
+    }
+
+    /// Pays the insurance premium on each contribution, so contributors can
+    /// claim a top-up with [`ClaimInsurance`] if the remittance fails
+    pub fn insured(mut self) -> Self {
+        self.insured = true;
+        self
+    }
+
+    /// Retains part of each refund under `kind`, at `rate_bps` flat or per
+    /// day since creation
+    pub fn with_refund_policy(mut self, kind: RefundPolicyKind, rate_bps: u64) -> Self {
+        self.refund_policy = Some((kind, rate_bps));
+        self
+    }
+
+    /// Creates the remittance in a namespace, under its fee and pause switch
+    pub fn in_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Creates the remittance in a category, whose template supplies the
+    /// deadline, fee, and excess-refund setting not set explicitly
+    pub fn in_category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+}
+
+impl EntryPointCall for CreateRemittance {
+    fn entry_point(&self) -> &'static str {
+        if self.key_salt.is_some() {
+            "create_private_remittance"
+        } else {
+            "create_remittance"
+        }
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        insert(&mut args, "recipient", self.recipient);
+        if let Some(target_amount) = self.target_amount {
+            insert(&mut args, "target_amount", target_amount);
+        }
+        insert(&mut args, "purpose", self.purpose.clone());
+        if let Some(key_salt) = self.key_salt {
+            insert(&mut args, "key_salt", key_salt);
+        }
+        if let Some(commitment) = self.purpose_commitment {
+            insert(&mut args, "purpose_commitment", commitment);
+        }
+        if self.confidential {
+            insert(&mut args, "confidential", true);
+        }
+        if self.refund_excess {
+            insert(&mut args, "refund_excess", true);
+        }
+        if let Some(reference) = &self.external_reference {
+            insert(&mut args, "external_reference", reference.clone());
+        }
+        if let Some(corridor) = &self.corridor {
+            insert(&mut args, "corridor", corridor.clone());
+        }
+        if let Some(deadline) = self.deadline {
+            insert(&mut args, "deadline", deadline);
+        }
+        if let Some(starts_at) = self.starts_at {
+            insert(&mut args, "starts_at", starts_at);
+        }
+        if let Some(max_contributors) = self.max_contributors {
+            insert(&mut args, "max_contributors", max_contributors);
+        }
+        if let Some(share_bps) = self.instant_share_bps {
+            insert(&mut args, "instant_share_bps", share_bps);
+        }
+        if let Some((backup, grace_days)) = self.backup_recipient {
+            insert(&mut args, "backup_recipient", backup);
+            insert(&mut args, "backup_grace_days", grace_days);
+        }
+        if let Some((amount, interval_days)) = self.installments {
+            insert(&mut args, "installment_amount", amount);
+            insert(&mut args, "installment_interval_days", interval_days);
+        }
+        if let Some((signers, threshold)) = &self.recipient_signers {
+            insert(&mut args, "recipient_signers", signers.clone());
+            insert(&mut args, "recipient_threshold", *threshold);
+        }
+        if self.insured {
+            insert(&mut args, "insured", true);
+        }
+        if let Some((kind, rate_bps)) = self.refund_policy {
+            insert(&mut args, "refund_policy", kind as u8);
+            insert(&mut args, "refund_policy_bps", rate_bps);
+        }
+        if let Some(namespace) = &self.namespace {
+            insert(&mut args, "namespace", namespace.clone());
+        }
+        if let Some(category) = &self.category {
+            insert(&mut args, "category", category.clone());
+        }
+        args
+    }
+}
+
+/// Creates a rotating savings pool paying each member in turn, in the order
+/// of `members`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateSavingsPool {
+    pub members: Vec<AccountHash>,
+    pub contribution: U512,
+    pub cycle_days: u64,
+    pub purpose: String,
+}
+
+impl EntryPointCall for CreateSavingsPool {
+    fn entry_point(&self) -> &'static str {
+        "create_savings_pool"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        insert(&mut args, "members", self.members.clone());
+        insert(&mut args, "contribution", self.contribution);
+        insert(&mut args, "cycle_days", self.cycle_days);
+        insert(&mut args, "purpose", self.purpose.clone());
+        args
+    }
+}
+
+/// Reveals a committed purpose
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RevealPurpose {
+    pub remittance: RemittanceRef,
+    pub purpose: String,
+    pub salt: [u8; 32],
+}
+
+impl EntryPointCall for RevealPurpose {
+    fn entry_point(&self) -> &'static str {
+        "reveal_purpose"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "purpose", self.purpose.clone());
+        insert(&mut args, "salt", self.salt);
+        args
+    }
+}
+
+/// Attaches an off-chain document reference
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttachDocument {
+    pub remittance: RemittanceRef,
+    pub cid: String,
+    pub content_hash: [u8; 32],
+    pub doc_type: DocumentType,
+}
+
+impl EntryPointCall for AttachDocument {
+    fn entry_point(&self) -> &'static str {
+        "attach_document"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "cid", self.cid.clone());
+        insert(&mut args, "content_hash", self.content_hash);
+        insert(&mut args, "doc_type", self.doc_type as u8);
+        args
+    }
+}
+
+/// Posts a progress update for contributors (creator only)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PostUpdate {
+    pub remittance: RemittanceRef,
+    pub message: String,
+}
+
+impl EntryPointCall for PostUpdate {
+    fn entry_point(&self) -> &'static str {
+        "post_update"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "message", self.message.clone());
+        args
+    }
+}
+
+/// Sets or removes a translation of the purpose (creator only); an empty
+/// purpose removes it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetLocalizedPurpose {
+    pub remittance: RemittanceRef,
+    pub lang: String,
+    pub purpose: String,
+}
+
+impl EntryPointCall for SetLocalizedPurpose {
+    fn entry_point(&self) -> &'static str {
+        "set_localized_purpose"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "lang", self.lang.clone());
+        insert(&mut args, "purpose", self.purpose.clone());
+        args
+    }
+}
+
+/// Confirms released funds arrived (recipient only)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AcknowledgeReceipt {
+    pub remittance: RemittanceRef,
+    pub message: String,
+}
+
+impl EntryPointCall for AcknowledgeReceipt {
+    fn entry_point(&self) -> &'static str {
+        "acknowledge_receipt"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "message", self.message.clone());
+        args
+    }
+}
+
+/// Registers a unique human-readable handle for a remittance (creator only)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegisterHandle {
+    pub remittance: RemittanceRef,
+    /// 3-64 lowercase letters, digits, or inner hyphens
+    pub handle: String,
+}
+
+impl EntryPointCall for RegisterHandle {
+    fn entry_point(&self) -> &'static str {
+        "register_handle"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "handle", self.handle.clone());
+        args
+    }
+}
+
+/// Attaches travel-rule originator/beneficiary data
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttachTravelRuleData {
+    pub remittance: RemittanceRef,
+    pub data_hash: [u8; 32],
+    pub payload_uri: String,
+}
+
+impl EntryPointCall for AttachTravelRuleData {
+    fn entry_point(&self) -> &'static str {
+        "attach_travel_rule_data"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "data_hash", self.data_hash);
+        insert(&mut args, "payload_uri", self.payload_uri.clone());
+        args
+    }
+}
+
+/// Releases funds to the recipient
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReleaseFunds {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for ReleaseFunds {
+    fn entry_point(&self) -> &'static str {
+        "release_funds"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Releases funds to a settlement partner for cash pickup (recipient only)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReleaseToPartner {
+    pub remittance: RemittanceRef,
+    /// Registered partner serving the remittance's corridor
+    pub partner: AccountHash,
+    /// Code the recipient presents to the partner to collect
+    pub reference_code: String,
+}
+
+impl EntryPointCall for ReleaseToPartner {
+    fn entry_point(&self) -> &'static str {
+        "release_funds"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "partner", self.partner);
+        insert(&mut args, "reference_code", self.reference_code.clone());
+        args
+    }
+}
+
+/// Releases funds to an account from the recipient's payout address book
+/// (recipient only)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReleaseToPayoutTarget {
+    pub remittance: RemittanceRef,
+    /// Label of the address book entry to pay
+    pub label: String,
+}
+
+impl EntryPointCall for ReleaseToPayoutTarget {
+    fn entry_point(&self) -> &'static str {
+        "release_funds"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "payout_target", self.label.clone());
+        args
+    }
+}
+
+/// Adds or repoints an entry in the caller's payout address book
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetPayoutTarget {
+    pub label: String,
+    pub target: AccountHash,
+}
+
+impl EntryPointCall for SetPayoutTarget {
+    fn entry_point(&self) -> &'static str {
+        "set_payout_target"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        insert(&mut args, "label", self.label.clone());
+        insert(&mut args, "target", self.target);
+        args
+    }
+}
+
+/// Removes an entry from the caller's payout address book
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RemovePayoutTarget {
+    pub label: String,
+}
+
+impl EntryPointCall for RemovePayoutTarget {
+    fn entry_point(&self) -> &'static str {
+        "remove_payout_target"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        insert(&mut args, "label", self.label.clone());
+        args
+    }
+}
+
+/// Cancels a remittance so contributors can claim refunds
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CancelRemittance {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for CancelRemittance {
+    fn entry_point(&self) -> &'static str {
+        "cancel_remittance"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Expires a remittance past its deadline with its target unmet
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExpireRemittance {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for ExpireRemittance {
+    fn entry_point(&self) -> &'static str {
+        "expire_remittance"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Pays the next due installment of a scheduled payout (anyone)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProcessInstallment {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for ProcessInstallment {
+    fn entry_point(&self) -> &'static str {
+        "process_installment"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Withdraws what an open-ended pot holds, leaving it open (recipient only)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithdrawPot {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for WithdrawPot {
+    fn entry_point(&self) -> &'static str {
+        "withdraw_pot"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Releases a remittance the recipient left unreleased (backup recipient only)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReleaseToBackup {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for ReleaseToBackup {
+    fn entry_point(&self) -> &'static str {
+        "release_to_backup"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Approves a release to a multi-signature recipient (recipient signers only)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ApproveReleaseAsRecipient {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for ApproveReleaseAsRecipient {
+    fn entry_point(&self) -> &'static str {
+        "approve_release_as_recipient"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Freezes the contributor amounts of a remittance under a snapshot ID
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnapshotContributions {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for SnapshotContributions {
+    fn entry_point(&self) -> &'static str {
+        "snapshot_contributions"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Declines a remittance so contributors can claim refunds (recipient only)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RejectRemittance {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for RejectRemittance {
+    fn entry_point(&self) -> &'static str {
+        "reject_remittance"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Refuses a funded remittance with a reason for contributors, enabling
+/// refunds (recipient only)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeclineRelease {
+    pub remittance: RemittanceRef,
+    pub reason: String,
+}
+
+impl EntryPointCall for DeclineRelease {
+    fn entry_point(&self) -> &'static str {
+        "decline_release"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "reason", self.reason.clone());
+        args
+    }
+}
+
+/// Asks to be paid out on another chain through the bridge (recipient only)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestBridgePayout {
+    pub remittance: RemittanceRef,
+    /// Destination chain ID (e.g. "eip155:1")
+    pub chain_id: String,
+    /// Payout address on the destination chain
+    pub foreign_address: String,
+}
+
+impl EntryPointCall for RequestBridgePayout {
+    fn entry_point(&self) -> &'static str {
+        "request_bridge_payout"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "chain_id", self.chain_id.clone());
+        insert(&mut args, "foreign_address", self.foreign_address.clone());
+        args
+    }
+}
+
+/// Confirms a bridge payout and reimburses the operator (bridge operator only)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfirmBridgePayout {
+    pub remittance: RemittanceRef,
+    /// Payout transaction on the destination chain
+    pub foreign_tx_hash: String,
+}
+
+impl EntryPointCall for ConfirmBridgePayout {
+    fn entry_point(&self) -> &'static str {
+        "confirm_bridge_payout"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "foreign_tx_hash", self.foreign_tx_hash.clone());
+        args
+    }
+}
+
+/// Withdraws an unconfirmed bridge payout (recipient or compliance officer,
+/// anyone after its deadline)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CancelBridgePayout {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for CancelBridgePayout {
+    fn entry_point(&self) -> &'static str {
+        "cancel_bridge_payout"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Pushes back a remittance's deadline (creator only)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExtendDeadline {
+    pub remittance: RemittanceRef,
+    /// New block time (Unix ms) after which the remittance can be expired
+    pub new_deadline: u64,
+}
+
+impl EntryPointCall for ExtendDeadline {
+    fn entry_point(&self) -> &'static str {
+        "extend_deadline"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "new_deadline", self.new_deadline);
+        args
+    }
+}
+
+/// Approves and pays out a release held for review (compliance only)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ApproveRelease {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for ApproveRelease {
+    fn entry_point(&self) -> &'static str {
+        "approve_release"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Rejects a release held for review so contributors can claim refunds
+/// (compliance only, or anyone once the review window has passed)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RejectRelease {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for RejectRelease {
+    fn entry_point(&self) -> &'static str {
+        "reject_release"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Claims the caller's refund from a cancelled or expired remittance, or
+/// their share of the excess over target of a released one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClaimRefund {
+    remittance: RemittanceRef,
+    opening: Option<(U512, [u8; 32])>,
+    excess: bool,
+    contributor: Option<AccountHash>,
+}
+
+impl ClaimRefund {
+    /// Claims a refund of the recorded contribution
+    pub fn new(remittance: impl Into<RemittanceRef>) -> Self {
+        ClaimRefund {
+            remittance: remittance.into(),
+            opening: None,
+            excess: false,
+            contributor: None,
+        }
+    }
+
+    /// Claims for `contributor` as their refund delegate; the refund is
+    /// still paid to them
+    pub fn on_behalf_of(mut self, contributor: AccountHash) -> Self {
+        self.contributor = Some(contributor);
+        self
+    }
+
+    /// Claims the share of the excess over target instead, after release of a
+    /// remittance created with [`CreateRemittance::refund_excess`]
+    pub fn excess(mut self) -> Self {
+        self.excess = true;
+        self
+    }
+
+    /// Opens the caller's amount commitment on a confidential remittance
+    pub fn with_opening(mut self, amount: U512, blinding: [u8; 32]) -> Self {
+        self.opening = Some((amount, blinding));
+        self
+    }
+}
+
+impl EntryPointCall for ClaimRefund {
+    fn entry_point(&self) -> &'static str {
+        if self.excess {
+            "claim_excess_refund"
+        } else {
+            "claim_refund"
+        }
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        if let Some((amount, blinding)) = self.opening {
+            insert(&mut args, "amount", amount);
+            insert(&mut args, "blinding", blinding);
+        }
+        if let Some(contributor) = self.contributor {
+            insert(&mut args, "contributor", contributor);
+        }
+        args
+    }
+}
+
+/// Authorizes an account to claim the caller's refunds, or revokes the
+/// current delegate when `delegate` is `None`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SetRefundDelegate {
+    pub delegate: Option<AccountHash>,
+}
+
+impl EntryPointCall for SetRefundDelegate {
+    fn entry_point(&self) -> &'static str {
+        "set_refund_delegate"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        if let Some(delegate) = self.delegate {
+            insert(&mut args, "delegate", delegate);
+        }
+        args
+    }
+}
+
+/// Claims the caller's approved insurance top-up on a cancelled or expired
+/// insured remittance
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClaimInsurance {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for ClaimInsurance {
+    fn entry_point(&self) -> &'static str {
+        "claim_insurance"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Claims back the unused fee cover of a cancelled or expired remittance
+/// (sponsor only)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClaimFeeCover {
+    pub remittance: RemittanceRef,
+}
+
+impl EntryPointCall for ClaimFeeCover {
+    fn entry_point(&self) -> &'static str {
+        "claim_fee_cover"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        args
+    }
+}
+
+/// Approves insurance top-ups of `coverage_bps` of each contribution to a
+/// failed insured remittance (compliance only)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ApproveInsuranceClaims {
+    pub remittance: RemittanceRef,
+    pub coverage_bps: u64,
+}
+
+impl EntryPointCall for ApproveInsuranceClaims {
+    fn entry_point(&self) -> &'static str {
+        "approve_insurance_claims"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "coverage_bps", self.coverage_bps);
+        args
+    }
+}
+
+/// Cancels a remittance that violates the terms, recording why (compliance
+/// only)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForceCancel {
+    pub remittance: RemittanceRef,
+    pub reason: String,
+}
+
+impl EntryPointCall for ForceCancel {
+    fn entry_point(&self) -> &'static str {
+        "force_cancel"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "reason", self.reason.clone());
+        args
+    }
+}
+
+/// Refunds one contributor from an active remittance, recording why
+/// (compliance only)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForceRefund {
+    pub remittance: RemittanceRef,
+    pub contributor: AccountHash,
+    pub reason: String,
+}
+
+impl EntryPointCall for ForceRefund {
+    fn entry_point(&self) -> &'static str {
+        "force_refund"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "contributor", self.contributor);
+        insert(&mut args, "reason", self.reason.clone());
+        args
+    }
+}
+
+/// Sets the defaults applied to remittances created in a category (owner
+/// only)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetCategory {
+    pub category: String,
+    /// Days from creation until the default deadline; 0 for none
+    pub deadline_days: u64,
+    /// Fee in basis points; the global or namespace fee applies when `None`
+    pub fee_bps: Option<u64>,
+    pub refund_excess: bool,
+}
+
+impl EntryPointCall for SetCategory {
+    fn entry_point(&self) -> &'static str {
+        "set_category"
+    }
+
+    fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        insert(&mut args, "category", self.category.clone());
+        insert(&mut args, "deadline_days", self.deadline_days);
+        if let Some(fee_bps) = self.fee_bps {
+            insert(&mut args, "fee_bps", fee_bps);
+        }
+        insert(&mut args, "refund_excess", self.refund_excess);
+        args
+    }
+}
+
+/// Defines a call whose args map one-to-one onto struct fields.
+macro_rules! simple_call {
+    ($(#[$doc:meta])* $name:ident => $entry_point:literal { $($field:ident: $ty:ty => $arg:literal),* $(,)? }) => {
+        $(#[$doc])*
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $name {
+            $(pub $field: $ty,)*
+        }
+
+        impl EntryPointCall for $name {
+            fn entry_point(&self) -> &'static str {
+                $entry_point
+            }
+
+            fn runtime_args(&self) -> RuntimeArgs {
+                #[allow(unused_mut)]
+                let mut args = RuntimeArgs::new();
+                $(insert(&mut args, $arg, self.$field.clone());)*
+                args
+            }
+        }
+    };
+}
+
+simple_call!(
+    /// Claims the caller's accrued creator fee rebates
+    ClaimCreatorRewards => "claim_creator_rewards" {}
+);
+
+simple_call!(
+    /// Expires every stalled remittance in a range of IDs
+    ExpireBatch => "expire_batch" { start_id: u64 => "start_id", count: u64 => "count" }
+);
+
+simple_call!(
+    /// Claims the caller's refunds from up to `max_items` cancelled or
+    /// expired remittances
+    ClaimAllRefunds => "claim_all_refunds" { max_items: u64 => "max_items" }
+);
+
+simple_call!(
+    /// Contributes from the caller's main purse without session wasm, so
+    /// hardware wallets only have to show primitive args
+    ContributeSimple => "contribute_simple" {
+        remittance_id: u64 => "remittance_id",
+        amount: U512 => "amount",
+    }
+);
+
+simple_call!(
+    /// Releases funds to the recipient with only the remittance ID (recipient only)
+    ReleaseSimple => "release_simple" { remittance_id: u64 => "remittance_id" }
+);
+
+simple_call!(
+    /// Escalates funded remittances left unreleased in a range of IDs
+    EscalateOverdue => "escalate_overdue" { start_id: u64 => "start_id", count: u64 => "count" }
+);
+
+simple_call!(
+    /// Sets the platform fee in basis points (owner only)
+    SetPlatformFee => "set_platform_fee" { fee_bps: u64 => "fee_bps" }
+);
+
+simple_call!(
+    /// Sets the platform fee in parts per million (owner only)
+    SetPlatformFeePpm => "set_platform_fee_ppm" { fee_ppm: u64 => "fee_ppm" }
+);
+
+simple_call!(
+    /// Lowers the platform fee cap in parts per million (owner only)
+    LowerMaxFee => "lower_max_fee" { max_fee_ppm: u64 => "max_fee_ppm" }
+);
+
+simple_call!(
+    /// Sets the fee rounding mode (owner only)
+    SetFeeRounding => "set_fee_rounding" { rounding: u8 => "rounding" }
+);
+
+simple_call!(
+    /// Sets the creator share of platform fees in basis points (owner only)
+    SetCreatorFeeShare => "set_creator_fee_share" { share_bps: u64 => "share_bps" }
+);
+
+simple_call!(
+    /// Limits creations and contributions per account per window (owner only)
+    SetRateLimits => "set_rate_limits" {
+        window_ms: u64 => "window_ms",
+        max_creations: u64 => "max_creations",
+        max_contributions: u64 => "max_contributions",
+    }
+);
+
+simple_call!(
+    /// Bounds batch sizes, page sizes, and contributors processed per call
+    /// (owner only)
+    SetGasLimits => "set_gas_limits" {
+        max_batch_size: u64 => "max_batch_size",
+        max_page_size: u64 => "max_page_size",
+        max_contributors: u64 => "max_contributors",
+    }
+);
+
+simple_call!(
+    /// Rejects self-remittances and allows, flags, or rejects contributions
+    /// from a remittance's recipient (owner only)
+    SetPaymentGuards => "set_payment_guards" {
+        reject_self_remittance: bool => "reject_self_remittance",
+        recipient_contributions: u8 => "recipient_contributions",
+    }
+);
+
+simple_call!(
+    /// Caps the total time creators may extend a deadline by (owner only)
+    SetMaxDeadlineExtension => "set_max_deadline_extension" {
+        max_extension_ms: u64 => "max_extension_ms",
+    }
+);
+
+simple_call!(
+    /// Caps the target amount of new remittances; zero removes the cap (owner
+    /// only)
+    SetMaxTargetAmount => "set_max_target_amount" {
+        max_target_amount: U512 => "max_target_amount",
+    }
+);
+
+simple_call!(
+    /// Sets the minimum target amount of new remittances; zero removes it
+    /// (owner only)
+    SetMinTargetAmount => "set_min_target_amount" {
+        min_target_amount: U512 => "min_target_amount",
+    }
+);
+
+simple_call!(
+    /// Raises the target cap for remittances to KYB-verified businesses;
+    /// zero removes it (owner only)
+    SetKybMaxTargetAmount => "set_kyb_max_target_amount" {
+        kyb_max_target_amount: U512 => "kyb_max_target_amount",
+    }
+);
+
+simple_call!(
+    /// Sets how frontends should present the instance (owner only)
+    SetBranding => "set_branding" {
+        platform_name: String => "platform_name",
+        logo_cid: String => "logo_cid",
+        support_url: String => "support_url",
+        currency_display: String => "currency_display",
+    }
+);
+
+simple_call!(
+    /// Creates a namespace administered by its own operator (owner only)
+    CreateNamespace => "create_namespace" {
+        namespace: String => "namespace",
+        namespace_owner: AccountHash => "namespace_owner",
+        fee_ppm: u64 => "fee_ppm",
+    }
+);
+
+simple_call!(
+    /// Sets a namespace's fee in parts per million (namespace owner only)
+    SetNamespaceFee => "set_namespace_fee" {
+        namespace: String => "namespace",
+        fee_ppm: u64 => "fee_ppm",
+    }
+);
+
+simple_call!(
+    /// Pauses contributions and releases in a namespace (namespace owner only)
+    PauseNamespace => "pause_namespace" { namespace: String => "namespace" }
+);
+
+simple_call!(
+    /// Resumes a paused namespace (namespace owner only)
+    UnpauseNamespace => "unpause_namespace" { namespace: String => "namespace" }
+);
+
+simple_call!(
+    /// Sets the intervals after funding at which unreleased remittances are
+    /// escalated (owner only)
+    SetSlaIntervals => "set_sla_intervals" { intervals_ms: Vec<u64> => "intervals_ms" }
+);
+
+simple_call!(
+    /// Sets the insurance premium on new insured remittances in basis points
+    /// (owner only)
+    SetInsurancePremium => "set_insurance_premium" { premium_bps: u64 => "premium_bps" }
+);
+
+simple_call!(
+    /// Migrates a batch of legacy records (owner only)
+    MigrateRecords => "migrate_records" { start_id: u64 => "start_id", count: u64 => "count" }
+);
+
+simple_call!(
+    /// Transfers contract ownership (owner only)
+    RotateOwner => "rotate_owner" { new_owner: AccountHash => "new_owner" }
+);
+
+simple_call!(
+    /// Appoints the compliance officer (owner only)
+    SetComplianceOfficer => "set_compliance_officer" { officer: AccountHash => "officer" }
+);
+
+simple_call!(
+    /// Registers or updates a cash-pickup settlement partner (compliance only)
+    RegisterSettlementPartner => "register_settlement_partner" {
+        partner: AccountHash => "partner",
+        name: String => "name",
+        corridors: Vec<String> => "corridors",
+    }
+);
+
+simple_call!(
+    /// Removes a settlement partner (compliance only)
+    UnregisterSettlementPartner => "unregister_settlement_partner" {
+        partner: AccountHash => "partner",
+    }
+);
+
+simple_call!(
+    /// Appoints the bridge operator (owner only)
+    SetBridgeOperator => "set_bridge_operator" { operator: AccountHash => "operator" }
+);
+
+simple_call!(
+    /// Adds an account to the verified charity registry (compliance only)
+    RegisterCharity => "register_charity" { charity: AccountHash => "charity" }
+);
+
+simple_call!(
+    /// Removes an account from the verified charity registry (compliance only)
+    UnregisterCharity => "unregister_charity" { charity: AccountHash => "charity" }
+);
+
+simple_call!(
+    /// Marks a recipient as a KYB-verified business (compliance only)
+    RegisterBusiness => "register_business" { business: AccountHash => "business" }
+);
+
+simple_call!(
+    /// Removes an account from the KYB business registry (compliance only)
+    UnregisterBusiness => "unregister_business" { business: AccountHash => "business" }
+);
+
+simple_call!(
+    /// Blocklists a purpose template by its `purpose_hash` (compliance only)
+    BlockPurpose => "block_purpose" { purpose_hash: [u8; 32] => "purpose_hash" }
+);
+
+simple_call!(
+    /// Lifts a purpose template block (compliance only)
+    UnblockPurpose => "unblock_purpose" { purpose_hash: [u8; 32] => "purpose_hash" }
+);
+
+simple_call!(
+    /// Sets the travel-rule threshold in motes (compliance only)
+    SetTravelRuleThreshold => "set_travel_rule_threshold" { threshold: U512 => "threshold" }
+);
+
+simple_call!(
+    /// Scores releases of at least `min_amount` with a risk engine (compliance only)
+    SetRiskEngine => "set_risk_engine" {
+        contract_hash: ContractHash => "contract_hash",
+        min_amount: U512 => "min_amount",
+        max_score: u64 => "max_score",
+    }
+);
+
+simple_call!(
+    /// Holds releases of at least `threshold` for review (compliance only)
+    SetReviewPolicy => "set_review_policy" {
+        threshold: U512 => "threshold",
+        window_ms: u64 => "window_ms",
+    }
+);
+
+simple_call!(
+    /// Stops scoring releases (compliance only)
+    ClearRiskEngine => "clear_risk_engine" {}
+);
+
+simple_call!(
+    /// Records the contract purse balance against the ledger (owner only)
+    SnapshotAccounting => "snapshot_accounting" {}
+);
+
+simple_call!(
+    /// Revokes the installer access token (owner only)
+    RevokeAccessToken => "revoke_access_token" {}
+);
+
+simple_call!(
+    /// Removes an unprotected named key from the contract (owner only)
+    RemoveNamedKey => "remove_named_key" { name: String => "name" }
+);
+
+simple_call!(
+    /// Pauses the contract (owner only)
+    PauseContract => "pause_contract" {}
+);
+
+simple_call!(
+    /// Unpauses the contract (owner only)
+    UnpauseContract => "unpause_contract" {}
+);
+
+simple_call!(
+    /// Permanently disables fee changes, pausing, and access token
+    /// withdrawal (owner only)
+    RenounceAdmin => "renounce_admin" {}
+);
+
+/// Contributes from the caller's main purse through session code.
+///
+/// Stored contracts cannot draw from the caller's main purse, so the session
+/// moves `amount` into a fresh cargo purse and passes it to `contribute` as
+/// `purse`, or to `top_up_fee_cover` for a fee cover.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContributeSession {
+    session_wasm: Bytes,
+    contract_hash: ContractHash,
+    remittance: RemittanceRef,
+    amount: U512,
+    amount_commitment: Option<([u8; 32], [u8; 32])>,
+    idempotency_key: Option<[u8; 32]>,
+    fee_cover: bool,
+}
+
+impl ContributeSession {
+    /// Contributes `amount` using the compiled contribute session wasm
+    pub fn new(
+        session_wasm: impl Into<Bytes>,
+        contract_hash: ContractHash,
+        remittance: impl Into<RemittanceRef>,
+        amount: U512,
+    ) -> Self {
+        ContributeSession {
+            session_wasm: session_wasm.into(),
+            contract_hash,
+            remittance: remittance.into(),
+            amount,
+            amount_commitment: None,
+            idempotency_key: None,
+            fee_cover: false,
+        }
+    }
+
+    /// Records `blake2b(amount || blinding)` for a confidential remittance;
+    /// the contract checks it against `blinding` and the amount paid
+    pub fn with_amount_commitment(mut self, commitment: [u8; 32], blinding: [u8; 32]) -> Self {
+        self.amount_commitment = Some((commitment, blinding));
+        self
+    }
+
+    /// Tags the contribution so a retried deploy cannot pay in twice
+    pub fn with_idempotency_key(mut self, idempotency_key: [u8; 32]) -> Self {
+        self.idempotency_key = Some(idempotency_key);
+        self
+    }
+
+    /// Pre-funds the platform fee instead of contributing, so the recipient
+    /// receives the full amount (creator or recipient only)
+    pub fn fee_cover(mut self) -> Self {
+        self.fee_cover = true;
+        self
+    }
+
+    /// Compiled session wasm
+    pub fn session_wasm(&self) -> &Bytes {
+        &self.session_wasm
+    }
+
+    /// Runtime args for the session
+    pub fn runtime_args(&self) -> RuntimeArgs {
+        let mut args = RuntimeArgs::new();
+        insert(&mut args, "contract_hash", self.contract_hash);
+        self.remittance.insert_into(&mut args);
+        insert(&mut args, "amount", self.amount);
+        if let Some((commitment, blinding)) = self.amount_commitment {
+            insert(&mut args, "amount_commitment", commitment);
+            insert(&mut args, "blinding", blinding);
+        }
+        if let Some(idempotency_key) = self.idempotency_key {
+            insert(&mut args, "idempotency_key", idempotency_key);
+        }
+        if self.fee_cover {
+            insert(&mut args, "fee_cover", true);
+        }
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_remittance_args() {
+        let recipient = AccountHash::new([1u8; 32]);
+        let call = CreateRemittance::new(recipient, U512::from(1_000), "rent");
+        let args = call.runtime_args();
+
+        assert_eq!(call.entry_point(), "create_remittance");
+        assert_eq!(args.len(), 3);
+        assert_eq!(
+            args.get("recipient")
+                .unwrap()
+                .clone()
+                .into_t::<AccountHash>()
+                .unwrap(),
+            recipient
+        );
+
+        let call = call
+            .private([7u8; 32])
+            .confidential()
+            .refund_excess()
+            .with_corridor("US-PH")
+            .with_max_contributors(5)
+            .with_purpose_commitment([2u8; 32]);
+        let args = call.runtime_args();
+
+        assert_eq!(call.entry_point(), "create_private_remittance");
+        assert_eq!(args.len(), 9);
+        assert!(args
+            .get("confidential")
+            .unwrap()
+            .clone()
+            .into_t::<bool>()
+            .unwrap());
+
+        // An open-ended pot omits the target
+        let args = CreateRemittance::open_ended(recipient, "family pot").runtime_args();
+        assert_eq!(args.len(), 2);
+        assert!(args.get("target_amount").is_none());
+    }
+
+    #[test]
+    fn test_remittance_ref_args() {
+        let args = ReleaseFunds {
+            remittance: RemittanceRef::Id(7),
+        }
+        .runtime_args();
+        assert_eq!(
+            args.get("remittance_id")
+                .unwrap()
+                .clone()
+                .into_t::<u64>()
+                .unwrap(),
+            7
+        );
+
+        let args = ClaimRefund::new([3u8; 32])
+            .with_opening(U512::from(5), [4u8; 32])
+            .runtime_args();
+        assert!(args.get("remittance_id").is_none());
+        assert!(args.get("remittance_key").is_some());
+        assert!(args.get("blinding").is_some());
+
+        let call = ClaimRefund::new(7).excess();
+        assert_eq!(call.entry_point(), "claim_excess_refund");
+        assert_eq!(call.runtime_args().len(), 1);
+    }
+
+    #[test]
+    fn test_simple_call_args() {
+        let call = MigrateRecords {
+            start_id: 1,
+            count: 50,
+        };
+        let args = call.runtime_args();
+
+        assert_eq!(call.entry_point(), "migrate_records");
+        assert_eq!(
+            args.get("count").unwrap().clone().into_t::<u64>().unwrap(),
+            50
+        );
+        assert!(PauseContract {}.runtime_args().is_empty());
+    }
+}
@@ -0,0 +1,1257 @@
+//! JSON-RPC client for a deployed CasperFlow contract.
+
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{Duration, Instant};
+
+use casper_client::{
+    rpcs::{common::GlobalStateIdentifier, DictionaryItemIdentifier},
+    types::{Deploy, DeployBuilder, DeployHash, ExecutableDeployItem, StoredValue},
+    Error as RpcError, JsonRpcId, Verbosity,
+};
+use casper_types::{account::AccountHash, ContractHash, ExecutionResult, Key, SecretKey, U512};
+use casperflow_types::{
+    casper_types::{self as types_v4, bytesrepr::FromBytes},
+    contributor_key, localized_purpose, purpose_hash,
+    remittance::{
+        AccountOverview, AccountingSnapshot, BackupRecipient, Branding, BridgePayout, BuildInfo,
+        CategoryTemplate, ContributionSnapshot, ContributionWeight, DocumentRef, FeeCover,
+        FundingState, GasLimits, InstantSplit, InsurancePolicy, LegacyRemittance, LocalizedPurpose,
+        Namespace, PaymentGuards, PayoutRoute, PayoutSchedule, PayoutTarget, PeriodStats,
+        PotDeposit, RateLimits, ReceiptAcknowledgement, RecipientMultisig, RefundPolicy,
+        ReleaseBreakdown, ReleaseSla, Remittance, RemittanceAnalytics, RemittanceUpdate,
+        ReviewPolicy, RiskEngine, SavingsPool, ScheduledStart, SettlementPartner, TravelRuleData,
+    },
+    ContractEvent, ContractSchema, LedgerEntry,
+};
+
+use crate::{
+    calls::{ContributeSession, EntryPointCall},
+    convert::decode_stored_value,
+    error::{classify_failure, ClientError},
+    events::{decode_execution_events, EventListener},
+};
+
+/// Dictionary names; must match `contracts/src/storage.rs`
+mod dictionaries {
+    pub const REMITTANCES: &str = "remittance_records";
+    pub const LEGACY_REMITTANCES: &str = "remittances";
+    pub const CONTRIBUTIONS: &str = "contributions";
+    pub const CONTRIBUTION_COMMITMENTS: &str = "contribution_commitments";
+    pub const CONTRIBUTORS: &str = "contributors";
+    pub const REFUND_CLAIMED: &str = "refund_claimed";
+    pub const USER_REMITTANCES: &str = "user_remittances";
+    pub const RECIPIENT_REMITTANCES: &str = "recipient_remittances";
+    pub const CREATOR_REWARDS: &str = "creator_rewards";
+    pub const REMITTANCE_KEYS: &str = "remittance_keys";
+    pub const DOCUMENTS: &str = "remittance_documents";
+    pub const VERIFIED_CHARITIES: &str = "verified_charities";
+    pub const TRAVEL_RULE: &str = "travel_rule_data";
+    pub const PERIOD_STATS: &str = "period_stats";
+    pub const SNAPSHOTS: &str = "accounting_snapshots";
+    pub const LEDGER: &str = "remittance_ledger";
+    pub const LEDGER_LENGTHS: &str = "remittance_ledger_lengths";
+    pub const BRIDGE_PAYOUTS: &str = "bridge_payouts";
+    pub const SETTLEMENT_PARTNERS: &str = "settlement_partners";
+    pub const PAYOUT_ROUTES: &str = "payout_routes";
+    pub const CONTRIBUTOR_COUNTS: &str = "contributor_counts";
+    pub const CREATION_INDEX: &str = "creation_index";
+    pub const HANDLES: &str = "remittance_handles";
+    pub const HANDLE_BY_REMITTANCE: &str = "handle_by_remittance";
+    pub const INSTANT_SPLITS: &str = "instant_splits";
+    pub const BACKUP_RECIPIENTS: &str = "backup_recipients";
+    pub const PAYOUT_SCHEDULES: &str = "payout_schedules";
+    pub const INSURANCE_POLICIES: &str = "insurance_policies";
+    pub const INSURANCE_CLAIMS: &str = "insurance_claims";
+    pub const ANALYTICS: &str = "remittance_analytics";
+    pub const UPDATES: &str = "remittance_updates";
+    pub const LOCALIZED_PURPOSES: &str = "localized_purposes";
+    pub const ACKNOWLEDGEMENTS: &str = "receipt_acknowledgements";
+    pub const REFUND_POLICIES: &str = "refund_policies";
+    pub const PAYOUT_TARGETS: &str = "payout_targets";
+    pub const NAMESPACES: &str = "namespaces";
+    pub const REMITTANCE_NAMESPACES: &str = "remittance_namespaces";
+    pub const SCHEDULED_STARTS: &str = "scheduled_starts";
+    pub const CATEGORIES: &str = "categories";
+    pub const REMITTANCE_CATEGORIES: &str = "remittance_categories";
+    pub const RELEASE_SLAS: &str = "release_slas";
+    pub const DECLINE_REASONS: &str = "decline_reasons";
+    pub const FEE_COVERS: &str = "fee_covers";
+    pub const RECIPIENT_MULTISIGS: &str = "recipient_multisigs";
+    pub const CONTRIBUTION_SNAPSHOTS: &str = "contribution_snapshots";
+    pub const CONTRIBUTION_WEIGHTS: &str = "contribution_weights";
+    pub const REMITTANCE_WEIGHTS: &str = "remittance_weights";
+    pub const ESCROW_CLOSED_AT: &str = "escrow_closed_at";
+    pub const CONTRIBUTED_REMITTANCES: &str = "contributed_remittances";
+    pub const CLAIMABLE_REFUNDS: &str = "claimable_refunds";
+    pub const REFUND_DELEGATES: &str = "refund_delegates";
+    pub const VERIFIED_BUSINESSES: &str = "verified_businesses";
+    pub const BLOCKED_PURPOSES: &str = "blocked_purposes";
+    pub const RELEASE_BREAKDOWNS: &str = "release_breakdowns";
+    pub const SAVINGS_POOLS: &str = "savings_pools";
+    pub const POOL_CYCLES: &str = "pool_cycles";
+    pub const POT_DEPOSITS: &str = "pot_deposits";
+}
+
+/// Named key of the contract's CES events dictionary
+const EVENTS_DICT: &str = "__events";
+
+/// Named keys holding values; must match `contracts/src/storage.rs`
+mod named_keys {
+    pub const CONTRACT_SCHEMA: &str = "contract_schema";
+    pub const BUILD_INFO: &str = "build_info";
+    pub const RATE_LIMITS: &str = "rate_limits";
+    pub const RISK_ENGINE: &str = "risk_engine";
+    pub const REVIEW_POLICY: &str = "review_policy";
+    pub const INSURANCE_PREMIUM_BPS: &str = "insurance_premium_bps";
+    pub const INSURANCE_POOL: &str = "insurance_pool";
+    pub const MAX_TARGET_AMOUNT: &str = "max_target_amount";
+    pub const MIN_TARGET_AMOUNT: &str = "min_target_amount";
+    pub const BRANDING: &str = "branding";
+    pub const SLA_INTERVALS: &str = "sla_intervals";
+    pub const EVENT_SCHEMA_VERSION: &str = "event_schema_version";
+    pub const CONTRIBUTION_SNAPSHOT_COUNT: &str = "contribution_snapshot_count";
+    pub const KYB_MAX_TARGET_AMOUNT: &str = "kyb_max_target_amount";
+    pub const ADMIN_RENOUNCED: &str = "admin_renounced";
+    pub const GAS_LIMITS: &str = "gas_limits";
+    pub const PAYMENT_GUARDS: &str = "payment_guards";
+    pub const SAVINGS_POOL_COUNT: &str = "savings_pool_count";
+}
+
+/// RPC error code the node returns when a queried value does not exist
+const QUERY_FAILED: i64 = -32003;
+
+/// RPC error code the node returns when a dictionary named key does not exist
+const NO_SUCH_DICTIONARY: i64 = -32010;
+
+/// Client for a single CasperFlow contract on one network
+pub struct CasperFlowClient {
+    node_address: String,
+    chain_name: String,
+    contract_hash: ContractHash,
+}
+
+impl CasperFlowClient {
+    /// Creates a client for the contract at `contract_hash`.
+    ///
+    /// `node_address` is the node's RPC root, e.g. `http://localhost:11101`.
+    pub fn new(
+        node_address: impl Into<String>,
+        chain_name: impl Into<String>,
+        contract_hash: ContractHash,
+    ) -> Self {
+        CasperFlowClient {
+            node_address: node_address.into(),
+            chain_name: chain_name.into(),
+            contract_hash,
+        }
+    }
+
+    /// Hash of the contract this client targets
+    pub fn contract_hash(&self) -> ContractHash {
+        self.contract_hash
+    }
+
+    // ============================================================================
+    // Deploy Builders
+    // ============================================================================
+
+    /// Builds a signed deploy calling a stored entry point
+    pub fn call_deploy(
+        &self,
+        call: &impl EntryPointCall,
+        payment_amount: U512,
+        secret_key: &SecretKey,
+    ) -> Result<Deploy, ClientError> {
+        let session = ExecutableDeployItem::new_stored_contract_by_hash(
+            self.contract_hash,
+            call.entry_point().to_string(),
+            call.runtime_args(),
+        );
+        build_deploy(&self.chain_name, session, payment_amount, secret_key)
+    }
+
+    /// Builds a signed deploy running the contribute session
+    pub fn contribute_deploy(
+        &self,
+        contribution: &ContributeSession,
+        payment_amount: U512,
+        secret_key: &SecretKey,
+    ) -> Result<Deploy, ClientError> {
+        let session = ExecutableDeployItem::new_module_bytes(
+            contribution.session_wasm().clone(),
+            contribution.runtime_args(),
+        );
+        build_deploy(&self.chain_name, session, payment_amount, secret_key)
+    }
+
+    // ============================================================================
+    // Deploy Submission
+    // ============================================================================
+
+    /// Sends a deploy to the node and returns its hash
+    pub async fn put_deploy(&self, deploy: Deploy) -> Result<DeployHash, ClientError> {
+        put_deploy(&self.node_address, deploy).await
+    }
+
+    /// Polls until a deploy has executed and returns its cost.
+    ///
+    /// A contract revert is returned as [`ClientError::Contract`].
+    pub async fn wait_for_deploy(
+        &self,
+        deploy_hash: DeployHash,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<U512, ClientError> {
+        wait_for_execution(&self.node_address, deploy_hash, poll_interval, timeout).await
+    }
+
+    /// Sends a deploy and waits for it to execute
+    pub async fn send_and_wait(
+        &self,
+        deploy: Deploy,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<U512, ClientError> {
+        let deploy_hash = self.put_deploy(deploy).await?;
+        self.wait_for_deploy(deploy_hash, poll_interval, timeout)
+            .await
+    }
+
+    // ============================================================================
+    // State Queries
+    // ============================================================================
+
+    /// Gets a remittance by ID, reading the legacy layout if not yet migrated
+    pub async fn get_remittance(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<Remittance>, ClientError> {
+        let key = remittance_id.to_string();
+
+        if let Some(remittance) = self
+            .dictionary_item(dictionaries::REMITTANCES, &key)
+            .await?
+        {
+            return Ok(Some(remittance));
+        }
+
+        let legacy: Option<LegacyRemittance> = self
+            .dictionary_item(dictionaries::LEGACY_REMITTANCES, &key)
+            .await?;
+        Ok(legacy.map(|LegacyRemittance(remittance)| remittance))
+    }
+
+    /// Resolves a private remittance key to its internal ID
+    pub async fn resolve_remittance_key(
+        &self,
+        remittance_key: [u8; 32],
+    ) -> Result<Option<u64>, ClientError> {
+        let key = hex_key(&remittance_key);
+        self.dictionary_item(dictionaries::REMITTANCE_KEYS, &key)
+            .await
+    }
+
+    /// Gets the amount a contributor has put into a remittance
+    pub async fn get_contribution(
+        &self,
+        remittance_id: u64,
+        contributor: AccountHash,
+    ) -> Result<types_v4::U512, ClientError> {
+        let key = contributor_dictionary_key(remittance_id, contributor);
+        Ok(self
+            .dictionary_item(dictionaries::CONTRIBUTIONS, &key)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets a contributor's time weight as last updated; project it to a
+    /// block time with [`ContributionWeight::weight_at`]
+    pub async fn get_contribution_weight(
+        &self,
+        remittance_id: u64,
+        contributor: AccountHash,
+    ) -> Result<ContributionWeight, ClientError> {
+        let key = contributor_dictionary_key(remittance_id, contributor);
+        Ok(self
+            .dictionary_item(dictionaries::CONTRIBUTION_WEIGHTS, &key)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the combined time weight of all contributions to a remittance
+    pub async fn get_remittance_weight(
+        &self,
+        remittance_id: u64,
+    ) -> Result<ContributionWeight, ClientError> {
+        Ok(self
+            .dictionary_item(dictionaries::REMITTANCE_WEIGHTS, &remittance_id.to_string())
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the block time a remittance stopped accruing time weight, if it
+    /// has been released, cancelled, or expired
+    pub async fn get_escrow_closed_at(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<u64>, ClientError> {
+        self.dictionary_item(dictionaries::ESCROW_CLOSED_AT, &remittance_id.to_string())
+            .await
+    }
+
+    /// Gets a contributor's amount commitment on a confidential remittance
+    pub async fn get_contribution_commitment(
+        &self,
+        remittance_id: u64,
+        contributor: AccountHash,
+    ) -> Result<Option<[u8; 32]>, ClientError> {
+        let key = contributor_dictionary_key(remittance_id, contributor);
+        self.dictionary_item(dictionaries::CONTRIBUTION_COMMITMENTS, &key)
+            .await
+    }
+
+    /// Gets the accounts that have contributed to a remittance
+    pub async fn get_contributors(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Vec<types_v4::account::AccountHash>, ClientError> {
+        let key = remittance_id.to_string();
+        Ok(self
+            .dictionary_item(dictionaries::CONTRIBUTORS, &key)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Checks if a contributor has claimed their refund
+    pub async fn is_refund_claimed(
+        &self,
+        remittance_id: u64,
+        contributor: AccountHash,
+    ) -> Result<bool, ClientError> {
+        let key = contributor_dictionary_key(remittance_id, contributor);
+        Ok(self
+            .dictionary_item(dictionaries::REFUND_CLAIMED, &key)
+            .await?
+            .unwrap_or(false))
+    }
+
+    /// Gets the IDs of remittances an account created
+    pub async fn get_user_remittances(&self, user: AccountHash) -> Result<Vec<u64>, ClientError> {
+        Ok(self
+            .dictionary_item(dictionaries::USER_REMITTANCES, &user.to_string())
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the IDs of remittances an account contributed to, in order of
+    /// first contribution
+    pub async fn get_contributed_remittances(
+        &self,
+        contributor: AccountHash,
+    ) -> Result<Vec<u64>, ClientError> {
+        Ok(self
+            .dictionary_item(dictionaries::CONTRIBUTED_REMITTANCES, &contributor.to_string())
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the IDs of cancelled or expired remittances a contributor has not
+    /// claimed a refund from yet, in order of closing
+    pub async fn get_claimable_refunds(
+        &self,
+        contributor: AccountHash,
+    ) -> Result<Vec<u64>, ClientError> {
+        Ok(self
+            .dictionary_item(dictionaries::CLAIMABLE_REFUNDS, &contributor.to_string())
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the account allowed to claim a contributor's refunds, if any
+    pub async fn get_refund_delegate(
+        &self,
+        contributor: AccountHash,
+    ) -> Result<Option<types_v4::account::AccountHash>, ClientError> {
+        let delegate: Option<Option<types_v4::account::AccountHash>> = self
+            .dictionary_item(dictionaries::REFUND_DELEGATES, &contributor.to_string())
+            .await?;
+        Ok(delegate.flatten())
+    }
+
+    /// Gets everything a wallet shows for one account, as the
+    /// `get_account_overview` view returns it, with refunds priced at block
+    /// time `now`
+    pub async fn get_account_overview(
+        &self,
+        account: AccountHash,
+        now: u64,
+    ) -> Result<AccountOverview, ClientError> {
+        let mut refundable = Vec::new();
+        for remittance_id in self.get_claimable_refunds(account).await? {
+            let remittance = match self.get_remittance(remittance_id).await? {
+                Some(remittance) => remittance,
+                None => continue,
+            };
+            if remittance.is_confidential() {
+                continue;
+            }
+
+            let share = if remittance.is_open_ended() {
+                let deposit = self.get_pot_deposit(remittance_id, account).await?;
+                Ok(deposit.unwithdrawn(remittance.released_amount))
+            } else {
+                let contribution = self.get_contribution(remittance_id, account).await?;
+                remittance.unreleased_share(contribution)
+            };
+            let share = match share {
+                Ok(share) if !share.is_zero() => share,
+                _ => continue,
+            };
+            let retained = self.get_refund_policy(remittance_id).await?.deduction(
+                share,
+                remittance.created_at,
+                now,
+            );
+            refundable.push((remittance_id, share - retained));
+        }
+
+        Ok(AccountOverview {
+            created: self.get_user_remittances(account).await?,
+            receiving: self.get_recipient_remittances(account).await?,
+            contributed: self.get_contributed_remittances(account).await?,
+            refundable,
+            creator_rewards: self.get_creator_rewards(account).await?,
+        })
+    }
+
+    /// Resolves a handle to the ID of the remittance it names
+    pub async fn resolve_handle(&self, handle: &str) -> Result<Option<u64>, ClientError> {
+        self.dictionary_item(dictionaries::HANDLES, handle).await
+    }
+
+    /// Gets the handle registered for a remittance, if any
+    pub async fn get_handle(&self, remittance_id: u64) -> Result<Option<String>, ClientError> {
+        self.dictionary_item(dictionaries::HANDLE_BY_REMITTANCE, &remittance_id.to_string())
+            .await
+    }
+
+    /// Gets the IDs of public remittances created between two days
+    /// (inclusive), oldest first
+    ///
+    /// Days are block time in milliseconds divided by `MILLIS_PER_DAY`.
+    pub async fn get_remittances_created_between(
+        &self,
+        day_from: u64,
+        day_to: u64,
+    ) -> Result<Vec<u64>, ClientError> {
+        let mut remittance_ids = Vec::new();
+        for day in day_from..=day_to {
+            let ids: Option<Vec<u64>> = self
+                .dictionary_item(dictionaries::CREATION_INDEX, &day.to_string())
+                .await?;
+            remittance_ids.extend(ids.unwrap_or_default());
+        }
+        Ok(remittance_ids)
+    }
+
+    /// Gets the IDs of remittances an account is the recipient of
+    pub async fn get_recipient_remittances(
+        &self,
+        recipient: AccountHash,
+    ) -> Result<Vec<u64>, ClientError> {
+        Ok(self
+            .dictionary_item(dictionaries::RECIPIENT_REMITTANCES, &recipient.to_string())
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets a creator's claimable fee rebates
+    pub async fn get_creator_rewards(
+        &self,
+        creator: AccountHash,
+    ) -> Result<types_v4::U512, ClientError> {
+        Ok(self
+            .dictionary_item(dictionaries::CREATOR_REWARDS, &creator.to_string())
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the documents attached to a remittance, oldest first
+    pub async fn get_documents(&self, remittance_id: u64) -> Result<Vec<DocumentRef>, ClientError> {
+        let key = remittance_id.to_string();
+        Ok(self
+            .dictionary_item(dictionaries::DOCUMENTS, &key)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the updates posted on a remittance, oldest first
+    pub async fn get_updates(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Vec<RemittanceUpdate>, ClientError> {
+        let key = remittance_id.to_string();
+        Ok(self
+            .dictionary_item(dictionaries::UPDATES, &key)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the translations of a remittance's purpose, in the order first set
+    pub async fn get_localized_purposes(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Vec<LocalizedPurpose>, ClientError> {
+        let key = remittance_id.to_string();
+        Ok(self
+            .dictionary_item(dictionaries::LOCALIZED_PURPOSES, &key)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets a remittance's purpose in language `lang`, falling back to the
+    /// primary language and then to the purpose given at creation
+    pub async fn get_purpose(
+        &self,
+        remittance_id: u64,
+        lang: &str,
+    ) -> Result<Option<String>, ClientError> {
+        let remittance = match self.get_remittance(remittance_id).await? {
+            Some(remittance) => remittance,
+            None => return Ok(None),
+        };
+        let purposes = self.get_localized_purposes(remittance_id).await?;
+        Ok(Some(
+            localized_purpose(&purposes, lang).map_or(remittance.purpose, String::from),
+        ))
+    }
+
+    /// Gets the recipient's acknowledgement of a released remittance
+    pub async fn get_acknowledgement(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<ReceiptAcknowledgement>, ClientError> {
+        self.dictionary_item(dictionaries::ACKNOWLEDGEMENTS, &remittance_id.to_string())
+            .await
+    }
+
+    /// Gets progress towards a remittance's target in basis points, or
+    /// `None` if the remittance does not exist
+    pub async fn get_progress(&self, remittance_id: u64) -> Result<Option<u64>, ClientError> {
+        Ok(self
+            .get_remittance(remittance_id)
+            .await?
+            .map(|remittance| remittance.progress_bps()))
+    }
+
+    /// Gets a remittance's amounts, deadline, and status as of block time
+    /// `now` (Unix ms), or `None` if the remittance does not exist
+    pub async fn get_funding_state(
+        &self,
+        remittance_id: u64,
+        now: u64,
+    ) -> Result<Option<FundingState>, ClientError> {
+        Ok(self
+            .get_remittance(remittance_id)
+            .await?
+            .map(|remittance| remittance.funding_state(now)))
+    }
+
+    /// Gets the number of unique contributors to a remittance
+    ///
+    /// Remittances with no contributions since the counter was added read as
+    /// zero; the `get_contributor_count` view also counts those.
+    pub async fn get_contributor_count(&self, remittance_id: u64) -> Result<u64, ClientError> {
+        let key = remittance_id.to_string();
+        Ok(self
+            .dictionary_item(dictionaries::CONTRIBUTOR_COUNTS, &key)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the contribution analytics of a remittance.
+    ///
+    /// The stored hourly buckets are only aged on contribution; call
+    /// `advance` with the current block time before reading
+    /// `recent_contributions`.
+    pub async fn get_remittance_analytics(
+        &self,
+        remittance_id: u64,
+    ) -> Result<RemittanceAnalytics, ClientError> {
+        let key = remittance_id.to_string();
+        Ok(self
+            .dictionary_item(dictionaries::ANALYTICS, &key)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the number of entries in a remittance's ledger
+    pub async fn get_ledger_length(&self, remittance_id: u64) -> Result<u64, ClientError> {
+        let key = remittance_id.to_string();
+        Ok(self
+            .dictionary_item(dictionaries::LEDGER_LENGTHS, &key)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets all of a remittance's ledger entries, oldest first
+    pub async fn get_ledger_entries(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Vec<LedgerEntry>, ClientError> {
+        let length = self.get_ledger_length(remittance_id).await?;
+
+        let mut entries = Vec::new();
+        for index in 0..length {
+            let key = format!("{}_{}", remittance_id, index);
+            if let Some(entry) = self.dictionary_item(dictionaries::LEDGER, &key).await? {
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Gets the bridge payout pending or confirmed for a remittance, if any
+    pub async fn get_bridge_payout(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<BridgePayout>, ClientError> {
+        let key = remittance_id.to_string();
+        Ok(self
+            .dictionary_item::<Option<BridgePayout>>(dictionaries::BRIDGE_PAYOUTS, &key)
+            .await?
+            .flatten())
+    }
+
+    /// Gets a registered settlement partner
+    pub async fn get_settlement_partner(
+        &self,
+        partner: AccountHash,
+    ) -> Result<Option<SettlementPartner>, ClientError> {
+        Ok(self
+            .dictionary_item::<Option<SettlementPartner>>(
+                dictionaries::SETTLEMENT_PARTNERS,
+                &partner.to_string(),
+            )
+            .await?
+            .flatten())
+    }
+
+    /// Gets the settlement partner a release was routed to, if any
+    pub async fn get_payout_route(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<PayoutRoute>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::PAYOUT_ROUTES, &key).await
+    }
+
+    /// Gets a namespace by name
+    pub async fn get_namespace(&self, namespace: &str) -> Result<Option<Namespace>, ClientError> {
+        self.dictionary_item(dictionaries::NAMESPACES, namespace).await
+    }
+
+    /// Gets a category template by name
+    pub async fn get_category(
+        &self,
+        category: &str,
+    ) -> Result<Option<CategoryTemplate>, ClientError> {
+        self.dictionary_item(dictionaries::CATEGORIES, category).await
+    }
+
+    /// Gets the name of the category a remittance was created in, if any
+    pub async fn get_remittance_category(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<String>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::REMITTANCE_CATEGORIES, &key).await
+    }
+
+    /// Gets why the recipient declined a remittance, if they did
+    pub async fn get_decline_reason(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<String>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::DECLINE_REASONS, &key).await
+    }
+
+    /// Gets the name of the namespace a remittance belongs to, if any
+    pub async fn get_remittance_namespace(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<String>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::REMITTANCE_NAMESPACES, &key).await
+    }
+
+    /// Gets an account's payout address book
+    pub async fn get_payout_targets(
+        &self,
+        account: AccountHash,
+    ) -> Result<Vec<PayoutTarget>, ClientError> {
+        Ok(self
+            .dictionary_item(dictionaries::PAYOUT_TARGETS, &account.to_string())
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the instant split of a remittance, if it forwards contributions
+    pub async fn get_instant_split(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<InstantSplit>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::INSTANT_SPLITS, &key).await
+    }
+
+    /// Gets the backup recipient of a remittance, if the creator nominated one
+    pub async fn get_backup_recipient(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<BackupRecipient>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::BACKUP_RECIPIENTS, &key).await
+    }
+
+    /// Gets the installment schedule of a remittance, if it pays out in
+    /// installments
+    pub async fn get_payout_schedule(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<PayoutSchedule>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::PAYOUT_SCHEDULES, &key).await
+    }
+
+    /// Gets the signers, threshold, and approvals of a multi-signature
+    /// recipient, if the creator required them
+    pub async fn get_recipient_multisig(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<RecipientMultisig>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::RECIPIENT_MULTISIGS, &key).await
+    }
+
+    /// Gets the scheduled start of a remittance, if the creator set one
+    pub async fn get_scheduled_start(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<ScheduledStart>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::SCHEDULED_STARTS, &key).await
+    }
+
+    /// Gets the release SLA of a remittance, once its target has been met
+    pub async fn get_release_sla(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<ReleaseSla>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::RELEASE_SLAS, &key).await
+    }
+
+    /// Gets the insurance policy of a remittance, if it is insured
+    pub async fn get_insurance_policy(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<InsurancePolicy>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::INSURANCE_POLICIES, &key).await
+    }
+
+    /// Gets the fee cover of a remittance, if anyone pre-funded its fee
+    pub async fn get_fee_cover(&self, remittance_id: u64) -> Result<Option<FeeCover>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::FEE_COVERS, &key).await
+    }
+
+    /// Gets the itemized split of a remittance's release, once released
+    pub async fn get_release_breakdown(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<ReleaseBreakdown>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::RELEASE_BREAKDOWNS, &key).await
+    }
+
+    /// Gets the refund policy of a remittance; full refunds if the creator
+    /// chose none
+    pub async fn get_refund_policy(&self, remittance_id: u64) -> Result<RefundPolicy, ClientError> {
+        let key = remittance_id.to_string();
+        Ok(self
+            .dictionary_item(dictionaries::REFUND_POLICIES, &key)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Checks if a contributor has claimed their insurance top-up
+    pub async fn is_insurance_claimed(
+        &self,
+        remittance_id: u64,
+        contributor: AccountHash,
+    ) -> Result<bool, ClientError> {
+        let key = contributor_dictionary_key(remittance_id, contributor);
+        Ok(self
+            .dictionary_item(dictionaries::INSURANCE_CLAIMS, &key)
+            .await?
+            .unwrap_or(false))
+    }
+
+    /// Checks if an account is a verified charity
+    pub async fn is_verified_charity(&self, account: AccountHash) -> Result<bool, ClientError> {
+        Ok(self
+            .dictionary_item(dictionaries::VERIFIED_CHARITIES, &account.to_string())
+            .await?
+            .unwrap_or(false))
+    }
+
+    /// Checks if an account is a KYB-verified business
+    pub async fn is_verified_business(&self, account: AccountHash) -> Result<bool, ClientError> {
+        Ok(self
+            .dictionary_item(dictionaries::VERIFIED_BUSINESSES, &account.to_string())
+            .await?
+            .unwrap_or(false))
+    }
+
+    /// Checks if a purpose would be rejected by the purpose blocklist
+    pub async fn is_purpose_blocked(&self, purpose: &str) -> Result<bool, ClientError> {
+        Ok(self
+            .dictionary_item(dictionaries::BLOCKED_PURPOSES, &hex_key(&purpose_hash(purpose)))
+            .await?
+            .unwrap_or(false))
+    }
+
+    /// Checks if a remittance pays out to a KYB-verified business, so
+    /// contributors know they are funding a registered merchant
+    pub async fn is_business_payout(&self, remittance_id: u64) -> Result<bool, ClientError> {
+        match self.get_remittance(remittance_id).await? {
+            Some(remittance) => self.is_verified_business(remittance.recipient).await,
+            None => Ok(false),
+        }
+    }
+
+    /// Gets the travel-rule data attached to a remittance
+    pub async fn get_travel_rule_data(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<TravelRuleData>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::TRAVEL_RULE, &key).await
+    }
+
+    /// Gets the aggregates for a day, or for one corridor on that day
+    pub async fn get_period_stats(
+        &self,
+        day_index: u64,
+        corridor: Option<&str>,
+    ) -> Result<PeriodStats, ClientError> {
+        let key = match corridor {
+            Some(corridor) => format!("{}_{}", day_index, corridor),
+            None => day_index.to_string(),
+        };
+        Ok(self
+            .dictionary_item(dictionaries::PERIOD_STATS, &key)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets an accounting snapshot by index
+    pub async fn get_snapshot(
+        &self,
+        index: u64,
+    ) -> Result<Option<AccountingSnapshot>, ClientError> {
+        self.dictionary_item(dictionaries::SNAPSHOTS, &index.to_string())
+            .await
+    }
+
+    /// Gets a frozen contributor snapshot by ID
+    pub async fn get_contribution_snapshot(
+        &self,
+        snapshot_id: u64,
+    ) -> Result<Option<ContributionSnapshot>, ClientError> {
+        self.dictionary_item(dictionaries::CONTRIBUTION_SNAPSHOTS, &snapshot_id.to_string())
+            .await
+    }
+
+    /// Gets the number of contributor snapshots taken
+    pub async fn get_contribution_snapshot_count(&self) -> Result<u64, ClientError> {
+        Ok(self
+            .named_value(named_keys::CONTRIBUTION_SNAPSHOT_COUNT)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets a savings pool by ID
+    pub async fn get_savings_pool(&self, pool_id: u64) -> Result<Option<SavingsPool>, ClientError> {
+        self.dictionary_item(dictionaries::SAVINGS_POOLS, &pool_id.to_string()).await
+    }
+
+    /// Gets the number of savings pools created
+    pub async fn get_savings_pool_count(&self) -> Result<u64, ClientError> {
+        Ok(self
+            .named_value(named_keys::SAVINGS_POOL_COUNT)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the ID of the savings pool a remittance is a cycle of, if any
+    pub async fn get_remittance_pool(
+        &self,
+        remittance_id: u64,
+    ) -> Result<Option<u64>, ClientError> {
+        let key = remittance_id.to_string();
+        self.dictionary_item(dictionaries::POOL_CYCLES, &key).await
+    }
+
+    /// Gets a contributor's deposits into an open-ended pot; the refundable
+    /// part is [`PotDeposit::unwithdrawn`] at the pot's released amount
+    pub async fn get_pot_deposit(
+        &self,
+        remittance_id: u64,
+        contributor: AccountHash,
+    ) -> Result<PotDeposit, ClientError> {
+        let key = contributor_dictionary_key(remittance_id, contributor);
+        Ok(self
+            .dictionary_item(dictionaries::POT_DEPOSITS, &key)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the entry point and event schema recorded at install time.
+    ///
+    /// Returns `None` for installations that predate the schema key.
+    pub async fn get_schema(&self) -> Result<Option<ContractSchema>, ClientError> {
+        self.named_value(named_keys::CONTRACT_SCHEMA).await
+    }
+
+    /// Gets the version of the event layouts the contract emits; zero for
+    /// installations that predate versioned events
+    pub async fn get_event_schema_version(&self) -> Result<u32, ClientError> {
+        Ok(self
+            .named_value(named_keys::EVENT_SCHEMA_VERSION)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the crate version and git commit of the installed build.
+    ///
+    /// Returns `None` for installations that predate the build info key.
+    pub async fn get_version(&self) -> Result<Option<BuildInfo>, ClientError> {
+        self.named_value(named_keys::BUILD_INFO).await
+    }
+
+    /// Gets the per-account rate limits; all zero (disabled) for
+    /// installations that predate them.
+    pub async fn get_rate_limits(&self) -> Result<RateLimits, ClientError> {
+        Ok(self
+            .named_value(named_keys::RATE_LIMITS)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the self-remittance and recipient contribution guards; the
+    /// permissive defaults for installations that predate them.
+    pub async fn get_payment_guards(&self) -> Result<PaymentGuards, ClientError> {
+        Ok(self
+            .named_value(named_keys::PAYMENT_GUARDS)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the per-call batch, page, and contributor limits; all zero (the
+    /// built-in maximums) for installations that predate them.
+    pub async fn get_gas_limits(&self) -> Result<GasLimits, ClientError> {
+        Ok(self
+            .named_value(named_keys::GAS_LIMITS)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the release review policy; review is disabled for installations
+    /// that predate it.
+    pub async fn get_review_policy(&self) -> Result<ReviewPolicy, ClientError> {
+        Ok(self
+            .named_value(named_keys::REVIEW_POLICY)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the largest target amount a new remittance may have; zero means
+    /// no cap, as on installations that predate it.
+    pub async fn get_max_target_amount(&self) -> Result<types_v4::U512, ClientError> {
+        Ok(self
+            .named_value(named_keys::MAX_TARGET_AMOUNT)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the smallest target amount a new remittance may have; zero means
+    /// no minimum, as on installations that predate it.
+    pub async fn get_min_target_amount(&self) -> Result<types_v4::U512, ClientError> {
+        Ok(self
+            .named_value(named_keys::MIN_TARGET_AMOUNT)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Checks whether the owner has renounced admin control; false on
+    /// installations that predate it.
+    pub async fn is_admin_renounced(&self) -> Result<bool, ClientError> {
+        Ok(self
+            .named_value(named_keys::ADMIN_RENOUNCED)
+            .await?
+            .unwrap_or(false))
+    }
+
+    /// Gets the largest target amount a new remittance to a KYB-verified
+    /// business may have; zero means the standard maximum applies.
+    pub async fn get_kyb_max_target_amount(&self) -> Result<types_v4::U512, ClientError> {
+        Ok(self
+            .named_value(named_keys::KYB_MAX_TARGET_AMOUNT)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets how frontends should present the instance; empty for
+    /// installations whose owner has not set it.
+    pub async fn get_branding(&self) -> Result<Branding, ClientError> {
+        Ok(self
+            .named_value(named_keys::BRANDING)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the release escalation intervals in milliseconds; empty for
+    /// installations whose owner has not set them.
+    pub async fn get_sla_intervals(&self) -> Result<Vec<u64>, ClientError> {
+        Ok(self
+            .named_value(named_keys::SLA_INTERVALS)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the premium charged on new insured remittances in basis points;
+    /// zero for installations that predate insurance.
+    pub async fn get_insurance_premium(&self) -> Result<u64, ClientError> {
+        Ok(self
+            .named_value(named_keys::INSURANCE_PREMIUM_BPS)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the balance of the insurance pool; zero for installations that
+    /// predate insurance.
+    pub async fn get_insurance_pool(&self) -> Result<types_v4::U512, ClientError> {
+        Ok(self
+            .named_value(named_keys::INSURANCE_POOL)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the risk engine consulted before large releases, if one is
+    /// registered.
+    pub async fn get_risk_engine(&self) -> Result<Option<RiskEngine>, ClientError> {
+        Ok(self
+            .named_value::<Option<RiskEngine>>(named_keys::RISK_ENGINE)
+            .await?
+            .flatten())
+    }
+
+    // ============================================================================
+    // Events
+    // ============================================================================
+
+    /// Gets the seed address of the contract's CES events dictionary
+    pub async fn events_seed(&self) -> Result<[u8; 32], ClientError> {
+        let state_root_hash = self.state_root_hash().await?;
+        let response = casper_client::query_global_state(
+            rpc_id(),
+            &self.node_address,
+            Verbosity::Low,
+            GlobalStateIdentifier::StateRootHash(state_root_hash),
+            Key::Hash(self.contract_hash.value()),
+            Vec::new(),
+        )
+        .await?;
+
+        let contract = match response.result.stored_value {
+            StoredValue::Contract(contract) => contract,
+            _ => return Err(ClientError::UnexpectedStoredValue("Contract")),
+        };
+
+        let seed = contract
+            .named_keys()
+            .find(|named_key| named_key.name() == EVENTS_DICT)
+            .and_then(|named_key| named_key.key().ok())
+            .and_then(|key| key.into_uref())
+            .map(|uref| uref.addr());
+        seed.ok_or(ClientError::NoEventsDictionary)
+    }
+
+    /// Creates a listener for this contract's events on `events_url`
+    pub async fn event_listener(
+        &self,
+        events_url: impl Into<String>,
+    ) -> Result<EventListener, ClientError> {
+        Ok(EventListener::new(events_url, self.events_seed().await?))
+    }
+
+    /// Gets the events an executed deploy emitted, in emission order
+    pub async fn get_deploy_events(
+        &self,
+        deploy_hash: DeployHash,
+    ) -> Result<Vec<ContractEvent>, ClientError> {
+        let events_seed = self.events_seed().await?;
+        let response = casper_client::get_deploy(
+            rpc_id(),
+            &self.node_address,
+            Verbosity::Low,
+            deploy_hash,
+            false,
+        )
+        .await?;
+
+        let mut events = Vec::new();
+        for execution in &response.result.execution_results {
+            for decoded in decode_execution_events(&execution.result, &events_seed) {
+                events.push(decoded?);
+            }
+        }
+        events.sort_by_key(|(event_id, _)| *event_id);
+        Ok(events.into_iter().map(|(_, event)| event).collect())
+    }
+
+    /// Reads and decodes an item from one of the contract's dictionaries
+    async fn dictionary_item<T: FromBytes>(
+        &self,
+        dictionary_name: &str,
+        item_key: &str,
+    ) -> Result<Option<T>, ClientError> {
+        let state_root_hash = self.state_root_hash().await?;
+
+        let identifier = DictionaryItemIdentifier::ContractNamedKey {
+            key: Key::Hash(self.contract_hash.value()).to_formatted_string(),
+            dictionary_name: dictionary_name.to_string(),
+            dictionary_item_key: item_key.to_string(),
+        };
+
+        match casper_client::get_dictionary_item(
+            rpc_id(),
+            &self.node_address,
+            Verbosity::Low,
+            state_root_hash,
+            identifier,
+        )
+        .await
+        {
+            Ok(response) => decode_stored_value(response.result.stored_value).map(Some),
+            Err(RpcError::ResponseIsRpcError { error, .. })
+                if error.code == QUERY_FAILED || error.code == NO_SUCH_DICTIONARY =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Reads and decodes a value under one of the contract's named keys
+    async fn named_value<T: FromBytes>(&self, name: &str) -> Result<Option<T>, ClientError> {
+        let state_root_hash = self.state_root_hash().await?;
+        match casper_client::query_global_state(
+            rpc_id(),
+            &self.node_address,
+            Verbosity::Low,
+            GlobalStateIdentifier::StateRootHash(state_root_hash),
+            Key::Hash(self.contract_hash.value()),
+            vec![name.to_string()],
+        )
+        .await
+        {
+            Ok(response) => decode_stored_value(response.result.stored_value).map(Some),
+            Err(RpcError::ResponseIsRpcError { error, .. }) if error.code == QUERY_FAILED => {
+                Ok(None)
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    async fn state_root_hash(&self) -> Result<casper_hashing::Digest, ClientError> {
+        casper_client::get_state_root_hash(rpc_id(), &self.node_address, Verbosity::Low, None)
+            .await?
+            .result
+            .state_root_hash
+            .ok_or(ClientError::NoStateRootHash)
+    }
+}
+
+/// Builds a signed deploy with standard payment
+pub(crate) fn build_deploy(
+    chain_name: &str,
+    session: ExecutableDeployItem,
+    payment_amount: U512,
+    secret_key: &SecretKey,
+) -> Result<Deploy, ClientError> {
+    Ok(DeployBuilder::new(chain_name, session, secret_key)
+        .with_standard_payment(payment_amount)
+        .build()?)
+}
+
+/// Sends a deploy to the node and returns its hash
+pub(crate) async fn put_deploy(
+    node_address: &str,
+    deploy: Deploy,
+) -> Result<DeployHash, ClientError> {
+    let response =
+        casper_client::put_deploy(rpc_id(), node_address, Verbosity::Low, deploy).await?;
+    Ok(response.result.deploy_hash)
+}
+
+/// Polls until a deploy has executed and returns its cost
+pub(crate) async fn wait_for_execution(
+    node_address: &str,
+    deploy_hash: DeployHash,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> Result<U512, ClientError> {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        let response =
+            casper_client::get_deploy(rpc_id(), node_address, Verbosity::Low, deploy_hash, false)
+                .await?;
+
+        if let Some(execution) = response.result.execution_results.into_iter().next() {
+            return match execution.result {
+                ExecutionResult::Success { cost, .. } => Ok(cost),
+                ExecutionResult::Failure { error_message, .. } => {
+                    Err(classify_failure(&error_message))
+                }
+            };
+        }
+
+        if Instant::now() >= deadline {
+            return Err(ClientError::Timeout);
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// Returns a fresh JSON-RPC request ID
+pub(crate) fn rpc_id() -> JsonRpcId {
+    static NEXT_RPC_ID: AtomicI64 = AtomicI64::new(1);
+    JsonRpcId::from(NEXT_RPC_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Dictionary key for a contributor's entry on a remittance
+fn contributor_dictionary_key(remittance_id: u64, contributor: AccountHash) -> String {
+    let contributor = types_v4::account::AccountHash::new(contributor.value());
+    hex_key(&contributor_key(remittance_id, &contributor))
+}
+
+/// Hex-encodes a 32-byte key the way the contract keys its dictionaries
+fn hex_key(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
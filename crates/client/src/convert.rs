@@ -0,0 +1,59 @@
+//! Conversions between the casper-client value types and the contract types.
+//!
+//! casper-client is built on casper-types 3 while the contract types use
+//! casper-types 4. Both share the same bytesrepr encoding, so values cross
+//! over through their serialized bytes.
+
+use casper_client::types::StoredValue;
+use casper_types::{account::AccountHash, U512};
+use casperflow_types::casper_types::{self as types_v4, bytesrepr::FromBytes};
+
+use crate::error::ClientError;
+
+/// Decodes a stored `CLValue` into a contract type
+pub fn decode_stored_value<T: FromBytes>(stored_value: StoredValue) -> Result<T, ClientError> {
+    match stored_value {
+        StoredValue::CLValue(cl_value) => Ok(types_v4::bytesrepr::deserialize_from_slice(
+            cl_value.inner_bytes(),
+        )?),
+        _ => Err(ClientError::UnexpectedStoredValue("CLValue")),
+    }
+}
+
+/// Converts a contract account hash into the casper-client type
+pub fn account_hash(account_hash: &types_v4::account::AccountHash) -> AccountHash {
+    AccountHash::new(account_hash.value())
+}
+
+/// Converts a contract amount into the casper-client type
+pub fn motes(amount: &types_v4::U512) -> U512 {
+    let mut bytes = [0u8; 64];
+    amount.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use casper_types::CLValue;
+
+    #[test]
+    fn test_decode_stored_value() {
+        let stored = StoredValue::CLValue(CLValue::from_t(U512::from(42)).unwrap());
+        let amount: types_v4::U512 = decode_stored_value(stored).unwrap();
+        assert_eq!(amount, types_v4::U512::from(42));
+        assert_eq!(motes(&amount), U512::from(42));
+
+        let stored = StoredValue::ContractWasm(String::new());
+        assert!(matches!(
+            decode_stored_value::<u64>(stored),
+            Err(ClientError::UnexpectedStoredValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_account_hash() {
+        let hash = types_v4::account::AccountHash::new([9u8; 32]);
+        assert_eq!(account_hash(&hash), AccountHash::new([9u8; 32]));
+    }
+}
@@ -0,0 +1,111 @@
+//! Error type for the CasperFlow client.
+
+use core::fmt;
+
+use casperflow_types::casper_types::bytesrepr;
+
+/// Errors returned by the client
+#[derive(Debug)]
+pub enum ClientError {
+    /// The JSON-RPC request failed or the node returned an error
+    Rpc(Box<casper_client::Error>),
+    /// The node returned no state root hash
+    NoStateRootHash,
+    /// A stored value was not a `CLValue`
+    UnexpectedStoredValue(&'static str),
+    /// A stored value could not be decoded into the expected type
+    Decode(bytesrepr::Error),
+    /// The deploy executed and the contract reverted with a known error
+    Contract(casperflow_types::Error),
+    /// The deploy executed and failed for a reason other than a contract error
+    DeployFailed(String),
+    /// The deploy was not executed before the wait timed out
+    Timeout,
+    /// The contract has no CES events dictionary
+    NoEventsDictionary,
+    /// The SSE connection failed
+    EventStream(reqwest::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Rpc(error) => write!(f, "rpc error: {}", error),
+            ClientError::NoStateRootHash => write!(f, "node returned no state root hash"),
+            ClientError::UnexpectedStoredValue(expected) => {
+                write!(f, "unexpected stored value, expected {}", expected)
+            }
+            ClientError::Decode(error) => write!(f, "failed to decode stored value: {}", error),
+            ClientError::Contract(error) => {
+                write!(f, "contract error {}: {}", *error as u16, error.message())
+            }
+            ClientError::DeployFailed(message) => write!(f, "deploy failed: {}", message),
+            ClientError::Timeout => write!(f, "timed out waiting for deploy execution"),
+            ClientError::NoEventsDictionary => write!(f, "contract has no events dictionary"),
+            ClientError::EventStream(error) => write!(f, "event stream error: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Rpc(error) => Some(error.as_ref()),
+            ClientError::EventStream(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<casper_client::Error> for ClientError {
+    fn from(error: casper_client::Error) -> Self {
+        ClientError::Rpc(Box::new(error))
+    }
+}
+
+impl From<bytesrepr::Error> for ClientError {
+    fn from(error: bytesrepr::Error) -> Self {
+        ClientError::Decode(error)
+    }
+}
+
+impl From<casperflow_types::Error> for ClientError {
+    fn from(error: casperflow_types::Error) -> Self {
+        ClientError::Contract(error)
+    }
+}
+
+/// Maps a deploy's execution error message to a client error.
+///
+/// Contract reverts surface as `"User error: <code>"`; known codes are
+/// decoded into [`casperflow_types::Error`].
+pub fn classify_failure(error_message: &str) -> ClientError {
+    error_message
+        .strip_prefix("User error: ")
+        .and_then(|code| code.trim().parse::<u16>().ok())
+        .and_then(|code| casperflow_types::Error::try_from(code).ok())
+        .map(ClientError::Contract)
+        .unwrap_or_else(|| ClientError::DeployFailed(error_message.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use casperflow_types::Error;
+
+    #[test]
+    fn test_classify_failure() {
+        assert!(matches!(
+            classify_failure("User error: 4"),
+            ClientError::DeployFailed(_)
+        ));
+        assert!(matches!(
+            classify_failure("User error: 5"),
+            ClientError::Contract(Error::InvalidContributionAmount)
+        ));
+        assert!(matches!(
+            classify_failure("Out of gas error"),
+            ClientError::DeployFailed(_)
+        ));
+    }
+}
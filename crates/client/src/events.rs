@@ -0,0 +1,318 @@
+//! Streaming of contract events from a node's SSE endpoint.
+//!
+//! The contract writes each event into its CES `__events` dictionary, so an
+//! event shows up in a processed deploy's execution effects as a dictionary
+//! write under the events seed `URef`. [`EventListener`] follows the node's
+//! `/events/main` stream (or an event sidecar mirroring it) and decodes those
+//! writes into [`ContractEvent`]s.
+
+use std::collections::VecDeque;
+use std::future;
+
+use casper_types::{ExecutionResult, Transform};
+use casperflow_types::{
+    casper_types::{
+        self as types_v4,
+        bytesrepr::{self, Bytes, FromBytes},
+        CLValue, URefAddr,
+    },
+    ContractEvent,
+};
+use futures_util::{stream, Stream, StreamExt};
+use serde::Deserialize;
+
+use crate::error::ClientError;
+
+/// Prefix of the formatted global state key for dictionary items
+const DICTIONARY_KEY_PREFIX: &str = "dictionary-";
+
+/// A contract event together with where it was emitted
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmittedEvent {
+    /// Hex hash of the deploy that emitted the event
+    pub deploy_hash: String,
+    /// Hex hash of the block the deploy executed in
+    pub block_hash: String,
+    /// Position of the event in the contract's CES event list
+    pub event_id: u32,
+    /// The decoded event
+    pub event: ContractEvent,
+}
+
+/// Follows a node's event stream and yields the contract's events
+pub struct EventListener {
+    events_url: String,
+    events_seed: URefAddr,
+}
+
+impl EventListener {
+    /// Creates a listener for the events dictionary with seed `events_seed`.
+    ///
+    /// `events_url` is the SSE endpoint, e.g. `http://localhost:18101/events/main`.
+    /// Use [`CasperFlowClient::event_listener`](crate::CasperFlowClient::event_listener)
+    /// to look the seed up from the contract.
+    pub fn new(events_url: impl Into<String>, events_seed: URefAddr) -> Self {
+        EventListener {
+            events_url: events_url.into(),
+            events_seed,
+        }
+    }
+
+    /// Connects to the SSE endpoint and streams the contract's events.
+    ///
+    /// The stream ends when the node closes the connection.
+    pub async fn stream(
+        &self,
+    ) -> Result<impl Stream<Item = Result<EmittedEvent, ClientError>>, ClientError> {
+        let response = reqwest::get(&self.events_url)
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(ClientError::EventStream)?;
+
+        let events_seed = self.events_seed;
+        let state = (
+            response.bytes_stream().boxed(),
+            Vec::<u8>::new(),
+            VecDeque::<Result<EmittedEvent, ClientError>>::new(),
+        );
+
+        Ok(stream::unfold(
+            state,
+            move |(mut body, mut buffer, mut pending)| async move {
+                loop {
+                    if let Some(item) = pending.pop_front() {
+                        return Some((item, (body, buffer, pending)));
+                    }
+
+                    if let Some(newline) = buffer.iter().position(|byte| *byte == b'\n') {
+                        let line: Vec<u8> = buffer.drain(..=newline).collect();
+                        if let Some(processed) = parse_sse_line(&String::from_utf8_lossy(&line)) {
+                            pending.extend(processed.events(&events_seed));
+                        }
+                        continue;
+                    }
+
+                    match body.next().await {
+                        Some(Ok(chunk)) => buffer.extend_from_slice(&chunk),
+                        Some(Err(error)) => {
+                            return Some((
+                                Err(ClientError::EventStream(error)),
+                                (body, buffer, pending),
+                            ))
+                        }
+                        None => return None,
+                    }
+                }
+            },
+        ))
+    }
+}
+
+/// Keeps only events that concern `remittance_id`; errors pass through
+pub fn for_remittance<S>(
+    events: S,
+    remittance_id: u64,
+) -> impl Stream<Item = Result<EmittedEvent, ClientError>>
+where
+    S: Stream<Item = Result<EmittedEvent, ClientError>>,
+{
+    events.filter(move |item| {
+        future::ready(match item {
+            Ok(emitted) => emitted.event.remittance_id() == Some(remittance_id),
+            Err(_) => true,
+        })
+    })
+}
+
+/// Keeps only events that name `account`; errors pass through
+pub fn for_account<S>(
+    events: S,
+    account: types_v4::account::AccountHash,
+) -> impl Stream<Item = Result<EmittedEvent, ClientError>>
+where
+    S: Stream<Item = Result<EmittedEvent, ClientError>>,
+{
+    events.filter(move |item| {
+        future::ready(match item {
+            Ok(emitted) => emitted.event.involves(&account),
+            Err(_) => true,
+        })
+    })
+}
+
+/// Decodes the contract events written by one deploy execution.
+///
+/// Failed executions discard their writes and yield no events.
+pub fn decode_execution_events(
+    execution_result: &ExecutionResult,
+    events_seed: &URefAddr,
+) -> Vec<Result<(u32, ContractEvent), ClientError>> {
+    let transforms = match execution_result {
+        ExecutionResult::Success { effect, .. } => &effect.transforms,
+        ExecutionResult::Failure { .. } => return Vec::new(),
+    };
+
+    transforms
+        .iter()
+        .filter(|entry| entry.key.starts_with(DICTIONARY_KEY_PREFIX))
+        .filter_map(|entry| match &entry.transform {
+            Transform::WriteCLValue(cl_value) => {
+                decode_event_write(cl_value.inner_bytes(), events_seed).transpose()
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Decodes a dictionary write, returning `None` if it is not in the events
+/// dictionary.
+///
+/// Dictionary items are stored as the value's `CLValue`, then the seed
+/// `URef` address and the item key, each as length-prefixed bytes.
+fn decode_event_write(
+    bytes: &[u8],
+    events_seed: &URefAddr,
+) -> Result<Option<(u32, ContractEvent)>, ClientError> {
+    let (cl_value, remainder) = CLValue::from_bytes(bytes)?;
+    let (seed_uref_addr, remainder) = Bytes::from_bytes(remainder)?;
+    if seed_uref_addr.as_slice() != events_seed.as_slice() {
+        return Ok(None);
+    }
+
+    let (item_key, _) = Bytes::from_bytes(remainder)?;
+    let event_id = std::str::from_utf8(item_key.as_slice())
+        .ok()
+        .and_then(|key| key.parse::<u32>().ok())
+        .ok_or(ClientError::Decode(bytesrepr::Error::Formatting))?;
+
+    let event_bytes: Bytes = bytesrepr::deserialize_from_slice(cl_value.inner_bytes())?;
+    let event = bytesrepr::deserialize_from_slice(event_bytes.as_slice())?;
+    Ok(Some((event_id, event)))
+}
+
+/// The `DeployProcessed` payload of an SSE message
+#[derive(Deserialize)]
+struct DeployProcessed {
+    deploy_hash: String,
+    block_hash: String,
+    execution_result: ExecutionResult,
+}
+
+impl DeployProcessed {
+    fn events(&self, events_seed: &URefAddr) -> Vec<Result<EmittedEvent, ClientError>> {
+        decode_execution_events(&self.execution_result, events_seed)
+            .into_iter()
+            .map(|decoded| {
+                decoded.map(|(event_id, event)| EmittedEvent {
+                    deploy_hash: self.deploy_hash.clone(),
+                    block_hash: self.block_hash.clone(),
+                    event_id,
+                    event,
+                })
+            })
+            .collect()
+    }
+}
+
+/// An SSE message; messages other than `DeployProcessed` are ignored
+#[derive(Deserialize)]
+struct SseMessage {
+    #[serde(rename = "DeployProcessed")]
+    deploy_processed: Option<DeployProcessed>,
+}
+
+/// Parses one SSE line, returning the processed deploy it carries, if any
+fn parse_sse_line(line: &str) -> Option<DeployProcessed> {
+    let data = line.trim_end().strip_prefix("data:")?;
+    serde_json::from_str::<SseMessage>(data.trim_start())
+        .ok()?
+        .deploy_processed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use casper_types::{ExecutionEffect, TransformEntry, U512};
+    use casperflow_types::casper_types::bytesrepr::ToBytes;
+
+    const SEED: URefAddr = [7u8; 32];
+
+    fn paused_event() -> ContractEvent {
+        ContractEvent::ContractPaused { timestamp: 42 }
+    }
+
+    /// Builds the stored form of an item in the dictionary seeded by `seed`
+    fn dictionary_write(seed: URefAddr, item_key: &str, event: &ContractEvent) -> TransformEntry {
+        let value = CLValue::from_t(Bytes::from(event.to_bytes().unwrap())).unwrap();
+        let mut bytes = value.to_bytes().unwrap();
+        bytes.extend(Bytes::from(seed.to_vec()).to_bytes().unwrap());
+        bytes.extend(
+            Bytes::from(item_key.as_bytes().to_vec())
+                .to_bytes()
+                .unwrap(),
+        );
+
+        TransformEntry {
+            key: format!("{}{}", DICTIONARY_KEY_PREFIX, "ab".repeat(32)),
+            transform: Transform::WriteCLValue(casper_types::CLValue::from_components(
+                casper_types::CLType::Any,
+                bytes,
+            )),
+        }
+    }
+
+    fn success(transforms: Vec<TransformEntry>) -> ExecutionResult {
+        ExecutionResult::Success {
+            effect: ExecutionEffect {
+                operations: Vec::new(),
+                transforms,
+            },
+            transfers: Vec::new(),
+            cost: U512::from(1),
+        }
+    }
+
+    #[test]
+    fn test_decode_execution_events() {
+        let result = success(vec![
+            dictionary_write(SEED, "3", &paused_event()),
+            dictionary_write([8u8; 32], "0", &paused_event()),
+        ]);
+
+        let events: Vec<_> = decode_execution_events(&result, &SEED)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(events, vec![(3, paused_event())]);
+    }
+
+    #[test]
+    fn test_failed_execution_has_no_events() {
+        let result = ExecutionResult::Failure {
+            effect: ExecutionEffect::default(),
+            transfers: Vec::new(),
+            cost: U512::from(1),
+            error_message: "User error: 14".to_string(),
+        };
+        assert!(decode_execution_events(&result, &SEED).is_empty());
+    }
+
+    #[test]
+    fn test_parse_sse_line() {
+        let result = success(vec![dictionary_write(SEED, "0", &paused_event())]);
+        let line = format!(
+            "data:{{\"DeployProcessed\":{{\"deploy_hash\":\"aa\",\"block_hash\":\"bb\",\"execution_result\":{}}}}}\n",
+            serde_json::to_string(&result).unwrap()
+        );
+
+        let processed = parse_sse_line(&line).unwrap();
+        let events = processed.events(&SEED);
+        assert_eq!(events.len(), 1);
+        let emitted = events.into_iter().next().unwrap().unwrap();
+        assert_eq!(emitted.deploy_hash, "aa");
+        assert_eq!(emitted.event, paused_event());
+
+        assert!(parse_sse_line("data:{\"ApiVersion\":\"1.5.2\"}").is_none());
+        assert!(parse_sse_line("id:12").is_none());
+    }
+}
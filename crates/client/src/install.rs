@@ -0,0 +1,110 @@
+//! Installation of the contract wasm.
+
+use std::time::Duration;
+
+use casper_client::{
+    types::{Deploy, ExecutableDeployItem},
+    Verbosity,
+};
+use casper_types::{bytesrepr::Bytes, ContractHash, PublicKey, RuntimeArgs, SecretKey, U512};
+
+use crate::{
+    client::{build_deploy, put_deploy, rpc_id, wait_for_execution},
+    error::ClientError,
+};
+
+/// Named key under which the installer stores the contract hash
+pub const CONTRACT_HASH_KEY: &str = "casperflow_contract_hash";
+
+/// Installs the contract from an installer account
+pub struct Installer {
+    node_address: String,
+    chain_name: String,
+    max_fee_ppm: Option<u64>,
+    fee_on_contribution: bool,
+}
+
+impl Installer {
+    /// Creates an installer for the network at `node_address`
+    pub fn new(node_address: impl Into<String>, chain_name: impl Into<String>) -> Self {
+        Installer {
+            node_address: node_address.into(),
+            chain_name: chain_name.into(),
+            max_fee_ppm: None,
+            fee_on_contribution: false,
+        }
+    }
+
+    /// Caps the platform fee of the new installation, in parts per million.
+    ///
+    /// The cap can later be lowered with `LowerMaxFee` but never raised.
+    pub fn with_max_fee_ppm(mut self, max_fee_ppm: u64) -> Self {
+        self.max_fee_ppm = Some(max_fee_ppm);
+        self
+    }
+
+    /// Charges the platform fee on each contribution instead of on release.
+    ///
+    /// Contributions and refunds are then recorded net of the fee. The fee
+    /// model cannot be changed after installation.
+    pub fn with_fee_on_contribution(mut self) -> Self {
+        self.fee_on_contribution = true;
+        self
+    }
+
+    /// Builds a signed deploy running the contract wasm
+    pub fn install_deploy(
+        &self,
+        wasm: impl Into<Bytes>,
+        payment_amount: U512,
+        secret_key: &SecretKey,
+    ) -> Result<Deploy, ClientError> {
+        let mut args = RuntimeArgs::new();
+        if let Some(max_fee_ppm) = self.max_fee_ppm {
+            args.insert("max_fee_ppm", max_fee_ppm)
+                .expect("runtime arg should serialize");
+        }
+        if self.fee_on_contribution {
+            args.insert("fee_model", 1u8)
+                .expect("runtime arg should serialize");
+        }
+        let session = ExecutableDeployItem::new_module_bytes(wasm.into(), args);
+        build_deploy(&self.chain_name, session, payment_amount, secret_key)
+    }
+
+    /// Sends the install deploy and waits for it to execute
+    pub async fn send_and_wait(
+        &self,
+        deploy: Deploy,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<U512, ClientError> {
+        let deploy_hash = put_deploy(&self.node_address, deploy).await?;
+        wait_for_execution(&self.node_address, deploy_hash, poll_interval, timeout).await
+    }
+
+    /// Reads the contract hash stored in the installer account's named keys
+    pub async fn installed_contract_hash(
+        &self,
+        installer: PublicKey,
+    ) -> Result<Option<ContractHash>, ClientError> {
+        let response = casper_client::get_account(
+            rpc_id(),
+            &self.node_address,
+            Verbosity::Low,
+            None,
+            installer,
+        )
+        .await?;
+
+        let contract_hash = response
+            .result
+            .account
+            .named_keys()
+            .find(|named_key| named_key.name() == CONTRACT_HASH_KEY)
+            .and_then(|named_key| named_key.key().ok())
+            .and_then(|key| key.into_hash())
+            .map(ContractHash::new);
+        Ok(contract_hash)
+    }
+}
@@ -0,0 +1,42 @@
+//! Rust client for the CasperFlow remittance contract.
+//!
+//! Wraps casper-client with typed builders for each entry point, async
+//! helpers for sending deploys and waiting on their results, state queries
+//! that decode dictionary values into `casperflow-types`, and a listener
+//! that streams the contract's events.
+//!
+//! ```no_run
+//! # async fn run(secret_key: casper_types::SecretKey) -> Result<(), casperflow_client::ClientError> {
+//! use std::time::Duration;
+//!
+//! use casper_types::{account::AccountHash, ContractHash, U512};
+//! use casperflow_client::{calls::CreateRemittance, CasperFlowClient};
+//!
+//! let client = CasperFlowClient::new(
+//!     "http://localhost:11101",
+//!     "casper-net-1",
+//!     ContractHash::new([0u8; 32]),
+//! );
+//! let call = CreateRemittance::new(AccountHash::new([1u8; 32]), U512::from(10_000_000_000u64), "rent");
+//! let deploy = client.call_deploy(&call, U512::from(5_000_000_000u64), &secret_key)?;
+//! client
+//!     .send_and_wait(deploy, Duration::from_secs(5), Duration::from_secs(300))
+//!     .await?;
+//!
+//! let remittance = client.get_remittance(1).await?;
+//! # let _ = remittance;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod calls;
+pub mod client;
+pub mod convert;
+pub mod error;
+pub mod events;
+pub mod install;
+
+pub use client::CasperFlowClient;
+pub use error::ClientError;
+pub use events::{EmittedEvent, EventListener};
+pub use install::Installer;
@@ -0,0 +1,18 @@
+//! Writes the shared encoding fixture for the frontend.
+//!
+//! Usage: `export_test_vectors [PATH]`, defaulting to
+//! `frontend/src/lib/test-vectors.json` under the workspace root.
+
+use std::{env, fs, path::PathBuf};
+
+use casperflow_types::test_vectors;
+
+fn main() -> std::io::Result<()> {
+    let path = env::args().nth(1).map(PathBuf::from).unwrap_or_else(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../frontend/src/lib/test-vectors.json")
+    });
+
+    fs::write(&path, test_vectors::to_json() + "\n")?;
+    println!("wrote {}", path.display());
+    Ok(())
+}
@@ -0,0 +1,889 @@
+//! Error types and codes for the CasperFlow remittance contract.
+//!
+//! This module defines all possible error conditions that can occur
+//! during contract execution.
+
+use casper_types::ApiError;
+
+/// Custom error codes for the remittance contract.
+/// Each error represents a specific failure condition.
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Error {
+    /// Remittance with the given ID does not exist (1)
+    RemittanceNotFound = 1,
+
+    /// Caller is not authorized to perform this action (2)
+    Unauthorized = 2,
+
+    /// Target amount must be greater than zero (3)
+    InvalidTargetAmount = 3,
+
+    /// Contribution amount must be greater than zero (4)
+    InvalidContributionAmount = 5,
+
+    /// Remittance has already been released (6)
+    AlreadyReleased = 6,
+
+    /// Remittance has been cancelled (7)
+    RemittanceCancelled = 7,
+
+    /// Target amount not yet met, cannot release funds (8)
+    TargetNotMet = 8,
+
+    /// Purpose string exceeds maximum length (9)
+    PurposeMaxLength = 9,
+
+    /// Invalid recipient address (10)
+    InvalidRecipient = 10,
+
+    /// Refund has already been claimed (11)
+    RefundAlreadyClaimed = 11,
+
+    /// No contribution found for this address (12)
+    NoContribution = 12,
+
+    /// Remittance is neither cancelled nor expired, cannot claim refund (13)
+    NotCancelled = 13,
+
+    /// Contract is paused, operations are disabled (14)
+    ContractPaused = 14,
+
+    /// Platform fee exceeds maximum allowed (15)
+    FeeTooHigh = 15,
+
+    /// Failed to transfer funds (16)
+    TransferFailed = 16,
+
+    /// Arithmetic overflow detected (17)
+    ArithmeticOverflow = 17,
+
+    /// Storage operation failed (18)
+    StorageError = 18,
+
+    /// Invalid account hash (19)
+    InvalidAccountHash = 19,
+
+    /// Missing required argument (20)
+    MissingArgument = 20,
+
+    /// Unknown fee rounding mode (21)
+    InvalidFeeRounding = 21,
+
+    /// Creator fee share exceeds 100% of the fee (22)
+    InvalidFeeShare = 22,
+
+    /// No creator rewards available to claim (23)
+    NoCreatorRewards = 23,
+
+    /// Batch size is zero or exceeds the maximum (24)
+    InvalidBatchSize = 24,
+
+    /// Named key holds live contract state and cannot be removed (25)
+    ProtectedNamedKey = 25,
+
+    /// Package access token is no longer held by the contract (26)
+    AccessTokenUnavailable = 26,
+
+    /// Named key does not exist (27)
+    NamedKeyNotFound = 27,
+
+    /// Purpose does not match its commitment, or was sent alongside one (28)
+    InvalidPurposeCommitment = 28,
+
+    /// Remittance has no purpose commitment to reveal (29)
+    NoPurposeCommitment = 29,
+
+    /// Revealed amount does not match the contribution commitment (30)
+    InvalidAmountCommitment = 30,
+
+    /// Contributor already holds a commitment on this confidential remittance (31)
+    CommitmentAlreadyRecorded = 31,
+
+    /// Per-contributor amounts are not public for confidential remittances (32)
+    ConfidentialRemittance = 32,
+
+    /// Contract purse balance did not change by the contributed amount (33)
+    PurseDeltaMismatch = 33,
+
+    /// Unknown document type code (34)
+    InvalidDocumentType = 34,
+
+    /// Document CID is empty or too long (35)
+    InvalidDocumentCid = 35,
+
+    /// Remittance already has the maximum number of documents (36)
+    TooManyDocuments = 36,
+
+    /// External reference is empty, too long, or used on a private remittance (37)
+    InvalidExternalReference = 37,
+
+    /// Creator already used this external reference (38)
+    DuplicateExternalReference = 38,
+
+    /// Remittance is over the travel-rule threshold and has no travel-rule data (39)
+    TravelRuleDataRequired = 39,
+
+    /// Travel-rule payload URI is empty or too long (40)
+    InvalidTravelRulePayload = 40,
+
+    /// Corridor code is empty, too long, or has invalid characters (41)
+    InvalidCorridor = 41,
+
+    /// No accounting snapshot at this index (42)
+    SnapshotNotFound = 42,
+
+    /// Release payout purse was not fully drained (43)
+    PayoutIncomplete = 43,
+
+    /// A runtime argument could not be decoded as the expected type (44)
+    InvalidArgumentType = 44,
+
+    /// The maximum platform fee can only be lowered (45)
+    MaxFeeRaised = 45,
+
+    /// The contract purse holds less than the remittance's escrowed amount (46)
+    InsufficientEscrowBalance = 46,
+
+    /// Remittance has no excess refundable to the caller (47)
+    NoExcessRefund = 47,
+
+    /// Remittance expired with its target unmet (48)
+    RemittanceExpired = 48,
+
+    /// Remittance is not past its deadline with its target unmet (49)
+    NotExpirable = 49,
+
+    /// Deadline is not in the future (50)
+    InvalidDeadline = 50,
+
+    /// Account exceeded a rate limit for this action (51)
+    RateLimited = 51,
+
+    /// Rate limits need a non-zero window (52)
+    InvalidRateLimitWindow = 52,
+
+    /// Risk engine scored the release above the allowed maximum (53)
+    RiskScoreTooHigh = 53,
+
+    /// Release is awaiting compliance review (54)
+    ReleasePendingReview = 54,
+
+    /// Remittance has no release awaiting review (55)
+    NotPendingReview = 55,
+
+    /// Review window has passed (56)
+    ReviewWindowElapsed = 56,
+
+    /// Review threshold needs a non-zero window (57)
+    InvalidReviewWindow = 57,
+
+    /// Extension would exceed the maximum total extension (58)
+    DeadlineExtensionTooLong = 58,
+
+    /// Unknown fee model (59)
+    InvalidFeeModel = 59,
+
+    /// Remittance is locked for a bridge payout (60)
+    BridgePayoutPending = 60,
+
+    /// No bridge payout was requested for the remittance (61)
+    NoBridgePayout = 61,
+
+    /// Bridge chain ID or foreign address is empty or too long (62)
+    InvalidBridgeDestination = 62,
+
+    /// Releases that need compliance review cannot be bridged (63)
+    BridgeReviewRequired = 63,
+
+    /// Account is not a registered settlement partner (64)
+    PartnerNotRegistered = 64,
+
+    /// Settlement partner does not serve the remittance's corridor (65)
+    PartnerCorridorUnsupported = 65,
+
+    /// Settlement partner name or corridor list is empty or too long (66)
+    InvalidPartner = 66,
+
+    /// Payout reference code is empty or too long (67)
+    InvalidPayoutReference = 67,
+
+    /// Remittance already has its maximum number of contributors (68)
+    ContributorCapReached = 68,
+
+    /// Contributor cap must be greater than zero (69)
+    InvalidContributorCap = 69,
+
+    /// Day range is reversed or spans too many days (70)
+    InvalidDayRange = 70,
+
+    /// Handle is malformed or the remittance is private (71)
+    InvalidHandle = 71,
+
+    /// Handle is already registered to a remittance (72)
+    HandleTaken = 72,
+
+    /// Remittance already has a handle (73)
+    HandleAlreadySet = 73,
+
+    /// Instant share is out of range or incompatible with the remittance (74)
+    InvalidInstantShare = 74,
+
+    /// Backup recipient is the recipient, or its grace period is zero (75)
+    InvalidBackupRecipient = 75,
+
+    /// Backup recipient's grace period after funding has not passed (76)
+    BackupNotEligible = 76,
+
+    /// Release needs compliance review, which only the recipient can wait for (77)
+    BackupReviewRequired = 77,
+
+    /// Remittance is not insured (78)
+    NotInsured = 78,
+
+    /// Insurance premium or coverage is out of range, or the remittance cannot
+    /// be insured (79)
+    InvalidInsuranceTerms = 79,
+
+    /// Insurance claims have not been approved for this remittance (80)
+    InsuranceNotApproved = 80,
+
+    /// Insurance top-up already claimed (81)
+    InsuranceAlreadyClaimed = 81,
+
+    /// Insurance pool has no funds left (82)
+    InsurancePoolEmpty = 82,
+
+    /// Update message is empty or too long (83)
+    InvalidUpdateMessage = 83,
+
+    /// Remittance already has the maximum number of updates (84)
+    TooManyUpdates = 84,
+
+    /// Remittance has not been released yet (85)
+    NotReleased = 85,
+
+    /// Recipient has already acknowledged receipt (86)
+    AlreadyAcknowledged = 86,
+
+    /// Acknowledgement message is empty or too long (87)
+    InvalidAcknowledgement = 87,
+
+    /// Target amount exceeds the installation's maximum (88)
+    TargetTooLarge = 88,
+
+    /// Target amount is below the installation's minimum (89)
+    TargetTooSmall = 89,
+
+    /// Contribution with this idempotency key was already made (90)
+    DuplicateContribution = 90,
+
+    /// Unknown refund policy or rate out of range (91)
+    InvalidRefundPolicy = 91,
+
+    /// Force-cancellation or decline reason is empty or too long (92)
+    InvalidReason = 92,
+
+    /// Payout target label is empty or too long (93)
+    InvalidPayoutLabel = 93,
+
+    /// No payout target with this label in the recipient's address book (94)
+    PayoutTargetNotFound = 94,
+
+    /// Recipient's payout address book is full (95)
+    TooManyPayoutTargets = 95,
+
+    /// A release cannot use both a settlement partner and a payout target (96)
+    ConflictingPayoutRoute = 96,
+
+    /// Branding field is missing or too long (97)
+    InvalidBranding = 97,
+
+    /// Namespace name is malformed (98)
+    InvalidNamespace = 98,
+
+    /// A namespace with this name already exists (99)
+    NamespaceExists = 99,
+
+    /// No namespace with this name (100)
+    NamespaceNotFound = 100,
+
+    /// The remittance's namespace is paused (101)
+    NamespacePaused = 101,
+
+    /// Start time is not in the future or not before the deadline (102)
+    InvalidStartTime = 102,
+
+    /// The remittance does not accept contributions until its start time (103)
+    RemittanceNotStarted = 103,
+
+    /// Category name is malformed (104)
+    InvalidCategory = 104,
+
+    /// No category template with this name (105)
+    CategoryNotFound = 105,
+
+    /// Escalation intervals must be non-zero and strictly increasing (106)
+    InvalidSlaIntervals = 106,
+
+    /// Caller has no unused fee cover on this remittance (107)
+    NoFeeCover = 107,
+
+    /// Recipient signers or their approval threshold are invalid (108)
+    InvalidRecipientSigners = 108,
+
+    /// Release needs more recipient approvals (109)
+    RecipientApprovalRequired = 109,
+
+    /// Caller has already approved this release (110)
+    AlreadyApproved = 110,
+
+    /// The owner has renounced admin control over this setting (111)
+    AdminRenounced = 111,
+
+    /// Remittance has more contributors than one call may process (112)
+    TooManyContributors = 112,
+
+    /// The contract is already installed and initialized (113)
+    AlreadyInitialized = 113,
+
+    /// Creator and recipient are the same account (114)
+    SelfRemittance = 114,
+
+    /// Recipient is the platform fee collector (115)
+    RecipientIsFeeCollector = 115,
+
+    /// Recipient may not contribute to their own remittance (116)
+    RecipientContribution = 116,
+
+    /// Unknown recipient contribution policy (117)
+    InvalidPaymentGuards = 117,
+
+    /// Purpose matches a blocklisted template (118)
+    PurposeBlocked = 118,
+
+    /// Language code is empty, too long, or not lowercase letters, digits,
+    /// and hyphens (119)
+    InvalidLanguageCode = 119,
+
+    /// Remittance already has the maximum number of localized purposes (120)
+    TooManyLocalizedPurposes = 120,
+
+    /// Installment amount is zero, above the target, or large enough to need
+    /// compliance review, the interval is zero, or the remittance has no
+    /// installment schedule (121)
+    InvalidPayoutSchedule = 121,
+
+    /// Next installment is not due yet, or the remittance is not funded (122)
+    InstallmentNotDue = 122,
+
+    /// Remittance pays out in installments, not in a single release (123)
+    PayoutScheduled = 123,
+
+    /// Savings pool needs 2 to `MAX_POOL_MEMBERS` distinct members, a
+    /// non-zero contribution, and a cycle of at least a day (124)
+    InvalidSavingsPool = 124,
+
+    /// Only members of a savings pool may contribute to its cycles (125)
+    NotPoolMember = 125,
+
+    /// Contribution would take a member past the pool's per-cycle amount
+    /// (126)
+    PoolContributionExceeded = 126,
+
+    /// Remittance has a target, so it cannot be withdrawn from before
+    /// release (127)
+    NotOpenEnded = 127,
+
+    /// Withdrawal needs compliance review, so the pot must be released
+    /// instead (128)
+    WithdrawalReviewRequired = 128,
+
+    /// Private remittances emit no events, so they cannot be bridged (129)
+    PrivateBridgePayout = 129,
+}
+
+impl Error {
+    /// Human-readable description of the error, for wallets and explorers.
+    pub fn message(self) -> &'static str {
+        match self {
+            Error::RemittanceNotFound => "Remittance not found",
+            Error::Unauthorized => "You are not authorized to perform this action",
+            Error::InvalidTargetAmount => "Target amount must be greater than zero",
+            Error::InvalidContributionAmount => "Contribution amount must be greater than zero",
+            Error::AlreadyReleased => "Funds have already been released",
+            Error::RemittanceCancelled => "Remittance has been cancelled",
+            Error::TargetNotMet => "Target amount has not been reached yet",
+            Error::PurposeMaxLength => "Purpose must be between 1 and 256 characters",
+            Error::InvalidRecipient => "Invalid recipient address",
+            Error::RefundAlreadyClaimed => "Refund already claimed",
+            Error::NoContribution => "No contribution found for this account",
+            Error::NotCancelled => "Refunds are only available for cancelled or expired remittances",
+            Error::ContractPaused => "The contract is paused",
+            Error::FeeTooHigh => "Platform fee exceeds the maximum allowed",
+            Error::TransferFailed => "Fund transfer failed",
+            Error::ArithmeticOverflow => "Amount is too large",
+            Error::StorageError => "Contract storage error",
+            Error::InvalidAccountHash => "Invalid account address",
+            Error::MissingArgument => "A required argument is missing",
+            Error::InvalidFeeRounding => "Unknown fee rounding mode",
+            Error::InvalidFeeShare => "Creator fee share cannot exceed 100%",
+            Error::NoCreatorRewards => "No creator rewards to claim",
+            Error::InvalidBatchSize => "Batch size must be between 1 and the configured limit",
+            Error::ProtectedNamedKey => "This named key holds contract state and cannot be removed",
+            Error::AccessTokenUnavailable => "The contract no longer holds the package access token",
+            Error::NamedKeyNotFound => "Named key not found",
+            Error::InvalidPurposeCommitment => "Purpose does not match its commitment",
+            Error::NoPurposeCommitment => "This remittance has no purpose to reveal",
+            Error::InvalidAmountCommitment => "Amount does not match the contribution commitment",
+            Error::CommitmentAlreadyRecorded => "You have already contributed to this confidential remittance",
+            Error::ConfidentialRemittance => "Contribution amounts are private for this remittance",
+            Error::PurseDeltaMismatch => "Received amount does not match the contribution",
+            Error::InvalidDocumentType => "Unknown document type",
+            Error::InvalidDocumentCid => "Document CID must be between 1 and 128 characters",
+            Error::TooManyDocuments => "This remittance already has the maximum number of documents",
+            Error::InvalidExternalReference => "External reference must be 1 to 64 characters and cannot be used on private remittances",
+            Error::DuplicateExternalReference => "You have already used this external reference",
+            Error::TravelRuleDataRequired => "Travel-rule data is required before release",
+            Error::InvalidTravelRulePayload => "Travel-rule payload URI must be between 1 and 256 characters",
+            Error::InvalidCorridor => "Corridor must be 1 to 16 letters, digits, or hyphens",
+            Error::SnapshotNotFound => "Accounting snapshot not found",
+            Error::PayoutIncomplete => "Release payout did not complete",
+            Error::InvalidArgumentType => "An argument has the wrong type",
+            Error::MaxFeeRaised => "The maximum platform fee can only be lowered",
+            Error::InsufficientEscrowBalance => "The contract purse cannot cover this release",
+            Error::NoExcessRefund => "There is no excess contribution to refund",
+            Error::RemittanceExpired => "Remittance expired before reaching its target",
+            Error::NotExpirable => "Remittance has not passed its deadline with its target unmet",
+            Error::InvalidDeadline => "Deadline must be in the future",
+            Error::RateLimited => "Too many requests from this account; try again later",
+            Error::InvalidRateLimitWindow => "Rate limit window must be greater than zero",
+            Error::RiskScoreTooHigh => "Release was blocked by the risk check",
+            Error::ReleasePendingReview => "Release is awaiting compliance review",
+            Error::NotPendingReview => "No release is awaiting review for this remittance",
+            Error::ReviewWindowElapsed => "The review window has passed",
+            Error::InvalidReviewWindow => "Review window must be greater than zero",
+            Error::DeadlineExtensionTooLong => "Deadline cannot be extended that far",
+            Error::InvalidFeeModel => "Unknown fee model",
+            Error::BridgePayoutPending => "Remittance is locked for a bridge payout",
+            Error::NoBridgePayout => "No bridge payout was requested",
+            Error::InvalidBridgeDestination => "Invalid bridge chain ID or address",
+            Error::BridgeReviewRequired => "Releases under compliance review cannot be bridged",
+            Error::PartnerNotRegistered => "Account is not a registered settlement partner",
+            Error::PartnerCorridorUnsupported => "Settlement partner does not serve this corridor",
+            Error::InvalidPartner => "Partner needs a name of 1 to 64 characters and 1 to 16 corridors",
+            Error::InvalidPayoutReference => "Payout reference must be 1 to 64 characters",
+            Error::ContributorCapReached => "This remittance has reached its contributor limit",
+            Error::InvalidContributorCap => "Contributor limit must be greater than zero",
+            Error::InvalidDayRange => "Day range must be in order and span at most 366 days",
+            Error::InvalidHandle => {
+                "Handle must be 3 to 64 lowercase letters, digits, or inner hyphens"
+            }
+            Error::HandleTaken => "This handle is already taken",
+            Error::HandleAlreadySet => "This remittance already has a handle",
+            Error::InvalidInstantShare => {
+                "Instant share must be 1 to 9999 bps, without confidential or excess refunds"
+            }
+            Error::InvalidBackupRecipient => {
+                "Backup recipient must differ from the recipient and have a grace period"
+            }
+            Error::BackupNotEligible => "The backup recipient cannot release this remittance yet",
+            Error::BackupReviewRequired => {
+                "This release needs compliance review; the recipient must request it"
+            }
+            Error::NotInsured => "This remittance is not insured",
+            Error::InvalidInsuranceTerms => "Invalid insurance premium or coverage",
+            Error::InsuranceNotApproved => "Insurance claims have not been approved",
+            Error::InsuranceAlreadyClaimed => "Insurance top-up already claimed",
+            Error::InsurancePoolEmpty => "The insurance pool is empty",
+            Error::InvalidUpdateMessage => "Update message must be between 1 and 280 characters",
+            Error::TooManyUpdates => "This remittance already has the maximum number of updates",
+            Error::NotReleased => "This remittance has not been released yet",
+            Error::AlreadyAcknowledged => "Receipt has already been acknowledged",
+            Error::InvalidAcknowledgement => {
+                "Acknowledgement message must be between 1 and 280 characters"
+            }
+            Error::TargetTooLarge => "Target amount exceeds the maximum allowed",
+            Error::TargetTooSmall => "Target amount is below the minimum allowed",
+            Error::DuplicateContribution => {
+                "This contribution was already made (duplicate idempotency key)"
+            }
+            Error::InvalidRefundPolicy => "Invalid refund policy or deduction rate",
+            Error::InvalidReason => "Reason must be between 1 and 280 characters",
+            Error::InvalidPayoutLabel => "Payout target label must be between 1 and 32 characters",
+            Error::PayoutTargetNotFound => "Payout target not found in address book",
+            Error::TooManyPayoutTargets => "Payout address book is full",
+            Error::ConflictingPayoutRoute => {
+                "Choose either a settlement partner or a payout target"
+            }
+            Error::InvalidBranding => "Branding field is missing or too long",
+            Error::InvalidNamespace => {
+                "Namespace must be 1-32 lowercase letters, digits, or hyphens"
+            }
+            Error::NamespaceExists => "Namespace already exists",
+            Error::NamespaceNotFound => "Namespace not found",
+            Error::NamespacePaused => "The namespace is paused",
+            Error::InvalidStartTime => "Start time must be in the future and before the deadline",
+            Error::RemittanceNotStarted => "This remittance is not open for contributions yet",
+            Error::InvalidCategory => {
+                "Category must be 1-32 lowercase letters, digits, or hyphens"
+            }
+            Error::CategoryNotFound => "Category not found",
+            Error::InvalidSlaIntervals => {
+                "Escalation intervals must be non-zero, increasing, and at most 8"
+            }
+            Error::NoFeeCover => "No unused fee cover to claim",
+            Error::InvalidRecipientSigners => {
+                "Recipient signers must be 2 to 8 distinct accounts including the recipient, with a threshold between 1 and their number"
+            }
+            Error::RecipientApprovalRequired => "Release needs more recipient approvals",
+            Error::AlreadyApproved => "You have already approved this release",
+            Error::AdminRenounced => "Admin control has been renounced",
+            Error::TooManyContributors => {
+                "The remittance has more contributors than one call may process"
+            }
+            Error::AlreadyInitialized => "The contract is already installed and initialized",
+            Error::SelfRemittance => "Creator and recipient must be different accounts",
+            Error::RecipientIsFeeCollector => "The fee collector cannot be a recipient",
+            Error::RecipientContribution => {
+                "The recipient cannot contribute to their own remittance"
+            }
+            Error::InvalidPaymentGuards => "Unknown recipient contribution policy",
+            Error::PurposeBlocked => "This purpose has been blocked by compliance",
+            Error::InvalidLanguageCode => {
+                "Language code must be 2 to 16 lowercase letters, digits, or hyphens"
+            }
+            Error::TooManyLocalizedPurposes => {
+                "This remittance already has the maximum number of localized purposes"
+            }
+            Error::InvalidPayoutSchedule => "Invalid installment amount or interval",
+            Error::InstallmentNotDue => "The next installment is not due yet",
+            Error::PayoutScheduled => "This remittance pays out in installments",
+            Error::InvalidSavingsPool => {
+                "A savings pool needs 2 to 12 distinct members, a contribution, and a cycle length"
+            }
+            Error::NotPoolMember => "Only members of this savings pool can contribute",
+            Error::PoolContributionExceeded => {
+                "This would exceed your contribution for this savings pool cycle"
+            }
+            Error::NotOpenEnded => "Only open-ended pots can be withdrawn from before release",
+            Error::WithdrawalReviewRequired => {
+                "This withdrawal needs compliance review; release the pot instead"
+            }
+            Error::PrivateBridgePayout => "Private remittances cannot be paid out over a bridge",
+        }
+    }
+}
+
+impl TryFrom<u16> for Error {
+    type Error = ();
+
+    fn try_from(code: u16) -> Result<Self, Self::Error> {
+        match code {
+            1 => Ok(Error::RemittanceNotFound),
+            2 => Ok(Error::Unauthorized),
+            3 => Ok(Error::InvalidTargetAmount),
+            5 => Ok(Error::InvalidContributionAmount),
+            6 => Ok(Error::AlreadyReleased),
+            7 => Ok(Error::RemittanceCancelled),
+            8 => Ok(Error::TargetNotMet),
+            9 => Ok(Error::PurposeMaxLength),
+            10 => Ok(Error::InvalidRecipient),
+            11 => Ok(Error::RefundAlreadyClaimed),
+            12 => Ok(Error::NoContribution),
+            13 => Ok(Error::NotCancelled),
+            14 => Ok(Error::ContractPaused),
+            15 => Ok(Error::FeeTooHigh),
+            16 => Ok(Error::TransferFailed),
+            17 => Ok(Error::ArithmeticOverflow),
+            18 => Ok(Error::StorageError),
+            19 => Ok(Error::InvalidAccountHash),
+            20 => Ok(Error::MissingArgument),
+            21 => Ok(Error::InvalidFeeRounding),
+            22 => Ok(Error::InvalidFeeShare),
+            23 => Ok(Error::NoCreatorRewards),
+            24 => Ok(Error::InvalidBatchSize),
+            25 => Ok(Error::ProtectedNamedKey),
+            26 => Ok(Error::AccessTokenUnavailable),
+            27 => Ok(Error::NamedKeyNotFound),
+            28 => Ok(Error::InvalidPurposeCommitment),
+            29 => Ok(Error::NoPurposeCommitment),
+            30 => Ok(Error::InvalidAmountCommitment),
+            31 => Ok(Error::CommitmentAlreadyRecorded),
+            32 => Ok(Error::ConfidentialRemittance),
+            33 => Ok(Error::PurseDeltaMismatch),
+            34 => Ok(Error::InvalidDocumentType),
+            35 => Ok(Error::InvalidDocumentCid),
+            36 => Ok(Error::TooManyDocuments),
+            37 => Ok(Error::InvalidExternalReference),
+            38 => Ok(Error::DuplicateExternalReference),
+            39 => Ok(Error::TravelRuleDataRequired),
+            40 => Ok(Error::InvalidTravelRulePayload),
+            41 => Ok(Error::InvalidCorridor),
+            42 => Ok(Error::SnapshotNotFound),
+            43 => Ok(Error::PayoutIncomplete),
+            44 => Ok(Error::InvalidArgumentType),
+            45 => Ok(Error::MaxFeeRaised),
+            46 => Ok(Error::InsufficientEscrowBalance),
+            47 => Ok(Error::NoExcessRefund),
+            48 => Ok(Error::RemittanceExpired),
+            49 => Ok(Error::NotExpirable),
+            50 => Ok(Error::InvalidDeadline),
+            51 => Ok(Error::RateLimited),
+            52 => Ok(Error::InvalidRateLimitWindow),
+            53 => Ok(Error::RiskScoreTooHigh),
+            54 => Ok(Error::ReleasePendingReview),
+            55 => Ok(Error::NotPendingReview),
+            56 => Ok(Error::ReviewWindowElapsed),
+            57 => Ok(Error::InvalidReviewWindow),
+            58 => Ok(Error::DeadlineExtensionTooLong),
+            59 => Ok(Error::InvalidFeeModel),
+            60 => Ok(Error::BridgePayoutPending),
+            61 => Ok(Error::NoBridgePayout),
+            62 => Ok(Error::InvalidBridgeDestination),
+            63 => Ok(Error::BridgeReviewRequired),
+            64 => Ok(Error::PartnerNotRegistered),
+            65 => Ok(Error::PartnerCorridorUnsupported),
+            66 => Ok(Error::InvalidPartner),
+            67 => Ok(Error::InvalidPayoutReference),
+            68 => Ok(Error::ContributorCapReached),
+            69 => Ok(Error::InvalidContributorCap),
+            70 => Ok(Error::InvalidDayRange),
+            71 => Ok(Error::InvalidHandle),
+            72 => Ok(Error::HandleTaken),
+            73 => Ok(Error::HandleAlreadySet),
+            74 => Ok(Error::InvalidInstantShare),
+            75 => Ok(Error::InvalidBackupRecipient),
+            76 => Ok(Error::BackupNotEligible),
+            77 => Ok(Error::BackupReviewRequired),
+            78 => Ok(Error::NotInsured),
+            79 => Ok(Error::InvalidInsuranceTerms),
+            80 => Ok(Error::InsuranceNotApproved),
+            81 => Ok(Error::InsuranceAlreadyClaimed),
+            82 => Ok(Error::InsurancePoolEmpty),
+            83 => Ok(Error::InvalidUpdateMessage),
+            84 => Ok(Error::TooManyUpdates),
+            85 => Ok(Error::NotReleased),
+            86 => Ok(Error::AlreadyAcknowledged),
+            87 => Ok(Error::InvalidAcknowledgement),
+            88 => Ok(Error::TargetTooLarge),
+            89 => Ok(Error::TargetTooSmall),
+            90 => Ok(Error::DuplicateContribution),
+            91 => Ok(Error::InvalidRefundPolicy),
+            92 => Ok(Error::InvalidReason),
+            93 => Ok(Error::InvalidPayoutLabel),
+            94 => Ok(Error::PayoutTargetNotFound),
+            95 => Ok(Error::TooManyPayoutTargets),
+            96 => Ok(Error::ConflictingPayoutRoute),
+            97 => Ok(Error::InvalidBranding),
+            98 => Ok(Error::InvalidNamespace),
+            99 => Ok(Error::NamespaceExists),
+            100 => Ok(Error::NamespaceNotFound),
+            101 => Ok(Error::NamespacePaused),
+            102 => Ok(Error::InvalidStartTime),
+            103 => Ok(Error::RemittanceNotStarted),
+            104 => Ok(Error::InvalidCategory),
+            105 => Ok(Error::CategoryNotFound),
+            106 => Ok(Error::InvalidSlaIntervals),
+            107 => Ok(Error::NoFeeCover),
+            108 => Ok(Error::InvalidRecipientSigners),
+            109 => Ok(Error::RecipientApprovalRequired),
+            110 => Ok(Error::AlreadyApproved),
+            111 => Ok(Error::AdminRenounced),
+            112 => Ok(Error::TooManyContributors),
+            113 => Ok(Error::AlreadyInitialized),
+            114 => Ok(Error::SelfRemittance),
+            115 => Ok(Error::RecipientIsFeeCollector),
+            116 => Ok(Error::RecipientContribution),
+            117 => Ok(Error::InvalidPaymentGuards),
+            118 => Ok(Error::PurposeBlocked),
+            119 => Ok(Error::InvalidLanguageCode),
+            120 => Ok(Error::TooManyLocalizedPurposes),
+            121 => Ok(Error::InvalidPayoutSchedule),
+            122 => Ok(Error::InstallmentNotDue),
+            123 => Ok(Error::PayoutScheduled),
+            124 => Ok(Error::InvalidSavingsPool),
+            125 => Ok(Error::NotPoolMember),
+            126 => Ok(Error::PoolContributionExceeded),
+            127 => Ok(Error::NotOpenEnded),
+            128 => Ok(Error::WithdrawalReviewRequired),
+            129 => Ok(Error::PrivateBridgePayout),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+/// Maximum length for remittance purpose description
+pub const MAX_PURPOSE_LENGTH: usize = 256;
+
+/// Maximum platform fee in basis points (5% = 500 bps)
+///
+/// This is the hard ceiling; each installation stores its own cap at or
+/// below it, which the owner can only lower.
+pub const MAX_FEE_BPS: u64 = 500;
+
+/// Default platform fee in basis points (0.5% = 50 bps)
+pub const DEFAULT_FEE_BPS: u64 = 50;
+
+/// Maximum number of records processed by one batch entry point call
+pub const MAX_BATCH_SIZE: u64 = 100;
+
+/// Maximum number of release escalation intervals
+pub const MAX_SLA_INTERVALS: usize = 8;
+
+/// Maximum number of keys of a multi-signature recipient
+pub const MAX_RECIPIENT_SIGNERS: usize = 8;
+
+/// Maximum length of an IPFS content identifier
+pub const MAX_CID_LENGTH: usize = 128;
+
+/// Maximum number of documents attached to one remittance
+pub const MAX_DOCUMENTS: usize = 16;
+
+/// Maximum length of a creator update or recipient acknowledgement message
+pub const MAX_UPDATE_LENGTH: usize = 280;
+
+/// Maximum number of updates posted on one remittance
+pub const MAX_UPDATES: usize = 32;
+
+/// Maximum length of a localized purpose's language code, e.g. "zh-hant"
+pub const MAX_LANGUAGE_CODE_LENGTH: usize = 16;
+
+/// Maximum number of localized purposes on one remittance
+pub const MAX_LOCALIZED_PURPOSES: usize = 8;
+
+/// Maximum number of members of a savings pool, and so of its cycles
+pub const MAX_POOL_MEMBERS: usize = 12;
+
+/// Maximum length of a force-cancellation or decline reason
+pub const MAX_REASON_LENGTH: usize = 280;
+
+/// Maximum length of an external (invoice or case) reference
+pub const MAX_REFERENCE_LENGTH: usize = 64;
+
+/// Maximum length of a travel-rule payload URI
+pub const MAX_PAYLOAD_URI_LENGTH: usize = 256;
+
+/// Maximum length of a bridge chain ID (e.g. "eip155:1")
+pub const MAX_CHAIN_ID_LENGTH: usize = 32;
+
+/// Maximum length of a payout address on a foreign chain
+pub const MAX_FOREIGN_ADDRESS_LENGTH: usize = 128;
+
+/// Time the bridge operator has to confirm a bridge payout before anyone
+/// can cancel it (7 days)
+pub const BRIDGE_PAYOUT_WINDOW_MS: u64 = 7 * MILLIS_PER_DAY;
+
+/// Maximum length of a settlement partner's name
+pub const MAX_PARTNER_NAME_LENGTH: usize = 64;
+
+/// Maximum number of corridors one settlement partner serves
+pub const MAX_PARTNER_CORRIDORS: usize = 16;
+
+/// Maximum length of a payout reference code given to a settlement partner
+pub const MAX_PAYOUT_REFERENCE_LENGTH: usize = 64;
+
+/// Maximum number of payout targets in a recipient's address book
+pub const MAX_PAYOUT_TARGETS: usize = 8;
+
+/// Maximum length of a payout target label
+pub const MAX_PAYOUT_LABEL_LENGTH: usize = 32;
+
+/// Maximum length of an instance's platform name
+pub const MAX_PLATFORM_NAME_LENGTH: usize = 64;
+
+/// Maximum length of an instance's currency display string, e.g. "CSPR"
+pub const MAX_CURRENCY_DISPLAY_LENGTH: usize = 16;
+
+/// Maximum insurance premium on a contribution (2% = 200 bps)
+pub const MAX_INSURANCE_PREMIUM_BPS: u64 = 200;
+
+/// Maximum insurance top-up on a contribution (50% = 5000 bps)
+pub const MAX_INSURANCE_COVERAGE_BPS: u64 = 5_000;
+
+/// Maximum share of a refund a refund policy may retain (20% = 2000 bps)
+pub const MAX_REFUND_DEDUCTION_BPS: u64 = 2_000;
+
+/// Minimum length of a remittance handle
+pub const MIN_HANDLE_LENGTH: usize = 3;
+
+/// Maximum length of a remittance handle (e.g. "maria-surgery-2025")
+pub const MAX_HANDLE_LENGTH: usize = 64;
+
+/// Maximum length of a corridor code (e.g. "US-NG")
+pub const MAX_CORRIDOR_LENGTH: usize = 16;
+
+/// Maximum length of a namespace name (e.g. "lagos-hometown")
+pub const MAX_NAMESPACE_LENGTH: usize = 32;
+
+/// Maximum length of a category name (e.g. "tuition")
+pub const MAX_CATEGORY_LENGTH: usize = 32;
+
+/// Milliseconds per reporting day (block time is in milliseconds)
+pub const MILLIS_PER_DAY: u64 = 86_400_000;
+
+/// Milliseconds per contribution analytics bucket
+pub const MILLIS_PER_HOUR: u64 = 3_600_000;
+
+/// Hours covered by the recent-contributions window of remittance analytics
+pub const ANALYTICS_WINDOW_HOURS: u64 = 24;
+
+/// Maximum number of days one creation-time query scans
+pub const MAX_DAY_RANGE: u64 = 366;
+
+/// Denominator for basis point rates (100% = 10000 bps)
+pub const BASIS_POINTS: u64 = 10_000;
+
+/// Denominator for parts-per-million fee rates
+pub const PPM_DENOMINATOR: u64 = 1_000_000;
+
+/// Parts-per-million in one basis point
+pub const PPM_PER_BPS: u64 = 100;
+
+/// Maximum platform fee in parts-per-million (5% = 50,000 ppm)
+pub const MAX_FEE_PPM: u64 = MAX_FEE_BPS * PPM_PER_BPS;
+
+/// Version of the contract code, reported by `get_contract_info`
+///
+/// Bump with each release that changes the installed contract wasm.
+pub const CONTRACT_VERSION: u32 = 1;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_round_trip() {
+        for code in 0..=u16::from(u8::MAX) {
+            if let Ok(error) = Error::try_from(code) {
+                assert_eq!(error as u16, code);
+                assert!(!error.message().is_empty());
+            }
+        }
+
+        assert!(Error::try_from(4).is_err());
+        assert!(Error::try_from(0).is_err());
+        assert_eq!(
+            Error::RefundAlreadyClaimed.message(),
+            "Refund already claimed"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip() {
+        let json = serde_json::to_string(&Error::RecipientApprovalRequired).unwrap();
+        assert_eq!(json, "\"RecipientApprovalRequired\"");
+        assert_eq!(
+            serde_json::from_str::<Error>(&json).unwrap(),
+            Error::RecipientApprovalRequired
+        );
+    }
+}
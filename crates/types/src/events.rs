@@ -0,0 +1,1173 @@
+//! Event types for the CasperFlow remittance contract.
+//!
+//! The contract emits these on-chain in the Casper Event Standard (CES)
+//! layout; off-chain consumers decode them into the same enum.
+//!
+//! A serialized event is the CES name (`"event_"` followed by the variant
+//! name) as a bytesrepr `String`, then each field in declaration order, then
+//! the [`EVENT_SCHEMA_VERSION`] it was emitted under as a `u32`. Events
+//! emitted before versioning end after their last field and decode as
+//! version 0.
+
+use alloc::{format, string::String, vec, vec::Vec};
+
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    CLType, CLTyped, ContractHash, U512,
+};
+
+/// Prefix CES puts before each event name.
+pub const EVENT_PREFIX: &str = "event_";
+
+/// Version of the event layouts, appended to every emitted event.
+///
+/// Bump whenever a release adds, removes, or reorders event fields, so
+/// indexers can tell layouts apart across contract upgrades.
+pub const EVENT_SCHEMA_VERSION: u32 = 3;
+
+/// Name of the trailing schema version field in each event's CES schema.
+pub const SCHEMA_VERSION_FIELD: &str = "schema_version";
+
+/// Defines the event enum together with its CES encoding and schema, so the
+/// wire layout always follows the field order of the definition.
+macro_rules! contract_events {
+    (
+        $(#[$enum_meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident { $($field:ident: $ty:ty),* $(,)? }
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$enum_meta])*
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant { $($field: $ty),* },
+            )*
+        }
+
+        impl $name {
+            /// Event name without the CES prefix
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $($name::$variant { .. } => stringify!($variant),)*
+                }
+            }
+
+            /// Schema of every event: its name, then field names and types
+            /// in wire order, ending with the schema version
+            pub fn schemas() -> Vec<(&'static str, Vec<(&'static str, CLType)>)> {
+                vec![
+                    $((
+                        stringify!($variant),
+                        vec![
+                            $((stringify!($field), <$ty as CLTyped>::cl_type()),)*
+                            (SCHEMA_VERSION_FIELD, CLType::U32),
+                        ],
+                    ),)*
+                ]
+            }
+
+            /// Decodes a whole serialized event together with the schema
+            /// version it was emitted under
+            pub fn from_bytes_versioned(bytes: &[u8]) -> Result<(Self, u32), bytesrepr::Error> {
+                let (event, remainder) = Self::from_fields(bytes)?;
+                let (version, remainder) = read_schema_version(remainder)?;
+                if !remainder.is_empty() {
+                    return Err(bytesrepr::Error::LeftOverBytes);
+                }
+                Ok((event, version))
+            }
+
+            /// Decodes the name and fields of an event, up to its schema
+            /// version
+            fn from_fields(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+                let (full_name, remainder) = String::from_bytes(bytes)?;
+                match full_name.strip_prefix(EVENT_PREFIX) {
+                    $(Some(stringify!($variant)) => {
+                        $(let ($field, remainder) = <$ty>::from_bytes(remainder)?;)*
+                        Ok(($name::$variant { $($field),* }, remainder))
+                    })*
+                    _ => Err(bytesrepr::Error::Formatting),
+                }
+            }
+        }
+
+        impl ToBytes for $name {
+            fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+                let mut buffer = bytesrepr::allocate_buffer(self)?;
+                match self {
+                    $($name::$variant { $($field),* } => {
+                        buffer.extend(format!("{}{}", EVENT_PREFIX, stringify!($variant)).to_bytes()?);
+                        $(buffer.extend($field.to_bytes()?);)*
+                    })*
+                }
+                buffer.extend(EVENT_SCHEMA_VERSION.to_bytes()?);
+                Ok(buffer)
+            }
+
+            fn serialized_length(&self) -> usize {
+                match self {
+                    $($name::$variant { $($field),* } => {
+                        U32_SERIALIZED_LENGTH
+                            + EVENT_PREFIX.len()
+                            + stringify!($variant).len()
+                            $(+ $field.serialized_length())*
+                            + U32_SERIALIZED_LENGTH
+                    })*
+                }
+            }
+        }
+
+        impl FromBytes for $name {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+                let (event, remainder) = Self::from_fields(bytes)?;
+                let (_version, remainder) = read_schema_version(remainder)?;
+                Ok((event, remainder))
+            }
+        }
+    };
+}
+
+/// Reads the schema version trailing an event's fields; events emitted
+/// before versioning end right after their last field.
+fn read_schema_version(bytes: &[u8]) -> Result<(u32, &[u8]), bytesrepr::Error> {
+    if bytes.is_empty() {
+        return Ok((0, bytes));
+    }
+    u32::from_bytes(bytes)
+}
+
+contract_events! {
+    /// Event types emitted by the contract
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ContractEvent {
+        /// Emitted when a new remittance is created; `target_amount` is zero
+        /// for an open-ended pot
+        RemittanceCreated {
+            remittance_id: u64,
+            creator: AccountHash,
+            recipient: AccountHash,
+            target_amount: U512,
+            purpose: String,
+            charity_verified: bool,
+            timestamp: u64,
+        },
+
+        /// Emitted when a savings pool is created, after the
+        /// `RemittanceCreated` of each of its cycles
+        SavingsPoolCreated {
+            pool_id: u64,
+            organizer: AccountHash,
+            members: Vec<AccountHash>,
+            contribution: U512,
+            cycle_ms: u64,
+            first_remittance_id: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when a committed purpose is revealed
+        PurposeRevealed {
+            remittance_id: u64,
+            purpose: String,
+            timestamp: u64,
+        },
+
+        /// Emitted when a document reference is attached to a remittance
+        DocumentAttached {
+            remittance_id: u64,
+            cid: String,
+            doc_type: u8,
+            attached_by: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when a creator registers a handle for a remittance
+        HandleRegistered {
+            remittance_id: u64,
+            creator: AccountHash,
+            handle: String,
+            timestamp: u64,
+        },
+
+        /// Emitted when a creator sets or removes a localized purpose; an
+        /// empty `purpose` means the translation was removed
+        LocalizedPurposeSet {
+            remittance_id: u64,
+            lang: String,
+            purpose: String,
+            timestamp: u64,
+        },
+
+        /// Emitted when a creator posts a progress update
+        UpdatePosted {
+            remittance_id: u64,
+            creator: AccountHash,
+            message: String,
+            timestamp: u64,
+        },
+
+        /// Emitted when a recipient confirms that released funds arrived
+        ReceiptAcknowledged {
+            remittance_id: u64,
+            recipient: AccountHash,
+            message: String,
+            timestamp: u64,
+        },
+
+        /// Emitted when the first contribution after a scheduled start
+        /// arrives, opening the remittance
+        RemittanceActivated {
+            remittance_id: u64,
+            starts_at: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when a contribution is made to a remittance
+        ContributionMade {
+            remittance_id: u64,
+            contributor: AccountHash,
+            amount: U512,
+            new_total: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a commitment-backed contribution is made; neither the
+        /// amount nor the new total is published, since consecutive totals
+        /// would give the amount away
+        ConfidentialContributionMade {
+            remittance_id: u64,
+            contributor: AccountHash,
+            amount_commitment: [u8; 32],
+            timestamp: u64,
+        },
+
+        /// Emitted when funds are released to the recipient; `amount` is the
+        /// net payout, itemized as in `ReleaseBreakdown`
+        FundsReleased {
+            remittance_id: u64,
+            recipient: AccountHash,
+            amount: U512,
+            platform_fee: U512,
+            gross_amount: U512,
+            creator_rebate: U512,
+            fee_covered: U512,
+            charity_verified: bool,
+            timestamp: u64,
+        },
+
+        /// Emitted when part of a contribution is forwarded straight to the
+        /// recipient of a split remittance
+        InstantPayout {
+            remittance_id: u64,
+            recipient: AccountHash,
+            amount: U512,
+            platform_fee: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when an installment of a scheduled payout is paid;
+        /// `installment` counts from 1, and the last one also emits
+        /// `FundsReleased`
+        InstallmentPaid {
+            remittance_id: u64,
+            recipient: AccountHash,
+            installment: u64,
+            amount: U512,
+            platform_fee: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the recipient of an open-ended pot withdraws what it
+        /// holds, leaving it open for more
+        PotWithdrawn {
+            remittance_id: u64,
+            recipient: AccountHash,
+            amount: U512,
+            platform_fee: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a backup recipient releases a remittance the
+        /// recipient left unreleased
+        BackupReleaseTriggered {
+            remittance_id: u64,
+            recipient: AccountHash,
+            backup: AccountHash,
+            amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a key of a multi-signature recipient approves the
+        /// release
+        RecipientApprovalAdded {
+            remittance_id: u64,
+            signer: AccountHash,
+            approvals: u64,
+            threshold: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when a remittance is cancelled
+        RemittanceCancelled {
+            remittance_id: u64,
+            creator: AccountHash,
+            total_amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer cancels a remittance that
+        /// violates the terms, enabling refunds
+        RemittanceForceCancelled {
+            remittance_id: u64,
+            officer: AccountHash,
+            reason: String,
+            total_amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the recipient declines a remittance, enabling refunds
+        RemittanceRejected {
+            remittance_id: u64,
+            recipient: AccountHash,
+            total_amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the recipient refuses a funded remittance, with the
+        /// reason for contributors, enabling refunds
+        ReleaseDeclined {
+            remittance_id: u64,
+            recipient: AccountHash,
+            reason: String,
+            total_amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a funded remittance has gone unreleased past another
+        /// escalation interval
+        ReleaseOverdue {
+            remittance_id: u64,
+            recipient: AccountHash,
+            level: u64,
+            funded_at: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when a remittance passes its deadline with its target
+        /// unmet and is expired, enabling refunds
+        RemittanceExpired {
+            remittance_id: u64,
+            deadline: u64,
+            total_amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the creator pushes back a remittance's deadline
+        DeadlineExtended {
+            remittance_id: u64,
+            old_deadline: u64,
+            new_deadline: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when the recipient asks for payout on another chain; the
+        /// bridge relayer delivers `amount` to `foreign_address`
+        BridgePayoutRequested {
+            remittance_id: u64,
+            recipient: AccountHash,
+            chain_id: String,
+            foreign_address: String,
+            amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the bridge operator confirms delivery and is
+        /// reimbursed from escrow
+        BridgePayoutConfirmed {
+            remittance_id: u64,
+            operator: AccountHash,
+            foreign_tx_hash: String,
+            amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a pending bridge payout is withdrawn, unlocking the
+        /// remittance; the relayer must not deliver it
+        BridgePayoutCancelled {
+            remittance_id: u64,
+            cancelled_by: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when a release is paid to a settlement partner for cash
+        /// pickup; the recipient presents `reference_code` to collect
+        PartnerPayoutRouted {
+            remittance_id: u64,
+            recipient: AccountHash,
+            partner: AccountHash,
+            reference_code: String,
+            amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a release is paid to an account from the recipient's
+        /// payout address book
+        PayoutTargetUsed {
+            remittance_id: u64,
+            recipient: AccountHash,
+            label: String,
+            target: AccountHash,
+            amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a recipient adds or updates a payout target
+        PayoutTargetSet {
+            owner: AccountHash,
+            label: String,
+            target: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when a recipient removes a payout target
+        PayoutTargetRemoved {
+            owner: AccountHash,
+            label: String,
+            timestamp: u64,
+        },
+
+        /// Emitted when a contributor authorizes an account to claim refunds
+        /// on their behalf, or revokes it (`delegate` is `None`)
+        RefundDelegateSet {
+            contributor: AccountHash,
+            delegate: Option<AccountHash>,
+            timestamp: u64,
+        },
+
+        /// Emitted when a release is held for compliance review
+        ReleaseReviewRequested {
+            remittance_id: u64,
+            amount: U512,
+            review_deadline: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer approves a held release
+        ReleaseApproved {
+            remittance_id: u64,
+            officer: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when the creator or recipient pre-funds a remittance's
+        /// platform fee
+        FeeCoverToppedUp {
+            remittance_id: u64,
+            sponsor: AccountHash,
+            amount: U512,
+            balance: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a release draws the platform fee from the fee cover,
+        /// returning the unused rest to the sponsor
+        FeeCoverApplied {
+            remittance_id: u64,
+            sponsor: AccountHash,
+            covered: U512,
+            returned: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the sponsor claims back the fee cover of a refundable
+        /// remittance
+        FeeCoverRefunded {
+            remittance_id: u64,
+            sponsor: AccountHash,
+            amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a contribution pays an insurance premium
+        InsurancePremiumCollected {
+            remittance_id: u64,
+            contributor: AccountHash,
+            premium: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer approves insurance claims on
+        /// a refundable remittance
+        InsuranceClaimsApproved {
+            remittance_id: u64,
+            coverage_bps: u64,
+            officer: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when a contributor claims an insurance top-up
+        InsuranceClaimPaid {
+            remittance_id: u64,
+            contributor: AccountHash,
+            amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a held release is rejected and refunds are enabled
+        ReleaseRejected {
+            remittance_id: u64,
+            rejected_by: AccountHash,
+            total_amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a platform fee is taken from a contribution
+        ContributionFeeCharged {
+            remittance_id: u64,
+            contributor: AccountHash,
+            fee: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a contributor claims their refund
+        RefundClaimed {
+            remittance_id: u64,
+            contributor: AccountHash,
+            amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer refunds one contributor from
+        /// an active remittance, e.g. after a chargeback
+        ContributionForceRefunded {
+            remittance_id: u64,
+            officer: AccountHash,
+            contributor: AccountHash,
+            amount: U512,
+            new_total: U512,
+            reason: String,
+            timestamp: u64,
+        },
+
+        /// Emitted when a contributor claims their share of a released
+        /// remittance's excess
+        ExcessRefundClaimed {
+            remittance_id: u64,
+            contributor: AccountHash,
+            amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when part of a release fee is accrued to the creator
+        CreatorRebateAccrued {
+            remittance_id: u64,
+            creator: AccountHash,
+            amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when a creator claims their accrued rewards
+        CreatorRewardsClaimed {
+            creator: AccountHash,
+            amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when platform fee is updated
+        PlatformFeeUpdated {
+            old_fee_bps: u64,
+            new_fee_bps: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when platform fee is updated in parts-per-million
+        PlatformFeePpmUpdated {
+            old_fee_ppm: u64,
+            new_fee_ppm: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when fee rounding mode is updated
+        FeeRoundingUpdated {
+            old_mode: u8,
+            new_mode: u8,
+            timestamp: u64,
+        },
+
+        /// Emitted when the creator fee share is updated
+        CreatorFeeShareUpdated {
+            old_share_bps: u64,
+            new_share_bps: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner changes the insurance premium
+        InsurancePremiumUpdated {
+            old_premium_bps: u64,
+            new_premium_bps: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner lowers the maximum platform fee
+        MaxFeeLowered {
+            old_max_fee_ppm: u64,
+            new_max_fee_ppm: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when a batch of legacy records is migrated
+        RecordsMigrated {
+            start_id: u64,
+            count: u64,
+            migrated: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when the contract owner is rotated to a new account
+        OwnerRotated {
+            old_owner: AccountHash,
+            new_owner: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner appoints a new compliance officer
+        ComplianceOfficerUpdated {
+            old_officer: AccountHash,
+            new_officer: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner appoints a new bridge operator
+        BridgeOperatorUpdated {
+            old_operator: AccountHash,
+            new_operator: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer adds a verified charity
+        CharityRegistered {
+            charity: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer removes a verified charity
+        CharityUnregistered {
+            charity: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer marks a recipient as a
+        /// KYB-verified business
+        BusinessRegistered {
+            business: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer removes a KYB-verified
+        /// business
+        BusinessUnregistered {
+            business: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer registers or updates a
+        /// settlement partner
+        SettlementPartnerRegistered {
+            partner: AccountHash,
+            name: String,
+            corridors: Vec<String>,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer removes a settlement partner
+        SettlementPartnerUnregistered {
+            partner: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer blocklists a purpose hash
+        PurposeBlocked {
+            purpose_hash: [u8; 32],
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer lifts a purpose hash block
+        PurposeUnblocked {
+            purpose_hash: [u8; 32],
+            timestamp: u64,
+        },
+
+        /// Emitted when a creator attaches travel-rule data to a remittance
+        TravelRuleDataAttached {
+            remittance_id: u64,
+            data_hash: [u8; 32],
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer changes the travel-rule threshold
+        TravelRuleThresholdUpdated {
+            old_threshold: U512,
+            new_threshold: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner changes the maximum deadline extension
+        MaxDeadlineExtensionUpdated {
+            old_max_extension_ms: u64,
+            new_max_extension_ms: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner changes the maximum target amount
+        MaxTargetAmountUpdated {
+            old_max_target_amount: U512,
+            new_max_target_amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner changes the minimum target amount
+        MinTargetAmountUpdated {
+            old_min_target_amount: U512,
+            new_min_target_amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner changes the maximum target amount for
+        /// KYB-verified businesses
+        KybMaxTargetAmountUpdated {
+            old_kyb_max_target_amount: U512,
+            new_kyb_max_target_amount: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner changes the instance branding
+        BrandingUpdated {
+            platform_name: String,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner creates a namespace for an operator
+        NamespaceCreated {
+            namespace: String,
+            owner: AccountHash,
+            fee_ppm: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when a namespace owner changes its fee
+        NamespaceFeeUpdated {
+            namespace: String,
+            old_fee_ppm: u64,
+            new_fee_ppm: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when a namespace owner pauses it
+        NamespacePaused { namespace: String, timestamp: u64 },
+
+        /// Emitted when a namespace owner unpauses it
+        NamespaceUnpaused { namespace: String, timestamp: u64 },
+
+        /// Emitted when the owner changes the release escalation intervals
+        SlaIntervalsUpdated {
+            intervals_ms: Vec<u64>,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner sets the defaults of a category
+        CategoryTemplateSet {
+            category: String,
+            deadline_days: u64,
+            fee_ppm: Option<u64>,
+            refund_excess: bool,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer changes the review policy
+        ReviewPolicyUpdated {
+            threshold: U512,
+            window_ms: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer registers a risk engine
+        RiskEngineSet {
+            contract_hash: ContractHash,
+            min_amount: U512,
+            max_score: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when the compliance officer removes the risk engine
+        RiskEngineCleared { timestamp: u64 },
+
+        /// Emitted when the owner changes per-account rate limits
+        RateLimitsUpdated {
+            window_ms: u64,
+            max_creations: u64,
+            max_contributions: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner changes the per-call gas-sanity limits
+        GasLimitsUpdated {
+            max_batch_size: u64,
+            max_page_size: u64,
+            max_contributors: u64,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner changes the payment guards
+        PaymentGuardsUpdated {
+            reject_self_remittance: bool,
+            recipient_contributions: u8,
+            timestamp: u64,
+        },
+
+        /// Emitted when a recipient contributes to their own remittance
+        /// under the flagging policy
+        RecipientContributionFlagged {
+            remittance_id: u64,
+            recipient: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when an accounting snapshot is recorded
+        AccountingSnapshotTaken {
+            index: u64,
+            purse_balance: U512,
+            escrow_liabilities: U512,
+            accrued_fees: U512,
+            purse_short: bool,
+            timestamp: u64,
+        },
+
+        /// Emitted when the contributor amounts of a remittance are frozen
+        /// under a snapshot ID
+        ContributionsSnapshotTaken {
+            snapshot_id: u64,
+            remittance_id: u64,
+            taken_by: AccountHash,
+            contributors: u64,
+            total: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted just before a release reverts because the contract purse
+        /// holds less than the remittance's escrowed amount
+        EscrowShortfall {
+            remittance_id: u64,
+            required: U512,
+            available: U512,
+            timestamp: u64,
+        },
+
+        /// Emitted just before reverting on a missing or mistyped runtime argument
+        ArgumentRejected {
+            name: String,
+            error_code: u32,
+        },
+
+        /// Emitted when the owner withdraws the package access token for an upgrade
+        AccessTokenWithdrawn {
+            owner: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when the package access token is permanently revoked
+        AccessTokenRevoked {
+            owner: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when the owner permanently gives up fee changes, pausing,
+        /// and access token withdrawal
+        AdminRenounced {
+            owner: AccountHash,
+            timestamp: u64,
+        },
+
+        /// Emitted when a stale named key is removed
+        NamedKeyRemoved {
+            name: String,
+            timestamp: u64,
+        },
+
+        /// Emitted when contract is paused
+        ContractPaused { timestamp: u64 },
+
+        /// Emitted when contract is unpaused
+        ContractUnpaused { timestamp: u64 },
+    }
+}
+
+impl ContractEvent {
+    /// ID of the remittance the event concerns, if any
+    pub fn remittance_id(&self) -> Option<u64> {
+        match self {
+            ContractEvent::RemittanceCreated { remittance_id, .. }
+            | ContractEvent::PurposeRevealed { remittance_id, .. }
+            | ContractEvent::DocumentAttached { remittance_id, .. }
+            | ContractEvent::HandleRegistered { remittance_id, .. }
+            | ContractEvent::UpdatePosted { remittance_id, .. }
+            | ContractEvent::ReceiptAcknowledged { remittance_id, .. }
+            | ContractEvent::RemittanceActivated { remittance_id, .. }
+            | ContractEvent::ContributionMade { remittance_id, .. }
+            | ContractEvent::ConfidentialContributionMade { remittance_id, .. }
+            | ContractEvent::ContributionFeeCharged { remittance_id, .. }
+            | ContractEvent::FundsReleased { remittance_id, .. }
+            | ContractEvent::InstantPayout { remittance_id, .. }
+            | ContractEvent::InstallmentPaid { remittance_id, .. }
+            | ContractEvent::PotWithdrawn { remittance_id, .. }
+            | ContractEvent::BackupReleaseTriggered { remittance_id, .. }
+            | ContractEvent::RemittanceCancelled { remittance_id, .. }
+            | ContractEvent::RemittanceForceCancelled { remittance_id, .. }
+            | ContractEvent::ContributionForceRefunded { remittance_id, .. }
+            | ContractEvent::RemittanceRejected { remittance_id, .. }
+            | ContractEvent::ReleaseDeclined { remittance_id, .. }
+            | ContractEvent::ReleaseOverdue { remittance_id, .. }
+            | ContractEvent::RemittanceExpired { remittance_id, .. }
+            | ContractEvent::DeadlineExtended { remittance_id, .. }
+            | ContractEvent::BridgePayoutRequested { remittance_id, .. }
+            | ContractEvent::BridgePayoutConfirmed { remittance_id, .. }
+            | ContractEvent::BridgePayoutCancelled { remittance_id, .. }
+            | ContractEvent::PartnerPayoutRouted { remittance_id, .. }
+            | ContractEvent::PayoutTargetUsed { remittance_id, .. }
+            | ContractEvent::ReleaseReviewRequested { remittance_id, .. }
+            | ContractEvent::ReleaseApproved { remittance_id, .. }
+            | ContractEvent::RecipientApprovalAdded { remittance_id, .. }
+            | ContractEvent::ContributionsSnapshotTaken { remittance_id, .. }
+            | ContractEvent::FeeCoverToppedUp { remittance_id, .. }
+            | ContractEvent::FeeCoverApplied { remittance_id, .. }
+            | ContractEvent::FeeCoverRefunded { remittance_id, .. }
+            | ContractEvent::InsurancePremiumCollected { remittance_id, .. }
+            | ContractEvent::InsuranceClaimsApproved { remittance_id, .. }
+            | ContractEvent::InsuranceClaimPaid { remittance_id, .. }
+            | ContractEvent::ReleaseRejected { remittance_id, .. }
+            | ContractEvent::RefundClaimed { remittance_id, .. }
+            | ContractEvent::ExcessRefundClaimed { remittance_id, .. }
+            | ContractEvent::CreatorRebateAccrued { remittance_id, .. }
+            | ContractEvent::TravelRuleDataAttached { remittance_id, .. } => Some(*remittance_id),
+            _ => None,
+        }
+    }
+
+    /// Accounts named in the event
+    pub fn accounts(&self) -> Vec<AccountHash> {
+        match self {
+            ContractEvent::RemittanceCreated {
+                creator, recipient, ..
+            } => vec![*creator, *recipient],
+            ContractEvent::SavingsPoolCreated {
+                organizer, members, ..
+            } => {
+                let mut accounts = vec![*organizer];
+                accounts.extend(members.iter().filter(|member| *member != organizer));
+                accounts
+            }
+            ContractEvent::DocumentAttached { attached_by, .. } => vec![*attached_by],
+            ContractEvent::ContributionMade { contributor, .. }
+            | ContractEvent::ConfidentialContributionMade { contributor, .. }
+            | ContractEvent::ContributionFeeCharged { contributor, .. }
+            | ContractEvent::RefundClaimed { contributor, .. }
+            | ContractEvent::InsurancePremiumCollected { contributor, .. }
+            | ContractEvent::InsuranceClaimPaid { contributor, .. }
+            | ContractEvent::ExcessRefundClaimed { contributor, .. } => vec![*contributor],
+            ContractEvent::FundsReleased { recipient, .. }
+            | ContractEvent::InstantPayout { recipient, .. }
+            | ContractEvent::InstallmentPaid { recipient, .. }
+            | ContractEvent::PotWithdrawn { recipient, .. }
+            | ContractEvent::RemittanceRejected { recipient, .. }
+            | ContractEvent::ReleaseDeclined { recipient, .. }
+            | ContractEvent::ReleaseOverdue { recipient, .. }
+            | ContractEvent::ReceiptAcknowledged { recipient, .. }
+            | ContractEvent::BridgePayoutRequested { recipient, .. }
+            | ContractEvent::RecipientContributionFlagged { recipient, .. } => vec![*recipient],
+            ContractEvent::BridgePayoutConfirmed { operator, .. } => vec![*operator],
+            ContractEvent::BridgePayoutCancelled { cancelled_by, .. } => vec![*cancelled_by],
+            ContractEvent::BackupReleaseTriggered {
+                recipient, backup, ..
+            } => vec![*recipient, *backup],
+            ContractEvent::ReleaseApproved { officer, .. }
+            | ContractEvent::InsuranceClaimsApproved { officer, .. }
+            | ContractEvent::RemittanceForceCancelled { officer, .. } => vec![*officer],
+            ContractEvent::ContributionForceRefunded {
+                officer, contributor, ..
+            } => vec![*officer, *contributor],
+            ContractEvent::ReleaseRejected { rejected_by, .. } => vec![*rejected_by],
+            ContractEvent::RecipientApprovalAdded { signer, .. } => vec![*signer],
+            ContractEvent::ContributionsSnapshotTaken { taken_by, .. } => vec![*taken_by],
+            ContractEvent::FeeCoverToppedUp { sponsor, .. }
+            | ContractEvent::FeeCoverApplied { sponsor, .. }
+            | ContractEvent::FeeCoverRefunded { sponsor, .. } => vec![*sponsor],
+            ContractEvent::RemittanceCancelled { creator, .. }
+            | ContractEvent::HandleRegistered { creator, .. }
+            | ContractEvent::UpdatePosted { creator, .. }
+            | ContractEvent::CreatorRebateAccrued { creator, .. }
+            | ContractEvent::CreatorRewardsClaimed { creator, .. } => vec![*creator],
+            ContractEvent::OwnerRotated {
+                old_owner,
+                new_owner,
+                ..
+            } => vec![*old_owner, *new_owner],
+            ContractEvent::ComplianceOfficerUpdated {
+                old_officer,
+                new_officer,
+                ..
+            } => vec![*old_officer, *new_officer],
+            ContractEvent::BridgeOperatorUpdated {
+                old_operator,
+                new_operator,
+                ..
+            } => vec![*old_operator, *new_operator],
+            ContractEvent::CharityRegistered { charity, .. }
+            | ContractEvent::CharityUnregistered { charity, .. } => vec![*charity],
+            ContractEvent::BusinessRegistered { business, .. }
+            | ContractEvent::BusinessUnregistered { business, .. } => vec![*business],
+            ContractEvent::SettlementPartnerRegistered { partner, .. }
+            | ContractEvent::SettlementPartnerUnregistered { partner, .. } => vec![*partner],
+            ContractEvent::PartnerPayoutRouted {
+                recipient,
+                partner,
+                ..
+            } => vec![*recipient, *partner],
+            ContractEvent::PayoutTargetUsed {
+                recipient, target, ..
+            } => vec![*recipient, *target],
+            ContractEvent::PayoutTargetSet { owner, target, .. } => vec![*owner, *target],
+            ContractEvent::RefundDelegateSet {
+                contributor,
+                delegate,
+                ..
+            } => {
+                let mut accounts = vec![*contributor];
+                accounts.extend(*delegate);
+                accounts
+            }
+            ContractEvent::AccessTokenWithdrawn { owner, .. }
+            | ContractEvent::AccessTokenRevoked { owner, .. }
+            | ContractEvent::AdminRenounced { owner, .. }
+            | ContractEvent::PayoutTargetRemoved { owner, .. }
+            | ContractEvent::NamespaceCreated { owner, .. } => vec![*owner],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Checks if the event names `account`
+    pub fn involves(&self, account: &AccountHash) -> bool {
+        self.accounts().contains(account)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ces_round_trip() {
+        let event = ContractEvent::FundsReleased {
+            remittance_id: 7,
+            recipient: AccountHash::new([2u8; 32]),
+            amount: U512::from(990),
+            platform_fee: U512::from(10),
+            gross_amount: U512::from(1_000),
+            creator_rebate: U512::from(2),
+            fee_covered: U512::zero(),
+            charity_verified: false,
+            timestamp: 1_700_000_000_000,
+        };
+
+        let bytes = event.to_bytes().unwrap();
+        assert_eq!(bytes.len(), event.serialized_length());
+
+        let (name, _) = String::from_bytes(&bytes).unwrap();
+        assert_eq!(name, "event_FundsReleased");
+
+        let decoded: ContractEvent = bytesrepr::deserialize_from_slice(&bytes).unwrap();
+        assert_eq!(decoded, event);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip() {
+        let event = ContractEvent::ContributionMade {
+            remittance_id: 7,
+            contributor: AccountHash::new([3u8; 32]),
+            amount: U512::from(250),
+            new_total: U512::from(1_000),
+            timestamp: 1_700_000_000_000,
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.starts_with("{\"ContributionMade\":"));
+        assert_eq!(serde_json::from_str::<ContractEvent>(&json).unwrap(), event);
+    }
+
+    #[test]
+    fn test_schema_version() {
+        let event = ContractEvent::ContractPaused { timestamp: 1 };
+        let bytes = event.to_bytes().unwrap();
+        let (version, _) = u32::from_bytes(&bytes[bytes.len() - 4..]).unwrap();
+        assert_eq!(version, EVENT_SCHEMA_VERSION);
+        assert_eq!(
+            ContractEvent::from_bytes_versioned(&bytes).unwrap(),
+            (event.clone(), EVENT_SCHEMA_VERSION)
+        );
+
+        // Events emitted before versioning have no trailing version
+        let legacy = &bytes[..bytes.len() - 4];
+        assert_eq!(ContractEvent::from_bytes_versioned(legacy).unwrap(), (event, 0));
+
+        for (_, fields) in ContractEvent::schemas() {
+            assert_eq!(fields.last(), Some(&(SCHEMA_VERSION_FIELD, CLType::U32)));
+        }
+    }
+
+    #[test]
+    fn test_unknown_event_name() {
+        let bytes = String::from("event_Unknown").to_bytes().unwrap();
+        assert!(ContractEvent::from_bytes(&bytes).is_err());
+
+        let bytes = String::from("ContractPaused").to_bytes().unwrap();
+        assert!(ContractEvent::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_schemas_cover_every_event() {
+        let schemas = ContractEvent::schemas();
+        let paused = ContractEvent::ContractPaused { timestamp: 0 };
+
+        assert!(schemas.iter().any(|(name, _)| *name == paused.name()));
+        let (_, fields) = schemas
+            .iter()
+            .find(|(name, _)| *name == "ContributionMade")
+            .unwrap();
+        assert_eq!(fields[0], ("remittance_id", CLType::U64));
+        assert_eq!(fields[1], ("contributor", CLType::ByteArray(32)));
+    }
+
+    #[test]
+    fn test_filters() {
+        let contributor = AccountHash::new([3u8; 32]);
+        let event = ContractEvent::ContributionMade {
+            remittance_id: 4,
+            contributor,
+            amount: U512::from(100),
+            new_total: U512::from(100),
+            timestamp: 0,
+        };
+
+        assert_eq!(event.remittance_id(), Some(4));
+        assert!(event.involves(&contributor));
+        assert!(!event.involves(&AccountHash::new([4u8; 32])));
+        assert_eq!(
+            ContractEvent::ContractPaused { timestamp: 0 }.remittance_id(),
+            None
+        );
+    }
+}
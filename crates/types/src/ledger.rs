@@ -0,0 +1,208 @@
+//! Per-remittance ledger of escrow credits and debits.
+//!
+//! Each movement of a remittance's escrow is appended as an entry, so its
+//! balance can be audited from individual contributions, matches, yield,
+//! penalties, fees, releases, refunds, and insurance premiums rather than
+//! from the running `current_amount` alone.
+
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{account::AccountHash, CLType, CLTyped, U512};
+
+/// Kind of ledger entry; the kind decides whether it credits or debits escrow.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LedgerEntryKind {
+    /// Funds paid in by a contributor
+    Contribution = 0,
+    /// Funds added by a sponsor, such as a match or a fee cover top-up
+    Match = 1,
+    /// Yield earned on escrowed funds
+    Yield = 2,
+    /// Penalty deducted from escrow
+    Penalty = 3,
+    /// Platform fee taken from escrow
+    Fee = 4,
+    /// Funds paid out to the recipient
+    Release = 5,
+    /// Funds returned to a contributor
+    Refund = 6,
+    /// Insurance premium moved into the insurance pool; the account is the
+    /// contributor who paid it
+    Premium = 7,
+}
+
+impl LedgerEntryKind {
+    /// Decodes a ledger entry kind code.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(LedgerEntryKind::Contribution),
+            1 => Some(LedgerEntryKind::Match),
+            2 => Some(LedgerEntryKind::Yield),
+            3 => Some(LedgerEntryKind::Penalty),
+            4 => Some(LedgerEntryKind::Fee),
+            5 => Some(LedgerEntryKind::Release),
+            6 => Some(LedgerEntryKind::Refund),
+            7 => Some(LedgerEntryKind::Premium),
+            _ => None,
+        }
+    }
+
+    /// Checks if entries of this kind add to escrow.
+    pub fn is_credit(self) -> bool {
+        matches!(
+            self,
+            LedgerEntryKind::Contribution | LedgerEntryKind::Match | LedgerEntryKind::Yield
+        )
+    }
+}
+
+impl ToBytes for LedgerEntryKind {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, bytesrepr::Error> {
+        (*self as u8).to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        bytesrepr::U8_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for LedgerEntryKind {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (code, remainder) = u8::from_bytes(bytes)?;
+        let kind = LedgerEntryKind::from_u8(code).ok_or(bytesrepr::Error::Formatting)?;
+        Ok((kind, remainder))
+    }
+}
+
+/// A single credit or debit to a remittance's escrow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LedgerEntry {
+    /// What moved the funds
+    pub kind: LedgerEntryKind,
+
+    /// Counterparty: the payer of a credit or the payee of a debit
+    pub account: AccountHash,
+
+    /// Amount in motes
+    pub amount: U512,
+
+    /// Block timestamp of the movement
+    pub timestamp: u64,
+}
+
+impl LedgerEntry {
+    /// Applies the entry to a running balance.
+    ///
+    /// Returns `None` if a debit exceeds the balance or a credit overflows.
+    pub fn apply(&self, balance: U512) -> Option<U512> {
+        if self.kind.is_credit() {
+            balance.checked_add(self.amount)
+        } else {
+            balance.checked_sub(self.amount)
+        }
+    }
+}
+
+/// Replays entries, oldest first, into the escrow balance they leave.
+///
+/// Returns `None` if any debit would overdraw the balance.
+pub fn ledger_balance<'a>(entries: impl IntoIterator<Item = &'a LedgerEntry>) -> Option<U512> {
+    entries
+        .into_iter()
+        .try_fold(U512::zero(), |balance, entry| entry.apply(balance))
+}
+
+impl ToBytes for LedgerEntry {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.kind.to_bytes()?);
+        result.append(&mut self.account.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.timestamp.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.kind.serialized_length()
+            + self.account.serialized_length()
+            + self.amount.serialized_length()
+            + self.timestamp.serialized_length()
+    }
+}
+
+impl FromBytes for LedgerEntry {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (kind, remainder) = LedgerEntryKind::from_bytes(bytes)?;
+        let (account, remainder) = AccountHash::from_bytes(remainder)?;
+        let (amount, remainder) = U512::from_bytes(remainder)?;
+        let (timestamp, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            LedgerEntry {
+                kind,
+                account,
+                amount,
+                timestamp,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for LedgerEntry {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(kind: LedgerEntryKind, amount: u64) -> LedgerEntry {
+        LedgerEntry {
+            kind,
+            account: AccountHash::new([7u8; 32]),
+            amount: U512::from(amount),
+            timestamp: 1_700_000_000_000,
+        }
+    }
+
+    #[test]
+    fn test_ledger_entry_round_trip() {
+        let original = entry(LedgerEntryKind::Penalty, 250);
+
+        let bytes = original.to_bytes().unwrap();
+        assert_eq!(bytes.len(), original.serialized_length());
+        let (decoded, remainder) = LedgerEntry::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, original);
+
+        // Unknown kinds are rejected
+        let mut bytes = bytes;
+        bytes[0] = 8;
+        assert!(LedgerEntry::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_ledger_balance() {
+        let entries = [
+            entry(LedgerEntryKind::Contribution, 600),
+            entry(LedgerEntryKind::Contribution, 400),
+            entry(LedgerEntryKind::Match, 100),
+            entry(LedgerEntryKind::Fee, 5),
+            entry(LedgerEntryKind::Release, 995),
+        ];
+        assert_eq!(ledger_balance(&entries), Some(U512::from(100)));
+        assert_eq!(ledger_balance(&entries[..2]), Some(U512::from(1000)));
+
+        // A debit may never overdraw escrow
+        let overdrawn = [
+            entry(LedgerEntryKind::Contribution, 10),
+            entry(LedgerEntryKind::Refund, 11),
+        ];
+        assert_eq!(ledger_balance(&overdrawn), None);
+    }
+}
@@ -0,0 +1,32 @@
+//! Shared types for the CasperFlow remittance contract.
+//!
+//! The contract and off-chain consumers (indexers, backends, clients) use
+//! these definitions so that storage encoding, error codes, and events have
+//! a single source of truth.
+//!
+//! The crate is `no_std` by default for use inside the contract. Enable
+//! `std` for host builds; it brings in `serde`, so remittances,
+//! contributions, errors, and events serialize to and from JSON. `serde`
+//! alone gives the same impls without `std`. `test-vectors` adds canonical
+//! encoding examples shared with the frontend.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod errors;
+pub mod events;
+pub mod ledger;
+pub mod remittance;
+pub mod schema;
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
+
+/// The `casper-types` version these definitions are encoded against.
+pub use casper_types;
+
+pub use errors::Error;
+pub use events::ContractEvent;
+pub use ledger::{LedgerEntry, LedgerEntryKind};
+pub use remittance::{contributor_key, localized_purpose, purpose_hash, Contribution, Remittance};
+pub use schema::ContractSchema;
@@ -0,0 +1,4917 @@
+//! Core data structures for the CasperFlow remittance contract.
+//!
+//! This module defines the Remittance and Contribution types that represent
+//! the core business logic of the platform, plus the document references,
+//! travel-rule records, reporting aggregates, and accounting snapshots kept
+//! alongside them.
+
+use alloc::string::String;
+
+use casper_types::bytesrepr::{FromBytes, ToBytes};
+use casper_types::CLTyped;
+use casper_types::{account::AccountHash, ContractHash, U512};
+
+use crate::errors::{
+    Error, ANALYTICS_WINDOW_HOURS, BASIS_POINTS, MAX_BATCH_SIZE, MAX_REFUND_DEDUCTION_BPS,
+    MILLIS_PER_DAY, MILLIS_PER_HOUR,
+};
+
+/// Status flag: funds have been released to the recipient
+pub const FLAG_RELEASED: u8 = 1 << 0;
+
+/// Status flag: the remittance has been cancelled
+pub const FLAG_CANCELLED: u8 = 1 << 1;
+
+/// Status flag: the remittance is addressed by a hash key, not its numeric ID
+pub const FLAG_HASHED_ID: u8 = 1 << 2;
+
+/// Status flag: contributions record amount commitments instead of amounts
+pub const FLAG_CONFIDENTIAL: u8 = 1 << 3;
+
+/// Status flag: the recipient is a verified charity exempt from platform fees
+pub const FLAG_CHARITY_VERIFIED: u8 = 1 << 4;
+
+/// Status flag: release pays out only the target; the excess is refundable
+pub const FLAG_REFUND_EXCESS: u8 = 1 << 5;
+
+/// Status flag: the deadline passed with the target unmet
+pub const FLAG_EXPIRED: u8 = 1 << 6;
+
+/// Status flag: the release is awaiting compliance review
+pub const FLAG_PENDING_REVIEW: u8 = 1 << 7;
+
+/// Represents a single remittance request with escrow functionality.
+///
+/// A remittance holds funds in escrow until the target amount is reached,
+/// at which point the recipient can release the funds. An open-ended pot
+/// has no target: the recipient withdraws what it holds at any time.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Remittance {
+    /// Unique identifier for this remittance
+    pub id: u64,
+
+    /// Account that created this remittance
+    pub creator: AccountHash,
+
+    /// Account that will receive the funds
+    pub recipient: AccountHash,
+
+    /// Target amount to be collected (in motes); `None` for an open-ended pot
+    pub target_amount: Option<U512>,
+
+    /// Current amount contributed (in motes)
+    pub current_amount: U512,
+
+    /// Description of the remittance purpose
+    pub purpose: String,
+
+    /// Timestamp when the remittance was created
+    pub created_at: u64,
+
+    /// Packed status flags (see the `FLAG_*` constants)
+    pub flags: u8,
+
+    /// Hash commitment to a purpose not yet revealed on-chain
+    pub purpose_commitment: Option<[u8; 32]>,
+
+    /// Amount paid out of escrow so far, platform fee included (in motes)
+    pub released_amount: U512,
+
+    /// Block time after which an unfunded remittance can be expired
+    pub deadline: Option<u64>,
+}
+
+impl Remittance {
+    /// Creates a new remittance instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Unique identifier
+    /// * `creator` - Account creating the remittance
+    /// * `recipient` - Account to receive funds
+    /// * `target_amount` - Target amount in motes, or `None` for an
+    ///   open-ended pot
+    /// * `purpose` - Description of the remittance
+    /// * `created_at` - Creation timestamp
+    pub fn new(
+        id: u64,
+        creator: AccountHash,
+        recipient: AccountHash,
+        target_amount: Option<U512>,
+        purpose: String,
+        created_at: u64,
+    ) -> Self {
+        Self {
+            id,
+            creator,
+            recipient,
+            target_amount,
+            current_amount: U512::zero(),
+            purpose,
+            created_at,
+            flags: 0,
+            purpose_commitment: None,
+            released_amount: U512::zero(),
+            deadline: None,
+        }
+    }
+
+    /// Checks whether a status flag is set.
+    pub fn has_flag(&self, flag: u8) -> bool {
+        self.flags & flag != 0
+    }
+
+    /// Sets a status flag.
+    pub fn set_flag(&mut self, flag: u8) {
+        self.flags |= flag;
+    }
+
+    /// Clears a status flag.
+    pub fn clear_flag(&mut self, flag: u8) {
+        self.flags &= !flag;
+    }
+
+    /// Checks if funds have been released to the recipient.
+    pub fn is_released(&self) -> bool {
+        self.has_flag(FLAG_RELEASED)
+    }
+
+    /// Checks if the remittance has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.has_flag(FLAG_CANCELLED)
+    }
+
+    /// Marks the remittance as released.
+    pub fn mark_released(&mut self) {
+        self.set_flag(FLAG_RELEASED);
+    }
+
+    /// Marks the remittance as cancelled.
+    pub fn mark_cancelled(&mut self) {
+        self.set_flag(FLAG_CANCELLED);
+    }
+
+    /// Checks if the remittance expired with its target unmet.
+    pub fn is_expired(&self) -> bool {
+        self.has_flag(FLAG_EXPIRED)
+    }
+
+    /// Marks the remittance as expired.
+    pub fn mark_expired(&mut self) {
+        self.set_flag(FLAG_EXPIRED);
+    }
+
+    /// Checks if the release is awaiting compliance review.
+    pub fn is_pending_review(&self) -> bool {
+        self.has_flag(FLAG_PENDING_REVIEW)
+    }
+
+    /// Checks if contributors can claim refunds (cancelled or expired).
+    pub fn is_refundable(&self) -> bool {
+        self.has_flag(FLAG_CANCELLED | FLAG_EXPIRED)
+    }
+
+    /// Checks if the deadline, if any, has passed at block time `now`.
+    pub fn is_past_deadline(&self, now: u64) -> bool {
+        matches!(self.deadline, Some(deadline) if now >= deadline)
+    }
+
+    /// Checks if the remittance can be expired at block time `now`: still
+    /// active, past its deadline, and short of its target.
+    pub fn is_expirable(&self, now: u64) -> bool {
+        self.is_active() && self.is_past_deadline(now) && !self.is_target_met()
+    }
+
+    /// Checks if the purpose is hidden behind a commitment awaiting reveal.
+    pub fn is_purpose_committed(&self) -> bool {
+        self.purpose_commitment.is_some()
+    }
+
+    /// Checks if contributions record amount commitments instead of amounts.
+    pub fn is_confidential(&self) -> bool {
+        self.has_flag(FLAG_CONFIDENTIAL)
+    }
+
+    /// Checks if the recipient was a verified charity when last checked.
+    pub fn is_charity_verified(&self) -> bool {
+        self.has_flag(FLAG_CHARITY_VERIFIED)
+    }
+
+    /// Checks if contributions beyond the target are refunded after release.
+    pub fn refunds_excess(&self) -> bool {
+        self.has_flag(FLAG_REFUND_EXCESS)
+    }
+
+    /// Checks if the remittance is active (not released, cancelled, or expired).
+    pub fn is_active(&self) -> bool {
+        !self.has_flag(FLAG_RELEASED | FLAG_CANCELLED | FLAG_EXPIRED)
+    }
+
+    /// Checks if the remittance is an open-ended pot with no target.
+    pub fn is_open_ended(&self) -> bool {
+        self.target_amount.is_none()
+    }
+
+    /// Checks if the target amount has been met or exceeded.
+    ///
+    /// An open-ended pot has no target to meet, so it counts as met whenever
+    /// it holds funds not yet withdrawn.
+    pub fn is_target_met(&self) -> bool {
+        match self.target_amount {
+            Some(target_amount) => self.current_amount >= target_amount,
+            None => !self.unreleased_amount().is_zero(),
+        }
+    }
+
+    /// Calculates the remaining amount needed to reach the target; always
+    /// zero for an open-ended pot.
+    pub fn remaining_amount(&self) -> U512 {
+        match self.target_amount {
+            Some(target_amount) if self.current_amount < target_amount => {
+                target_amount - self.current_amount
+            }
+            _ => U512::zero(),
+        }
+    }
+
+    /// Calculates the amount paid out on release, platform fee included.
+    ///
+    /// This is the full contributed amount unless the remittance refunds
+    /// excess contributions, in which case it is capped at the target.
+    pub fn release_amount(&self) -> U512 {
+        match self.target_amount {
+            Some(target_amount) if self.refunds_excess() => self.current_amount.min(target_amount),
+            _ => self.current_amount,
+        }
+    }
+
+    /// Calculates the amount a release still pays out of escrow: the release
+    /// amount less anything already forwarded to the recipient.
+    pub fn remaining_release_amount(&self) -> U512 {
+        self.release_amount().saturating_sub(self.released_amount)
+    }
+
+    /// Calculates the amount still held in escrow for contributors.
+    pub fn unreleased_amount(&self) -> U512 {
+        self.current_amount.saturating_sub(self.released_amount)
+    }
+
+    /// Calculates a contributor's pro-rata share of the unreleased amount.
+    ///
+    /// Shares round down, so together they never exceed what escrow still
+    /// holds; any remainder of a mote per contributor stays in the purse. A
+    /// share is never more than the unreleased amount, whatever
+    /// `contribution` claims.
+    pub fn unreleased_share(&self, contribution: U512) -> Result<U512, Error> {
+        if self.current_amount.is_zero() {
+            return Ok(U512::zero());
+        }
+        if self.released_amount.is_zero() {
+            return Ok(contribution.min(self.unreleased_amount()));
+        }
+
+        let numerator = self
+            .unreleased_amount()
+            .checked_mul(contribution)
+            .ok_or(Error::ArithmeticOverflow)?;
+        Ok((numerator / self.current_amount).min(self.unreleased_amount()))
+    }
+
+    /// Calculates progress towards the target in basis points (0-10000).
+    ///
+    /// Uses full U512 math, so targets beyond `u64::MAX` are reported
+    /// correctly. An open-ended pot reports full progress while it holds
+    /// funds, as it counts as funded.
+    pub fn progress_bps(&self) -> u64 {
+        let target_amount = match self.target_amount {
+            Some(target_amount) if self.current_amount < target_amount => target_amount,
+            _ if self.is_target_met() => return BASIS_POINTS,
+            _ => return 0,
+        };
+
+        // current < target here; (current * 10000) / target
+        let basis_points = U512::from(BASIS_POINTS);
+        let progress = match self.current_amount.checked_mul(basis_points) {
+            Some(scaled) => scaled / target_amount,
+            // Only near U512::MAX, where target / 10000 is still huge and the
+            // rounding is negligible
+            None => self.current_amount / (target_amount / basis_points),
+        };
+        progress.as_u64().min(BASIS_POINTS)
+    }
+
+    /// Calculates the progress percentage (0-100).
+    pub fn progress_percentage(&self) -> u64 {
+        self.progress_bps() / 100
+    }
+
+    /// Derives the lifecycle status at block time `now`.
+    ///
+    /// A remittance past its deadline reads as expired before anyone calls
+    /// `expire_remittance`, since it already takes no new funds.
+    pub fn status(&self, now: u64) -> RemittanceStatus {
+        if self.is_released() {
+            RemittanceStatus::Released
+        } else if self.is_cancelled() {
+            RemittanceStatus::Cancelled
+        } else if self.is_expired() || self.is_past_deadline(now) {
+            RemittanceStatus::Expired
+        } else if self.is_pending_review() {
+            RemittanceStatus::PendingReview
+        } else if self.is_target_met() {
+            RemittanceStatus::Funded
+        } else {
+            RemittanceStatus::Active
+        }
+    }
+
+    /// Summarizes how far the remittance is funded at block time `now`.
+    pub fn funding_state(&self, now: u64) -> FundingState {
+        FundingState {
+            current_amount: self.current_amount,
+            target_amount: self.target_amount,
+            remaining_amount: self.remaining_amount(),
+            is_target_met: self.is_target_met(),
+            deadline: self.deadline,
+            status: self.status(now) as u8,
+        }
+    }
+}
+
+/// Current on-chain encoding version of `Remittance`.
+///
+/// Every encoded remittance starts with this byte so the layout can evolve;
+/// older records are upgraded in place by the `migrate_records` entry point.
+///
+/// Each version appends fields to, or widens a field of, the previous layout:
+/// - 1: base fields and status flags
+/// - 2: `purpose_commitment`
+/// - 3: `released_amount`
+/// - 4: `deadline`
+/// - 5: `target_amount` becomes optional, for open-ended pots
+pub const REMITTANCE_FORMAT_VERSION: u8 = 5;
+
+impl Remittance {
+    /// Deserializes the fields of the layout identified by `version`.
+    ///
+    /// Fields introduced after `version` take their default values.
+    fn body_from_bytes(
+        version: u8,
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (id, remainder) = u64::from_bytes(bytes)?;
+        let (creator, remainder) = AccountHash::from_bytes(remainder)?;
+        let (recipient, remainder) = AccountHash::from_bytes(remainder)?;
+
+        // Older layouts always had a target
+        let (target_amount, remainder) = if version >= 5 {
+            Option::<U512>::from_bytes(remainder)?
+        } else {
+            let (target_amount, remainder) = U512::from_bytes(remainder)?;
+            (Some(target_amount), remainder)
+        };
+
+        let (current_amount, remainder) = U512::from_bytes(remainder)?;
+        let (purpose, remainder) = String::from_bytes(remainder)?;
+        let (created_at, remainder) = u64::from_bytes(remainder)?;
+        let (flags, remainder) = u8::from_bytes(remainder)?;
+
+        let (purpose_commitment, remainder) = if version >= 2 {
+            Option::<[u8; 32]>::from_bytes(remainder)?
+        } else {
+            (None, remainder)
+        };
+
+        let (released_amount, remainder) = if version >= 3 {
+            U512::from_bytes(remainder)?
+        } else {
+            (U512::zero(), remainder)
+        };
+
+        let (deadline, remainder) = if version >= 4 {
+            Option::<u64>::from_bytes(remainder)?
+        } else {
+            (None, remainder)
+        };
+
+        let mut remittance = Remittance {
+            id,
+            creator,
+            recipient,
+            target_amount,
+            current_amount,
+            purpose,
+            created_at,
+            flags,
+            purpose_commitment,
+            released_amount,
+            deadline,
+        };
+
+        // Older layouts only released in full
+        if version < 3 && remittance.is_released() {
+            remittance.released_amount = remittance.release_amount();
+        }
+
+        Ok((remittance, remainder))
+    }
+}
+
+// Manual implementations of serialization traits for Remittance
+impl ToBytes for Remittance {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.push(REMITTANCE_FORMAT_VERSION);
+        result.append(&mut self.id.to_bytes()?);
+        result.append(&mut self.creator.to_bytes()?);
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.target_amount.to_bytes()?);
+        result.append(&mut self.current_amount.to_bytes()?);
+        result.append(&mut self.purpose.to_bytes()?);
+        result.append(&mut self.created_at.to_bytes()?);
+        result.append(&mut self.flags.to_bytes()?);
+        result.append(&mut self.purpose_commitment.to_bytes()?);
+        result.append(&mut self.released_amount.to_bytes()?);
+        result.append(&mut self.deadline.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        REMITTANCE_FORMAT_VERSION.serialized_length()
+            + self.id.serialized_length()
+            + self.creator.serialized_length()
+            + self.recipient.serialized_length()
+            + self.target_amount.serialized_length()
+            + self.current_amount.serialized_length()
+            + self.purpose.serialized_length()
+            + self.created_at.serialized_length()
+            + self.flags.serialized_length()
+            + self.purpose_commitment.serialized_length()
+            + self.released_amount.serialized_length()
+            + self.deadline.serialized_length()
+    }
+}
+
+impl FromBytes for Remittance {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (version, remainder) = u8::from_bytes(bytes)?;
+
+        match version {
+            1..=REMITTANCE_FORMAT_VERSION => Remittance::body_from_bytes(version, remainder),
+            _ => Err(casper_types::bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// The encoding version of a stored remittance, read without decoding it.
+///
+/// Lets `migrate_records` skip records that are already current.
+pub struct RemittanceFormatVersion(pub u8);
+
+impl FromBytes for RemittanceFormatVersion {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (version, _body) = u8::from_bytes(bytes)?;
+        Ok((RemittanceFormatVersion(version), &[]))
+    }
+}
+
+impl CLTyped for RemittanceFormatVersion {
+    fn cl_type() -> casper_types::CLType {
+        Remittance::cl_type()
+    }
+}
+
+impl CLTyped for Remittance {
+    fn cl_type() -> casper_types::CLType {
+        // Represent as a tuple of all fields
+        use casper_types::CLType;
+        CLType::Any // Using Any for complex custom types
+    }
+}
+
+/// A remittance stored in the original, unversioned layout.
+///
+/// Records written before versioned encoding live in the legacy
+/// `remittances` dictionary with two trailing booleans instead of a flags
+/// byte. They are decoded through this wrapper and rewritten by
+/// `migrate_records`.
+pub struct LegacyRemittance(pub Remittance);
+
+impl FromBytes for LegacyRemittance {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (id, remainder) = u64::from_bytes(bytes)?;
+        let (creator, remainder) = AccountHash::from_bytes(remainder)?;
+        let (recipient, remainder) = AccountHash::from_bytes(remainder)?;
+        let (target_amount, remainder) = U512::from_bytes(remainder)?;
+        let (current_amount, remainder) = U512::from_bytes(remainder)?;
+        let (purpose, remainder) = String::from_bytes(remainder)?;
+        let (created_at, remainder) = u64::from_bytes(remainder)?;
+        let (is_released, remainder) = bool::from_bytes(remainder)?;
+        let (is_cancelled, remainder) = bool::from_bytes(remainder)?;
+
+        let mut flags = 0;
+        if is_released {
+            flags |= FLAG_RELEASED;
+        }
+        if is_cancelled {
+            flags |= FLAG_CANCELLED;
+        }
+
+        // Legacy remittances were released in full
+        let released_amount = if is_released {
+            current_amount
+        } else {
+            U512::zero()
+        };
+
+        Ok((
+            LegacyRemittance(Remittance {
+                id,
+                creator,
+                recipient,
+                target_amount: Some(target_amount),
+                current_amount,
+                purpose,
+                created_at,
+                flags,
+                purpose_commitment: None,
+                released_amount,
+                deadline: None,
+            }),
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for LegacyRemittance {
+    fn cl_type() -> casper_types::CLType {
+        // Legacy records were stored with the same CLType as Remittance
+        Remittance::cl_type()
+    }
+}
+
+/// Lifecycle status of a remittance, as reported by `get_funding_state`.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RemittanceStatus {
+    /// Accepting contributions towards an unmet target
+    Active = 0,
+    /// Target met, awaiting release
+    Funded = 1,
+    /// Release held for compliance review
+    PendingReview = 2,
+    /// Funds released
+    Released = 3,
+    /// Cancelled or rejected; contributors can claim refunds
+    Cancelled = 4,
+    /// Deadline passed with no release
+    Expired = 5,
+}
+
+/// Everything a contribution UI needs to decide whether to accept funds,
+/// returned by `get_funding_state`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FundingState {
+    /// Amount contributed so far
+    pub current_amount: U512,
+
+    /// Target amount; `None` for an open-ended pot
+    pub target_amount: Option<U512>,
+
+    /// Amount still needed to reach the target
+    pub remaining_amount: U512,
+
+    /// Whether the target has been met or exceeded
+    pub is_target_met: bool,
+
+    /// Block time (Unix ms) after which no new funds are accepted, if any
+    pub deadline: Option<u64>,
+
+    /// Status code (see `RemittanceStatus`)
+    pub status: u8,
+}
+
+impl ToBytes for FundingState {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.current_amount.to_bytes()?);
+        result.append(&mut self.target_amount.to_bytes()?);
+        result.append(&mut self.remaining_amount.to_bytes()?);
+        result.append(&mut self.is_target_met.to_bytes()?);
+        result.append(&mut self.deadline.to_bytes()?);
+        result.append(&mut self.status.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.current_amount.serialized_length()
+            + self.target_amount.serialized_length()
+            + self.remaining_amount.serialized_length()
+            + self.is_target_met.serialized_length()
+            + self.deadline.serialized_length()
+            + self.status.serialized_length()
+    }
+}
+
+impl FromBytes for FundingState {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (current_amount, remainder) = U512::from_bytes(bytes)?;
+        let (target_amount, remainder) = Option::<U512>::from_bytes(remainder)?;
+        let (remaining_amount, remainder) = U512::from_bytes(remainder)?;
+        let (is_target_met, remainder) = bool::from_bytes(remainder)?;
+        let (deadline, remainder) = Option::<u64>::from_bytes(remainder)?;
+        let (status, remainder) = u8::from_bytes(remainder)?;
+
+        Ok((
+            FundingState {
+                current_amount,
+                target_amount,
+                remaining_amount,
+                is_target_met,
+                deadline,
+                status,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for FundingState {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Represents a single contribution to a remittance.
+///
+/// Contributions are tracked per-contributor for the refund mechanism.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contribution {
+    /// Account that made the contribution
+    pub contributor: AccountHash,
+
+    /// Amount contributed (in motes)
+    pub amount: U512,
+
+    /// Timestamp of the contribution
+    pub timestamp: u64,
+}
+
+impl Contribution {
+    /// Creates a new contribution instance.
+    pub fn new(contributor: AccountHash, amount: U512, timestamp: u64) -> Self {
+        Self {
+            contributor,
+            amount,
+            timestamp,
+        }
+    }
+}
+
+impl ToBytes for Contribution {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.contributor.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.timestamp.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.contributor.serialized_length()
+            + self.amount.serialized_length()
+            + self.timestamp.serialized_length()
+    }
+}
+
+impl FromBytes for Contribution {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (contributor, remainder) = AccountHash::from_bytes(bytes)?;
+        let (amount, remainder) = U512::from_bytes(remainder)?;
+        let (timestamp, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            Contribution {
+                contributor,
+                amount,
+                timestamp,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for Contribution {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Derives the per-contributor dictionary key hash for a remittance.
+///
+/// Contributions, contribution commitments, and refund claims are keyed by
+/// this hash, hex-encoded. Dictionary item keys are capped at 64 bytes, so
+/// `"{id}_{account}"` with a 64-character account hash would not fit.
+pub fn contributor_key(remittance_id: u64, contributor: &AccountHash) -> [u8; 32] {
+    let mut preimage = [0u8; 40];
+    preimage[..8].copy_from_slice(&remittance_id.to_le_bytes());
+    preimage[8..].copy_from_slice(contributor.as_bytes());
+    casper_types::crypto::blake2b(preimage)
+}
+
+/// Hash under which a purpose is matched against the purpose blocklist.
+///
+/// The purpose is trimmed, ASCII-lowercased, and its runs of whitespace are
+/// collapsed first, so trivial edits to a blocked template still match.
+pub fn purpose_hash(purpose: &str) -> [u8; 32] {
+    let mut normalized = String::with_capacity(purpose.len());
+    for word in purpose.split_whitespace() {
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        normalized.push_str(word);
+    }
+    normalized.make_ascii_lowercase();
+    casper_types::crypto::blake2b(normalized.as_bytes())
+}
+
+/// Kind of document attached to a remittance.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DocumentType {
+    /// Invoice or bill of sale
+    Invoice = 0,
+    /// Hospital or medical bill
+    MedicalBill = 1,
+    /// School fee letter or tuition statement
+    SchoolFees = 2,
+    /// Any other supporting document
+    Other = 3,
+}
+
+impl DocumentType {
+    /// Decodes a document type code.
+    pub fn from_u8(value: u8) -> Result<Self, Error> {
+        match value {
+            0 => Ok(DocumentType::Invoice),
+            1 => Ok(DocumentType::MedicalBill),
+            2 => Ok(DocumentType::SchoolFees),
+            3 => Ok(DocumentType::Other),
+            _ => Err(Error::InvalidDocumentType),
+        }
+    }
+}
+
+/// An off-chain document referenced by a remittance.
+///
+/// The document itself lives on IPFS; the contract stores its CID and a
+/// content hash so contributors can check the file they fetch is the one
+/// that was attached.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DocumentRef {
+    /// IPFS content identifier
+    pub cid: String,
+
+    /// Hash of the document contents
+    pub content_hash: [u8; 32],
+
+    /// Document type code (see `DocumentType`)
+    pub doc_type: u8,
+
+    /// Account that attached the document
+    pub attached_by: AccountHash,
+
+    /// Block timestamp when the document was attached
+    pub attached_at: u64,
+}
+
+impl ToBytes for DocumentRef {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.cid.to_bytes()?);
+        result.append(&mut self.content_hash.to_bytes()?);
+        result.append(&mut self.doc_type.to_bytes()?);
+        result.append(&mut self.attached_by.to_bytes()?);
+        result.append(&mut self.attached_at.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.cid.serialized_length()
+            + self.content_hash.serialized_length()
+            + self.doc_type.serialized_length()
+            + self.attached_by.serialized_length()
+            + self.attached_at.serialized_length()
+    }
+}
+
+impl FromBytes for DocumentRef {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (cid, remainder) = String::from_bytes(bytes)?;
+        let (content_hash, remainder) = <[u8; 32]>::from_bytes(remainder)?;
+        let (doc_type, remainder) = u8::from_bytes(remainder)?;
+        let (attached_by, remainder) = AccountHash::from_bytes(remainder)?;
+        let (attached_at, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            DocumentRef {
+                cid,
+                content_hash,
+                doc_type,
+                attached_by,
+                attached_at,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for DocumentRef {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// A progress update posted by a remittance's creator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemittanceUpdate {
+    /// Update text (max `MAX_UPDATE_LENGTH` bytes)
+    pub message: String,
+
+    /// Block timestamp when the update was posted
+    pub posted_at: u64,
+}
+
+impl ToBytes for RemittanceUpdate {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.message.to_bytes()?);
+        result.append(&mut self.posted_at.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.message.serialized_length() + self.posted_at.serialized_length()
+    }
+}
+
+impl FromBytes for RemittanceUpdate {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (message, remainder) = String::from_bytes(bytes)?;
+        let (posted_at, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((RemittanceUpdate { message, posted_at }, remainder))
+    }
+}
+
+impl CLTyped for RemittanceUpdate {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// A translation of a remittance's purpose, for bilingual communities.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalizedPurpose {
+    /// Lowercase language code, e.g. "fr" or "pt-br" (max
+    /// `MAX_LANGUAGE_CODE_LENGTH` bytes)
+    pub lang: String,
+
+    /// Purpose in that language (max `MAX_PURPOSE_LENGTH` bytes)
+    pub purpose: String,
+}
+
+impl ToBytes for LocalizedPurpose {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.lang.to_bytes()?);
+        result.append(&mut self.purpose.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.lang.serialized_length() + self.purpose.serialized_length()
+    }
+}
+
+impl FromBytes for LocalizedPurpose {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (lang, remainder) = String::from_bytes(bytes)?;
+        let (purpose, remainder) = String::from_bytes(remainder)?;
+
+        Ok((LocalizedPurpose { lang, purpose }, remainder))
+    }
+}
+
+impl CLTyped for LocalizedPurpose {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Picks the translation to show for language `lang`: an exact match, else
+/// one for its primary subtag (`"pt"` for `"pt-BR"`), ignoring case.
+///
+/// Returns `None` when the default purpose should be shown instead.
+pub fn localized_purpose<'a>(purposes: &'a [LocalizedPurpose], lang: &str) -> Option<&'a str> {
+    let primary = lang.split('-').next().unwrap_or(lang);
+    purposes
+        .iter()
+        .find(|localized| localized.lang.eq_ignore_ascii_case(lang))
+        .or_else(|| {
+            purposes
+                .iter()
+                .find(|localized| localized.lang.eq_ignore_ascii_case(primary))
+        })
+        .map(|localized| localized.purpose.as_str())
+}
+
+/// A recipient's confirmation that released funds arrived.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReceiptAcknowledgement {
+    /// Thank-you or usage note (max `MAX_UPDATE_LENGTH` bytes)
+    pub message: String,
+
+    /// Block timestamp when receipt was acknowledged
+    pub acknowledged_at: u64,
+}
+
+impl ToBytes for ReceiptAcknowledgement {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.message.to_bytes()?);
+        result.append(&mut self.acknowledged_at.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.message.serialized_length() + self.acknowledged_at.serialized_length()
+    }
+}
+
+impl FromBytes for ReceiptAcknowledgement {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (message, remainder) = String::from_bytes(bytes)?;
+        let (acknowledged_at, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            ReceiptAcknowledgement {
+                message,
+                acknowledged_at,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for ReceiptAcknowledgement {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Travel-rule data attached to a remittance in a regulated corridor.
+///
+/// Originator and beneficiary details never touch the chain; the contract
+/// keeps a hash of them and a pointer to the encrypted off-chain payload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TravelRuleData {
+    /// Hash of the originator/beneficiary data
+    pub data_hash: [u8; 32],
+
+    /// Location of the encrypted payload (e.g. an IPFS or HTTPS URI)
+    pub payload_uri: String,
+
+    /// Block timestamp when the data was attached
+    pub attached_at: u64,
+}
+
+impl ToBytes for TravelRuleData {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.data_hash.to_bytes()?);
+        result.append(&mut self.payload_uri.to_bytes()?);
+        result.append(&mut self.attached_at.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.data_hash.serialized_length()
+            + self.payload_uri.serialized_length()
+            + self.attached_at.serialized_length()
+    }
+}
+
+impl FromBytes for TravelRuleData {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (data_hash, remainder) = <[u8; 32]>::from_bytes(bytes)?;
+        let (payload_uri, remainder) = String::from_bytes(remainder)?;
+        let (attached_at, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            TravelRuleData {
+                data_hash,
+                payload_uri,
+                attached_at,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for TravelRuleData {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Aggregate activity for one reporting period (a day, optionally narrowed
+/// to a corridor).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeriodStats {
+    /// Number of remittances created
+    pub created_count: u64,
+
+    /// Sum of target amounts of remittances created
+    pub created_volume: U512,
+
+    /// Number of remittances released
+    pub released_count: u64,
+
+    /// Sum of gross amounts released (before fees)
+    pub released_volume: U512,
+
+    /// Platform fees charged on releases
+    pub fees: U512,
+}
+
+impl PeriodStats {
+    /// Records a newly created remittance.
+    pub fn record_created(&mut self, target_amount: U512) {
+        self.created_count = self.created_count.saturating_add(1);
+        self.created_volume = self.created_volume.saturating_add(target_amount);
+    }
+
+    /// Records a release and the platform fee it paid.
+    pub fn record_released(&mut self, gross_amount: U512, fee: U512) {
+        self.released_count = self.released_count.saturating_add(1);
+        self.released_volume = self.released_volume.saturating_add(gross_amount);
+        self.fees = self.fees.saturating_add(fee);
+    }
+
+    /// Records a platform fee taken from a contribution.
+    pub fn record_fee(&mut self, fee: U512) {
+        self.fees = self.fees.saturating_add(fee);
+    }
+}
+
+impl ToBytes for PeriodStats {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.created_count.to_bytes()?);
+        result.append(&mut self.created_volume.to_bytes()?);
+        result.append(&mut self.released_count.to_bytes()?);
+        result.append(&mut self.released_volume.to_bytes()?);
+        result.append(&mut self.fees.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.created_count.serialized_length()
+            + self.created_volume.serialized_length()
+            + self.released_count.serialized_length()
+            + self.released_volume.serialized_length()
+            + self.fees.serialized_length()
+    }
+}
+
+impl FromBytes for PeriodStats {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (created_count, remainder) = u64::from_bytes(bytes)?;
+        let (created_volume, remainder) = U512::from_bytes(remainder)?;
+        let (released_count, remainder) = u64::from_bytes(remainder)?;
+        let (released_volume, remainder) = U512::from_bytes(remainder)?;
+        let (fees, remainder) = U512::from_bytes(remainder)?;
+
+        Ok((
+            PeriodStats {
+                created_count,
+                created_volume,
+                released_count,
+                released_volume,
+                fees,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for PeriodStats {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Point-in-time comparison of the contract purse against its ledger.
+///
+/// With no drift, `purse_balance` equals `escrow_liabilities +
+/// accrued_fees`; any difference shows funds the ledger does not account
+/// for (or is missing).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountingSnapshot {
+    /// Block timestamp when the snapshot was taken
+    pub timestamp: u64,
+
+    /// Balance of the contract purse
+    pub purse_balance: U512,
+
+    /// Contributions held for unreleased, unrefunded remittances
+    pub escrow_liabilities: U512,
+
+    /// Creator fee rebates accrued but not yet claimed
+    pub accrued_fees: U512,
+}
+
+impl AccountingSnapshot {
+    /// Purse balance minus ledger obligations, or `None` if the purse is short.
+    pub fn surplus(&self) -> Option<U512> {
+        let obligations = self.escrow_liabilities.checked_add(self.accrued_fees)?;
+        self.purse_balance.checked_sub(obligations)
+    }
+}
+
+impl ToBytes for AccountingSnapshot {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.timestamp.to_bytes()?);
+        result.append(&mut self.purse_balance.to_bytes()?);
+        result.append(&mut self.escrow_liabilities.to_bytes()?);
+        result.append(&mut self.accrued_fees.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.timestamp.serialized_length()
+            + self.purse_balance.serialized_length()
+            + self.escrow_liabilities.serialized_length()
+            + self.accrued_fees.serialized_length()
+    }
+}
+
+impl FromBytes for AccountingSnapshot {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (timestamp, remainder) = u64::from_bytes(bytes)?;
+        let (purse_balance, remainder) = U512::from_bytes(remainder)?;
+        let (escrow_liabilities, remainder) = U512::from_bytes(remainder)?;
+        let (accrued_fees, remainder) = U512::from_bytes(remainder)?;
+
+        Ok((
+            AccountingSnapshot {
+                timestamp,
+                purse_balance,
+                escrow_liabilities,
+                accrued_fees,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for AccountingSnapshot {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Contributor amounts of a remittance frozen at one point in time.
+///
+/// Taken with `snapshot_contributions` as a basis for reward airdrops or
+/// governance weight; later refunds and releases leave it unchanged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContributionSnapshot {
+    /// Remittance the amounts were contributed to
+    pub remittance_id: u64,
+
+    /// Block timestamp when the snapshot was taken
+    pub taken_at: u64,
+
+    /// Each contributor with a non-zero amount, in order of first contribution
+    pub contributions: alloc::vec::Vec<(AccountHash, U512)>,
+}
+
+impl ContributionSnapshot {
+    /// Amount `account` had contributed when the snapshot was taken.
+    pub fn amount_of(&self, account: &AccountHash) -> U512 {
+        self.contributions
+            .iter()
+            .find(|(contributor, _)| contributor == account)
+            .map_or(U512::zero(), |(_, amount)| *amount)
+    }
+
+    /// Sum of all frozen amounts.
+    pub fn total(&self) -> U512 {
+        self.contributions
+            .iter()
+            .fold(U512::zero(), |total, (_, amount)| total.saturating_add(*amount))
+    }
+}
+
+impl ToBytes for ContributionSnapshot {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.remittance_id.to_bytes()?);
+        result.append(&mut self.taken_at.to_bytes()?);
+        result.append(&mut self.contributions.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.remittance_id.serialized_length()
+            + self.taken_at.serialized_length()
+            + self.contributions.serialized_length()
+    }
+}
+
+impl FromBytes for ContributionSnapshot {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (remittance_id, remainder) = u64::from_bytes(bytes)?;
+        let (taken_at, remainder) = u64::from_bytes(remainder)?;
+        let (contributions, remainder) =
+            alloc::vec::Vec::<(AccountHash, U512)>::from_bytes(remainder)?;
+
+        Ok((
+            ContributionSnapshot {
+                remittance_id,
+                taken_at,
+                contributions,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for ContributionSnapshot {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Running amount × duration of funds held in escrow, for one contributor or
+/// a whole remittance.
+///
+/// The weight is in mote-milliseconds and only accrues on changes, so
+/// [`ContributionWeight::weight_at`] adds the stretch since `updated_at`.
+/// Shares of a reward pool are a contributor's weight over the remittance's.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContributionWeight {
+    /// Amount currently escrowed (in motes)
+    pub amount: U512,
+
+    /// Weight accrued up to `updated_at` (mote-milliseconds)
+    pub weight: U512,
+
+    /// Block time of the last deposit or withdrawal
+    pub updated_at: u64,
+}
+
+impl ContributionWeight {
+    /// Weight accrued by block time `now`.
+    pub fn weight_at(&self, now: u64) -> U512 {
+        let elapsed = U512::from(now.saturating_sub(self.updated_at));
+        self.weight.saturating_add(self.amount.saturating_mul(elapsed))
+    }
+
+    /// Accrues up to `now`, then adds `amount` to the escrowed amount.
+    pub fn deposit(&mut self, amount: U512, now: u64) {
+        self.accrue(now);
+        self.amount = self.amount.saturating_add(amount);
+    }
+
+    /// Accrues up to `now`, then takes `amount` out of the escrowed amount.
+    pub fn withdraw(&mut self, amount: U512, now: u64) {
+        self.accrue(now);
+        self.amount = self.amount.saturating_sub(amount);
+    }
+
+    fn accrue(&mut self, now: u64) {
+        self.weight = self.weight_at(now);
+        self.updated_at = self.updated_at.max(now);
+    }
+}
+
+impl ToBytes for ContributionWeight {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.weight.to_bytes()?);
+        result.append(&mut self.updated_at.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.amount.serialized_length()
+            + self.weight.serialized_length()
+            + self.updated_at.serialized_length()
+    }
+}
+
+impl FromBytes for ContributionWeight {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (amount, remainder) = U512::from_bytes(bytes)?;
+        let (weight, remainder) = U512::from_bytes(remainder)?;
+        let (updated_at, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            ContributionWeight {
+                amount,
+                weight,
+                updated_at,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for ContributionWeight {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Everything a wallet shows for one account, returned by
+/// `get_account_overview`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountOverview {
+    /// IDs of remittances the account created, oldest first
+    pub created: alloc::vec::Vec<u64>,
+
+    /// IDs of remittances the account is the recipient of, oldest first
+    pub receiving: alloc::vec::Vec<u64>,
+
+    /// IDs of remittances the account contributed to, in order of first
+    /// contribution
+    pub contributed: alloc::vec::Vec<u64>,
+
+    /// Refunds the account can claim now, by remittance ID, net of any
+    /// refund policy deduction
+    pub refundable: alloc::vec::Vec<(u64, U512)>,
+
+    /// Creator fee rebates the account can claim
+    pub creator_rewards: U512,
+}
+
+impl AccountOverview {
+    /// Sum of all claimable refunds.
+    pub fn total_refundable(&self) -> U512 {
+        self.refundable
+            .iter()
+            .fold(U512::zero(), |total, (_, amount)| total.saturating_add(*amount))
+    }
+}
+
+impl ToBytes for AccountOverview {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.created.to_bytes()?);
+        result.append(&mut self.receiving.to_bytes()?);
+        result.append(&mut self.contributed.to_bytes()?);
+        result.append(&mut self.refundable.to_bytes()?);
+        result.append(&mut self.creator_rewards.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.created.serialized_length()
+            + self.receiving.serialized_length()
+            + self.contributed.serialized_length()
+            + self.refundable.serialized_length()
+            + self.creator_rewards.serialized_length()
+    }
+}
+
+impl FromBytes for AccountOverview {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (created, remainder) = alloc::vec::Vec::<u64>::from_bytes(bytes)?;
+        let (receiving, remainder) = alloc::vec::Vec::<u64>::from_bytes(remainder)?;
+        let (contributed, remainder) = alloc::vec::Vec::<u64>::from_bytes(remainder)?;
+        let (refundable, remainder) = alloc::vec::Vec::<(u64, U512)>::from_bytes(remainder)?;
+        let (creator_rewards, remainder) = U512::from_bytes(remainder)?;
+
+        Ok((
+            AccountOverview {
+                created,
+                receiving,
+                contributed,
+                refundable,
+                creator_rewards,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for AccountOverview {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Contract-wide settings and state returned by `get_contract_info`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContractInfo {
+    /// Account holding admin rights
+    pub owner: AccountHash,
+
+    /// Account receiving platform fees
+    pub fee_collector: AccountHash,
+
+    /// Platform fee in basis points (floored from the ppm rate)
+    pub platform_fee_bps: u64,
+
+    /// Whether operations are paused
+    pub is_paused: bool,
+
+    /// Number of remittances created
+    pub remittance_count: u64,
+
+    /// Version of the contract code (`CONTRACT_VERSION`)
+    pub contract_version: u32,
+
+    /// Balance of the contract purse
+    pub purse_balance: U512,
+}
+
+impl ToBytes for ContractInfo {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.owner.to_bytes()?);
+        result.append(&mut self.fee_collector.to_bytes()?);
+        result.append(&mut self.platform_fee_bps.to_bytes()?);
+        result.append(&mut self.is_paused.to_bytes()?);
+        result.append(&mut self.remittance_count.to_bytes()?);
+        result.append(&mut self.contract_version.to_bytes()?);
+        result.append(&mut self.purse_balance.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.owner.serialized_length()
+            + self.fee_collector.serialized_length()
+            + self.platform_fee_bps.serialized_length()
+            + self.is_paused.serialized_length()
+            + self.remittance_count.serialized_length()
+            + self.contract_version.serialized_length()
+            + self.purse_balance.serialized_length()
+    }
+}
+
+impl FromBytes for ContractInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (owner, remainder) = AccountHash::from_bytes(bytes)?;
+        let (fee_collector, remainder) = AccountHash::from_bytes(remainder)?;
+        let (platform_fee_bps, remainder) = u64::from_bytes(remainder)?;
+        let (is_paused, remainder) = bool::from_bytes(remainder)?;
+        let (remittance_count, remainder) = u64::from_bytes(remainder)?;
+        let (contract_version, remainder) = u32::from_bytes(remainder)?;
+        let (purse_balance, remainder) = U512::from_bytes(remainder)?;
+
+        Ok((
+            ContractInfo {
+                owner,
+                fee_collector,
+                platform_fee_bps,
+                is_paused,
+                remittance_count,
+                contract_version,
+                purse_balance,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for ContractInfo {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Build of the installed contract, returned by `get_version`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BuildInfo {
+    /// Semantic version of the contract crate, e.g. `"1.0.0"`
+    pub version: String,
+
+    /// Git commit the wasm was built from, with a `-dirty` suffix for
+    /// uncommitted changes, or `"unknown"` outside a checkout
+    pub git_hash: String,
+}
+
+impl ToBytes for BuildInfo {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.version.to_bytes()?);
+        result.append(&mut self.git_hash.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.version.serialized_length() + self.git_hash.serialized_length()
+    }
+}
+
+impl FromBytes for BuildInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (version, remainder) = String::from_bytes(bytes)?;
+        let (git_hash, remainder) = String::from_bytes(remainder)?;
+        Ok((BuildInfo { version, git_hash }, remainder))
+    }
+}
+
+impl CLTyped for BuildInfo {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Threshold above which releases wait for compliance review.
+///
+/// A zero threshold disables review.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReviewPolicy {
+    /// Releases of at least this amount (in motes) are reviewed
+    pub threshold: U512,
+
+    /// Time the compliance officer has to decide, in milliseconds
+    pub window_ms: u64,
+}
+
+impl ReviewPolicy {
+    /// Checks if a release of `amount` must be reviewed.
+    pub fn requires_review(&self, amount: &U512) -> bool {
+        !self.threshold.is_zero() && *amount >= self.threshold
+    }
+}
+
+impl ToBytes for ReviewPolicy {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.threshold.to_bytes()?);
+        result.append(&mut self.window_ms.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.threshold.serialized_length() + self.window_ms.serialized_length()
+    }
+}
+
+impl FromBytes for ReviewPolicy {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (threshold, remainder) = U512::from_bytes(bytes)?;
+        let (window_ms, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            ReviewPolicy {
+                threshold,
+                window_ms,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for ReviewPolicy {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Entry point the contract calls on a registered risk engine.
+///
+/// It takes `creator` and `recipient` (AccountHash) and `amount` (U512) and
+/// returns a `u64` risk score, higher meaning riskier.
+pub const RISK_SCORE_ENTRY_POINT: &str = "risk_score";
+
+/// External risk-scoring contract consulted before large releases.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RiskEngine {
+    /// Contract exposing `risk_score`
+    pub contract_hash: ContractHash,
+
+    /// Releases of at least this amount (in motes) are scored
+    pub min_amount: U512,
+
+    /// Highest score a release may have and still proceed
+    pub max_score: u64,
+}
+
+impl ToBytes for RiskEngine {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.contract_hash.to_bytes()?);
+        result.append(&mut self.min_amount.to_bytes()?);
+        result.append(&mut self.max_score.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.contract_hash.serialized_length()
+            + self.min_amount.serialized_length()
+            + self.max_score.serialized_length()
+    }
+}
+
+impl FromBytes for RiskEngine {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (contract_hash, remainder) = ContractHash::from_bytes(bytes)?;
+        let (min_amount, remainder) = U512::from_bytes(remainder)?;
+        let (max_score, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            RiskEngine {
+                contract_hash,
+                min_amount,
+                max_score,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for RiskEngine {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// How frontends should present a self-hosted instance, set by the owner.
+///
+/// Empty fields mean the frontend uses its own default.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Branding {
+    /// Name shown for the platform
+    pub platform_name: String,
+
+    /// IPFS CID of the platform logo
+    pub logo_cid: String,
+
+    /// Where users can get help
+    pub support_url: String,
+
+    /// How amounts are labelled, e.g. "CSPR"
+    pub currency_display: String,
+}
+
+impl ToBytes for Branding {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.platform_name.to_bytes()?);
+        result.append(&mut self.logo_cid.to_bytes()?);
+        result.append(&mut self.support_url.to_bytes()?);
+        result.append(&mut self.currency_display.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.platform_name.serialized_length()
+            + self.logo_cid.serialized_length()
+            + self.support_url.serialized_length()
+            + self.currency_display.serialized_length()
+    }
+}
+
+impl FromBytes for Branding {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (platform_name, remainder) = String::from_bytes(bytes)?;
+        let (logo_cid, remainder) = String::from_bytes(remainder)?;
+        let (support_url, remainder) = String::from_bytes(remainder)?;
+        let (currency_display, remainder) = String::from_bytes(remainder)?;
+
+        Ok((
+            Branding {
+                platform_name,
+                logo_cid,
+                support_url,
+                currency_display,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for Branding {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// A sub-platform within one deployment, administered by its own owner.
+///
+/// Remittances created in a namespace are charged its fee, which is paid to
+/// the namespace owner, and stop taking contributions and releases while it
+/// is paused.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Namespace {
+    /// Operator administering the namespace and collecting its fees
+    pub owner: AccountHash,
+
+    /// Platform fee in parts per million, within the installation's cap
+    pub fee_ppm: u64,
+
+    /// Whether contributions and releases are paused
+    pub paused: bool,
+}
+
+impl ToBytes for Namespace {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.owner.to_bytes()?);
+        result.append(&mut self.fee_ppm.to_bytes()?);
+        result.append(&mut self.paused.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.owner.serialized_length()
+            + self.fee_ppm.serialized_length()
+            + self.paused.serialized_length()
+    }
+}
+
+impl FromBytes for Namespace {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (owner, remainder) = AccountHash::from_bytes(bytes)?;
+        let (fee_ppm, remainder) = u64::from_bytes(remainder)?;
+        let (paused, remainder) = bool::from_bytes(remainder)?;
+
+        Ok((
+            Namespace {
+                owner,
+                fee_ppm,
+                paused,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for Namespace {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Defaults applied to remittances created in a category.
+///
+/// Explicit creation arguments take precedence. The template is read at
+/// creation, so changing it later does not affect existing remittances.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CategoryTemplate {
+    /// Days from creation until the deadline; 0 for no default deadline
+    pub deadline_days: u64,
+
+    /// Platform fee in parts per million, in place of the global or
+    /// namespace fee
+    pub fee_ppm: Option<u64>,
+
+    /// Whether contributions beyond the target are refunded by default
+    pub refund_excess: bool,
+}
+
+impl CategoryTemplate {
+    /// Default deadline of a remittance created at `created_at`, if any.
+    pub fn deadline_from(&self, created_at: u64) -> Option<u64> {
+        if self.deadline_days == 0 {
+            return None;
+        }
+        Some(created_at.saturating_add(self.deadline_days.saturating_mul(MILLIS_PER_DAY)))
+    }
+}
+
+impl ToBytes for CategoryTemplate {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.deadline_days.to_bytes()?);
+        result.append(&mut self.fee_ppm.to_bytes()?);
+        result.append(&mut self.refund_excess.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.deadline_days.serialized_length()
+            + self.fee_ppm.serialized_length()
+            + self.refund_excess.serialized_length()
+    }
+}
+
+impl FromBytes for CategoryTemplate {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (deadline_days, remainder) = u64::from_bytes(bytes)?;
+        let (fee_ppm, remainder) = Option::<u64>::from_bytes(remainder)?;
+        let (refund_excess, remainder) = bool::from_bytes(remainder)?;
+
+        Ok((
+            CategoryTemplate {
+                deadline_days,
+                fee_ppm,
+                refund_excess,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for CategoryTemplate {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// A recipient's request to be paid out on another chain.
+///
+/// While it exists the remittance is locked: it can no longer be funded,
+/// cancelled, rejected, or released except by the bridge operator's
+/// confirmation. Cancelling the payout removes it and unlocks the
+/// remittance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BridgePayout {
+    /// Destination chain (e.g. a CAIP-2 ID such as "eip155:1")
+    pub chain_id: String,
+
+    /// Recipient address on the destination chain
+    pub foreign_address: String,
+
+    /// Block timestamp when the payout was requested
+    pub requested_at: u64,
+
+    /// Block timestamp after which anyone can cancel an unconfirmed payout
+    pub deadline: u64,
+}
+
+impl ToBytes for BridgePayout {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.chain_id.to_bytes()?);
+        result.append(&mut self.foreign_address.to_bytes()?);
+        result.append(&mut self.requested_at.to_bytes()?);
+        result.append(&mut self.deadline.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.chain_id.serialized_length()
+            + self.foreign_address.serialized_length()
+            + self.requested_at.serialized_length()
+            + self.deadline.serialized_length()
+    }
+}
+
+impl FromBytes for BridgePayout {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (chain_id, remainder) = String::from_bytes(bytes)?;
+        let (foreign_address, remainder) = String::from_bytes(remainder)?;
+        let (requested_at, remainder) = u64::from_bytes(remainder)?;
+        let (deadline, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            BridgePayout {
+                chain_id,
+                foreign_address,
+                requested_at,
+                deadline,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for BridgePayout {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// An approved payout partner (e.g. a cash-pickup agent) that recipients can
+/// route releases to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SettlementPartner {
+    /// Display name of the partner
+    pub name: String,
+
+    /// Corridor codes (e.g. "US-NG") the partner pays out in
+    pub corridors: alloc::vec::Vec<String>,
+}
+
+impl SettlementPartner {
+    /// Checks if the partner pays out in `corridor`.
+    pub fn serves(&self, corridor: &str) -> bool {
+        self.corridors.iter().any(|served| served == corridor)
+    }
+}
+
+impl ToBytes for SettlementPartner {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.name.to_bytes()?);
+        result.append(&mut self.corridors.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.name.serialized_length() + self.corridors.serialized_length()
+    }
+}
+
+impl FromBytes for SettlementPartner {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (name, remainder) = String::from_bytes(bytes)?;
+        let (corridors, remainder) = alloc::vec::Vec::<String>::from_bytes(remainder)?;
+
+        Ok((SettlementPartner { name, corridors }, remainder))
+    }
+}
+
+impl CLTyped for SettlementPartner {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// A recipient's choice to collect a release through a settlement partner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PayoutRoute {
+    /// Settlement partner paid instead of the recipient
+    pub partner: AccountHash,
+
+    /// Code the recipient presents to the partner to collect
+    pub reference_code: String,
+}
+
+impl ToBytes for PayoutRoute {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.partner.to_bytes()?);
+        result.append(&mut self.reference_code.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.partner.serialized_length() + self.reference_code.serialized_length()
+    }
+}
+
+impl FromBytes for PayoutRoute {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (partner, remainder) = AccountHash::from_bytes(bytes)?;
+        let (reference_code, remainder) = String::from_bytes(remainder)?;
+
+        Ok((
+            PayoutRoute {
+                partner,
+                reference_code,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for PayoutRoute {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// An account in a recipient's payout address book, chosen by label at
+/// release time instead of paying the recipient's own main purse.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PayoutTarget {
+    /// Recipient-chosen name, e.g. "savings" or "agent"
+    pub label: String,
+
+    /// Account paid when this target is chosen
+    pub account: AccountHash,
+}
+
+impl ToBytes for PayoutTarget {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.label.to_bytes()?);
+        result.append(&mut self.account.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.label.serialized_length() + self.account.serialized_length()
+    }
+}
+
+impl FromBytes for PayoutTarget {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (label, remainder) = String::from_bytes(bytes)?;
+        let (account, remainder) = AccountHash::from_bytes(remainder)?;
+
+        Ok((PayoutTarget { label, account }, remainder))
+    }
+}
+
+impl CLTyped for PayoutTarget {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Instant-forwarding terms and running totals of a split remittance.
+///
+/// A share of each contribution is paid to the recipient immediately and
+/// the rest is escrowed until release. Forwarded amounts count towards the
+/// target but are no longer refundable.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InstantSplit {
+    /// Share of each net contribution forwarded immediately, in basis points
+    pub share_bps: u64,
+
+    /// Total forwarded so far, platform fees included (in motes)
+    pub forwarded: U512,
+
+    /// Platform fees taken from forwarded amounts (in motes)
+    pub fees: U512,
+}
+
+impl InstantSplit {
+    /// Creates a split forwarding `share_bps` of each contribution.
+    pub fn new(share_bps: u64) -> Self {
+        InstantSplit {
+            share_bps,
+            ..InstantSplit::default()
+        }
+    }
+
+    /// Splits a net contribution into its forwarded and escrowed parts.
+    ///
+    /// The forwarded part rounds down, so escrow keeps any remainder.
+    pub fn split(&self, amount: U512) -> (U512, U512) {
+        let instant = amount * U512::from(self.share_bps) / U512::from(BASIS_POINTS);
+        (instant, amount - instant)
+    }
+
+    /// Adds a forwarded amount and the fee taken from it to the totals.
+    pub fn record(&mut self, forwarded: U512, fee: U512) -> Result<(), Error> {
+        self.forwarded = self
+            .forwarded
+            .checked_add(forwarded)
+            .ok_or(Error::ArithmeticOverflow)?;
+        self.fees = self.fees.checked_add(fee).ok_or(Error::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+impl ToBytes for InstantSplit {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.share_bps.to_bytes()?);
+        result.append(&mut self.forwarded.to_bytes()?);
+        result.append(&mut self.fees.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.share_bps.serialized_length()
+            + self.forwarded.serialized_length()
+            + self.fees.serialized_length()
+    }
+}
+
+impl FromBytes for InstantSplit {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (share_bps, remainder) = u64::from_bytes(bytes)?;
+        let (forwarded, remainder) = U512::from_bytes(remainder)?;
+        let (fees, remainder) = U512::from_bytes(remainder)?;
+
+        Ok((
+            InstantSplit {
+                share_bps,
+                forwarded,
+                fees,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for InstantSplit {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// A backup recipient nominated by the creator.
+///
+/// If the recipient has not released a funded remittance within the grace
+/// period, the backup may release it to themselves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BackupRecipient {
+    /// Account that may release in place of the recipient
+    pub account: AccountHash,
+
+    /// Time after funding before the backup may release (in ms)
+    pub grace_ms: u64,
+
+    /// Block time the target was first met, once it has been
+    pub funded_at: Option<u64>,
+}
+
+impl BackupRecipient {
+    /// Block time from which the backup may release, once funded.
+    pub fn eligible_at(&self) -> Option<u64> {
+        self.funded_at
+            .map(|funded_at| funded_at.saturating_add(self.grace_ms))
+    }
+
+    /// Checks if the backup may release at `now`.
+    pub fn is_eligible(&self, now: u64) -> bool {
+        self.eligible_at().map_or(false, |eligible_at| now >= eligible_at)
+    }
+}
+
+impl ToBytes for BackupRecipient {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.account.to_bytes()?);
+        result.append(&mut self.grace_ms.to_bytes()?);
+        result.append(&mut self.funded_at.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.account.serialized_length()
+            + self.grace_ms.serialized_length()
+            + self.funded_at.serialized_length()
+    }
+}
+
+impl FromBytes for BackupRecipient {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (account, remainder) = AccountHash::from_bytes(bytes)?;
+        let (grace_ms, remainder) = u64::from_bytes(remainder)?;
+        let (funded_at, remainder) = Option::<u64>::from_bytes(remainder)?;
+
+        Ok((
+            BackupRecipient {
+                account,
+                grace_ms,
+                funded_at,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for BackupRecipient {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Fixed installments paid out of a funded remittance instead of a single
+/// release.
+///
+/// Turns an upfront-funded remittance into a stipend: from funding, a keeper
+/// calls `process_installment` every `interval_ms` to pay the recipient
+/// `installment`, and the last installment pays whatever remains.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PayoutSchedule {
+    /// Amount of each installment before the platform fee (in motes)
+    pub installment: U512,
+
+    /// Time between installments (in ms)
+    pub interval_ms: u64,
+
+    /// Block time the target was first met, once it has been; the first
+    /// installment is due from then
+    pub funded_at: Option<u64>,
+
+    /// Number of installments paid so far
+    pub paid_count: u64,
+}
+
+impl PayoutSchedule {
+    /// Creates a schedule paying `installment` every `interval_ms`.
+    pub fn new(installment: U512, interval_ms: u64) -> Self {
+        PayoutSchedule {
+            installment,
+            interval_ms,
+            funded_at: None,
+            paid_count: 0,
+        }
+    }
+
+    /// Block time from which the next installment may be paid, once funded.
+    pub fn next_due_at(&self) -> Option<u64> {
+        self.funded_at.map(|funded_at| {
+            funded_at.saturating_add(self.interval_ms.saturating_mul(self.paid_count))
+        })
+    }
+
+    /// Checks if the next installment may be paid at `now`.
+    pub fn is_due(&self, now: u64) -> bool {
+        self.next_due_at().map_or(false, |due_at| now >= due_at)
+    }
+}
+
+impl ToBytes for PayoutSchedule {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.installment.to_bytes()?);
+        result.append(&mut self.interval_ms.to_bytes()?);
+        result.append(&mut self.funded_at.to_bytes()?);
+        result.append(&mut self.paid_count.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.installment.serialized_length()
+            + self.interval_ms.serialized_length()
+            + self.funded_at.serialized_length()
+            + self.paid_count.serialized_length()
+    }
+}
+
+impl FromBytes for PayoutSchedule {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (installment, remainder) = U512::from_bytes(bytes)?;
+        let (interval_ms, remainder) = u64::from_bytes(remainder)?;
+        let (funded_at, remainder) = Option::<u64>::from_bytes(remainder)?;
+        let (paid_count, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            PayoutSchedule {
+                installment,
+                interval_ms,
+                funded_at,
+                paid_count,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for PayoutSchedule {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// A rotating savings pool (ROSCA, tontine, susu) with no single recipient.
+///
+/// Members each pay `contribution` per cycle, and each cycle's pot goes to
+/// the next member in turn. Every cycle is an ordinary remittance from the
+/// organizer to that cycle's member, created with the pool, so escrow,
+/// release, expiry, and refunds work as for any other remittance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavingsPool {
+    /// Account that set the pool up; creator of every cycle's remittance
+    pub organizer: AccountHash,
+
+    /// Members in payout order; member `i` receives cycle `i`'s pot
+    pub members: alloc::vec::Vec<AccountHash>,
+
+    /// Amount each member pays per cycle (in motes)
+    pub contribution: U512,
+
+    /// Length of each cycle (in ms)
+    pub cycle_ms: u64,
+
+    /// Block time the pool was created, when the first cycle opens
+    pub created_at: u64,
+
+    /// ID of the first cycle's remittance; the others follow consecutively
+    pub first_remittance_id: u64,
+}
+
+impl SavingsPool {
+    /// Number of cycles, one per member.
+    pub fn cycle_count(&self) -> u64 {
+        self.members.len() as u64
+    }
+
+    /// Target of each cycle's remittance: one contribution per member.
+    pub fn pot(&self) -> Result<U512, Error> {
+        self.contribution
+            .checked_mul(U512::from(self.cycle_count()))
+            .ok_or(Error::ArithmeticOverflow)
+    }
+
+    /// Checks if `account` is one of the members.
+    pub fn is_member(&self, account: &AccountHash) -> bool {
+        self.members.contains(account)
+    }
+
+    /// ID of the remittance for `cycle`, counting from 0.
+    pub fn cycle_remittance_id(&self, cycle: u64) -> u64 {
+        self.first_remittance_id.saturating_add(cycle)
+    }
+
+    /// Block time `cycle` opens for contributions; it closes when the next
+    /// cycle opens.
+    pub fn cycle_opens_at(&self, cycle: u64) -> u64 {
+        self.created_at.saturating_add(self.cycle_ms.saturating_mul(cycle))
+    }
+}
+
+impl ToBytes for SavingsPool {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.organizer.to_bytes()?);
+        result.append(&mut self.members.to_bytes()?);
+        result.append(&mut self.contribution.to_bytes()?);
+        result.append(&mut self.cycle_ms.to_bytes()?);
+        result.append(&mut self.created_at.to_bytes()?);
+        result.append(&mut self.first_remittance_id.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.organizer.serialized_length()
+            + self.members.serialized_length()
+            + self.contribution.serialized_length()
+            + self.cycle_ms.serialized_length()
+            + self.created_at.serialized_length()
+            + self.first_remittance_id.serialized_length()
+    }
+}
+
+impl FromBytes for SavingsPool {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (organizer, remainder) = AccountHash::from_bytes(bytes)?;
+        let (members, remainder) = alloc::vec::Vec::<AccountHash>::from_bytes(remainder)?;
+        let (contribution, remainder) = U512::from_bytes(remainder)?;
+        let (cycle_ms, remainder) = u64::from_bytes(remainder)?;
+        let (created_at, remainder) = u64::from_bytes(remainder)?;
+        let (first_remittance_id, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            SavingsPool {
+                organizer,
+                members,
+                contribution,
+                cycle_ms,
+                created_at,
+                first_remittance_id,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for SavingsPool {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Keys that must approve a release before the recipient can take it.
+///
+/// Protects large family funds from a single compromised recipient key:
+/// release needs approvals from `threshold` of the `signers`, one of which
+/// is the recipient.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecipientMultisig {
+    /// Accounts that may approve the release
+    pub signers: alloc::vec::Vec<AccountHash>,
+
+    /// Number of approvals needed to release
+    pub threshold: u64,
+
+    /// Signers who have approved so far
+    pub approvals: alloc::vec::Vec<AccountHash>,
+}
+
+impl RecipientMultisig {
+    /// Creates a multi-signature recipient with no approvals yet.
+    pub fn new(signers: alloc::vec::Vec<AccountHash>, threshold: u64) -> Self {
+        RecipientMultisig {
+            signers,
+            threshold,
+            approvals: alloc::vec::Vec::new(),
+        }
+    }
+
+    /// Checks if `account` is one of the signers.
+    pub fn is_signer(&self, account: &AccountHash) -> bool {
+        self.signers.contains(account)
+    }
+
+    /// Checks if `account` has already approved.
+    pub fn has_approved(&self, account: &AccountHash) -> bool {
+        self.approvals.contains(account)
+    }
+
+    /// Checks if enough signers have approved to release.
+    pub fn is_approved(&self) -> bool {
+        self.approvals.len() as u64 >= self.threshold
+    }
+}
+
+impl ToBytes for RecipientMultisig {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.signers.to_bytes()?);
+        result.append(&mut self.threshold.to_bytes()?);
+        result.append(&mut self.approvals.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.signers.serialized_length()
+            + self.threshold.serialized_length()
+            + self.approvals.serialized_length()
+    }
+}
+
+impl FromBytes for RecipientMultisig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (signers, remainder) = alloc::vec::Vec::<AccountHash>::from_bytes(bytes)?;
+        let (threshold, remainder) = u64::from_bytes(remainder)?;
+        let (approvals, remainder) = alloc::vec::Vec::<AccountHash>::from_bytes(remainder)?;
+
+        Ok((
+            RecipientMultisig {
+                signers,
+                threshold,
+                approvals,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for RecipientMultisig {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Scheduled start of a remittance.
+///
+/// Contributions are rejected before `starts_at`, so a campaign can be set
+/// up ahead of a coordinated launch. The first contribution after the start
+/// activates the remittance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduledStart {
+    /// Block time from which contributions are accepted (in ms)
+    pub starts_at: u64,
+
+    /// Block time of the first contribution, once one has arrived
+    pub activated_at: Option<u64>,
+}
+
+impl ScheduledStart {
+    /// Creates a start that has not yet been activated.
+    pub fn new(starts_at: u64) -> Self {
+        ScheduledStart {
+            starts_at,
+            activated_at: None,
+        }
+    }
+
+    /// Checks if contributions are accepted at `now`.
+    pub fn has_started(&self, now: u64) -> bool {
+        now >= self.starts_at
+    }
+}
+
+impl ToBytes for ScheduledStart {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.starts_at.to_bytes()?);
+        result.append(&mut self.activated_at.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.starts_at.serialized_length() + self.activated_at.serialized_length()
+    }
+}
+
+impl FromBytes for ScheduledStart {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (starts_at, remainder) = u64::from_bytes(bytes)?;
+        let (activated_at, remainder) = Option::<u64>::from_bytes(remainder)?;
+
+        Ok((
+            ScheduledStart {
+                starts_at,
+                activated_at,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for ScheduledStart {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Release service-level tracking of a funded remittance.
+///
+/// The clock starts when the target is first met. Each configured
+/// escalation interval that elapses before release raises the level once,
+/// so support teams are alerted to unresponsive recipients.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReleaseSla {
+    /// Block time the target was first met
+    pub funded_at: u64,
+
+    /// Number of escalation intervals already reported
+    pub escalation_level: u64,
+}
+
+impl ReleaseSla {
+    /// Starts tracking a remittance funded at `funded_at`.
+    pub fn new(funded_at: u64) -> Self {
+        ReleaseSla {
+            funded_at,
+            escalation_level: 0,
+        }
+    }
+
+    /// Number of `intervals_ms` elapsed since funding at block time `now`.
+    pub fn due_level(&self, intervals_ms: &[u64], now: u64) -> u64 {
+        let waiting_ms = now.saturating_sub(self.funded_at);
+        intervals_ms
+            .iter()
+            .filter(|interval_ms| waiting_ms >= **interval_ms)
+            .count() as u64
+    }
+}
+
+impl ToBytes for ReleaseSla {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.funded_at.to_bytes()?);
+        result.append(&mut self.escalation_level.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.funded_at.serialized_length() + self.escalation_level.serialized_length()
+    }
+}
+
+impl FromBytes for ReleaseSla {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (funded_at, remainder) = u64::from_bytes(bytes)?;
+        let (escalation_level, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            ReleaseSla {
+                funded_at,
+                escalation_level,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for ReleaseSla {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Balance pre-funded by the creator or recipient to pay a remittance's
+/// platform fee, so the recipient receives the full amount.
+///
+/// Release draws the fee from the balance and returns any unused rest to
+/// the sponsor; if the remittance ends refundable, the sponsor claims the
+/// balance back instead.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeCover {
+    /// Account that pre-funded the cover; only it may top up or claim it
+    pub sponsor: AccountHash,
+
+    /// Unused cover held in escrow (in motes)
+    pub balance: U512,
+}
+
+impl FeeCover {
+    /// Creates an empty cover sponsored by `sponsor`.
+    pub fn new(sponsor: AccountHash) -> Self {
+        FeeCover {
+            sponsor,
+            balance: U512::zero(),
+        }
+    }
+
+    /// Splits the balance into the part paying `fee` and the unused rest.
+    pub fn split(&self, fee: &U512) -> (U512, U512) {
+        let covered = self.balance.min(*fee);
+        (covered, self.balance - covered)
+    }
+}
+
+impl ToBytes for FeeCover {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.sponsor.to_bytes()?);
+        result.append(&mut self.balance.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.sponsor.serialized_length() + self.balance.serialized_length()
+    }
+}
+
+impl FromBytes for FeeCover {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (sponsor, remainder) = AccountHash::from_bytes(bytes)?;
+        let (balance, remainder) = U512::from_bytes(remainder)?;
+
+        Ok((FeeCover { sponsor, balance }, remainder))
+    }
+}
+
+impl CLTyped for FeeCover {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Itemized split of a release, recorded so statements shown to users match
+/// the on-chain math exactly.
+///
+/// `platform_fee` is `collector_fee + creator_rebate`, and `net` is
+/// `gross - platform_fee + fee_covered`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReleaseBreakdown {
+    /// Escrowed amount paid out by the release (in motes), excluding any
+    /// instant shares already forwarded
+    pub gross: U512,
+
+    /// Platform fee charged on `gross`
+    pub platform_fee: U512,
+
+    /// Part of the fee paid to the fee collector (or namespace owner)
+    pub collector_fee: U512,
+
+    /// Part of the fee accrued to the creator as a rebate
+    pub creator_rebate: U512,
+
+    /// Part of the fee paid from the remittance's fee cover
+    pub fee_covered: U512,
+
+    /// Amount paid to the payee
+    pub net: U512,
+}
+
+impl ToBytes for ReleaseBreakdown {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.gross.to_bytes()?);
+        result.append(&mut self.platform_fee.to_bytes()?);
+        result.append(&mut self.collector_fee.to_bytes()?);
+        result.append(&mut self.creator_rebate.to_bytes()?);
+        result.append(&mut self.fee_covered.to_bytes()?);
+        result.append(&mut self.net.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.gross.serialized_length()
+            + self.platform_fee.serialized_length()
+            + self.collector_fee.serialized_length()
+            + self.creator_rebate.serialized_length()
+            + self.fee_covered.serialized_length()
+            + self.net.serialized_length()
+    }
+}
+
+impl FromBytes for ReleaseBreakdown {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (gross, remainder) = U512::from_bytes(bytes)?;
+        let (platform_fee, remainder) = U512::from_bytes(remainder)?;
+        let (collector_fee, remainder) = U512::from_bytes(remainder)?;
+        let (creator_rebate, remainder) = U512::from_bytes(remainder)?;
+        let (fee_covered, remainder) = U512::from_bytes(remainder)?;
+        let (net, remainder) = U512::from_bytes(remainder)?;
+
+        Ok((
+            ReleaseBreakdown {
+                gross,
+                platform_fee,
+                collector_fee,
+                creator_rebate,
+                fee_covered,
+                net,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for ReleaseBreakdown {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Insurance cover of a remittance.
+///
+/// Each contribution pays a premium into the shared insurance pool. If the
+/// remittance ends refundable and the compliance officer approves claims,
+/// contributors can claim a top-up of `coverage_bps` of their contribution
+/// on top of their refund.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsurancePolicy {
+    /// Premium taken from each net contribution, in basis points
+    pub premium_bps: u64,
+
+    /// Total premiums paid into the pool (in motes)
+    pub premiums: U512,
+
+    /// Approved top-up per contributor in basis points; zero until approved
+    pub coverage_bps: u64,
+}
+
+impl InsurancePolicy {
+    /// Creates an unapproved policy charging `premium_bps`.
+    pub fn new(premium_bps: u64) -> Self {
+        InsurancePolicy {
+            premium_bps,
+            ..InsurancePolicy::default()
+        }
+    }
+
+    /// Calculates the premium on a net contribution, rounded down.
+    pub fn premium(&self, amount: U512) -> U512 {
+        amount * U512::from(self.premium_bps) / U512::from(BASIS_POINTS)
+    }
+
+    /// Checks if the compliance officer has approved claims.
+    pub fn is_approved(&self) -> bool {
+        self.coverage_bps > 0
+    }
+
+    /// Calculates the top-up due on a contribution, rounded down.
+    pub fn top_up(&self, contribution: U512) -> U512 {
+        contribution * U512::from(self.coverage_bps) / U512::from(BASIS_POINTS)
+    }
+}
+
+impl ToBytes for InsurancePolicy {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.premium_bps.to_bytes()?);
+        result.append(&mut self.premiums.to_bytes()?);
+        result.append(&mut self.coverage_bps.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.premium_bps.serialized_length()
+            + self.premiums.serialized_length()
+            + self.coverage_bps.serialized_length()
+    }
+}
+
+impl FromBytes for InsurancePolicy {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (premium_bps, remainder) = u64::from_bytes(bytes)?;
+        let (premiums, remainder) = U512::from_bytes(remainder)?;
+        let (coverage_bps, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            InsurancePolicy {
+                premium_bps,
+                premiums,
+                coverage_bps,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for InsurancePolicy {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// How a remittance's refunds are paid out.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RefundPolicyKind {
+    /// The whole refundable share is returned
+    Full = 0,
+    /// A flat `rate_bps` of each refund is retained as a processing fee
+    ProcessingFee = 1,
+    /// `rate_bps` of each refund is retained per full day since creation
+    TimeDecayed = 2,
+}
+
+impl RefundPolicyKind {
+    /// Decodes a refund policy code.
+    pub fn from_u8(value: u8) -> Result<Self, Error> {
+        match value {
+            0 => Ok(RefundPolicyKind::Full),
+            1 => Ok(RefundPolicyKind::ProcessingFee),
+            2 => Ok(RefundPolicyKind::TimeDecayed),
+            _ => Err(Error::InvalidRefundPolicy),
+        }
+    }
+}
+
+/// Refund terms chosen by the creator and fixed at creation.
+///
+/// The retained part of a refund goes to the fee collector and never
+/// exceeds `MAX_REFUND_DEDUCTION_BPS`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RefundPolicy {
+    /// Policy code (see `RefundPolicyKind`)
+    pub kind: u8,
+
+    /// Deduction in basis points: flat for `ProcessingFee`, per day for
+    /// `TimeDecayed`; zero for `Full`
+    pub rate_bps: u64,
+}
+
+impl RefundPolicy {
+    /// Validates and creates a refund policy.
+    pub fn new(kind: u8, rate_bps: u64) -> Result<Self, Error> {
+        let valid = match RefundPolicyKind::from_u8(kind)? {
+            RefundPolicyKind::Full => rate_bps == 0,
+            RefundPolicyKind::ProcessingFee | RefundPolicyKind::TimeDecayed => {
+                rate_bps > 0 && rate_bps <= MAX_REFUND_DEDUCTION_BPS
+            }
+        };
+        if !valid {
+            return Err(Error::InvalidRefundPolicy);
+        }
+        Ok(RefundPolicy { kind, rate_bps })
+    }
+
+    /// Calculates the deduction in basis points for a refund claimed at
+    /// block time `now` on a remittance created at `created_at`.
+    pub fn deduction_bps(&self, created_at: u64, now: u64) -> u64 {
+        let bps = match RefundPolicyKind::from_u8(self.kind) {
+            Ok(RefundPolicyKind::ProcessingFee) => self.rate_bps,
+            Ok(RefundPolicyKind::TimeDecayed) => {
+                let days = now.saturating_sub(created_at) / MILLIS_PER_DAY;
+                self.rate_bps.saturating_mul(days)
+            }
+            Ok(RefundPolicyKind::Full) | Err(_) => 0,
+        };
+        bps.min(MAX_REFUND_DEDUCTION_BPS)
+    }
+
+    /// Calculates the part of a refundable `share` that is retained, rounded
+    /// down.
+    pub fn deduction(&self, share: U512, created_at: u64, now: u64) -> U512 {
+        share * U512::from(self.deduction_bps(created_at, now)) / U512::from(BASIS_POINTS)
+    }
+}
+
+impl ToBytes for RefundPolicy {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.kind.to_bytes()?);
+        result.append(&mut self.rate_bps.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.kind.serialized_length() + self.rate_bps.serialized_length()
+    }
+}
+
+impl FromBytes for RefundPolicy {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (kind, remainder) = u8::from_bytes(bytes)?;
+        let (rate_bps, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((RefundPolicy { kind, rate_bps }, remainder))
+    }
+}
+
+impl CLTyped for RefundPolicy {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Rolling contribution aggregates of a remittance.
+///
+/// Recent contributions are counted in hourly buckets covering the last
+/// `ANALYTICS_WINDOW_HOURS`, so the window is exact to the hour. Buckets are
+/// only aged when a contribution is recorded; call [`advance`] with the
+/// current block time before reading [`recent_contributions`].
+///
+/// [`advance`]: RemittanceAnalytics::advance
+/// [`recent_contributions`]: RemittanceAnalytics::recent_contributions
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemittanceAnalytics {
+    /// Number of contributions recorded
+    pub contribution_count: u64,
+
+    /// Sum of recorded contributions (in motes)
+    pub total_contributed: U512,
+
+    /// Largest single contribution (in motes)
+    pub largest_contribution: U512,
+
+    /// Hour index (block time / `MILLIS_PER_HOUR`) of the newest bucket
+    pub current_hour: u64,
+
+    /// Contribution counts per hour, indexed by hour modulo
+    /// `ANALYTICS_WINDOW_HOURS`; empty until the first contribution
+    pub hourly_counts: alloc::vec::Vec<u64>,
+}
+
+impl RemittanceAnalytics {
+    /// Ages the hourly buckets to block time `now`, clearing hours that have
+    /// left the window.
+    pub fn advance(&mut self, now: u64) {
+        let hour = now / MILLIS_PER_HOUR;
+        if self.hourly_counts.is_empty() || hour <= self.current_hour {
+            self.current_hour = self.current_hour.max(hour);
+            return;
+        }
+
+        let elapsed = core::cmp::min(hour - self.current_hour, ANALYTICS_WINDOW_HOURS);
+        for offset in 1..=elapsed {
+            let slot = (self.current_hour + offset) % ANALYTICS_WINDOW_HOURS;
+            self.hourly_counts[slot as usize] = 0;
+        }
+        self.current_hour = hour;
+    }
+
+    /// Records a contribution made at block time `timestamp`.
+    pub fn record(&mut self, amount: U512, timestamp: u64) -> Result<(), Error> {
+        if self.hourly_counts.is_empty() {
+            self.hourly_counts = alloc::vec![0; ANALYTICS_WINDOW_HOURS as usize];
+        }
+        self.advance(timestamp);
+
+        let slot = (self.current_hour % ANALYTICS_WINDOW_HOURS) as usize;
+        self.hourly_counts[slot] = self.hourly_counts[slot].saturating_add(1);
+        self.contribution_count = self.contribution_count.saturating_add(1);
+        self.total_contributed = self
+            .total_contributed
+            .checked_add(amount)
+            .ok_or(Error::ArithmeticOverflow)?;
+        self.largest_contribution = self.largest_contribution.max(amount);
+        Ok(())
+    }
+
+    /// Number of contributions in the window ending at the last
+    /// [`advance`](RemittanceAnalytics::advance).
+    pub fn recent_contributions(&self) -> u64 {
+        self.hourly_counts
+            .iter()
+            .fold(0u64, |total, count| total.saturating_add(*count))
+    }
+
+    /// Average contribution size, rounded down; zero before any contribution.
+    pub fn average_contribution(&self) -> U512 {
+        if self.contribution_count == 0 {
+            return U512::zero();
+        }
+        self.total_contributed / U512::from(self.contribution_count)
+    }
+}
+
+impl ToBytes for RemittanceAnalytics {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.contribution_count.to_bytes()?);
+        result.append(&mut self.total_contributed.to_bytes()?);
+        result.append(&mut self.largest_contribution.to_bytes()?);
+        result.append(&mut self.current_hour.to_bytes()?);
+        result.append(&mut self.hourly_counts.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.contribution_count.serialized_length()
+            + self.total_contributed.serialized_length()
+            + self.largest_contribution.serialized_length()
+            + self.current_hour.serialized_length()
+            + self.hourly_counts.serialized_length()
+    }
+}
+
+impl FromBytes for RemittanceAnalytics {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (contribution_count, remainder) = u64::from_bytes(bytes)?;
+        let (total_contributed, remainder) = U512::from_bytes(remainder)?;
+        let (largest_contribution, remainder) = U512::from_bytes(remainder)?;
+        let (current_hour, remainder) = u64::from_bytes(remainder)?;
+        let (hourly_counts, remainder) = alloc::vec::Vec::<u64>::from_bytes(remainder)?;
+
+        Ok((
+            RemittanceAnalytics {
+                contribution_count,
+                total_contributed,
+                largest_contribution,
+                current_hour,
+                hourly_counts,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for RemittanceAnalytics {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// An action subject to per-account rate limits.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RateLimitedAction {
+    /// Creating a remittance
+    Create = 0,
+    /// Contributing to a remittance
+    Contribute = 1,
+}
+
+/// Dictionary key for an account's rate window for one action.
+///
+/// Hashed for the same reason as [`contributor_key`]: the account hash alone
+/// fills the 64-byte item key limit once hex-encoded.
+pub fn rate_window_key(account: &AccountHash, action: RateLimitedAction) -> [u8; 32] {
+    let mut preimage = [0u8; 33];
+    preimage[0] = action as u8;
+    preimage[1..].copy_from_slice(account.as_bytes());
+    casper_types::crypto::blake2b(preimage)
+}
+
+/// Per-account limits on actions within a rolling window.
+///
+/// A limit of zero disables limiting for that action.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RateLimits {
+    /// Length of the rolling window in milliseconds
+    pub window_ms: u64,
+
+    /// Remittances one account may create per window
+    pub max_creations: u64,
+
+    /// Contributions one account may make per window
+    pub max_contributions: u64,
+}
+
+impl RateLimits {
+    /// Returns the limit for `action`, or zero if it is not limited.
+    pub fn limit(&self, action: RateLimitedAction) -> u64 {
+        match action {
+            RateLimitedAction::Create => self.max_creations,
+            RateLimitedAction::Contribute => self.max_contributions,
+        }
+    }
+}
+
+impl ToBytes for RateLimits {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.window_ms.to_bytes()?);
+        result.append(&mut self.max_creations.to_bytes()?);
+        result.append(&mut self.max_contributions.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.window_ms.serialized_length()
+            + self.max_creations.serialized_length()
+            + self.max_contributions.serialized_length()
+    }
+}
+
+impl FromBytes for RateLimits {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (window_ms, remainder) = u64::from_bytes(bytes)?;
+        let (max_creations, remainder) = u64::from_bytes(remainder)?;
+        let (max_contributions, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            RateLimits {
+                window_ms,
+                max_creations,
+                max_contributions,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for RateLimits {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Limits on how much work one call may do, so calls sized by a
+/// misconfigured client fail fast instead of running out of gas.
+///
+/// A batch or page size of zero means the built-in maximum
+/// (`MAX_BATCH_SIZE`); a contributor limit of zero disables that limit.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GasLimits {
+    /// Most remittances or refunds one batch call may process
+    pub max_batch_size: u64,
+
+    /// Most items one paginated view may return
+    pub max_page_size: u64,
+
+    /// Most contributors one call may process for a single remittance
+    pub max_contributors: u64,
+}
+
+impl GasLimits {
+    /// Returns the largest batch a call may process.
+    pub fn batch_size_limit(&self) -> u64 {
+        Self::or_default(self.max_batch_size)
+    }
+
+    /// Returns the largest page a view may return.
+    pub fn page_size_limit(&self) -> u64 {
+        Self::or_default(self.max_page_size)
+    }
+
+    /// Checks whether a call may process `contributors` contributors.
+    pub fn allows_contributors(&self, contributors: u64) -> bool {
+        self.max_contributors == 0 || contributors <= self.max_contributors
+    }
+
+    fn or_default(limit: u64) -> u64 {
+        if limit == 0 {
+            MAX_BATCH_SIZE
+        } else {
+            limit
+        }
+    }
+}
+
+impl ToBytes for GasLimits {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.max_batch_size.to_bytes()?);
+        result.append(&mut self.max_page_size.to_bytes()?);
+        result.append(&mut self.max_contributors.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.max_batch_size.serialized_length()
+            + self.max_page_size.serialized_length()
+            + self.max_contributors.serialized_length()
+    }
+}
+
+impl FromBytes for GasLimits {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (max_batch_size, remainder) = u64::from_bytes(bytes)?;
+        let (max_page_size, remainder) = u64::from_bytes(remainder)?;
+        let (max_contributors, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            GasLimits {
+                max_batch_size,
+                max_page_size,
+                max_contributors,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for GasLimits {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// How the contract treats a contribution from a remittance's own recipient.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RecipientContributionPolicy {
+    /// The contribution is accepted like any other
+    Allow = 0,
+    /// The contribution is accepted and flagged with an event for review
+    Flag = 1,
+    /// The contribution is rejected
+    Reject = 2,
+}
+
+impl RecipientContributionPolicy {
+    /// Decodes a recipient contribution policy code.
+    pub fn from_u8(value: u8) -> Result<Self, Error> {
+        match value {
+            0 => Ok(RecipientContributionPolicy::Allow),
+            1 => Ok(RecipientContributionPolicy::Flag),
+            2 => Ok(RecipientContributionPolicy::Reject),
+            _ => Err(Error::InvalidPaymentGuards),
+        }
+    }
+}
+
+/// Owner-set checks on who may pay whom, beyond the all-zero account check
+/// applied to every account.
+///
+/// The default allows self-remittances and recipient contributions, as
+/// installs did before these guards existed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PaymentGuards {
+    /// Whether creating a remittance to oneself is rejected
+    pub reject_self_remittance: bool,
+
+    /// Treatment of contributions from the recipient (see
+    /// `RecipientContributionPolicy`)
+    pub recipient_contributions: u8,
+}
+
+impl PaymentGuards {
+    /// Validates and creates payment guards.
+    pub fn new(reject_self_remittance: bool, recipient_contributions: u8) -> Result<Self, Error> {
+        RecipientContributionPolicy::from_u8(recipient_contributions)?;
+        Ok(PaymentGuards {
+            reject_self_remittance,
+            recipient_contributions,
+        })
+    }
+
+    /// Returns the policy for contributions from the recipient.
+    pub fn recipient_contribution_policy(&self) -> RecipientContributionPolicy {
+        RecipientContributionPolicy::from_u8(self.recipient_contributions)
+            .unwrap_or(RecipientContributionPolicy::Allow)
+    }
+}
+
+impl ToBytes for PaymentGuards {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.reject_self_remittance.to_bytes()?);
+        result.append(&mut self.recipient_contributions.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.reject_self_remittance.serialized_length()
+            + self.recipient_contributions.serialized_length()
+    }
+}
+
+impl FromBytes for PaymentGuards {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (reject_self_remittance, remainder) = bool::from_bytes(bytes)?;
+        let (recipient_contributions, remainder) = u8::from_bytes(remainder)?;
+
+        Ok((
+            PaymentGuards {
+                reject_self_remittance,
+                recipient_contributions,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for PaymentGuards {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// Counts of one account's actions in the current and previous fixed
+/// windows, used to approximate a rolling window.
+///
+/// The previous window's count is weighted by how much of it still overlaps
+/// the rolling window ending now, so bursts across a window boundary are
+/// still caught without storing every timestamp.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RateWindow {
+    /// Block time at which the current fixed window began
+    pub start: u64,
+
+    /// Actions recorded in the current fixed window
+    pub current: u64,
+
+    /// Actions recorded in the previous fixed window
+    pub previous: u64,
+}
+
+impl RateWindow {
+    /// Records an action at block time `now` if it stays within `limit` per
+    /// `window_ms`; returns `false`, recording nothing, if it would not.
+    ///
+    /// `window_ms` must be non-zero.
+    pub fn try_record(&mut self, now: u64, window_ms: u64, limit: u64) -> bool {
+        let elapsed_windows = now.saturating_sub(self.start) / window_ms;
+        match elapsed_windows {
+            0 => {}
+            1 => {
+                self.previous = self.current;
+                self.current = 0;
+            }
+            _ => {
+                self.previous = 0;
+                self.current = 0;
+            }
+        }
+        self.start = self
+            .start
+            .saturating_add(elapsed_windows.saturating_mul(window_ms));
+
+        // Round the carried share up so the estimate never undercounts
+        let overlap = window_ms - now.saturating_sub(self.start).min(window_ms);
+        let window = u128::from(window_ms);
+        let carried = ((u128::from(self.previous) * u128::from(overlap) + window - 1) / window) as u64;
+
+        if carried.saturating_add(self.current) >= limit {
+            return false;
+        }
+        self.current += 1;
+        true
+    }
+}
+
+impl ToBytes for RateWindow {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.start.to_bytes()?);
+        result.append(&mut self.current.to_bytes()?);
+        result.append(&mut self.previous.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.start.serialized_length()
+            + self.current.serialized_length()
+            + self.previous.serialized_length()
+    }
+}
+
+impl FromBytes for RateWindow {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (start, remainder) = u64::from_bytes(bytes)?;
+        let (current, remainder) = u64::from_bytes(remainder)?;
+        let (previous, remainder) = u64::from_bytes(remainder)?;
+
+        Ok((
+            RateWindow {
+                start,
+                current,
+                previous,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for RateWindow {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+/// What one contributor has paid into an open-ended pot since its last
+/// withdrawal.
+///
+/// Each withdrawal takes everything escrowed, so deposits made before it were
+/// all paid out and only those since are refundable. `released_mark` is the
+/// pot's released amount when the deposits began; it only grows, so a later
+/// withdrawal always moves it on.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PotDeposit {
+    /// Pot's released amount when these deposits began (in motes)
+    pub released_mark: U512,
+
+    /// Amount deposited since then (in motes)
+    pub amount: U512,
+}
+
+impl PotDeposit {
+    /// Adds `amount` deposited while the pot had released `released_amount`,
+    /// first dropping deposits a withdrawal has since paid out.
+    pub fn deposit(&mut self, amount: U512, released_amount: U512) -> Result<(), Error> {
+        if self.released_mark != released_amount {
+            self.released_mark = released_amount;
+            self.amount = U512::zero();
+        }
+        self.amount = self
+            .amount
+            .checked_add(amount)
+            .ok_or(Error::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Amount still escrowed once the pot has released `released_amount`.
+    pub fn unwithdrawn(&self, released_amount: U512) -> U512 {
+        if self.released_mark == released_amount {
+            self.amount
+        } else {
+            U512::zero()
+        }
+    }
+}
+
+impl ToBytes for PotDeposit {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut result = alloc::vec::Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.released_mark.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.released_mark.serialized_length() + self.amount.serialized_length()
+    }
+}
+
+impl FromBytes for PotDeposit {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (released_mark, remainder) = U512::from_bytes(bytes)?;
+        let (amount, remainder) = U512::from_bytes(remainder)?;
+
+        Ok((
+            PotDeposit {
+                released_mark,
+                amount,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for PotDeposit {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::Any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use casper_types::account::AccountHash;
+
+    fn mock_account_hash() -> AccountHash {
+        AccountHash::new([1u8; 32])
+    }
+
+    #[test]
+    fn test_remittance_creation() {
+        let creator = mock_account_hash();
+        let recipient = AccountHash::new([2u8; 32]);
+        let target = U512::from(1000);
+
+        let remittance = Remittance::new(
+            1,
+            creator,
+            recipient,
+            Some(target),
+            "Test remittance".to_string(),
+            1234567890,
+        );
+
+        assert_eq!(remittance.id, 1);
+        assert_eq!(remittance.creator, creator);
+        assert_eq!(remittance.recipient, recipient);
+        assert_eq!(remittance.target_amount, Some(target));
+        assert_eq!(remittance.current_amount, U512::zero());
+        assert!(remittance.is_active());
+        assert!(!remittance.is_target_met());
+    }
+
+    #[test]
+    fn test_progress_calculation() {
+        let remittance = Remittance {
+            id: 1,
+            creator: mock_account_hash(),
+            recipient: mock_account_hash(),
+            target_amount: Some(U512::from(1000)),
+            current_amount: U512::from(500),
+            purpose: "Test".to_string(),
+            created_at: 0,
+            flags: 0,
+            purpose_commitment: None,
+            released_amount: U512::zero(),
+            deadline: None,
+        };
+
+        assert_eq!(remittance.progress_percentage(), 50);
+        assert_eq!(remittance.progress_bps(), 5_000);
+        assert_eq!(remittance.remaining_amount(), U512::from(500));
+    }
+
+    #[test]
+    fn test_progress_beyond_u64() {
+        let mut remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(u64::MAX) * 2),
+            "Test".to_string(),
+            0,
+        );
+
+        // Truncating to u64 would have read the target as u64::MAX - 1
+        remittance.current_amount = U512::from(u64::MAX);
+        assert_eq!(remittance.progress_bps(), 5_000);
+        assert_eq!(remittance.progress_percentage(), 50);
+
+        remittance.current_amount = U512::from(u64::MAX) * 2 - 1;
+        assert_eq!(remittance.progress_bps(), 9_999);
+
+        remittance.current_amount = U512::from(u64::MAX) * 3;
+        assert_eq!(remittance.progress_bps(), 10_000);
+
+        // Scaling by 10000 would overflow U512
+        remittance.target_amount = Some(U512::MAX);
+        remittance.current_amount = U512::MAX / 4;
+        assert_eq!(remittance.progress_bps(), 2_500);
+    }
+
+    #[test]
+    fn test_funding_state() {
+        let mut remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(1000)),
+            "Test".to_string(),
+            0,
+        );
+        remittance.deadline = Some(5_000);
+        remittance.current_amount = U512::from(400);
+
+        let state = remittance.funding_state(1_000);
+        assert_eq!(state.remaining_amount, U512::from(600));
+        assert!(!state.is_target_met);
+        assert_eq!(state.status, RemittanceStatus::Active as u8);
+
+        // Past the deadline it no longer takes funds, even before expiry
+        assert_eq!(remittance.status(5_000), RemittanceStatus::Expired);
+
+        remittance.current_amount = U512::from(1000);
+        assert_eq!(remittance.status(1_000), RemittanceStatus::Funded);
+
+        remittance.mark_released();
+        let state = remittance.funding_state(1_000);
+        assert!(state.is_target_met);
+        assert_eq!(state.status, RemittanceStatus::Released as u8);
+
+        let bytes = state.to_bytes().unwrap();
+        assert_eq!(bytes.len(), state.serialized_length());
+        let (decoded, remainder) = FundingState::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, state);
+    }
+
+    #[test]
+    fn test_progress_at_u64_max() {
+        let mut remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(u64::MAX)),
+            "Test".to_string(),
+            0,
+        );
+
+        assert_eq!(remittance.progress_bps(), 0);
+
+        // Saturating u64 math reported 1% here
+        remittance.current_amount = U512::from(u64::MAX / 10);
+        assert_eq!(remittance.progress_bps(), 999);
+
+        remittance.current_amount = U512::from(u64::MAX);
+        assert_eq!(remittance.progress_bps(), 10_000);
+    }
+
+    #[test]
+    fn test_target_met() {
+        let mut remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(1000)),
+            "Test".to_string(),
+            0,
+        );
+
+        assert!(!remittance.is_target_met());
+
+        remittance.current_amount = U512::from(1000);
+        assert!(remittance.is_target_met());
+
+        remittance.current_amount = U512::from(1500);
+        assert!(remittance.is_target_met());
+    }
+
+    #[test]
+    fn test_status_flags() {
+        let mut remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(1000)),
+            "Test".to_string(),
+            0,
+        );
+
+        assert!(remittance.is_active());
+        assert!(!remittance.is_released());
+        assert!(!remittance.is_cancelled());
+
+        remittance.mark_cancelled();
+        assert!(remittance.is_cancelled());
+        assert!(!remittance.is_released());
+        assert!(!remittance.is_active());
+        assert_eq!(remittance.flags, FLAG_CANCELLED);
+
+        remittance.mark_released();
+        assert!(remittance.is_released());
+        assert_eq!(remittance.flags, FLAG_RELEASED | FLAG_CANCELLED);
+    }
+
+    #[test]
+    fn test_confidential_flag() {
+        let mut remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(1000)),
+            "Test".to_string(),
+            0,
+        );
+
+        assert!(!remittance.is_confidential());
+        remittance.set_flag(FLAG_CONFIDENTIAL);
+        assert!(remittance.is_confidential());
+        assert!(remittance.is_active());
+    }
+
+    #[test]
+    fn test_clear_flag() {
+        let mut remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(1000)),
+            "Test".to_string(),
+            0,
+        );
+
+        remittance.set_flag(FLAG_CHARITY_VERIFIED | FLAG_CONFIDENTIAL);
+        assert!(remittance.is_charity_verified());
+
+        remittance.clear_flag(FLAG_CHARITY_VERIFIED);
+        assert!(!remittance.is_charity_verified());
+        assert!(remittance.is_confidential());
+    }
+
+    #[test]
+    fn test_excess_refund_amounts() {
+        let mut remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(1000)),
+            "Test".to_string(),
+            0,
+        );
+        remittance.current_amount = U512::from(1500);
+
+        assert_eq!(remittance.release_amount(), U512::from(1500));
+        assert_eq!(remittance.excess_amount(), U512::zero());
+
+        remittance.set_flag(FLAG_REFUND_EXCESS);
+        assert!(remittance.refunds_excess());
+        assert_eq!(remittance.release_amount(), U512::from(1000));
+
+        remittance.released_amount = remittance.release_amount();
+        assert_eq!(remittance.unreleased_amount(), U512::from(500));
+        assert_eq!(
+            remittance.unreleased_share(U512::from(600)),
+            Ok(U512::from(200))
+        );
+
+        remittance.current_amount = U512::from(800);
+        assert_eq!(remittance.release_amount(), U512::from(800));
+    }
+
+    #[test]
+    fn test_expiry() {
+        let mut remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(1000)),
+            "Test".to_string(),
+            0,
+        );
+
+        // No deadline: never expires
+        assert!(!remittance.is_past_deadline(u64::MAX));
+        assert!(!remittance.is_expirable(u64::MAX));
+
+        remittance.deadline = Some(100);
+        assert!(!remittance.is_expirable(99));
+        assert!(remittance.is_expirable(100));
+
+        // A funded remittance is released, not expired
+        remittance.current_amount = U512::from(1000);
+        assert!(!remittance.is_expirable(100));
+
+        remittance.current_amount = U512::from(400);
+        remittance.mark_expired();
+        assert!(remittance.is_expired());
+        assert!(remittance.is_refundable());
+        assert!(!remittance.is_active());
+        assert!(!remittance.is_expirable(100));
+    }
+
+    #[test]
+    fn test_open_ended_pot() {
+        let mut remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            mock_account_hash(),
+            None,
+            "Family pot".to_string(),
+            0,
+        );
+        assert!(remittance.is_open_ended());
+        assert!(!remittance.is_target_met());
+        assert_eq!(remittance.status(0), RemittanceStatus::Active);
+        assert_eq!(remittance.progress_bps(), 0);
+
+        // Funded whenever it holds something to withdraw
+        remittance.current_amount = U512::from(700);
+        assert!(remittance.is_target_met());
+        assert_eq!(remittance.status(0), RemittanceStatus::Funded);
+        assert_eq!(remittance.progress_bps(), BASIS_POINTS);
+        assert_eq!(remittance.remaining_amount(), U512::zero());
+
+        // Nothing beyond a target to refund
+        remittance.set_flag(FLAG_REFUND_EXCESS);
+        assert_eq!(remittance.release_amount(), U512::from(700));
+
+        remittance.released_amount = U512::from(700);
+        assert!(!remittance.is_target_met());
+        assert_eq!(remittance.status(0), RemittanceStatus::Active);
+
+        let bytes = remittance.to_bytes().unwrap();
+        let (decoded, remainder) = Remittance::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded.target_amount, None);
+    }
+
+    #[test]
+    fn test_pot_deposit() {
+        let mut deposit = PotDeposit::default();
+        deposit.deposit(U512::from(300), U512::zero()).unwrap();
+        deposit.deposit(U512::from(200), U512::zero()).unwrap();
+        assert_eq!(deposit.unwithdrawn(U512::zero()), U512::from(500));
+
+        // A withdrawal paid out everything deposited before it
+        assert_eq!(deposit.unwithdrawn(U512::from(900)), U512::zero());
+
+        deposit.deposit(U512::from(100), U512::from(900)).unwrap();
+        assert_eq!(deposit.unwithdrawn(U512::from(900)), U512::from(100));
+
+        let bytes = deposit.to_bytes().unwrap();
+        assert_eq!(bytes.len(), deposit.serialized_length());
+        let (decoded, remainder) = PotDeposit::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, deposit);
+    }
+
+    #[test]
+    fn test_rate_window_limits_bursts() {
+        let mut window = RateWindow::default();
+
+        // Three per 1000 ms
+        assert!(window.try_record(5_000, 1_000, 3));
+        assert!(window.try_record(5_100, 1_000, 3));
+        assert!(window.try_record(5_900, 1_000, 3));
+        assert!(!window.try_record(5_950, 1_000, 3));
+        assert_eq!(window.current, 3);
+
+        // Just past the boundary most of the burst still counts
+        assert!(!window.try_record(6_100, 1_000, 3));
+        assert_eq!(window.previous, 3);
+
+        // Most of the way through, one earlier action is still carried
+        assert!(window.try_record(6_700, 1_000, 3));
+        assert!(window.try_record(6_701, 1_000, 3));
+        assert!(!window.try_record(6_702, 1_000, 3));
+
+        // After a quiet window everything is forgotten
+        assert!(window.try_record(9_000, 1_000, 3));
+        assert_eq!((window.start, window.current, window.previous), (9_000, 1, 0));
+    }
+
+    #[test]
+    fn test_gas_limits_default_to_built_in_maximum() {
+        let limits = GasLimits::default();
+        assert_eq!(limits.batch_size_limit(), MAX_BATCH_SIZE);
+        assert_eq!(limits.page_size_limit(), MAX_BATCH_SIZE);
+        assert!(limits.allows_contributors(u64::MAX));
+
+        let limits = GasLimits {
+            max_batch_size: 10,
+            max_page_size: 25,
+            max_contributors: 50,
+        };
+        assert_eq!(limits.batch_size_limit(), 10);
+        assert_eq!(limits.page_size_limit(), 25);
+        assert!(limits.allows_contributors(50));
+        assert!(!limits.allows_contributors(51));
+    }
+
+    #[test]
+    fn test_payment_guards_validate_policy() {
+        let guards = PaymentGuards::default();
+        assert!(!guards.reject_self_remittance);
+        assert_eq!(
+            guards.recipient_contribution_policy(),
+            RecipientContributionPolicy::Allow
+        );
+
+        let guards = PaymentGuards::new(true, 2).unwrap();
+        assert_eq!(
+            guards.recipient_contribution_policy(),
+            RecipientContributionPolicy::Reject
+        );
+        assert_eq!(PaymentGuards::new(false, 3), Err(Error::InvalidPaymentGuards));
+    }
+
+    #[test]
+    fn test_rate_window_keys_differ_by_action() {
+        let account = mock_account_hash();
+        assert_ne!(
+            rate_window_key(&account, RateLimitedAction::Create),
+            rate_window_key(&account, RateLimitedAction::Contribute)
+        );
+    }
+
+    #[test]
+    fn test_unreleased_share() {
+        let mut remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(900)),
+            "Test".to_string(),
+            0,
+        );
+        remittance.current_amount = U512::from(900);
+
+        // Nothing released yet: every contribution is returned in full
+        assert_eq!(remittance.unreleased_share(U512::from(300)), Ok(U512::from(300)));
+
+        // Claims beyond what escrow holds are capped at it
+        assert_eq!(remittance.unreleased_share(U512::from(5_000)), Ok(U512::from(900)));
+
+        // A third released: each contributor gets two thirds back
+        remittance.released_amount = U512::from(300);
+        assert_eq!(remittance.unreleased_amount(), U512::from(600));
+        assert_eq!(remittance.unreleased_share(U512::from(450)), Ok(U512::from(300)));
+
+        // Shares round down so their sum never exceeds the remainder
+        let shares: U512 = [100u64, 400, 400]
+            .iter()
+            .map(|amount| remittance.unreleased_share(U512::from(*amount)).unwrap())
+            .fold(U512::zero(), |total, share| total + share);
+        assert!(shares <= remittance.unreleased_amount());
+        assert_eq!(remittance.unreleased_share(U512::from(1)), Ok(U512::zero()));
+
+        remittance.released_amount = remittance.current_amount;
+        assert_eq!(remittance.unreleased_share(U512::from(450)), Ok(U512::zero()));
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let mut remittance = Remittance::new(
+            7,
+            mock_account_hash(),
+            AccountHash::new([2u8; 32]),
+            Some(U512::from(1000)),
+            "Round trip".to_string(),
+            42,
+        );
+        remittance.current_amount = U512::from(250);
+        remittance.mark_released();
+
+        let bytes = remittance.to_bytes().unwrap();
+        assert_eq!(bytes.len(), remittance.serialized_length());
+
+        let (decoded, remainder) = Remittance::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded.id, 7);
+        assert_eq!(decoded.current_amount, U512::from(250));
+        assert_eq!(decoded.purpose, "Round trip");
+        assert_eq!(decoded.created_at, 42);
+        assert!(decoded.is_released());
+        assert!(!decoded.is_cancelled());
+    }
+
+    #[test]
+    fn test_unknown_format_version_rejected() {
+        let remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(1000)),
+            "Test".to_string(),
+            0,
+        );
+
+        let mut bytes = remittance.to_bytes().unwrap();
+        assert_eq!(bytes[0], REMITTANCE_FORMAT_VERSION);
+
+        bytes[0] = REMITTANCE_FORMAT_VERSION + 1;
+        assert!(Remittance::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_legacy_decoding() {
+        let creator = mock_account_hash();
+        let recipient = AccountHash::new([2u8; 32]);
+
+        // Original layout: fields followed by is_released and is_cancelled
+        let mut bytes = alloc::vec::Vec::new();
+        bytes.append(&mut 3u64.to_bytes().unwrap());
+        bytes.append(&mut creator.to_bytes().unwrap());
+        bytes.append(&mut recipient.to_bytes().unwrap());
+        bytes.append(&mut U512::from(1000).to_bytes().unwrap());
+        bytes.append(&mut U512::from(400).to_bytes().unwrap());
+        bytes.append(&mut "Legacy".to_string().to_bytes().unwrap());
+        bytes.append(&mut 99u64.to_bytes().unwrap());
+        bytes.append(&mut false.to_bytes().unwrap());
+        bytes.append(&mut true.to_bytes().unwrap());
+
+        let (LegacyRemittance(decoded), remainder) = LegacyRemittance::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded.id, 3);
+        assert_eq!(decoded.recipient, recipient);
+        assert_eq!(decoded.current_amount, U512::from(400));
+        assert_eq!(decoded.created_at, 99);
+        assert!(!decoded.is_released());
+        assert!(decoded.is_cancelled());
+
+        // Re-encoding produces the current versioned layout
+        let migrated = decoded.to_bytes().unwrap();
+        let (round_trip, _) = Remittance::from_bytes(&migrated).unwrap();
+        assert_eq!(round_trip.id, 3);
+        assert!(round_trip.is_cancelled());
+    }
+
+    #[test]
+    fn test_purpose_commitment_round_trip() {
+        let mut remittance = Remittance::new(
+            5,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(1000)),
+            String::new(),
+            0,
+        );
+        remittance.purpose_commitment = Some([9u8; 32]);
+
+        let bytes = remittance.to_bytes().unwrap();
+        let (decoded, remainder) = Remittance::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert!(decoded.is_purpose_committed());
+        assert_eq!(decoded.purpose_commitment, Some([9u8; 32]));
+    }
+
+    #[test]
+    fn test_version_1_decoding() {
+        let remittance = Remittance::new(
+            6,
+            mock_account_hash(),
+            mock_account_hash(),
+            Some(U512::from(1000)),
+            "Version one".to_string(),
+            0,
+        );
+
+        // Version 1 layout: the base fields with a plain target, then flags
+        let mut bytes = alloc::vec![1u8];
+        bytes.append(&mut remittance.id.to_bytes().unwrap());
+        bytes.append(&mut remittance.creator.to_bytes().unwrap());
+        bytes.append(&mut remittance.recipient.to_bytes().unwrap());
+        bytes.append(&mut U512::from(1000).to_bytes().unwrap());
+        bytes.append(&mut remittance.current_amount.to_bytes().unwrap());
+        bytes.append(&mut remittance.purpose.to_bytes().unwrap());
+        bytes.append(&mut remittance.created_at.to_bytes().unwrap());
+        bytes.push(remittance.flags);
+
+        let (decoded, remainder) = Remittance::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded.id, 6);
+        assert_eq!(decoded.target_amount, Some(U512::from(1000)));
+        assert_eq!(decoded.purpose, "Version one");
+        assert!(!decoded.is_purpose_committed());
+
+        let (RemittanceFormatVersion(version), _) =
+            RemittanceFormatVersion::from_bytes(&bytes).unwrap();
+        assert_eq!(version, 1);
+    }
+
+    #[test]
+    fn test_document_ref_round_trip() {
+        let document = DocumentRef {
+            cid: "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+            content_hash: [7u8; 32],
+            doc_type: DocumentType::MedicalBill as u8,
+            attached_by: mock_account_hash(),
+            attached_at: 1_700_000_000_000,
+        };
+
+        let bytes = document.to_bytes().unwrap();
+        assert_eq!(bytes.len(), document.serialized_length());
+
+        let (decoded, remainder) = DocumentRef::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, document);
+    }
+
+    #[test]
+    fn test_remittance_update_round_trip() {
+        let update = RemittanceUpdate {
+            message: "Funds will buy the wheelchair Friday".to_string(),
+            posted_at: 1_700_000_000_000,
+        };
+
+        let bytes = update.to_bytes().unwrap();
+        assert_eq!(bytes.len(), update.serialized_length());
+
+        let (decoded, remainder) = RemittanceUpdate::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, update);
+    }
+
+    #[test]
+    fn test_receipt_acknowledgement_round_trip() {
+        let acknowledgement = ReceiptAcknowledgement {
+            message: "Fees paid for the term, thank you all".to_string(),
+            acknowledged_at: 1_700_000_000_000,
+        };
+
+        let bytes = acknowledgement.to_bytes().unwrap();
+        assert_eq!(bytes.len(), acknowledgement.serialized_length());
+
+        let (decoded, remainder) = ReceiptAcknowledgement::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, acknowledgement);
+    }
+
+    #[test]
+    fn test_branding_round_trip() {
+        let branding = Branding {
+            platform_name: "Harbor Remit".to_string(),
+            logo_cid: "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+            support_url: "https://help.example.org".to_string(),
+            currency_display: "CSPR".to_string(),
+        };
+
+        let bytes = branding.to_bytes().unwrap();
+        assert_eq!(bytes.len(), branding.serialized_length());
+
+        let (decoded, remainder) = Branding::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, branding);
+    }
+
+    #[test]
+    fn test_category_template() {
+        let mut template = CategoryTemplate {
+            deadline_days: 30,
+            fee_ppm: Some(2_500),
+            refund_excess: true,
+        };
+        assert_eq!(template.deadline_from(1_000), Some(1_000 + 30 * MILLIS_PER_DAY));
+
+        let bytes = template.to_bytes().unwrap();
+        assert_eq!(bytes.len(), template.serialized_length());
+        let (decoded, remainder) = CategoryTemplate::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, template);
+
+        template.deadline_days = 0;
+        assert_eq!(template.deadline_from(1_000), None);
+    }
+
+    #[test]
+    fn test_document_type_from_u8() {
+        assert!(matches!(
+            DocumentType::from_u8(2),
+            Ok(DocumentType::SchoolFees)
+        ));
+        assert!(DocumentType::from_u8(4).is_err());
+    }
+
+    #[test]
+    fn test_travel_rule_data_round_trip() {
+        let data = TravelRuleData {
+            data_hash: [9u8; 32],
+            payload_uri: "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"
+                .to_string(),
+            attached_at: 1_700_000_000_000,
+        };
+
+        let bytes = data.to_bytes().unwrap();
+        assert_eq!(bytes.len(), data.serialized_length());
+
+        let (decoded, remainder) = TravelRuleData::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_period_stats() {
+        let mut stats = PeriodStats::default();
+        stats.record_created(U512::from(1000));
+        stats.record_created(U512::from(500));
+        stats.record_released(U512::from(1000), U512::from(5));
+        stats.record_fee(U512::from(2));
+
+        assert_eq!(stats.created_count, 2);
+        assert_eq!(stats.created_volume, U512::from(1500));
+        assert_eq!(stats.released_count, 1);
+        assert_eq!(stats.released_volume, U512::from(1000));
+        assert_eq!(stats.fees, U512::from(7));
+
+        let bytes = stats.to_bytes().unwrap();
+        let (decoded, remainder) = PeriodStats::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, stats);
+    }
+
+    #[test]
+    fn test_accounting_snapshot_surplus() {
+        let mut snapshot = AccountingSnapshot {
+            timestamp: 0,
+            purse_balance: U512::from(1_000),
+            escrow_liabilities: U512::from(900),
+            accrued_fees: U512::from(40),
+        };
+        assert_eq!(snapshot.surplus(), Some(U512::from(60)));
+
+        snapshot.purse_balance = U512::from(939);
+        assert_eq!(snapshot.surplus(), None);
+
+        let bytes = snapshot.to_bytes().unwrap();
+        let (decoded, remainder) = AccountingSnapshot::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, snapshot);
+    }
+
+    #[test]
+    fn test_contract_info_round_trip() {
+        let info = ContractInfo {
+            owner: mock_account_hash(),
+            fee_collector: AccountHash::new([2u8; 32]),
+            platform_fee_bps: 50,
+            is_paused: true,
+            remittance_count: 7,
+            contract_version: crate::errors::CONTRACT_VERSION,
+            purse_balance: U512::from(1_000),
+        };
+
+        let bytes = info.to_bytes().unwrap();
+        assert_eq!(bytes.len(), info.serialized_length());
+        let (decoded, remainder) = ContractInfo::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, info);
+    }
+
+    #[test]
+    fn test_build_info_round_trip() {
+        let info = BuildInfo {
+            version: String::from("1.0.0"),
+            git_hash: String::from("0123456789ab-dirty"),
+        };
+
+        let bytes = info.to_bytes().unwrap();
+        assert_eq!(bytes.len(), info.serialized_length());
+        let (decoded, remainder) = BuildInfo::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, info);
+    }
+
+    #[test]
+    fn test_review_policy() {
+        let mut policy = ReviewPolicy::default();
+        assert!(!policy.requires_review(&U512::MAX));
+
+        policy.threshold = U512::from(1000);
+        policy.window_ms = 86_400_000;
+        assert!(!policy.requires_review(&U512::from(999)));
+        assert!(policy.requires_review(&U512::from(1000)));
+
+        let bytes = policy.to_bytes().unwrap();
+        let (decoded, remainder) = ReviewPolicy::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, policy);
+    }
+
+    #[test]
+    fn test_risk_engine_round_trip() {
+        let engine = RiskEngine {
+            contract_hash: ContractHash::new([9u8; 32]),
+            min_amount: U512::from(1_000_000_000_000u64),
+            max_score: 70,
+        };
+
+        let bytes = engine.to_bytes().unwrap();
+        assert_eq!(bytes.len(), engine.serialized_length());
+        let (decoded, remainder) = RiskEngine::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, engine);
+    }
+
+    #[test]
+    fn test_bridge_payout_round_trip() {
+        let payout = BridgePayout {
+            chain_id: String::from("eip155:1"),
+            foreign_address: String::from("0x52908400098527886E0F7030069857D2E4169EE7"),
+            requested_at: 1_700_000_000_000,
+            deadline: 1_700_604_800_000,
+        };
+
+        let bytes = payout.to_bytes().unwrap();
+        assert_eq!(bytes.len(), payout.serialized_length());
+        let (decoded, remainder) = BridgePayout::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, payout);
+    }
+
+    #[test]
+    fn test_settlement_partner() {
+        let partner = SettlementPartner {
+            name: String::from("Lagos Cash Express"),
+            corridors: alloc::vec![String::from("US-NG"), String::from("GB-NG")],
+        };
+        assert!(partner.serves("GB-NG"));
+        assert!(!partner.serves("US-GH"));
+
+        let bytes = partner.to_bytes().unwrap();
+        assert_eq!(bytes.len(), partner.serialized_length());
+        let (decoded, remainder) = SettlementPartner::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, partner);
+    }
+
+    #[test]
+    fn test_instant_split() {
+        let mut split = InstantSplit::new(2_500);
+        assert_eq!(split.split(U512::from(1_000)), (U512::from(250), U512::from(750)));
+
+        // Escrow keeps the rounding remainder
+        assert_eq!(split.split(U512::from(3)), (U512::zero(), U512::from(3)));
+
+        split.record(U512::from(250), U512::from(2)).unwrap();
+        split.record(U512::from(100), U512::from(1)).unwrap();
+        assert_eq!(split.forwarded, U512::from(350));
+        assert_eq!(split.fees, U512::from(3));
+
+        let bytes = split.to_bytes().unwrap();
+        assert_eq!(bytes.len(), split.serialized_length());
+        let (decoded, remainder) = InstantSplit::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, split);
+    }
+
+    #[test]
+    fn test_backup_recipient() {
+        let mut backup = BackupRecipient {
+            account: AccountHash::new([9u8; 32]),
+            grace_ms: 1_000,
+            funded_at: None,
+        };
+        assert_eq!(backup.eligible_at(), None);
+        assert!(!backup.is_eligible(u64::MAX));
+
+        backup.funded_at = Some(5_000);
+        assert!(!backup.is_eligible(5_999));
+        assert!(backup.is_eligible(6_000));
+
+        let bytes = backup.to_bytes().unwrap();
+        assert_eq!(bytes.len(), backup.serialized_length());
+        let (decoded, remainder) = BackupRecipient::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, backup);
+    }
+
+    #[test]
+    fn test_payout_schedule() {
+        let mut schedule = PayoutSchedule::new(U512::from(100u64), 1_000);
+        assert_eq!(schedule.next_due_at(), None);
+        assert!(!schedule.is_due(u64::MAX));
+
+        schedule.funded_at = Some(5_000);
+        assert!(schedule.is_due(5_000));
+
+        schedule.paid_count = 2;
+        assert_eq!(schedule.next_due_at(), Some(7_000));
+        assert!(!schedule.is_due(6_999));
+        assert!(schedule.is_due(7_000));
+
+        let bytes = schedule.to_bytes().unwrap();
+        assert_eq!(bytes.len(), schedule.serialized_length());
+        let (decoded, remainder) = PayoutSchedule::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, schedule);
+    }
+
+    #[test]
+    fn test_savings_pool() {
+        let (alice, bob, carol) = (
+            AccountHash::new([1u8; 32]),
+            AccountHash::new([2u8; 32]),
+            AccountHash::new([3u8; 32]),
+        );
+        let pool = SavingsPool {
+            organizer: alice,
+            members: alloc::vec![alice, bob],
+            contribution: U512::from(500u64),
+            cycle_ms: 1_000,
+            created_at: 5_000,
+            first_remittance_id: 7,
+        };
+        assert_eq!(pool.cycle_count(), 2);
+        assert_eq!(pool.pot(), Ok(U512::from(1_000u64)));
+        assert!(pool.is_member(&bob));
+        assert!(!pool.is_member(&carol));
+        assert_eq!(pool.cycle_remittance_id(1), 8);
+        assert_eq!(pool.cycle_opens_at(0), 5_000);
+        assert_eq!(pool.cycle_opens_at(1), 6_000);
+
+        let bytes = pool.to_bytes().unwrap();
+        assert_eq!(bytes.len(), pool.serialized_length());
+        let (decoded, remainder) = SavingsPool::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, pool);
+    }
+
+    #[test]
+    fn test_release_sla() {
+        let mut sla = ReleaseSla::new(1_000);
+        let intervals_ms = [3 * MILLIS_PER_DAY, 7 * MILLIS_PER_DAY];
+        assert_eq!(sla.due_level(&intervals_ms, 1_000), 0);
+        assert_eq!(sla.due_level(&intervals_ms, 1_000 + 3 * MILLIS_PER_DAY), 1);
+        assert_eq!(sla.due_level(&intervals_ms, 1_000 + 30 * MILLIS_PER_DAY), 2);
+        assert_eq!(sla.due_level(&[], u64::MAX), 0);
+
+        sla.escalation_level = 1;
+        let bytes = sla.to_bytes().unwrap();
+        assert_eq!(bytes.len(), sla.serialized_length());
+        let (decoded, remainder) = ReleaseSla::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, sla);
+    }
+
+    #[test]
+    fn test_recipient_multisig() {
+        let (alice, bob, carol) = (
+            AccountHash::new([1u8; 32]),
+            AccountHash::new([2u8; 32]),
+            AccountHash::new([3u8; 32]),
+        );
+        let mut multisig = RecipientMultisig::new(alloc::vec![alice, bob, carol], 2);
+        assert!(multisig.is_signer(&bob));
+        assert!(!multisig.is_signer(&AccountHash::new([4u8; 32])));
+
+        multisig.approvals.push(alice);
+        assert!(multisig.has_approved(&alice));
+        assert!(!multisig.is_approved());
+        multisig.approvals.push(carol);
+        assert!(multisig.is_approved());
+
+        let bytes = multisig.to_bytes().unwrap();
+        assert_eq!(bytes.len(), multisig.serialized_length());
+        let (decoded, remainder) = RecipientMultisig::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, multisig);
+    }
+
+    #[test]
+    fn test_contribution_snapshot() {
+        let (alice, bob) = (AccountHash::new([1u8; 32]), AccountHash::new([2u8; 32]));
+        let snapshot = ContributionSnapshot {
+            remittance_id: 3,
+            taken_at: 1_000,
+            contributions: alloc::vec![(alice, U512::from(70)), (bob, U512::from(30))],
+        };
+        assert_eq!(snapshot.amount_of(&bob), U512::from(30));
+        assert_eq!(snapshot.amount_of(&AccountHash::new([3u8; 32])), U512::zero());
+        assert_eq!(snapshot.total(), U512::from(100));
+
+        let bytes = snapshot.to_bytes().unwrap();
+        assert_eq!(bytes.len(), snapshot.serialized_length());
+        let (decoded, remainder) = ContributionSnapshot::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, snapshot);
+    }
+
+    #[test]
+    fn test_contribution_weight() {
+        let mut weight = ContributionWeight::default();
+        weight.deposit(U512::from(100), 1_000);
+        assert_eq!(weight.weight_at(1_000), U512::zero());
+        assert_eq!(weight.weight_at(1_010), U512::from(1_000));
+
+        // 100 for 10 ms, then 150 for 20 ms, then 50 for 10 ms
+        weight.deposit(U512::from(50), 1_010);
+        weight.withdraw(U512::from(100), 1_030);
+        assert_eq!(weight.amount, U512::from(50));
+        assert_eq!(weight.weight_at(1_040), U512::from(4_500));
+
+        // A stale block time never takes weight away
+        assert_eq!(weight.weight_at(0), U512::from(4_000));
+
+        let bytes = weight.to_bytes().unwrap();
+        assert_eq!(bytes.len(), weight.serialized_length());
+        let (decoded, remainder) = ContributionWeight::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, weight);
+    }
+
+    #[test]
+    fn test_account_overview() {
+        let overview = AccountOverview {
+            created: alloc::vec![1, 4],
+            receiving: alloc::vec![2],
+            contributed: alloc::vec![3, 5],
+            refundable: alloc::vec![(3, U512::from(40)), (5, U512::from(2))],
+            creator_rewards: U512::from(7),
+        };
+        assert_eq!(overview.total_refundable(), U512::from(42));
+        assert_eq!(AccountOverview::default().total_refundable(), U512::zero());
+
+        let bytes = overview.to_bytes().unwrap();
+        assert_eq!(bytes.len(), overview.serialized_length());
+        let (decoded, remainder) = AccountOverview::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, overview);
+    }
+
+    #[test]
+    fn test_fee_cover() {
+        let mut cover = FeeCover::new(AccountHash::new([7u8; 32]));
+        assert_eq!(cover.split(&U512::from(50)), (U512::zero(), U512::zero()));
+
+        cover.balance = U512::from(60);
+        assert_eq!(cover.split(&U512::from(50)), (U512::from(50), U512::from(10)));
+        assert_eq!(cover.split(&U512::from(80)), (U512::from(60), U512::zero()));
+
+        let bytes = cover.to_bytes().unwrap();
+        assert_eq!(bytes.len(), cover.serialized_length());
+        let (decoded, remainder) = FeeCover::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, cover);
+    }
+
+    #[test]
+    fn test_scheduled_start() {
+        let mut start = ScheduledStart::new(10_000);
+        assert!(!start.has_started(9_999));
+        assert!(start.has_started(10_000));
+
+        start.activated_at = Some(12_000);
+        let bytes = start.to_bytes().unwrap();
+        assert_eq!(bytes.len(), start.serialized_length());
+        let (decoded, remainder) = ScheduledStart::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, start);
+    }
+
+    #[test]
+    fn test_insurance_policy() {
+        let mut policy = InsurancePolicy::new(50);
+        assert_eq!(policy.premium(U512::from(10_000)), U512::from(50));
+        assert!(!policy.is_approved());
+        assert_eq!(policy.top_up(U512::from(10_000)), U512::zero());
+
+        policy.coverage_bps = 2_000;
+        assert!(policy.is_approved());
+        assert_eq!(policy.top_up(U512::from(9_950)), U512::from(1_990));
+
+        let bytes = policy.to_bytes().unwrap();
+        assert_eq!(bytes.len(), policy.serialized_length());
+        let (decoded, remainder) = InsurancePolicy::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, policy);
+    }
+
+    #[test]
+    fn test_refund_policy() {
+        assert_eq!(RefundPolicy::new(3, 0), Err(Error::InvalidRefundPolicy));
+        assert_eq!(RefundPolicy::new(0, 100), Err(Error::InvalidRefundPolicy));
+        assert_eq!(RefundPolicy::new(1, 0), Err(Error::InvalidRefundPolicy));
+        assert_eq!(RefundPolicy::new(1, 2_001), Err(Error::InvalidRefundPolicy));
+
+        let full = RefundPolicy::default();
+        assert_eq!(full.deduction(U512::from(10_000), 0, 30 * MILLIS_PER_DAY), U512::zero());
+
+        let fee = RefundPolicy::new(RefundPolicyKind::ProcessingFee as u8, 250).unwrap();
+        assert_eq!(fee.deduction(U512::from(10_000), 0, 0), U512::from(250));
+
+        let decayed = RefundPolicy::new(RefundPolicyKind::TimeDecayed as u8, 100).unwrap();
+        assert_eq!(decayed.deduction_bps(0, MILLIS_PER_DAY - 1), 0);
+        assert_eq!(decayed.deduction_bps(0, 3 * MILLIS_PER_DAY), 300);
+        assert_eq!(decayed.deduction_bps(0, 365 * MILLIS_PER_DAY), MAX_REFUND_DEDUCTION_BPS);
+
+        let bytes = decayed.to_bytes().unwrap();
+        assert_eq!(bytes.len(), decayed.serialized_length());
+        let (decoded, remainder) = RefundPolicy::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, decayed);
+    }
+
+    #[test]
+    fn test_remittance_analytics() {
+        let mut analytics = RemittanceAnalytics::default();
+        assert_eq!(analytics.average_contribution(), U512::zero());
+
+        analytics.record(U512::from(100), 0).unwrap();
+        analytics.record(U512::from(300), 2 * MILLIS_PER_HOUR).unwrap();
+        analytics.record(U512::from(200), 23 * MILLIS_PER_HOUR).unwrap();
+        assert_eq!(analytics.contribution_count, 3);
+        assert_eq!(analytics.largest_contribution, U512::from(300));
+        assert_eq!(analytics.average_contribution(), U512::from(200));
+        assert_eq!(analytics.recent_contributions(), 3);
+
+        // The first hour leaves the window, then the rest
+        analytics.advance(24 * MILLIS_PER_HOUR);
+        assert_eq!(analytics.recent_contributions(), 2);
+        analytics.advance(100 * MILLIS_PER_HOUR);
+        assert_eq!(analytics.recent_contributions(), 0);
+        assert_eq!(analytics.contribution_count, 3);
+
+        let bytes = analytics.to_bytes().unwrap();
+        assert_eq!(bytes.len(), analytics.serialized_length());
+        let (decoded, remainder) = RemittanceAnalytics::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, analytics);
+    }
+
+    #[test]
+    fn test_contributor_key() {
+        let contributor = mock_account_hash();
+        let key = contributor_key(1, &contributor);
+
+        assert_eq!(key, contributor_key(1, &contributor));
+        assert_ne!(key, contributor_key(2, &contributor));
+        assert_ne!(key, contributor_key(1, &AccountHash::new([2u8; 32])));
+    }
+
+    #[test]
+    fn test_localized_purpose_falls_back_to_primary_language() {
+        let purposes = [
+            LocalizedPurpose {
+                lang: "pt".into(),
+                purpose: "Propinas escolares".into(),
+            },
+            LocalizedPurpose {
+                lang: "fr-ca".into(),
+                purpose: "Frais de scolarité".into(),
+            },
+        ];
+
+        assert_eq!(localized_purpose(&purposes, "pt-BR"), Some("Propinas escolares"));
+        assert_eq!(localized_purpose(&purposes, "fr-CA"), Some("Frais de scolarité"));
+        assert_eq!(localized_purpose(&purposes, "fr"), None);
+        assert_eq!(localized_purpose(&purposes, "en"), None);
+    }
+
+    #[test]
+    fn test_purpose_hash_normalizes_whitespace_and_case() {
+        let hash = purpose_hash("Send fees to unlock your prize");
+
+        assert_eq!(hash, purpose_hash("  send FEES to\tunlock  your prize "));
+        assert_ne!(hash, purpose_hash("Send fees to unlock your prizes"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip() {
+        let mut remittance = Remittance::new(
+            1,
+            mock_account_hash(),
+            AccountHash::new([2u8; 32]),
+            Some(U512::from(1000)),
+            "Test remittance".to_string(),
+            1234567890,
+        );
+        remittance.deadline = Some(1234567890 + 86_400_000);
+        remittance.set_flag(FLAG_REFUND_EXCESS);
+
+        let json = serde_json::to_string(&remittance).unwrap();
+        let decoded: Remittance = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.to_bytes().unwrap(), remittance.to_bytes().unwrap());
+
+        let contribution = Contribution::new(mock_account_hash(), U512::from(250), 1234567890);
+        let json = serde_json::to_string(&contribution).unwrap();
+        let decoded: Contribution = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.to_bytes().unwrap(), contribution.to_bytes().unwrap());
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use alloc::vec::Vec;
+    use casper_types::bytesrepr;
+    use proptest::prelude::*;
+
+    fn account_hash() -> impl Strategy<Value = AccountHash> {
+        any::<[u8; 32]>().prop_map(AccountHash::new)
+    }
+
+    fn amount() -> impl Strategy<Value = U512> {
+        any::<[u8; 64]>().prop_map(|bytes| U512::from_little_endian(&bytes))
+    }
+
+    prop_compose! {
+        fn remittance()(
+            id in any::<u64>(),
+            creator in account_hash(),
+            recipient in account_hash(),
+            target_amount in proptest::option::of(amount()),
+            current_amount in amount(),
+            purpose in ".{0,64}",
+            created_at in any::<u64>(),
+            flags in any::<u8>(),
+            purpose_commitment in any::<Option<[u8; 32]>>(),
+            released_amount in amount(),
+            deadline in any::<Option<u64>>(),
+        ) -> Remittance {
+            Remittance {
+                id,
+                creator,
+                recipient,
+                target_amount,
+                current_amount,
+                purpose,
+                created_at,
+                flags,
+                purpose_commitment,
+                released_amount,
+                deadline,
+            }
+        }
+    }
+
+    prop_compose! {
+        fn contribution()(
+            contributor in account_hash(),
+            amount in amount(),
+            timestamp in any::<u64>(),
+        ) -> Contribution {
+            Contribution::new(contributor, amount, timestamp)
+        }
+    }
+
+    /// Encodes the fields shared by every layout before version 5, in order
+    fn base_fields(remittance: &Remittance) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.append(&mut remittance.id.to_bytes().unwrap());
+        bytes.append(&mut remittance.creator.to_bytes().unwrap());
+        bytes.append(&mut remittance.recipient.to_bytes().unwrap());
+        bytes.append(&mut remittance.target_amount.unwrap_or_default().to_bytes().unwrap());
+        bytes.append(&mut remittance.current_amount.to_bytes().unwrap());
+        bytes.append(&mut remittance.purpose.to_bytes().unwrap());
+        bytes.append(&mut remittance.created_at.to_bytes().unwrap());
+        bytes
+    }
+
+    fn assert_same_remittance(decoded: &Remittance, expected: &Remittance) {
+        assert_eq!(decoded.id, expected.id);
+        assert_eq!(decoded.creator, expected.creator);
+        assert_eq!(decoded.recipient, expected.recipient);
+        assert_eq!(decoded.target_amount, expected.target_amount);
+        assert_eq!(decoded.current_amount, expected.current_amount);
+        assert_eq!(decoded.purpose, expected.purpose);
+        assert_eq!(decoded.created_at, expected.created_at);
+        assert_eq!(decoded.flags, expected.flags);
+        assert_eq!(decoded.purpose_commitment, expected.purpose_commitment);
+        assert_eq!(decoded.released_amount, expected.released_amount);
+        assert_eq!(decoded.deadline, expected.deadline);
+    }
+
+    /// The remittance as a layout before version 5 decodes it, with a target
+    fn with_plain_target(remittance: Remittance) -> Remittance {
+        Remittance {
+            target_amount: Some(remittance.target_amount.unwrap_or_default()),
+            ..remittance
+        }
+    }
+
+    /// The `released_amount` an older layout decodes with
+    fn implied_released_amount(remittance: &Remittance) -> U512 {
+        if remittance.is_released() {
+            remittance.release_amount()
+        } else {
+            U512::zero()
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn remittance_round_trips(remittance in remittance()) {
+            let bytes = remittance.to_bytes().unwrap();
+            prop_assert_eq!(bytes.len(), remittance.serialized_length());
+            prop_assert_eq!(bytes[0], REMITTANCE_FORMAT_VERSION);
+
+            let decoded: Remittance = bytesrepr::deserialize_from_slice(&bytes).unwrap();
+            assert_same_remittance(&decoded, &remittance);
+            prop_assert_eq!(decoded.to_bytes().unwrap(), bytes);
+        }
+
+        #[test]
+        fn remittance_leaves_trailing_bytes(
+            remittance in remittance(),
+            trailing in proptest::collection::vec(any::<u8>(), 0..32),
+        ) {
+            let mut bytes = remittance.to_bytes().unwrap();
+            bytes.extend_from_slice(&trailing);
+
+            let (decoded, remainder) = Remittance::from_bytes(&bytes).unwrap();
+            assert_same_remittance(&decoded, &remittance);
+            prop_assert_eq!(remainder, &trailing[..]);
+        }
+
+        #[test]
+        fn remittance_decodes_version_1(remittance in remittance()) {
+            let mut bytes = alloc::vec![1u8];
+            bytes.append(&mut base_fields(&remittance));
+            bytes.push(remittance.flags);
+
+            let decoded: Remittance = bytesrepr::deserialize_from_slice(&bytes).unwrap();
+            let mut expected = Remittance {
+                purpose_commitment: None,
+                deadline: None,
+                ..with_plain_target(remittance)
+            };
+            expected.released_amount = implied_released_amount(&expected);
+            assert_same_remittance(&decoded, &expected);
+        }
+
+        #[test]
+        fn remittance_decodes_version_2(remittance in remittance()) {
+            let mut bytes = alloc::vec![2u8];
+            bytes.append(&mut base_fields(&remittance));
+            bytes.push(remittance.flags);
+            bytes.append(&mut remittance.purpose_commitment.to_bytes().unwrap());
+
+            let decoded: Remittance = bytesrepr::deserialize_from_slice(&bytes).unwrap();
+            let mut expected = Remittance { deadline: None, ..with_plain_target(remittance) };
+            expected.released_amount = implied_released_amount(&expected);
+            assert_same_remittance(&decoded, &expected);
+        }
+
+        #[test]
+        fn remittance_decodes_version_3(remittance in remittance()) {
+            let mut bytes = alloc::vec![3u8];
+            bytes.append(&mut base_fields(&remittance));
+            bytes.push(remittance.flags);
+            bytes.append(&mut remittance.purpose_commitment.to_bytes().unwrap());
+            bytes.append(&mut remittance.released_amount.to_bytes().unwrap());
+
+            let decoded: Remittance = bytesrepr::deserialize_from_slice(&bytes).unwrap();
+            let expected = Remittance { deadline: None, ..with_plain_target(remittance) };
+            assert_same_remittance(&decoded, &expected);
+        }
+
+        #[test]
+        fn remittance_decodes_version_4(remittance in remittance()) {
+            let mut bytes = alloc::vec![4u8];
+            bytes.append(&mut base_fields(&remittance));
+            bytes.push(remittance.flags);
+            bytes.append(&mut remittance.purpose_commitment.to_bytes().unwrap());
+            bytes.append(&mut remittance.released_amount.to_bytes().unwrap());
+            bytes.append(&mut remittance.deadline.to_bytes().unwrap());
+
+            let decoded: Remittance = bytesrepr::deserialize_from_slice(&bytes).unwrap();
+            assert_same_remittance(&decoded, &with_plain_target(remittance));
+        }
+
+        #[test]
+        fn remittance_decodes_legacy_layout(
+            remittance in remittance(),
+            is_released in any::<bool>(),
+            is_cancelled in any::<bool>(),
+        ) {
+            let mut bytes = base_fields(&remittance);
+            bytes.push(is_released as u8);
+            bytes.push(is_cancelled as u8);
+
+            let LegacyRemittance(decoded) = bytesrepr::deserialize_from_slice(&bytes).unwrap();
+            prop_assert_eq!(decoded.is_released(), is_released);
+            prop_assert_eq!(decoded.is_cancelled(), is_cancelled);
+            prop_assert_eq!(decoded.flags & !(FLAG_RELEASED | FLAG_CANCELLED), 0);
+            prop_assert_eq!(decoded.purpose_commitment, None);
+
+            let mut expected = Remittance {
+                flags: decoded.flags,
+                purpose_commitment: None,
+                deadline: None,
+                ..with_plain_target(remittance)
+            };
+            expected.released_amount = implied_released_amount(&expected);
+            assert_same_remittance(&decoded, &expected);
+        }
+
+        #[test]
+        fn remittance_rejects_unknown_versions(
+            remittance in remittance(),
+            version in prop_oneof![Just(0u8), (REMITTANCE_FORMAT_VERSION + 1)..=u8::MAX],
+        ) {
+            let mut bytes = remittance.to_bytes().unwrap();
+            bytes[0] = version;
+
+            prop_assert_eq!(
+                Remittance::from_bytes(&bytes).err(),
+                Some(bytesrepr::Error::Formatting)
+            );
+            let (RemittanceFormatVersion(read), _) =
+                RemittanceFormatVersion::from_bytes(&bytes).unwrap();
+            prop_assert_eq!(read, version);
+        }
+
+        #[test]
+        fn remittance_rejects_truncated_bytes(remittance in remittance(), cut in any::<usize>()) {
+            let bytes = remittance.to_bytes().unwrap();
+            let truncated = &bytes[..cut % bytes.len()];
+            prop_assert!(Remittance::from_bytes(truncated).is_err());
+        }
+
+        #[test]
+        fn contribution_round_trips(contribution in contribution()) {
+            let bytes = contribution.to_bytes().unwrap();
+            prop_assert_eq!(bytes.len(), contribution.serialized_length());
+
+            let decoded: Contribution = bytesrepr::deserialize_from_slice(&bytes).unwrap();
+            prop_assert_eq!(decoded.contributor, contribution.contributor);
+            prop_assert_eq!(decoded.amount, contribution.amount);
+            prop_assert_eq!(decoded.timestamp, contribution.timestamp);
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Machine-readable description of the contract interface.
+//!
+//! The contract stores a [`ContractSchema`] under the `contract_schema`
+//! named key at install time and returns it from `get_schema`, so
+//! explorers and code generators can build bindings from chain state.
+//! Entry points and event fields use the `casper-types` `EntryPoint` and
+//! `Parameter` encodings, so any Casper SDK can decode them.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{CLType, CLTyped, EntryPoint, EntryPoints, Parameter};
+
+use crate::errors::CONTRACT_VERSION;
+use crate::events::ContractEvent;
+
+/// Current encoding version of [`ContractSchema`]
+pub const SCHEMA_FORMAT_VERSION: u8 = 1;
+
+/// Name and fields of one CES event, in wire order
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventSchema {
+    /// Event name, without the CES `event_` prefix
+    pub name: String,
+
+    /// Field names and types
+    pub fields: Vec<Parameter>,
+}
+
+impl ToBytes for EventSchema {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.append(&mut self.name.to_bytes()?);
+        result.append(&mut self.fields.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.name.serialized_length() + self.fields.serialized_length()
+    }
+}
+
+impl FromBytes for EventSchema {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (name, remainder) = String::from_bytes(bytes)?;
+        let (fields, remainder) = Vec::<Parameter>::from_bytes(remainder)?;
+        Ok((EventSchema { name, fields }, remainder))
+    }
+}
+
+/// Entry points and event schemas of a contract version.
+///
+/// Encoded with a leading [`SCHEMA_FORMAT_VERSION`] byte.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContractSchema {
+    /// Version of the contract code (`CONTRACT_VERSION`)
+    pub contract_version: u32,
+
+    /// Entry points with their argument and return types, sorted by name
+    pub entry_points: Vec<EntryPoint>,
+
+    /// Events the contract emits
+    pub events: Vec<EventSchema>,
+}
+
+impl ContractSchema {
+    /// Describes `entry_points` and every [`ContractEvent`]
+    pub fn new(entry_points: EntryPoints) -> Self {
+        let events = ContractEvent::schemas()
+            .into_iter()
+            .map(|(name, fields)| EventSchema {
+                name: String::from(name),
+                fields: fields
+                    .into_iter()
+                    .map(|(field, cl_type)| Parameter::new(field, cl_type))
+                    .collect(),
+            })
+            .collect();
+
+        ContractSchema {
+            contract_version: CONTRACT_VERSION,
+            entry_points: entry_points.take_entry_points(),
+            events,
+        }
+    }
+
+    /// Gets an entry point by name
+    pub fn entry_point(&self, name: &str) -> Option<&EntryPoint> {
+        self.entry_points
+            .iter()
+            .find(|entry_point| entry_point.name() == name)
+    }
+
+    /// Gets an event schema by name
+    pub fn event(&self, name: &str) -> Option<&EventSchema> {
+        self.events.iter().find(|event| event.name == name)
+    }
+}
+
+impl ToBytes for ContractSchema {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::with_capacity(self.serialized_length());
+        result.push(SCHEMA_FORMAT_VERSION);
+        result.append(&mut self.contract_version.to_bytes()?);
+        result.append(&mut self.entry_points.to_bytes()?);
+        result.append(&mut self.events.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        SCHEMA_FORMAT_VERSION.serialized_length()
+            + self.contract_version.serialized_length()
+            + self.entry_points.serialized_length()
+            + self.events.serialized_length()
+    }
+}
+
+impl FromBytes for ContractSchema {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (version, remainder) = u8::from_bytes(bytes)?;
+        if version != SCHEMA_FORMAT_VERSION {
+            return Err(bytesrepr::Error::Formatting);
+        }
+
+        let (contract_version, remainder) = u32::from_bytes(remainder)?;
+        let (entry_points, remainder) = Vec::<EntryPoint>::from_bytes(remainder)?;
+        let (events, remainder) = Vec::<EventSchema>::from_bytes(remainder)?;
+
+        Ok((
+            ContractSchema {
+                contract_version,
+                entry_points,
+                events,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl CLTyped for ContractSchema {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use casper_types::{EntryPointAccess, EntryPointType};
+
+    fn sample_schema() -> ContractSchema {
+        let mut entry_points = EntryPoints::new();
+        entry_points.add_entry_point(EntryPoint::new(
+            "set_platform_fee",
+            alloc::vec![Parameter::new("fee_bps", CLType::U64)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points.add_entry_point(EntryPoint::new(
+            "get_platform_fee",
+            alloc::vec![],
+            CLType::U64,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        ContractSchema::new(entry_points)
+    }
+
+    #[test]
+    fn test_schema_describes_entry_points_and_events() {
+        let schema = sample_schema();
+        assert_eq!(schema.contract_version, CONTRACT_VERSION);
+        assert_eq!(schema.entry_points[0].name(), "get_platform_fee");
+
+        let set_fee = schema.entry_point("set_platform_fee").unwrap();
+        assert_eq!(set_fee.args(), &[Parameter::new("fee_bps", CLType::U64)]);
+
+        assert_eq!(schema.events.len(), ContractEvent::schemas().len());
+        let paused = schema.event("ContractPaused").unwrap();
+        assert_eq!(paused.fields, [Parameter::new("timestamp", CLType::U64)]);
+    }
+
+    #[test]
+    fn test_schema_round_trip() {
+        let schema = sample_schema();
+
+        let bytes = schema.to_bytes().unwrap();
+        assert_eq!(bytes.len(), schema.serialized_length());
+        assert_eq!(bytes[0], SCHEMA_FORMAT_VERSION);
+        let (decoded, remainder) = ContractSchema::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, schema);
+
+        let mut unknown = bytes;
+        unknown[0] = SCHEMA_FORMAT_VERSION + 1;
+        assert!(ContractSchema::from_bytes(&unknown).is_err());
+    }
+}
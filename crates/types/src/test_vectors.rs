@@ -0,0 +1,161 @@
+//! Canonical encodings shared with the TypeScript frontend.
+//!
+//! Each vector pairs a fixed value with its serde JSON form and its
+//! bytesrepr encoding, so the frontend can check that it decodes exactly
+//! what the contract writes without a running node. Regenerate the shared
+//! fixture with
+//! `cargo run -p casperflow-types --example export_test_vectors --features test-vectors`.
+
+use casper_types::{account::AccountHash, bytesrepr::ToBytes, U512};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    remittance::{Contribution, Remittance, FLAG_RELEASED},
+    ContractEvent,
+};
+
+/// Block time every vector is stamped with.
+const TIMESTAMP: u64 = 1_700_000_000_000;
+
+/// One canonical value in both of its encodings.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TestVector {
+    /// Name of the vector, unique within the set
+    pub name: &'static str,
+    /// Rust type the value decodes as
+    pub type_name: &'static str,
+    /// Serde JSON form
+    pub json: Value,
+    /// bytesrepr encoding, as lowercase hex
+    pub bytes: String,
+}
+
+impl TestVector {
+    fn new<T: Serialize + ToBytes>(
+        name: &'static str,
+        type_name: &'static str,
+        value: &T,
+    ) -> Self {
+        let bytes = value.to_bytes().expect("test vectors always serialize");
+        TestVector {
+            name,
+            type_name,
+            json: serde_json::to_value(value).expect("test vectors always serialize"),
+            bytes: bytes.iter().map(|byte| format!("{:02x}", byte)).collect(),
+        }
+    }
+}
+
+/// Returns every vector, in a fixed order.
+pub fn vectors() -> Vec<TestVector> {
+    let alice = AccountHash::new([1u8; 32]);
+    let bob = AccountHash::new([2u8; 32]);
+    let carol = AccountHash::new([3u8; 32]);
+    let target = U512::from(10_000_000_000u64);
+    let fee = U512::from(50_000_000u64);
+
+    let open = Remittance::new(1, alice, bob, Some(target), "School fees".into(), TIMESTAMP);
+    let mut released = open.clone();
+    released.current_amount = target;
+    released.released_amount = target;
+    released.purpose_commitment = Some([7u8; 32]);
+    released.deadline = Some(TIMESTAMP + 86_400_000);
+    released.set_flag(FLAG_RELEASED);
+
+    vec![
+        TestVector::new("remittance_open", "Remittance", &open),
+        TestVector::new("remittance_released", "Remittance", &released),
+        TestVector::new(
+            "contribution",
+            "Contribution",
+            &Contribution::new(carol, target, TIMESTAMP),
+        ),
+        TestVector::new(
+            "event_remittance_created",
+            "ContractEvent",
+            &ContractEvent::RemittanceCreated {
+                remittance_id: 1,
+                creator: alice,
+                recipient: bob,
+                target_amount: target,
+                purpose: "School fees".into(),
+                charity_verified: false,
+                timestamp: TIMESTAMP,
+            },
+        ),
+        TestVector::new(
+            "event_contribution_made",
+            "ContractEvent",
+            &ContractEvent::ContributionMade {
+                remittance_id: 1,
+                contributor: carol,
+                amount: target,
+                new_total: target,
+                timestamp: TIMESTAMP,
+            },
+        ),
+        TestVector::new(
+            "event_funds_released",
+            "ContractEvent",
+            &ContractEvent::FundsReleased {
+                remittance_id: 1,
+                recipient: bob,
+                amount: target - fee,
+                platform_fee: fee,
+                gross_amount: target,
+                creator_rebate: U512::zero(),
+                fee_covered: U512::zero(),
+                charity_verified: false,
+                timestamp: TIMESTAMP,
+            },
+        ),
+    ]
+}
+
+/// Renders every vector as the pretty-printed JSON fixture.
+pub fn to_json() -> String {
+    serde_json::to_string_pretty(&vectors()).expect("test vectors always serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use casper_types::bytesrepr::{self, FromBytes};
+
+    fn decode_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn round_trip<T: FromBytes + ToBytes>(vector: &TestVector) {
+        let bytes = decode_hex(&vector.bytes);
+        let decoded: T = bytesrepr::deserialize_from_slice(&bytes).unwrap();
+        assert_eq!(decoded.to_bytes().unwrap(), bytes, "{}", vector.name);
+    }
+
+    #[test]
+    fn test_vectors_round_trip() {
+        let vectors = vectors();
+        for vector in &vectors {
+            match vector.type_name {
+                "Remittance" => round_trip::<Remittance>(vector),
+                "Contribution" => round_trip::<Contribution>(vector),
+                "ContractEvent" => round_trip::<ContractEvent>(vector),
+                other => panic!("no decoder for {}", other),
+            }
+        }
+
+        let mut names: Vec<_> = vectors.iter().map(|vector| vector.name).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), vectors.len());
+    }
+
+    #[test]
+    fn test_vectors_are_deterministic() {
+        assert_eq!(to_json(), to_json());
+    }
+}
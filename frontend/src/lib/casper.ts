@@ -4,6 +4,7 @@
 
 import { CasperClient, CLPublicKey, DeployUtil, RuntimeArgs, CLValueBuilder } from 'casper-js-sdk';
 import { CONFIG, CONTRACT_HASH } from './constants';
+import { describeDeployFailure } from './errors';
 
 // Initialize Casper client
 export const casperClient = new CasperClient(CONFIG.nodeUrl);
@@ -164,7 +165,7 @@ export async function waitForDeploy(
         if (result.Success) {
           return { success: true, deploy, result };
         } else if (result.Failure) {
-          return { success: false, deploy, result, error: result.Failure };
+          return { success: false, deploy, result, error: describeDeployFailure(result.Failure) };
         }
       }
     } catch (error) {
@@ -209,10 +210,12 @@ export async function queryRemittance(remittanceId: number): Promise<any> {
       []
     );
 
-    // Get the remittances dictionary URef from named keys
-    const remittancesDictURef = contractData.namedKeys.find(
-      (key: any) => key.name === 'remittances'
-    )?.key;
+    // Get the remittances dictionary URef from named keys, falling back to
+    // the legacy dictionary for records not yet migrated
+    const findNamedKey = (name: string) =>
+      contractData.namedKeys.find((key: any) => key.name === name)?.key;
+    const remittancesDictURef =
+      findNamedKey('remittance_records') ?? findNamedKey('remittances');
 
     if (!remittancesDictURef) {
       console.error('Remittances dictionary not found in contract');
@@ -0,0 +1,157 @@
+/**
+ * Contract error codes and messages
+ *
+ * Mirrors `Error::message` in contracts/src/errors.rs; keep both in sync.
+ */
+
+export const CONTRACT_ERROR_MESSAGES: Record<number, string> = {
+  1: 'Remittance not found',
+  2: 'You are not authorized to perform this action',
+  3: 'Target amount must be greater than zero',
+  5: 'Contribution amount must be greater than zero',
+  6: 'Funds have already been released',
+  7: 'Remittance has been cancelled',
+  8: 'Target amount has not been reached yet',
+  9: 'Purpose must be between 1 and 256 characters',
+  10: 'Invalid recipient address',
+  11: 'Refund already claimed',
+  12: 'No contribution found for this account',
+  13: 'Refunds are only available for cancelled or expired remittances',
+  14: 'The contract is paused',
+  15: 'Platform fee exceeds the maximum allowed',
+  16: 'Fund transfer failed',
+  17: 'Amount is too large',
+  18: 'Contract storage error',
+  19: 'Invalid account address',
+  20: 'A required argument is missing',
+  21: 'Unknown fee rounding mode',
+  22: 'Creator fee share cannot exceed 100%',
+  23: 'No creator rewards to claim',
+  24: 'Batch size must be between 1 and the configured limit',
+  25: 'This named key holds contract state and cannot be removed',
+  26: 'The contract no longer holds the package access token',
+  27: 'Named key not found',
+  28: 'Purpose does not match its commitment',
+  29: 'This remittance has no purpose to reveal',
+  30: 'Amount does not match the contribution commitment',
+  31: 'You have already contributed to this confidential remittance',
+  32: 'Contribution amounts are private for this remittance',
+  33: 'Received amount does not match the contribution',
+  34: 'Unknown document type',
+  35: 'Document CID must be between 1 and 128 characters',
+  36: 'This remittance already has the maximum number of documents',
+  37: 'External reference must be 1 to 64 characters and cannot be used on private remittances',
+  38: 'You have already used this external reference',
+  39: 'Travel-rule data is required before release',
+  40: 'Travel-rule payload URI must be between 1 and 256 characters',
+  41: 'Corridor must be 1 to 16 letters, digits, or hyphens',
+  42: 'Accounting snapshot not found',
+  43: 'Release payout did not complete',
+  44: 'An argument has the wrong type',
+  45: 'The maximum platform fee can only be lowered',
+  46: 'The contract purse cannot cover this release',
+  47: 'There is no excess contribution to refund',
+  48: 'Remittance expired before reaching its target',
+  49: 'Remittance has not passed its deadline with its target unmet',
+  50: 'Deadline must be in the future',
+  51: 'Too many requests from this account; try again later',
+  52: 'Rate limit window must be greater than zero',
+  53: 'Release was blocked by the risk check',
+  54: 'Release is awaiting compliance review',
+  55: 'No release is awaiting review for this remittance',
+  56: 'The review window has passed',
+  57: 'Review window must be greater than zero',
+  58: 'Deadline cannot be extended that far',
+  59: 'Unknown fee model',
+  60: 'Remittance is locked for a bridge payout',
+  61: 'No bridge payout was requested',
+  62: 'Invalid bridge chain ID or address',
+  63: 'Releases under compliance review cannot be bridged',
+  64: 'Account is not a registered settlement partner',
+  65: 'Settlement partner does not serve this corridor',
+  66: 'Partner needs a name of 1 to 64 characters and 1 to 16 corridors',
+  67: 'Payout reference must be 1 to 64 characters',
+  68: 'This remittance has reached its contributor limit',
+  69: 'Contributor limit must be greater than zero',
+  70: 'Day range must be in order and span at most 366 days',
+  71: 'Handle must be 3 to 64 lowercase letters, digits, or inner hyphens',
+  72: 'This handle is already taken',
+  73: 'This remittance already has a handle',
+  74: 'Instant share must be 1 to 9999 bps, without confidential or excess refunds',
+  75: 'Backup recipient must differ from the recipient and have a grace period',
+  76: 'The backup recipient cannot release this remittance yet',
+  77: 'This release needs compliance review; the recipient must request it',
+  78: 'This remittance is not insured',
+  79: 'Invalid insurance premium or coverage',
+  80: 'Insurance claims have not been approved',
+  81: 'Insurance top-up already claimed',
+  82: 'The insurance pool is empty',
+  83: 'Update message must be between 1 and 280 characters',
+  84: 'This remittance already has the maximum number of updates',
+  85: 'This remittance has not been released yet',
+  86: 'Receipt has already been acknowledged',
+  87: 'Acknowledgement message must be between 1 and 280 characters',
+  88: 'Target amount exceeds the maximum allowed',
+  89: 'Target amount is below the minimum allowed',
+  90: 'This contribution was already made (duplicate idempotency key)',
+  91: 'Invalid refund policy or deduction rate',
+  92: 'Reason must be between 1 and 280 characters',
+  93: 'Payout target label must be between 1 and 32 characters',
+  94: 'Payout target not found in address book',
+  95: 'Payout address book is full',
+  96: 'Choose either a settlement partner or a payout target',
+  97: 'Branding field is missing or too long',
+  98: 'Namespace must be 1-32 lowercase letters, digits, or hyphens',
+  99: 'Namespace already exists',
+  100: 'Namespace not found',
+  101: 'The namespace is paused',
+  102: 'Start time must be in the future and before the deadline',
+  103: 'This remittance is not open for contributions yet',
+  104: 'Category must be 1-32 lowercase letters, digits, or hyphens',
+  105: 'Category not found',
+  106: 'Escalation intervals must be non-zero, increasing, and at most 8',
+  107: 'No unused fee cover to claim',
+  108: 'Recipient signers must be 2 to 8 distinct accounts including the recipient, with a threshold between 1 and their number',
+  109: 'Release needs more recipient approvals',
+  110: 'You have already approved this release',
+  111: 'Admin control has been renounced',
+  112: 'The remittance has more contributors than one call may process',
+  113: 'The contract is already installed and initialized',
+  114: 'Creator and recipient must be different accounts',
+  115: 'The fee collector cannot be a recipient',
+  116: 'The recipient cannot contribute to their own remittance',
+  117: 'Unknown recipient contribution policy',
+  118: 'This purpose has been blocked by compliance',
+  119: 'Language code must be 2 to 16 lowercase letters, digits, or hyphens',
+  120: 'This remittance already has the maximum number of localized purposes',
+  121: 'Invalid installment amount or interval',
+  122: 'The next installment is not due yet',
+  123: 'This remittance pays out in installments',
+  124: 'A savings pool needs 2 to 12 distinct members, a contribution, and a cycle length',
+  125: 'Only members of this savings pool can contribute',
+  126: 'This would exceed your contribution for this savings pool cycle',
+  127: 'Only open-ended pots can be withdrawn from before release',
+  128: 'This withdrawal needs compliance review; release the pot instead',
+  129: 'Private remittances cannot be paid out over a bridge',
+};
+
+/**
+ * Gets the message for a contract user error code
+ * @param code User error code
+ * @returns Human-readable message, or a generic one for unknown codes
+ */
+export function getErrorMessage(code: number): string {
+  return CONTRACT_ERROR_MESSAGES[code] ?? `Contract error ${code}`;
+}
+
+/**
+ * Describes a failed deploy's execution result
+ * @param failure The `Failure` execution result returned by the node
+ * @returns Human-readable message for contract user errors, otherwise the raw error message
+ */
+export function describeDeployFailure(failure: any): string {
+  const errorMessage: string = failure?.error_message ?? 'Deploy failed';
+  const match = /User error: (\d+)/.exec(errorMessage);
+
+  return match ? getErrorMessage(Number(match[1])) : errorMessage;
+}